@@ -134,7 +134,7 @@ if "%~1" == "debug" (
     echo Running pre-build compilation check...
    
     echo ✅ Pre-build check passed. Building for production...
-    call pnpm run tauri build
+    call pnpm run tauri:build:cpu
     if errorlevel 1 (
         echo ❌ Error: Failed to build Tauri application for production
         exit /b 1
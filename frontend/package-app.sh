@@ -15,6 +15,6 @@ echo "Building Next.js app..."
 pnpm build
 
 echo "Building Tauri app..."
-pnpm tauri build
+pnpm tauri:build:cpu
 
 echo "App packaging complete! Check src-tauri/target/release/bundle for the packaged app."
\ No newline at end of file
@@ -27,11 +27,16 @@ try {
 
 console.log(''); // Empty line for spacing
 
+// Release bundles always ship encryption at rest (too slow to compile for dev)
+const features = [feature, command === 'build' ? 'encryption' : ''].filter(Boolean).join(',');
+
 // Build the tauri command
 let tauriCmd = `tauri ${command}`;
+if (features) {
+  tauriCmd += ` -- --features ${features}`;
+}
 if (feature) {
-  tauriCmd += ` -- --features ${feature}`;
-  console.log(`🚀 Running: tauri ${command} with features: ${feature}`);
+  console.log(`🚀 Running: tauri ${command} with features: ${features}`);
 } else {
   console.log(`🚀 Running: tauri ${command} (CPU-only mode)`);
 }
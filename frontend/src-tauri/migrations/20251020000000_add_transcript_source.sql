@@ -0,0 +1,8 @@
+-- Migration: Add speaker source tag to transcripts
+-- In dual-channel transcription mode every segment is tagged with the audio source
+-- it was transcribed from:
+--   - "You":    microphone
+--   - "Remote": system audio
+--   - "Audio":  mixed stream (legacy single-channel mode)
+-- Existing rows keep NULL (source unknown).
+ALTER TABLE transcripts ADD COLUMN source TEXT;
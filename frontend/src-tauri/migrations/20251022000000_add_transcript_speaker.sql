@@ -0,0 +1,5 @@
+-- Migration: Add diarized speaker label to transcripts
+-- Offline speaker diarization clusters the system-audio segments of a recording
+-- into "Speaker 1", "Speaker 2", ... Labels can be renamed per meeting by the user.
+-- Rows that were never diarized (or microphone segments) keep NULL.
+ALTER TABLE transcripts ADD COLUMN speaker TEXT;
@@ -0,0 +1,18 @@
+-- Migration: Add word-level timings for transcript segments
+-- Each row is one word of a transcript segment with recording-relative times
+-- (seconds from recording start), used for word-by-word playback highlighting
+-- and for seeking search hits to the exact word.
+CREATE TABLE IF NOT EXISTS transcript_words (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    transcript_id TEXT NOT NULL,
+    meeting_id TEXT NOT NULL,
+    word_index INTEGER NOT NULL,
+    word TEXT NOT NULL,
+    start_time REAL NOT NULL,
+    end_time REAL NOT NULL,
+    FOREIGN KEY (transcript_id) REFERENCES transcripts(id) ON DELETE CASCADE,
+    FOREIGN KEY (meeting_id) REFERENCES meetings(id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_transcript_words_transcript ON transcript_words(transcript_id, word_index);
+CREATE INDEX IF NOT EXISTS idx_transcript_words_meeting ON transcript_words(meeting_id);
@@ -0,0 +1,6 @@
+-- Migration: Add custom OpenAI-compatible summary provider settings
+-- Lets summaries run against self-hosted servers (vLLM, LM Studio, llama.cpp server,
+-- LiteLLM gateway). customHeaders holds a JSON object of extra HTTP headers.
+ALTER TABLE settings ADD COLUMN customBaseUrl TEXT;
+ALTER TABLE settings ADD COLUMN customApiKey TEXT;
+ALTER TABLE settings ADD COLUMN customHeaders TEXT;
@@ -0,0 +1,12 @@
+-- Migration: Cache per-chunk summaries of multi-level summary runs
+-- A failed or cancelled run resumes from the cached chunks instead of repeating every
+-- LLM call. chunk_fingerprint identifies model + chunk text so stale entries are ignored.
+CREATE TABLE IF NOT EXISTS summary_chunk_cache (
+    meeting_id TEXT NOT NULL,
+    chunk_index INTEGER NOT NULL,
+    chunk_fingerprint TEXT NOT NULL,
+    summary TEXT NOT NULL,
+    created_at TEXT NOT NULL,
+    PRIMARY KEY (meeting_id, chunk_index),
+    FOREIGN KEY (meeting_id) REFERENCES meetings(id) ON DELETE CASCADE
+);
@@ -0,0 +1,37 @@
+-- Migration: Keep a history of summaries instead of overwriting summary_processes.result
+-- Every generation, user edit and restore appends a row; summary_processes.result keeps
+-- holding the current version. source is 'generated', 'edited' or 'restored'.
+CREATE TABLE IF NOT EXISTS summary_versions (
+    id TEXT PRIMARY KEY,
+    meeting_id TEXT NOT NULL,
+    version_number INTEGER NOT NULL,
+    source TEXT NOT NULL,
+    template_id TEXT,
+    provider TEXT,
+    model TEXT,
+    custom_prompt TEXT,
+    restored_from TEXT,
+    result TEXT NOT NULL,
+    created_at TEXT NOT NULL,
+    updated_at TEXT NOT NULL,
+    FOREIGN KEY (meeting_id) REFERENCES meetings(id) ON DELETE CASCADE,
+    UNIQUE (meeting_id, version_number)
+);
+
+CREATE INDEX IF NOT EXISTS idx_summary_versions_meeting ON summary_versions(meeting_id);
+
+-- Existing summaries become version 1 of their meeting
+INSERT INTO summary_versions (id, meeting_id, version_number, source, provider, model, result, created_at, updated_at)
+SELECT
+    lower(hex(randomblob(16))),
+    sp.meeting_id,
+    1,
+    'generated',
+    tc.model,
+    tc.model_name,
+    sp.result,
+    COALESCE(sp.end_time, sp.updated_at),
+    sp.updated_at
+FROM summary_processes sp
+LEFT JOIN transcript_chunks tc ON tc.meeting_id = sp.meeting_id
+WHERE sp.result IS NOT NULL AND sp.status = 'completed';
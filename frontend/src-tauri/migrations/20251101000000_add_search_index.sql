@@ -0,0 +1,81 @@
+-- Migration: Full-text search index over transcripts, summaries and meeting titles
+-- search_index is an FTS5 table kept in sync by triggers. kind is 'transcript', 'summary'
+-- or 'title'; ref_id is the transcript id for transcript rows and the meeting id otherwise.
+CREATE VIRTUAL TABLE IF NOT EXISTS search_index USING fts5(
+    content,
+    meeting_id UNINDEXED,
+    kind UNINDEXED,
+    ref_id UNINDEXED,
+    tokenize = 'unicode61 remove_diacritics 2'
+);
+
+-- Transcripts
+CREATE TRIGGER IF NOT EXISTS search_index_transcripts_ai AFTER INSERT ON transcripts BEGIN
+    INSERT INTO search_index (content, meeting_id, kind, ref_id)
+    VALUES (new.transcript, new.meeting_id, 'transcript', new.id);
+END;
+
+CREATE TRIGGER IF NOT EXISTS search_index_transcripts_ad AFTER DELETE ON transcripts BEGIN
+    DELETE FROM search_index WHERE kind = 'transcript' AND ref_id = old.id;
+END;
+
+CREATE TRIGGER IF NOT EXISTS search_index_transcripts_au AFTER UPDATE OF transcript, meeting_id ON transcripts BEGIN
+    DELETE FROM search_index WHERE kind = 'transcript' AND ref_id = old.id;
+    INSERT INTO search_index (content, meeting_id, kind, ref_id)
+    VALUES (new.transcript, new.meeting_id, 'transcript', new.id);
+END;
+
+-- Meeting titles
+CREATE TRIGGER IF NOT EXISTS search_index_meetings_ai AFTER INSERT ON meetings BEGIN
+    INSERT INTO search_index (content, meeting_id, kind, ref_id)
+    VALUES (new.title, new.id, 'title', new.id);
+END;
+
+CREATE TRIGGER IF NOT EXISTS search_index_meetings_au AFTER UPDATE OF title ON meetings BEGIN
+    DELETE FROM search_index WHERE kind = 'title' AND meeting_id = old.id;
+    INSERT INTO search_index (content, meeting_id, kind, ref_id)
+    VALUES (new.title, new.id, 'title', new.id);
+END;
+
+CREATE TRIGGER IF NOT EXISTS search_index_meetings_ad AFTER DELETE ON meetings BEGIN
+    DELETE FROM search_index WHERE meeting_id = old.id;
+END;
+
+-- Summaries (markdown of the current result; legacy results are indexed as raw JSON)
+CREATE TRIGGER IF NOT EXISTS search_index_summaries_ai AFTER INSERT ON summary_processes
+WHEN new.result IS NOT NULL BEGIN
+    INSERT INTO search_index (content, meeting_id, kind, ref_id)
+    VALUES (
+        CASE WHEN json_valid(new.result) THEN COALESCE(json_extract(new.result, '$.markdown'), new.result) ELSE new.result END,
+        new.meeting_id, 'summary', new.meeting_id
+    );
+END;
+
+CREATE TRIGGER IF NOT EXISTS search_index_summaries_au AFTER UPDATE OF result ON summary_processes BEGIN
+    DELETE FROM search_index WHERE kind = 'summary' AND meeting_id = old.meeting_id;
+    INSERT INTO search_index (content, meeting_id, kind, ref_id)
+    SELECT
+        CASE WHEN json_valid(new.result) THEN COALESCE(json_extract(new.result, '$.markdown'), new.result) ELSE new.result END,
+        new.meeting_id, 'summary', new.meeting_id
+    WHERE new.result IS NOT NULL;
+END;
+
+CREATE TRIGGER IF NOT EXISTS search_index_summaries_ad AFTER DELETE ON summary_processes BEGIN
+    DELETE FROM search_index WHERE kind = 'summary' AND meeting_id = old.meeting_id;
+END;
+
+-- Backfill existing data
+DELETE FROM search_index;
+
+INSERT INTO search_index (content, meeting_id, kind, ref_id)
+SELECT transcript, meeting_id, 'transcript', id FROM transcripts;
+
+INSERT INTO search_index (content, meeting_id, kind, ref_id)
+SELECT title, id, 'title', id FROM meetings;
+
+INSERT INTO search_index (content, meeting_id, kind, ref_id)
+SELECT
+    CASE WHEN json_valid(result) THEN COALESCE(json_extract(result, '$.markdown'), result) ELSE result END,
+    meeting_id, 'summary', meeting_id
+FROM summary_processes
+WHERE result IS NOT NULL;
@@ -0,0 +1,38 @@
+-- Migration: Embedding index for semantic search and meeting Q&A
+-- One row per embedded piece of text: a transcript segment (kind 'transcript', ref_id is the
+-- transcript id) or a chunk of a summary (kind 'summary', ref_id is the meeting id).
+-- vector holds little-endian f32 values, L2-normalized. Triggers drop rows whose source
+-- text changed so the next indexing run re-embeds them.
+CREATE TABLE IF NOT EXISTS embeddings (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    meeting_id TEXT NOT NULL,
+    kind TEXT NOT NULL,
+    ref_id TEXT NOT NULL,
+    chunk_index INTEGER NOT NULL DEFAULT 0,
+    content TEXT NOT NULL,
+    model TEXT NOT NULL,
+    dimensions INTEGER NOT NULL,
+    vector BLOB NOT NULL,
+    created_at TEXT NOT NULL,
+    FOREIGN KEY (meeting_id) REFERENCES meetings(id) ON DELETE CASCADE,
+    UNIQUE (model, kind, ref_id, chunk_index)
+);
+
+CREATE INDEX IF NOT EXISTS idx_embeddings_model ON embeddings(model);
+CREATE INDEX IF NOT EXISTS idx_embeddings_ref ON embeddings(kind, ref_id);
+
+CREATE TRIGGER IF NOT EXISTS embeddings_transcripts_au AFTER UPDATE OF transcript ON transcripts BEGIN
+    DELETE FROM embeddings WHERE kind = 'transcript' AND ref_id = old.id;
+END;
+
+CREATE TRIGGER IF NOT EXISTS embeddings_transcripts_ad AFTER DELETE ON transcripts BEGIN
+    DELETE FROM embeddings WHERE kind = 'transcript' AND ref_id = old.id;
+END;
+
+CREATE TRIGGER IF NOT EXISTS embeddings_summaries_au AFTER UPDATE OF result ON summary_processes BEGIN
+    DELETE FROM embeddings WHERE kind = 'summary' AND ref_id = old.meeting_id;
+END;
+
+CREATE TRIGGER IF NOT EXISTS embeddings_summaries_ad AFTER DELETE ON summary_processes BEGIN
+    DELETE FROM embeddings WHERE kind = 'summary' AND ref_id = old.meeting_id;
+END;
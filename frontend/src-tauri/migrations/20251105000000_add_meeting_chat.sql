@@ -0,0 +1,15 @@
+-- Migration: Conversation turns of the per-meeting chat
+-- role is 'user' or 'assistant'; provider/model record which LLM produced an answer.
+CREATE TABLE IF NOT EXISTS meeting_chat_messages (
+    id TEXT PRIMARY KEY,
+    meeting_id TEXT NOT NULL,
+    role TEXT NOT NULL,
+    content TEXT NOT NULL,
+    provider TEXT,
+    model TEXT,
+    created_at TEXT NOT NULL,
+    FOREIGN KEY (meeting_id) REFERENCES meetings(id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_meeting_chat_messages_meeting
+    ON meeting_chat_messages(meeting_id, created_at);
@@ -0,0 +1,21 @@
+-- Migration: Action items extracted from meeting transcripts
+-- status is 'open' or 'done'. transcript_id links an item to the segment it was taken
+-- from (NULL when the model gave no usable source); source_timestamp keeps the
+-- timestamp the model cited. due_date is an ISO date (YYYY-MM-DD).
+CREATE TABLE IF NOT EXISTS action_items (
+    id TEXT PRIMARY KEY,
+    meeting_id TEXT NOT NULL,
+    task TEXT NOT NULL,
+    owner TEXT,
+    due_date TEXT,
+    source_timestamp TEXT,
+    transcript_id TEXT,
+    status TEXT NOT NULL DEFAULT 'open' CHECK (status IN ('open', 'done')),
+    created_at TEXT NOT NULL,
+    updated_at TEXT NOT NULL,
+    completed_at TEXT,
+    FOREIGN KEY (meeting_id) REFERENCES meetings(id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_action_items_meeting ON action_items(meeting_id);
+CREATE INDEX IF NOT EXISTS idx_action_items_status ON action_items(status, due_date);
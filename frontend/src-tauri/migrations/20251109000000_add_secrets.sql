@@ -0,0 +1,71 @@
+-- Migration: Move API keys out of the plain settings columns into a secrets table
+-- Values are encrypted with the per-install key (see src/secrets). SQL can't encrypt, so
+-- keys copied here are marked encrypted = 0 and encrypted at the next startup, right
+-- after migrations run. The old columns are kept (for older app versions) but cleared.
+CREATE TABLE IF NOT EXISTS secrets (
+    name TEXT PRIMARY KEY,
+    value TEXT NOT NULL,
+    encrypted INTEGER NOT NULL DEFAULT 1,
+    updated_at TEXT NOT NULL
+);
+
+-- Summary (LLM) provider keys
+INSERT OR IGNORE INTO secrets (name, value, encrypted, updated_at)
+SELECT 'summary.openai', openaiApiKey, 0, datetime('now') FROM settings
+WHERE id = '1' AND openaiApiKey IS NOT NULL AND openaiApiKey != '';
+
+INSERT OR IGNORE INTO secrets (name, value, encrypted, updated_at)
+SELECT 'summary.claude', anthropicApiKey, 0, datetime('now') FROM settings
+WHERE id = '1' AND anthropicApiKey IS NOT NULL AND anthropicApiKey != '';
+
+INSERT OR IGNORE INTO secrets (name, value, encrypted, updated_at)
+SELECT 'summary.ollama', ollamaApiKey, 0, datetime('now') FROM settings
+WHERE id = '1' AND ollamaApiKey IS NOT NULL AND ollamaApiKey != '';
+
+INSERT OR IGNORE INTO secrets (name, value, encrypted, updated_at)
+SELECT 'summary.groq', groqApiKey, 0, datetime('now') FROM settings
+WHERE id = '1' AND groqApiKey IS NOT NULL AND groqApiKey != '';
+
+INSERT OR IGNORE INTO secrets (name, value, encrypted, updated_at)
+SELECT 'summary.openrouter', openRouterApiKey, 0, datetime('now') FROM settings
+WHERE id = '1' AND openRouterApiKey IS NOT NULL AND openRouterApiKey != '';
+
+INSERT OR IGNORE INTO secrets (name, value, encrypted, updated_at)
+SELECT 'summary.custom', customApiKey, 0, datetime('now') FROM settings
+WHERE id = '1' AND customApiKey IS NOT NULL AND customApiKey != '';
+
+UPDATE settings SET
+    openaiApiKey = NULL,
+    anthropicApiKey = NULL,
+    ollamaApiKey = NULL,
+    groqApiKey = NULL,
+    openRouterApiKey = NULL,
+    customApiKey = NULL;
+
+-- Transcription provider keys
+INSERT OR IGNORE INTO secrets (name, value, encrypted, updated_at)
+SELECT 'transcript.localWhisper', whisperApiKey, 0, datetime('now') FROM transcript_settings
+WHERE id = '1' AND whisperApiKey IS NOT NULL AND whisperApiKey != '';
+
+INSERT OR IGNORE INTO secrets (name, value, encrypted, updated_at)
+SELECT 'transcript.deepgram', deepgramApiKey, 0, datetime('now') FROM transcript_settings
+WHERE id = '1' AND deepgramApiKey IS NOT NULL AND deepgramApiKey != '';
+
+INSERT OR IGNORE INTO secrets (name, value, encrypted, updated_at)
+SELECT 'transcript.elevenLabs', elevenLabsApiKey, 0, datetime('now') FROM transcript_settings
+WHERE id = '1' AND elevenLabsApiKey IS NOT NULL AND elevenLabsApiKey != '';
+
+INSERT OR IGNORE INTO secrets (name, value, encrypted, updated_at)
+SELECT 'transcript.groq', groqApiKey, 0, datetime('now') FROM transcript_settings
+WHERE id = '1' AND groqApiKey IS NOT NULL AND groqApiKey != '';
+
+INSERT OR IGNORE INTO secrets (name, value, encrypted, updated_at)
+SELECT 'transcript.openai', openaiApiKey, 0, datetime('now') FROM transcript_settings
+WHERE id = '1' AND openaiApiKey IS NOT NULL AND openaiApiKey != '';
+
+UPDATE transcript_settings SET
+    whisperApiKey = NULL,
+    deepgramApiKey = NULL,
+    elevenLabsApiKey = NULL,
+    groqApiKey = NULL,
+    openaiApiKey = NULL;
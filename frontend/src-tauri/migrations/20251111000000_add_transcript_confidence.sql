@@ -0,0 +1,8 @@
+-- Migration: Store decoder confidence for transcript segments and words
+-- Segment confidence is the geometric mean of the token probabilities, word probability the
+-- mean of the word's tokens. NULL for older transcripts and providers without scores.
+ALTER TABLE transcripts ADD COLUMN confidence REAL;
+ALTER TABLE transcript_words ADD COLUMN probability REAL;
+
+-- Low-confidence review scans one meeting's segments in time order
+CREATE INDEX IF NOT EXISTS idx_transcripts_meeting_audio_start ON transcripts(meeting_id, audio_start_time);
@@ -0,0 +1,13 @@
+-- Migration: Custom vocabulary (product names, acronyms, people) used to bias transcription
+-- meeting_id NULL is the user's own list, applied to every recording; a meeting list is
+-- added on top when that meeting is re-transcribed. Terms are unique per list, ignoring case.
+CREATE TABLE IF NOT EXISTS vocabulary_terms (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    meeting_id TEXT,
+    term TEXT NOT NULL,
+    created_at TEXT NOT NULL,
+    FOREIGN KEY (meeting_id) REFERENCES meetings(id) ON DELETE CASCADE
+);
+
+CREATE UNIQUE INDEX IF NOT EXISTS idx_vocabulary_terms_list_term
+    ON vocabulary_terms(COALESCE(meeting_id, ''), term COLLATE NOCASE);
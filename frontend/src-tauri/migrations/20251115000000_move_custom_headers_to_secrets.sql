@@ -0,0 +1,8 @@
+-- Migration: Move the custom provider's extra headers into the secrets table
+-- Gateway headers often carry credentials. Like the API keys (see add_secrets), they are
+-- copied as encrypted = 0 and encrypted at the next startup; the old column is cleared.
+INSERT OR IGNORE INTO secrets (name, value, encrypted, updated_at)
+SELECT 'summary.custom.headers', customHeaders, 0, datetime('now') FROM settings
+WHERE id = '1' AND customHeaders IS NOT NULL AND customHeaders != '';
+
+UPDATE settings SET customHeaders = NULL;
@@ -0,0 +1,158 @@
+use crate::action_items::extraction::extract_action_items;
+use crate::database::models::{ActionItem, ActionItemWithMeeting, Transcript};
+use crate::database::repositories::{
+    action_item::ActionItemsRepository, meeting::MeetingsRepository,
+    setting::SettingsRepository, transcript::TranscriptsRepository,
+};
+use crate::state::AppState;
+use crate::summary::llm_client::LLMProvider;
+use crate::summary::service::{ResolvedProvider, SummaryService};
+use log::{error as log_error, info as log_info};
+use reqwest::Client;
+use sqlx::SqlitePool;
+use tauri::{AppHandle, Runtime};
+
+/// Extracts the action items of a meeting and stores them, replacing earlier open items
+pub async fn extract_for_meeting(
+    pool: &SqlitePool,
+    meeting_id: &str,
+    provider: &LLMProvider,
+    model_name: &str,
+    api_key: &str,
+    ollama_endpoint: Option<&str>,
+) -> Result<Vec<ActionItem>, String> {
+    let transcripts = TranscriptsRepository::get_transcripts_for_meeting(pool, meeting_id)
+        .await
+        .map_err(|e| e.to_string())?;
+    if transcripts.is_empty() {
+        return Err("This meeting has no transcript".to_string());
+    }
+
+    // Relative due dates ("tomorrow") are resolved against the meeting date
+    let meeting_date = MeetingsRepository::get_meeting(pool, meeting_id)
+        .await
+        .map_err(|e| e.to_string())?
+        .and_then(|m| chrono::DateTime::parse_from_rfc3339(&m.created_at).ok())
+        .map(|d| d.date_naive())
+        .unwrap_or_else(|| chrono::Utc::now().date_naive());
+
+    let context_tokens =
+        SummaryService::context_token_limit(provider, model_name, api_key, ollama_endpoint).await;
+    let items = extract_action_items(
+        &Client::new(),
+        provider,
+        model_name,
+        api_key,
+        ollama_endpoint,
+        context_tokens,
+        &meeting_date.format("%Y-%m-%d (%A)").to_string(),
+        &transcripts,
+    )
+    .await?;
+
+    ActionItemsRepository::replace_open_items(pool, meeting_id, &items)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Runs action item extraction for a meeting with the given or the configured summary model
+#[tauri::command]
+pub async fn api_extract_action_items<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    meeting_id: String,
+    model_provider: Option<String>,
+    model_name: Option<String>,
+) -> Result<Vec<ActionItem>, String> {
+    log_info!("api_extract_action_items called for meeting_id: {}", meeting_id);
+    let pool = state.db_manager.pool();
+
+    let (model_provider, model_name) = match (model_provider, model_name) {
+        (Some(provider), Some(model)) => (provider, model),
+        (provider, model) => {
+            let config = SettingsRepository::get_model_config(pool)
+                .await
+                .map_err(|e| e.to_string())?
+                .ok_or_else(|| "No summary model configured".to_string())?;
+            (
+                provider.unwrap_or(config.provider),
+                model.unwrap_or(config.model),
+            )
+        }
+    };
+    let ResolvedProvider {
+        provider,
+        api_key,
+        ollama_endpoint,
+    } = SummaryService::resolve_provider(pool, &model_provider).await?;
+
+    extract_for_meeting(
+        pool,
+        &meeting_id,
+        &provider,
+        &model_name,
+        &api_key,
+        ollama_endpoint.as_deref(),
+    )
+    .await
+    .map_err(|e| {
+        log_error!("Action item extraction failed for {}: {}", meeting_id, e);
+        e
+    })
+}
+
+/// All action items of a meeting, open and done
+#[tauri::command]
+pub async fn api_list_action_items<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    meeting_id: String,
+) -> Result<Vec<ActionItem>, String> {
+    ActionItemsRepository::list_for_meeting(state.db_manager.pool(), &meeting_id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Open action items across all meetings
+#[tauri::command]
+pub async fn api_list_open_action_items<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+) -> Result<Vec<ActionItemWithMeeting>, String> {
+    ActionItemsRepository::list_open(state.db_manager.pool())
+        .await
+        .map_err(|e| {
+            log_error!("Failed to list open action items: {}", e);
+            e.to_string()
+        })
+}
+
+/// Marks an action item as "open" or "done"
+#[tauri::command]
+pub async fn api_update_action_item_status<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    action_item_id: String,
+    status: String,
+) -> Result<ActionItem, String> {
+    if status != "open" && status != "done" {
+        return Err(format!("Invalid action item status '{}', expected open or done", status));
+    }
+
+    ActionItemsRepository::update_status(state.db_manager.pool(), &action_item_id, &status)
+        .await
+        .map_err(|e| e.to_string())?
+        .ok_or_else(|| format!("Action item {} not found", action_item_id))
+}
+
+/// Transcript segment an action item was extracted from (None if it couldn't be linked)
+#[tauri::command]
+pub async fn api_get_action_item_source<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    action_item_id: String,
+) -> Result<Option<Transcript>, String> {
+    ActionItemsRepository::get_source_transcript(state.db_manager.pool(), &action_item_id)
+        .await
+        .map_err(|e| e.to_string())
+}
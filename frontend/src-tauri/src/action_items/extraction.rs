@@ -0,0 +1,332 @@
+// action_items/extraction.rs
+//
+// LLM extraction of action items as JSON. The transcript is sent as numbered segments so
+// every item can point back at the segment it came from; the reply is validated against
+// the expected shape before anything is stored.
+
+use crate::database::models::Transcript;
+use crate::database::repositories::action_item::NewActionItem;
+use crate::summary::llm_client::{generate_summary, LLMProvider};
+use crate::summary::processor::{clean_llm_markdown_output, rough_token_count};
+use chrono::NaiveDate;
+use reqwest::Client;
+use serde_json::Value;
+use tracing::{info, warn};
+
+/// Tokens kept free for the model's JSON answer
+const ANSWER_RESERVE_TOKENS: usize = 1500;
+const MAX_TASK_CHARS: usize = 500;
+
+const SYSTEM_PROMPT: &str = r#"You extract action items from meeting transcripts.
+Each transcript line starts with [#<segment> <timestamp>].
+Reply with JSON only, no prose and no code fences, in exactly this shape:
+{"action_items": [{"task": string, "owner": string | null, "due_date": "YYYY-MM-DD" | null, "source_timestamp": string | null, "source_segment": integer | null}]}
+- task: the concrete commitment or follow-up, phrased as an instruction
+- owner: the person responsible, as named in the transcript, or null
+- due_date: resolve relative dates ("next Friday") against the meeting date; null if none was given
+- source_timestamp / source_segment: timestamp and segment number of the line where the item was agreed
+Only include items someone actually committed to or was asked to do. Reply {"action_items": []} if there are none."#;
+
+/// One validated item of the model's reply
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExtractedActionItem {
+    pub task: String,
+    pub owner: Option<String>,
+    pub due_date: Option<String>,
+    pub source_timestamp: Option<String>,
+    /// Index into the transcript segments of the meeting
+    pub source_segment: Option<usize>,
+}
+
+/// Transcript segments as `[#<index> <timestamp>] Speaker: text` lines, indexed like `transcripts`
+pub fn format_segments(transcripts: &[Transcript]) -> Vec<String> {
+    transcripts
+        .iter()
+        .enumerate()
+        .map(|(i, t)| match t.speaker.as_deref().or(t.source.as_deref()) {
+            Some(speaker) => format!("[#{} {}] {}: {}", i, t.timestamp, speaker, t.transcript.trim()),
+            None => format!("[#{} {}] {}", i, t.timestamp, t.transcript.trim()),
+        })
+        .collect()
+}
+
+/// Groups whole lines into batches of at most `budget_tokens` (a longer line gets its own batch)
+fn batch_lines(lines: &[String], budget_tokens: usize) -> Vec<String> {
+    let mut batches = Vec::new();
+    let mut current = String::new();
+    for line in lines {
+        if !current.is_empty()
+            && rough_token_count(&current) + rough_token_count(line) > budget_tokens
+        {
+            batches.push(std::mem::take(&mut current));
+        }
+        if !current.is_empty() {
+            current.push('\n');
+        }
+        current.push_str(line);
+    }
+    if !current.is_empty() {
+        batches.push(current);
+    }
+    batches
+}
+
+/// The JSON value inside a model reply that may carry thinking tags, code fences or prose
+fn extract_json(raw: &str) -> Option<Value> {
+    let cleaned = clean_llm_markdown_output(raw);
+    let cleaned = cleaned
+        .strip_prefix("```json")
+        .and_then(|s| s.strip_suffix("```"))
+        .unwrap_or(&cleaned)
+        .trim();
+    if let Ok(value) = serde_json::from_str(cleaned) {
+        return Some(value);
+    }
+    let start = cleaned.find(['{', '['])?;
+    let end = cleaned.rfind(['}', ']'])?;
+    (start < end)
+        .then(|| serde_json::from_str(&cleaned[start..=end]).ok())
+        .flatten()
+}
+
+fn optional_string(item: &Value, field: &str) -> Result<Option<String>, String> {
+    match item.get(field) {
+        None | Some(Value::Null) => Ok(None),
+        Some(Value::String(s)) if s.trim().is_empty() => Ok(None),
+        Some(Value::String(s)) => Ok(Some(s.trim().to_string())),
+        Some(other) => Err(format!("'{}' must be a string or null, got {}", field, other)),
+    }
+}
+
+fn validate_item(item: &Value, segment_count: usize) -> Result<ExtractedActionItem, String> {
+    if !item.is_object() {
+        return Err("item is not an object".to_string());
+    }
+
+    let task = match item.get("task") {
+        Some(Value::String(task)) if !task.trim().is_empty() => task.trim(),
+        _ => return Err("'task' must be a non-empty string".to_string()),
+    };
+    if task.chars().count() > MAX_TASK_CHARS {
+        return Err(format!("'task' is longer than {} characters", MAX_TASK_CHARS));
+    }
+
+    // A date that isn't a real YYYY-MM-DD date is dropped rather than failing the item
+    let due_date = optional_string(item, "due_date")?.filter(|date| {
+        let valid = NaiveDate::parse_from_str(date, "%Y-%m-%d").is_ok();
+        if !valid {
+            warn!("Ignoring invalid due date '{}' for action item '{}'", date, task);
+        }
+        valid
+    });
+
+    let source_segment = match item.get("source_segment") {
+        None | Some(Value::Null) => None,
+        Some(Value::Number(n)) => n
+            .as_u64()
+            .map(|n| n as usize)
+            .filter(|&n| n < segment_count),
+        Some(other) => return Err(format!("'source_segment' must be an integer or null, got {}", other)),
+    };
+
+    Ok(ExtractedActionItem {
+        task: task.to_string(),
+        owner: optional_string(item, "owner")?,
+        due_date,
+        source_timestamp: optional_string(item, "source_timestamp")?,
+        source_segment,
+    })
+}
+
+/// Parses and validates a model reply. Fails when the reply isn't JSON of the expected
+/// shape; individual invalid items are skipped.
+pub fn parse_action_items(raw: &str, segment_count: usize) -> Result<Vec<ExtractedActionItem>, String> {
+    let value = extract_json(raw).ok_or_else(|| "Model reply is not valid JSON".to_string())?;
+    let items = match &value {
+        Value::Array(items) => items,
+        Value::Object(object) => match object.get("action_items") {
+            Some(Value::Array(items)) => items,
+            _ => return Err("Model reply has no 'action_items' array".to_string()),
+        },
+        _ => return Err("Model reply is neither an object nor an array".to_string()),
+    };
+
+    Ok(items
+        .iter()
+        .filter_map(|item| match validate_item(item, segment_count) {
+            Ok(item) => Some(item),
+            Err(e) => {
+                warn!("Skipping invalid action item {}: {}", item, e);
+                None
+            }
+        })
+        .collect())
+}
+
+/// Links items to their transcript segment (by segment number, falling back to an exact
+/// timestamp match) and drops duplicates
+pub fn link_to_transcripts(
+    items: Vec<ExtractedActionItem>,
+    transcripts: &[Transcript],
+) -> Vec<NewActionItem> {
+    let mut linked: Vec<NewActionItem> = Vec::with_capacity(items.len());
+    for item in items {
+        let segment = item
+            .source_segment
+            .and_then(|i| transcripts.get(i))
+            .or_else(|| {
+                let timestamp = item.source_timestamp.as_deref()?;
+                transcripts.iter().find(|t| t.timestamp == timestamp)
+            });
+        let task_key = item.task.to_lowercase();
+        if linked.iter().any(|l| l.task.to_lowercase() == task_key) {
+            continue;
+        }
+        linked.push(NewActionItem {
+            task: item.task,
+            owner: item.owner,
+            due_date: item.due_date,
+            source_timestamp: item
+                .source_timestamp
+                .or_else(|| segment.map(|t| t.timestamp.clone())),
+            transcript_id: segment.map(|t| t.id.clone()),
+        });
+    }
+    linked
+}
+
+/// Runs the extraction over a meeting transcript, in several requests when it doesn't fit
+/// `context_tokens`
+#[allow(clippy::too_many_arguments)]
+pub async fn extract_action_items(
+    client: &Client,
+    provider: &LLMProvider,
+    model_name: &str,
+    api_key: &str,
+    ollama_endpoint: Option<&str>,
+    context_tokens: usize,
+    meeting_date: &str,
+    transcripts: &[Transcript],
+) -> Result<Vec<NewActionItem>, String> {
+    let budget = context_tokens
+        .saturating_sub(ANSWER_RESERVE_TOKENS)
+        .saturating_sub(rough_token_count(SYSTEM_PROMPT))
+        .max(500);
+    let batches = batch_lines(&format_segments(transcripts), budget);
+    info!(
+        "📋 Extracting action items from {} segments in {} request(s)",
+        transcripts.len(),
+        batches.len()
+    );
+
+    let mut extracted = Vec::new();
+    for batch in batches {
+        let user_prompt = format!("Meeting date: {}\n\nTranscript:\n{}", meeting_date, batch);
+        let reply = generate_summary(
+            client,
+            provider,
+            model_name,
+            api_key,
+            SYSTEM_PROMPT,
+            &user_prompt,
+            ollama_endpoint,
+        )
+        .await?;
+        extracted.extend(parse_action_items(&reply, transcripts.len())?);
+    }
+
+    Ok(link_to_transcripts(extracted, transcripts))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn segment(id: &str, timestamp: &str, text: &str) -> Transcript {
+        Transcript {
+            id: id.to_string(),
+            meeting_id: "m1".to_string(),
+            transcript: text.to_string(),
+            timestamp: timestamp.to_string(),
+            summary: None,
+            action_items: None,
+            key_points: None,
+            audio_start_time: None,
+            audio_end_time: None,
+            duration: None,
+            source: None,
+            speaker: Some("Alice".to_string()),
+            confidence: None,
+        }
+    }
+
+    #[test]
+    fn test_parse_validates_items() {
+        let reply = r#"```json
+{"action_items": [
+  {"task": "Send the pricing deck", "owner": "Alice", "due_date": "2025-11-07", "source_timestamp": "10:02:11", "source_segment": 1},
+  {"task": "Book the venue", "owner": null, "due_date": "next Friday", "source_segment": 99},
+  {"task": "", "owner": "Bob"},
+  {"task": "Review the contract", "owner": 42}
+]}
+```"#;
+
+        let items = parse_action_items(reply, 2).unwrap();
+        assert_eq!(items.len(), 2);
+        assert_eq!(items[0].owner.as_deref(), Some("Alice"));
+        assert_eq!(items[0].due_date.as_deref(), Some("2025-11-07"));
+        assert_eq!(items[0].source_segment, Some(1));
+        // Unparseable date and out-of-range segment are dropped, the item is kept
+        assert_eq!(items[1].due_date, None);
+        assert_eq!(items[1].source_segment, None);
+    }
+
+    #[test]
+    fn test_parse_rejects_wrong_shape() {
+        assert!(parse_action_items("There are no action items.", 1).is_err());
+        assert!(parse_action_items(r#"{"items": []}"#, 1).is_err());
+        assert_eq!(
+            parse_action_items(r#"Sure! [{"task": "Ship it"}]"#, 1).unwrap().len(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_link_to_transcripts() {
+        let transcripts = vec![
+            segment("t0", "10:00:00", "Welcome"),
+            segment("t1", "10:02:11", "I'll send the deck"),
+        ];
+        let item = |task: &str, timestamp: Option<&str>, segment: Option<usize>| ExtractedActionItem {
+            task: task.to_string(),
+            owner: None,
+            due_date: None,
+            source_timestamp: timestamp.map(str::to_string),
+            source_segment: segment,
+        };
+
+        let linked = link_to_transcripts(
+            vec![
+                item("Send the deck", None, Some(1)),
+                item("send the deck", None, None),
+                item("Plan the offsite", Some("10:00:00"), None),
+                item("Hire a designer", Some("11:00:00"), None),
+            ],
+            &transcripts,
+        );
+
+        assert_eq!(linked.len(), 3);
+        assert_eq!(linked[0].transcript_id.as_deref(), Some("t1"));
+        assert_eq!(linked[0].source_timestamp.as_deref(), Some("10:02:11"));
+        assert_eq!(linked[1].transcript_id.as_deref(), Some("t0"));
+        assert_eq!(linked[2].transcript_id, None);
+    }
+
+    #[test]
+    fn test_batch_lines_keeps_lines_whole() {
+        let lines: Vec<String> = (0..10).map(|i| format!("[#{} 10:00] {}", i, "word ".repeat(20))).collect();
+        let batches = batch_lines(&lines, 60);
+        assert!(batches.len() > 1);
+        assert_eq!(batches.join("\n"), lines.join("\n"));
+    }
+}
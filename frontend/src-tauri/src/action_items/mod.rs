@@ -0,0 +1,15 @@
+/// Action items module - structured action items extracted from meeting transcripts
+///
+/// This module contains:
+/// - LLM extraction to JSON with validation and linking to transcript segments
+/// - Tauri commands to extract, list and complete action items
+
+pub mod commands;
+pub mod extraction;
+
+pub use commands::{
+    __cmd__api_extract_action_items, __cmd__api_get_action_item_source,
+    __cmd__api_list_action_items, __cmd__api_list_open_action_items,
+    __cmd__api_update_action_item_status, api_extract_action_items, api_get_action_item_source,
+    api_list_action_items, api_list_open_action_items, api_update_action_item_status,
+};
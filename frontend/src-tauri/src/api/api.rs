@@ -5,11 +5,15 @@ use tauri::{AppHandle, Runtime};
 use tauri_plugin_store::StoreExt;
 
 use crate::{
+    audio::transcription::{
+        confidence::{self, LowConfidenceRegion},
+        WordTiming,
+    },
     database::{
         models::MeetingModel,
         repositories::{
             meeting::MeetingsRepository, setting::SettingsRepository,
-            transcript::TranscriptsRepository,
+            transcript::{SearchFilters, TranscriptsRepository},
         },
     },
     state::AppState,
@@ -42,7 +46,22 @@ pub struct TranscriptSearchResult {
     pub title: String,
     #[serde(rename = "matchContext")]
     pub match_context: String,
+    // Same snippet with matched terms wrapped in <mark>...</mark>
+    #[serde(rename = "highlightedContext")]
+    pub highlighted_context: String,
     pub timestamp: String,
+    // Recording-relative start of the matched word, for seeking playback
+    #[serde(rename = "wordStartTime", skip_serializing_if = "Option::is_none")]
+    pub word_start_time: Option<f64>,
+    // Where the hit is: "transcript", "summary" or "title"
+    pub kind: String,
+    #[serde(rename = "transcriptId", skip_serializing_if = "Option::is_none")]
+    pub transcript_id: Option<String>,
+    // Recording-relative start of the matched segment
+    #[serde(rename = "audioStartTime", skip_serializing_if = "Option::is_none")]
+    pub audio_start_time: Option<f64>,
+    // BM25 score, lower is better
+    pub rank: f64,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -136,6 +155,18 @@ pub struct MeetingTranscript {
     pub audio_end_time: Option<f64>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub duration: Option<f64>,
+    // Speaker source tag ("You" / "Remote" in dual-channel mode)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source: Option<String>,
+    // Diarized speaker label ("Speaker 1", ... or a user-assigned name)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub speaker: Option<String>,
+    // Decoder confidence (0-1), absent for providers without scores
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confidence: Option<f32>,
+    // Word-level timings (recording-relative seconds)
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub words: Vec<WordTiming>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -168,6 +199,18 @@ pub struct TranscriptSegment {
     pub audio_end_time: Option<f64>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub duration: Option<f64>,
+    // Speaker source tag ("You" / "Remote" in dual-channel mode)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source: Option<String>,
+    // Diarized speaker label ("Speaker 1", ... or a user-assigned name)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub speaker: Option<String>,
+    // Decoder confidence (0-1), absent for providers without scores
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub confidence: Option<f32>,
+    // Word-level timings (recording-relative seconds) for word-by-word playback highlighting
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub words: Vec<WordTiming>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -337,17 +380,30 @@ pub async fn api_search_transcripts<R: Runtime>(
     _app: AppHandle<R>,
     state: tauri::State<'_, AppState>,
     query: String,
+    meeting_id: Option<String>,
+    date_from: Option<String>,
+    date_to: Option<String>,
+    limit: Option<i64>,
     auth_token: Option<String>,
 ) -> Result<Vec<TranscriptSearchResult>, String> {
     log_info!(
-        "api_search_transcripts called with query: '{}', auth_token: {}",
+        "api_search_transcripts called with query: '{}', meeting: {:?}, range: {:?}..{:?}, auth_token: {}",
         query,
+        meeting_id,
+        date_from,
+        date_to,
         auth_token.is_some()
     );
 
     let pool = state.db_manager.pool();
+    let filters = SearchFilters {
+        meeting_id,
+        date_from,
+        date_to,
+        limit,
+    };
 
-    match TranscriptsRepository::search_transcripts(pool, &query).await {
+    match TranscriptsRepository::search_transcripts(pool, &query, &filters).await {
         Ok(results) => {
             log_info!(
                 "Search completed successfully with {} results.",
@@ -362,6 +418,35 @@ pub async fn api_search_transcripts<R: Runtime>(
     }
 }
 
+/// Stretches of a meeting whose transcription is probably wrong: segments below
+/// `segment_threshold` confidence or containing words below `word_threshold`
+#[tauri::command]
+pub async fn api_get_low_confidence_regions(
+    state: tauri::State<'_, AppState>,
+    meeting_id: String,
+    segment_threshold: Option<f32>,
+    word_threshold: Option<f32>,
+) -> Result<Vec<LowConfidenceRegion>, String> {
+    let segments = TranscriptsRepository::get_confidence_segments(state.db_manager.pool(), &meeting_id)
+        .await
+        .map_err(|e| {
+            log_error!("Error loading confidence scores for meeting {}: {}", meeting_id, e);
+            format!("Failed to load transcript confidence: {}", e)
+        })?;
+
+    let regions = confidence::find_low_confidence_regions(
+        &segments,
+        segment_threshold.unwrap_or(confidence::DEFAULT_SEGMENT_THRESHOLD),
+        word_threshold.unwrap_or(confidence::DEFAULT_WORD_THRESHOLD),
+    );
+    log_info!(
+        "Found {} low-confidence regions in meeting {}",
+        regions.len(),
+        meeting_id
+    );
+    Ok(regions)
+}
+
 #[tauri::command]
 pub async fn api_get_profile<R: Runtime>(
     app: AppHandle<R>,
@@ -461,7 +546,8 @@ pub async fn api_get_model_config<R: Runtime>(
                 &config.whisper_model,
                 &config.ollama_endpoint
             );
-            match SettingsRepository::get_api_key(pool, &config.provider).await {
+            // Only a masked key is sent to the webview
+            match SettingsRepository::get_masked_api_key(pool, &config.provider).await {
                 Ok(api_key) => {
                     log_info!("Successfully retrieved model config and API key.");
                     Ok(Some(ModelConfig {
@@ -542,6 +628,7 @@ pub async fn api_save_model_config<R: Runtime>(
     )
 }
 
+/// Masked API key of a summary provider (e.g. "••••••••a1b2"); empty when none is stored
 #[tauri::command]
 pub async fn api_get_api_key<R: Runtime>(
     _app: AppHandle<R>,
@@ -553,7 +640,7 @@ pub async fn api_get_api_key<R: Runtime>(
         "api_get_api_key called (native) for provider '{}'",
         &provider
     );
-    match SettingsRepository::get_api_key(&state.db_manager.pool(), &provider).await {
+    match SettingsRepository::get_masked_api_key(&state.db_manager.pool(), &provider).await {
         Ok(key) => {
             log_info!(
                 "Successfully retrieved API key for provider '{}'.",
@@ -584,7 +671,9 @@ pub async fn api_get_transcript_config<R: Runtime>(
                 &config.provider,
                 &config.model
             );
-            match SettingsRepository::get_transcript_api_key(pool, &config.provider).await {
+            // Only a masked key is sent to the webview; the transcription engine loads the
+            // real key itself
+            match SettingsRepository::get_masked_transcript_api_key(pool, &config.provider).await {
                 Ok(api_key) => {
                     log_info!("Successfully retrieved transcript config and API key.");
                     Ok(Some(TranscriptConfig {
@@ -655,6 +744,7 @@ pub async fn api_save_transcript_config<R: Runtime>(
     )
 }
 
+/// Masked API key of a transcription provider; empty when none is stored
 #[tauri::command]
 pub async fn api_get_transcript_api_key<R: Runtime>(
     _app: AppHandle<R>,
@@ -666,7 +756,9 @@ pub async fn api_get_transcript_api_key<R: Runtime>(
         "api_get_transcript_api_key called (native) for provider '{}'",
         &provider
     );
-    match SettingsRepository::get_transcript_api_key(&state.db_manager.pool(), &provider).await {
+    match SettingsRepository::get_masked_transcript_api_key(&state.db_manager.pool(), &provider)
+        .await
+    {
         Ok(key) => {
             log_info!(
                 "Successfully retrieved transcript API key for provider '{}'.",
@@ -810,6 +902,50 @@ pub async fn api_save_meeting_title<R: Runtime>(
     }
 }
 
+#[tauri::command]
+pub async fn api_rename_speaker<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    meeting_id: String,
+    old_label: String,
+    new_label: String,
+) -> Result<serde_json::Value, String> {
+    log_info!(
+        "api_rename_speaker called for meeting_id: {}, '{}' -> '{}'",
+        meeting_id,
+        old_label,
+        new_label
+    );
+
+    let new_label = new_label.trim();
+    if new_label.is_empty() {
+        return Err("Speaker name cannot be empty".to_string());
+    }
+
+    let pool = state.db_manager.pool();
+    match TranscriptsRepository::rename_speaker(pool, &meeting_id, &old_label, new_label).await {
+        Ok(0) => {
+            log_warn!(
+                "No segments labeled '{}' found in meeting {}",
+                old_label,
+                meeting_id
+            );
+            Err(format!("Speaker '{}' not found in meeting", old_label))
+        }
+        Ok(updated) => {
+            log_info!("Renamed speaker in {} transcript segments", updated);
+            Ok(serde_json::json!({
+                "message": "Speaker renamed successfully",
+                "updated_segments": updated
+            }))
+        }
+        Err(e) => {
+            log_error!("Failed to rename speaker: {}", e);
+            Err(format!("Failed to rename speaker: {}", e))
+        }
+    }
+}
+
 #[tauri::command]
 pub async fn api_save_transcript<R: Runtime>(
     _app: AppHandle<R>,
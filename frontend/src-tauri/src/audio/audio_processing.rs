@@ -88,7 +88,7 @@ pub fn normalize_v2(audio: &[f32]) -> Vec<f32> {
 }
 
 /// True peak limiter with lookahead buffer (prevents clipping)
-struct TruePeakLimiter {
+pub struct TruePeakLimiter {
     lookahead_samples: usize,
     buffer: Vec<f32>,
     gain_reduction: Vec<f32>,
@@ -96,7 +96,7 @@ struct TruePeakLimiter {
 }
 
 impl TruePeakLimiter {
-    fn new(sample_rate: u32) -> Self {
+    pub fn new(sample_rate: u32) -> Self {
         const LIMITER_LOOKAHEAD_MS: usize = 10;
         let lookahead_samples = ((sample_rate as usize * LIMITER_LOOKAHEAD_MS) / 1000).max(1);
 
@@ -108,7 +108,7 @@ impl TruePeakLimiter {
         }
     }
 
-    fn process(&mut self, sample: f32, true_peak_limit: f32) -> f32 {
+    pub fn process(&mut self, sample: f32, true_peak_limit: f32) -> f32 {
         self.buffer[self.current_position] = sample;
 
         let sample_abs = sample.abs();
@@ -393,6 +393,69 @@ impl HighPassFilter {
     }
 }
 
+/// Automatic gain control for speech
+/// Follows the short-term level (300 ms envelope) and moves the gain smoothly towards a
+/// target RMS. Unlike the EBU R128 normalizer, which derives one gain from the cumulative
+/// loudness, it reacts within about a second when a speaker moves away from the microphone.
+/// Below the noise gate the gain is held, so pauses are not amplified into hiss.
+pub struct AutomaticGainControl {
+    target_rms: f32,
+    max_gain: f32,
+    gate_rms: f32,
+    envelope_coeff: f32,
+    attack_coeff: f32,
+    release_coeff: f32,
+    envelope: f32,
+    gain: f32,
+}
+
+impl AutomaticGainControl {
+    /// Create a new AGC
+    ///
+    /// # Arguments
+    /// * `sample_rate` - Audio sample rate in Hz
+    pub fn new(sample_rate: u32) -> Self {
+        const TARGET_DBFS: f32 = -20.0;
+        const MAX_GAIN_DB: f32 = 20.0;
+        const GATE_DBFS: f32 = -55.0;
+
+        // One-pole smoothing coefficient for a time constant in seconds
+        let coeff = |seconds: f32| (-1.0 / (seconds * sample_rate as f32)).exp();
+        let db_to_linear = |db: f32| 10_f32.powf(db / 20.0);
+
+        Self {
+            target_rms: db_to_linear(TARGET_DBFS),
+            max_gain: db_to_linear(MAX_GAIN_DB),
+            gate_rms: db_to_linear(GATE_DBFS),
+            envelope_coeff: coeff(0.3),
+            // Gain drops fast on loud input and rises slowly
+            attack_coeff: coeff(0.05),
+            release_coeff: coeff(1.0),
+            envelope: 0.0,
+            gain: 1.0,
+        }
+    }
+
+    pub fn process(&mut self, samples: &[f32]) -> Vec<f32> {
+        let mut output = Vec::with_capacity(samples.len());
+
+        for &sample in samples {
+            self.envelope = self.envelope_coeff * self.envelope + (1.0 - self.envelope_coeff) * sample * sample;
+            let rms = self.envelope.sqrt();
+
+            if rms >= self.gate_rms {
+                let desired = (self.target_rms / rms).clamp(1.0 / self.max_gain, self.max_gain);
+                let coeff = if desired < self.gain { self.attack_coeff } else { self.release_coeff };
+                self.gain = coeff * self.gain + (1.0 - coeff) * desired;
+            }
+
+            output.push((sample * self.gain).clamp(-1.0, 1.0));
+        }
+
+        output
+    }
+}
+
 pub fn spectral_subtraction(audio: &[f32], d: f32) -> Result<Vec<f32>> {
     let mut real_planner = RealFftPlanner::<f32>::new();
     let window_size = 1600; // 16k sample rate - 100ms
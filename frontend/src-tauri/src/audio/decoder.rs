@@ -0,0 +1,242 @@
+//! Audio file decoding for offline processing (diarization, re-transcription, imports).
+//!
+//! Decodes a recorded or imported audio file into mono f32 samples. Symphonia is used
+//! for the containers we write ourselves (AAC in MP4) and common formats (WAV, FLAC, OGG);
+//! anything Symphonia cannot handle falls back to the FFmpeg binary.
+
+use super::audio_processing::resample;
+use super::ffmpeg::find_ffmpeg_path;
+use anyhow::{anyhow, Result};
+use log::{debug, info, warn};
+use std::path::Path;
+use std::process::{Command, Stdio};
+use symphonia::core::audio::SampleBuffer;
+use symphonia::core::codecs::{DecoderOptions, CODEC_TYPE_NULL};
+use symphonia::core::errors::Error as SymphoniaError;
+use symphonia::core::formats::FormatOptions;
+use symphonia::core::io::MediaSourceStream;
+use symphonia::core::meta::MetadataOptions;
+use symphonia::core::probe::Hint;
+
+/// Sample rate expected by the transcription and diarization models
+pub const TARGET_SAMPLE_RATE: u32 = 16000;
+
+/// Decoded mono audio
+#[derive(Debug, Clone)]
+pub struct DecodedAudio {
+    pub samples: Vec<f32>,
+    pub sample_rate: u32,
+}
+
+impl DecodedAudio {
+    pub fn duration_seconds(&self) -> f64 {
+        if self.sample_rate == 0 {
+            return 0.0;
+        }
+        self.samples.len() as f64 / self.sample_rate as f64
+    }
+}
+
+/// Decode an audio file to mono samples at its native sample rate.
+/// Tries Symphonia first and falls back to FFmpeg.
+pub fn decode_audio_file(path: &Path) -> Result<DecodedAudio> {
+    if !path.exists() {
+        return Err(anyhow!("Audio file not found: {}", path.display()));
+    }
+
+    // Recordings encrypted at rest are decoded from a temporary plaintext copy
+    let readable = crate::encryption::readable_path(path)?;
+    let path = readable.path();
+
+    match decode_with_symphonia(path) {
+        Ok(decoded) => Ok(decoded),
+        Err(e) => {
+            warn!(
+                "Symphonia could not decode {} ({}), falling back to FFmpeg",
+                path.display(),
+                e
+            );
+            decode_with_ffmpeg(path, TARGET_SAMPLE_RATE)
+        }
+    }
+}
+
+/// Decode an audio file to 16kHz mono samples, ready for VAD / transcription / diarization.
+pub fn decode_audio_file_16k_mono(path: &Path) -> Result<Vec<f32>> {
+    let decoded = decode_audio_file(path)?;
+    info!(
+        "Decoded {} ({:.1}s @ {}Hz)",
+        path.display(),
+        decoded.duration_seconds(),
+        decoded.sample_rate
+    );
+
+    if decoded.sample_rate == TARGET_SAMPLE_RATE {
+        return Ok(decoded.samples);
+    }
+
+    resample(&decoded.samples, decoded.sample_rate, TARGET_SAMPLE_RATE)
+        .map_err(|e| anyhow!("Failed to resample decoded audio: {}", e))
+}
+
+fn decode_with_symphonia(path: &Path) -> Result<DecodedAudio> {
+    let file = std::fs::File::open(path)?;
+    let mss = MediaSourceStream::new(Box::new(file), Default::default());
+
+    let mut hint = Hint::new();
+    if let Some(ext) = path.extension().and_then(|e| e.to_str()) {
+        hint.with_extension(ext);
+    }
+
+    let probed = symphonia::default::get_probe().format(
+        &hint,
+        mss,
+        &FormatOptions::default(),
+        &MetadataOptions::default(),
+    )?;
+    let mut format = probed.format;
+
+    let track = format
+        .tracks()
+        .iter()
+        .find(|t| t.codec_params.codec != CODEC_TYPE_NULL)
+        .ok_or_else(|| anyhow!("No decodable audio track found"))?;
+    let track_id = track.id;
+    let sample_rate = track
+        .codec_params
+        .sample_rate
+        .ok_or_else(|| anyhow!("Audio track has no sample rate"))?;
+
+    let mut decoder =
+        symphonia::default::get_codecs().make(&track.codec_params, &DecoderOptions::default())?;
+
+    let mut samples: Vec<f32> = Vec::new();
+    let mut sample_buf: Option<SampleBuffer<f32>> = None;
+
+    loop {
+        let packet = match format.next_packet() {
+            Ok(packet) => packet,
+            Err(SymphoniaError::IoError(e)) if e.kind() == std::io::ErrorKind::UnexpectedEof => {
+                break
+            }
+            Err(SymphoniaError::ResetRequired) => break,
+            Err(e) => return Err(e.into()),
+        };
+
+        if packet.track_id() != track_id {
+            continue;
+        }
+
+        match decoder.decode(&packet) {
+            Ok(decoded) => {
+                let spec = *decoded.spec();
+                let channels = spec.channels.count().max(1);
+
+                let buf = sample_buf.get_or_insert_with(|| {
+                    SampleBuffer::<f32>::new(decoded.capacity() as u64, spec)
+                });
+                if buf.capacity() < decoded.capacity() * channels {
+                    *buf = SampleBuffer::<f32>::new(decoded.capacity() as u64, spec);
+                }
+                buf.copy_interleaved_ref(decoded);
+
+                // Downmix to mono
+                for frame in buf.samples().chunks(channels) {
+                    samples.push(frame.iter().sum::<f32>() / channels as f32);
+                }
+            }
+            Err(SymphoniaError::DecodeError(e)) => {
+                // Corrupted packets are skipped rather than aborting the whole file
+                debug!("Skipping undecodable packet: {}", e);
+                continue;
+            }
+            Err(e) => return Err(e.into()),
+        }
+    }
+
+    if samples.is_empty() {
+        return Err(anyhow!("Decoded audio is empty"));
+    }
+
+    Ok(DecodedAudio {
+        samples,
+        sample_rate,
+    })
+}
+
+fn decode_with_ffmpeg(path: &Path, sample_rate: u32) -> Result<DecodedAudio> {
+    let ffmpeg_path = find_ffmpeg_path()
+        .ok_or_else(|| anyhow!("FFmpeg not found. Please install FFmpeg to decode this file."))?;
+
+    let mut command = Command::new(ffmpeg_path);
+    command
+        .args(["-v", "error", "-i"])
+        .arg(path)
+        .args([
+            "-f",
+            "f32le",
+            "-ac",
+            "1",
+            "-ar",
+            &sample_rate.to_string(),
+            "pipe:1",
+        ])
+        .stdin(Stdio::null());
+
+    // Hide console window on Windows
+    #[cfg(target_os = "windows")]
+    {
+        use std::os::windows::process::CommandExt;
+        const CREATE_NO_WINDOW: u32 = 0x08000000;
+        command.creation_flags(CREATE_NO_WINDOW);
+    }
+
+    let output = command.output()?;
+
+    if !output.status.success() {
+        return Err(anyhow!(
+            "FFmpeg failed to decode {}: {}",
+            path.display(),
+            String::from_utf8_lossy(&output.stderr).trim()
+        ));
+    }
+
+    let samples: Vec<f32> = output
+        .stdout
+        .chunks_exact(4)
+        .map(|b| f32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+        .collect();
+
+    if samples.is_empty() {
+        return Err(anyhow!("Decoded audio is empty"));
+    }
+
+    Ok(DecodedAudio {
+        samples,
+        sample_rate,
+    })
+}
+
+/// Slice `samples` (at `sample_rate`) between two recording-relative timestamps in seconds.
+pub fn slice_seconds(samples: &[f32], sample_rate: u32, start: f64, end: f64) -> &[f32] {
+    let to_index = |t: f64| ((t.max(0.0) * sample_rate as f64) as usize).min(samples.len());
+    let start_idx = to_index(start);
+    let end_idx = to_index(end).max(start_idx);
+    &samples[start_idx..end_idx]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_slice_seconds_clamps_to_bounds() {
+        let samples: Vec<f32> = (0..16000).map(|i| i as f32).collect();
+
+        assert_eq!(slice_seconds(&samples, 16000, 0.25, 0.5).len(), 4000);
+        assert_eq!(slice_seconds(&samples, 16000, 0.5, 5.0).len(), 8000);
+        assert!(slice_seconds(&samples, 16000, 2.0, 3.0).is_empty());
+        assert!(slice_seconds(&samples, 16000, 0.5, 0.25).is_empty());
+        assert_eq!(slice_seconds(&samples, 16000, -1.0, 0.1).len(), 1600);
+    }
+}
@@ -0,0 +1,355 @@
+// audio/dsp_chain.rs
+//
+// Per-source audio preprocessing chain, applied in AudioCapture::process_audio_data at
+// 48kHz before mixing, VAD and transcription. Which stages run, and in which order, comes
+// from the DSP settings (dsp_settings.rs). Every stage's processing time is measured and
+// published as a share of real time, so users can see what each stage costs on their
+// machine.
+
+use anyhow::Result;
+use log::{info, warn};
+use serde::{Deserialize, Serialize};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use super::audio_processing::{
+    AutomaticGainControl, HighPassFilter, LoudnessNormalizer, NoiseSuppressionProcessor, TruePeakLimiter,
+};
+use super::recording_state::DeviceType;
+
+/// High-pass cutoff for speech (removes rumble and handling noise)
+const HIGH_PASS_CUTOFF_HZ: f32 = 80.0;
+
+/// Limiter ceiling, dBFS
+const LIMITER_CEILING_DB: f32 = -1.0;
+
+/// Costs are published every this many chunks (and when the chain is dropped)
+const PUBLISH_INTERVAL_CHUNKS: u64 = 100;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DspStageKind {
+    HighPass,
+    /// RNNoise noise suppression (48kHz only)
+    Denoise,
+    /// Automatic gain control (fast, short-term level)
+    Agc,
+    /// EBU R128 loudness normalization (slow, cumulative level)
+    Normalize,
+    Limiter,
+}
+
+impl DspStageKind {
+    pub const ALL: [DspStageKind; 5] = [
+        DspStageKind::HighPass,
+        DspStageKind::Denoise,
+        DspStageKind::Agc,
+        DspStageKind::Normalize,
+        DspStageKind::Limiter,
+    ];
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DspSource {
+    Microphone,
+    System,
+}
+
+impl From<&DeviceType> for DspSource {
+    fn from(device_type: &DeviceType) -> Self {
+        match device_type {
+            DeviceType::Microphone => DspSource::Microphone,
+            DeviceType::System => DspSource::System,
+        }
+    }
+}
+
+/// Measured cost of one stage
+#[derive(Debug, Clone, Serialize)]
+pub struct DspStageCost {
+    /// None for benchmark results
+    pub source: Option<DspSource>,
+    pub stage: DspStageKind,
+    /// Processing time as a percentage of the audio duration (of one CPU core)
+    pub cpu_percent: f32,
+    pub avg_micros_per_chunk: f32,
+    pub audio_seconds: f64,
+}
+
+// Costs of the chains of the current (or last) recording
+static STAGE_COSTS: Mutex<Vec<DspStageCost>> = Mutex::new(Vec::new());
+
+/// Stage costs of the current (or last) recording
+pub fn stage_costs() -> Vec<DspStageCost> {
+    STAGE_COSTS.lock().map(|costs| costs.clone()).unwrap_or_default()
+}
+
+trait DspStage: Send {
+    fn apply(&mut self, samples: &[f32]) -> Vec<f32>;
+}
+
+impl DspStage for HighPassFilter {
+    fn apply(&mut self, samples: &[f32]) -> Vec<f32> {
+        self.process(samples)
+    }
+}
+
+impl DspStage for NoiseSuppressionProcessor {
+    fn apply(&mut self, samples: &[f32]) -> Vec<f32> {
+        let output = self.process(samples);
+        // Frame-based: at most one partial frame should stay buffered
+        if self.buffered_samples() > 1000 {
+            warn!("⚠️ RNNoise accumulating samples: {} buffered (potential latency issue!)",
+                  self.buffered_samples());
+        }
+        output
+    }
+}
+
+impl DspStage for AutomaticGainControl {
+    fn apply(&mut self, samples: &[f32]) -> Vec<f32> {
+        self.process(samples)
+    }
+}
+
+impl DspStage for LoudnessNormalizer {
+    fn apply(&mut self, samples: &[f32]) -> Vec<f32> {
+        self.normalize_loudness(samples)
+    }
+}
+
+struct PeakLimiter {
+    limiter: TruePeakLimiter,
+    ceiling: f32,
+}
+
+impl DspStage for PeakLimiter {
+    fn apply(&mut self, samples: &[f32]) -> Vec<f32> {
+        samples
+            .iter()
+            .map(|&sample| self.limiter.process(sample, self.ceiling))
+            .collect()
+    }
+}
+
+fn create_stage(kind: DspStageKind, sample_rate: u32) -> Result<Box<dyn DspStage>> {
+    Ok(match kind {
+        DspStageKind::HighPass => Box::new(HighPassFilter::new(sample_rate, HIGH_PASS_CUTOFF_HZ)),
+        DspStageKind::Denoise => Box::new(NoiseSuppressionProcessor::new(sample_rate)?),
+        DspStageKind::Agc => Box::new(AutomaticGainControl::new(sample_rate)),
+        DspStageKind::Normalize => Box::new(LoudnessNormalizer::new(1, sample_rate)?),
+        DspStageKind::Limiter => Box::new(PeakLimiter {
+            limiter: TruePeakLimiter::new(sample_rate),
+            ceiling: 10_f32.powf(LIMITER_CEILING_DB / 20.0),
+        }),
+    })
+}
+
+#[derive(Default)]
+struct StageTiming {
+    busy: Duration,
+    samples: u64,
+    chunks: u64,
+}
+
+impl StageTiming {
+    fn cost(&self, source: Option<DspSource>, stage: DspStageKind, sample_rate: u32) -> DspStageCost {
+        let audio_seconds = self.samples as f64 / sample_rate as f64;
+        DspStageCost {
+            source,
+            stage,
+            cpu_percent: if audio_seconds > 0.0 {
+                (self.busy.as_secs_f64() / audio_seconds * 100.0) as f32
+            } else {
+                0.0
+            },
+            avg_micros_per_chunk: if self.chunks > 0 {
+                self.busy.as_micros() as f32 / self.chunks as f32
+            } else {
+                0.0
+            },
+            audio_seconds,
+        }
+    }
+}
+
+struct ChainStage {
+    kind: DspStageKind,
+    stage: Box<dyn DspStage>,
+    timing: StageTiming,
+}
+
+/// Ordered DSP stages for one source
+pub struct DspChain {
+    source: DspSource,
+    sample_rate: u32,
+    stages: Vec<ChainStage>,
+    chunks: u64,
+}
+
+impl DspChain {
+    /// Builds the given stages in order. Stages that can't be created (e.g. denoise at a
+    /// sample rate other than 48kHz) are logged and skipped.
+    pub fn new(source: DspSource, kinds: &[DspStageKind], sample_rate: u32) -> Self {
+        let stages = kinds
+            .iter()
+            .filter_map(|&kind| match create_stage(kind, sample_rate) {
+                Ok(stage) => Some(ChainStage { kind, stage, timing: StageTiming::default() }),
+                Err(e) => {
+                    warn!("⚠️ Skipping {:?} DSP stage for {:?}: {}", kind, source, e);
+                    None
+                }
+            })
+            .collect();
+
+        let chain = Self { source, sample_rate, stages, chunks: 0 };
+        info!("🎛️ {:?} DSP chain: {:?}", source, chain.stage_kinds());
+
+        // A new recording starts with fresh costs for this source
+        if let Ok(mut costs) = STAGE_COSTS.lock() {
+            costs.retain(|cost| cost.source != Some(source));
+        }
+        chain
+    }
+
+    pub fn stage_kinds(&self) -> Vec<DspStageKind> {
+        self.stages.iter().map(|stage| stage.kind).collect()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.stages.is_empty()
+    }
+
+    /// Runs the samples through every stage in order, timing each stage
+    pub fn process(&mut self, mut samples: Vec<f32>) -> Vec<f32> {
+        if self.stages.is_empty() {
+            return samples;
+        }
+
+        for chain_stage in &mut self.stages {
+            let input_len = samples.len();
+            let start = Instant::now();
+            samples = chain_stage.stage.apply(&samples);
+            chain_stage.timing.busy += start.elapsed();
+            chain_stage.timing.samples += input_len as u64;
+            chain_stage.timing.chunks += 1;
+        }
+
+        self.chunks += 1;
+        if self.chunks % PUBLISH_INTERVAL_CHUNKS == 0 {
+            self.publish_costs();
+        }
+        samples
+    }
+
+    fn publish_costs(&self) {
+        if let Ok(mut costs) = STAGE_COSTS.lock() {
+            costs.retain(|cost| cost.source != Some(self.source));
+            costs.extend(self.stages.iter().map(|chain_stage| {
+                chain_stage.timing.cost(Some(self.source), chain_stage.kind, self.sample_rate)
+            }));
+        }
+    }
+}
+
+impl Drop for DspChain {
+    fn drop(&mut self) {
+        if self.chunks > 0 {
+            self.publish_costs();
+        }
+    }
+}
+
+/// Measures every stage on `seconds` of synthetic speech-like audio processed in
+/// 1024-sample chunks (a typical capture callback), so users can compare stages without
+/// recording. CPU-bound, call it off the async runtime.
+pub fn benchmark_stages(sample_rate: u32, seconds: u32) -> Vec<DspStageCost> {
+    const CHUNK_SAMPLES: usize = 1024;
+
+    let mut seed: u32 = 1;
+    let audio: Vec<f32> = (0..(sample_rate * seconds) as usize)
+        .map(|i| {
+            seed = seed.wrapping_mul(1_103_515_245).wrapping_add(12345);
+            let noise = ((seed >> 16) as f32 / 32768.0 - 1.0) * 0.02;
+            let t = i as f32 / sample_rate as f32;
+            // 200 Hz voice-like tone, amplitude-modulated at a syllable rate of 4 Hz
+            let envelope = 0.5 + 0.5 * (t * 4.0 * std::f32::consts::TAU).sin();
+            noise + 0.2 * envelope * (t * 200.0 * std::f32::consts::TAU).sin()
+        })
+        .collect();
+
+    DspStageKind::ALL
+        .into_iter()
+        .filter_map(|kind| {
+            let mut stage = match create_stage(kind, sample_rate) {
+                Ok(stage) => stage,
+                Err(e) => {
+                    warn!("Skipping {:?} in DSP benchmark: {}", kind, e);
+                    return None;
+                }
+            };
+            let mut timing = StageTiming::default();
+            for chunk in audio.chunks(CHUNK_SAMPLES) {
+                let start = Instant::now();
+                let _ = stage.apply(chunk);
+                timing.busy += start.elapsed();
+                timing.samples += chunk.len() as u64;
+                timing.chunks += 1;
+            }
+            Some(timing.cost(None, kind, sample_rate))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rms(samples: &[f32]) -> f32 {
+        (samples.iter().map(|s| s * s).sum::<f32>() / samples.len() as f32).sqrt()
+    }
+
+    fn tone(amplitude: f32, seconds: f32) -> Vec<f32> {
+        (0..(48000.0 * seconds) as usize)
+            .map(|i| amplitude * (i as f32 * 300.0 * std::f32::consts::TAU / 48000.0).sin())
+            .collect()
+    }
+
+    #[test]
+    fn test_agc_brings_quiet_speech_to_target() {
+        let mut agc = AutomaticGainControl::new(48000);
+        let output = agc.process(&tone(0.02, 6.0));
+        // Target is -20 dBFS (RMS 0.1); the input is at RMS 0.014
+        let settled = rms(&output[output.len() - 48000..]);
+        assert!((settled - 0.1).abs() < 0.02, "settled RMS {}", settled);
+
+        // Silence is not amplified
+        let mut agc = AutomaticGainControl::new(48000);
+        let output = agc.process(&vec![0.0001; 48000]);
+        assert!(rms(&output) < 0.001);
+    }
+
+    #[test]
+    fn test_chain_runs_stages_in_order_and_publishes_costs() {
+        let kinds = [DspStageKind::HighPass, DspStageKind::Agc, DspStageKind::Limiter];
+        let mut chain = DspChain::new(DspSource::System, &kinds, 48000);
+        assert_eq!(chain.stage_kinds(), kinds);
+
+        for chunk in tone(0.5, 3.0).chunks(1024) {
+            let output = chain.process(chunk.to_vec());
+            assert_eq!(output.len(), chunk.len());
+            assert!(output.iter().all(|s| s.abs() <= 1.0));
+        }
+        drop(chain);
+
+        let costs: Vec<_> = stage_costs()
+            .into_iter()
+            .filter(|cost| cost.source == Some(DspSource::System))
+            .collect();
+        assert_eq!(costs.iter().map(|c| c.stage).collect::<Vec<_>>(), kinds);
+        assert!(costs.iter().all(|c| (c.audio_seconds - 3.0).abs() < 0.1));
+
+        assert!(DspChain::new(DspSource::System, &[], 48000).is_empty());
+    }
+}
@@ -0,0 +1,162 @@
+// audio/dsp_settings.rs
+//
+// Which preprocessing stages run on each source (mic vs system audio), and in which
+// order.
+
+use log::info;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::sync::{LazyLock, Mutex};
+use tauri::{AppHandle, Runtime};
+
+use super::dsp_chain::{self, DspSource, DspStageCost, DspStageKind};
+use super::{load_store_setting, save_store_setting};
+
+const STORE_KEY: &str = "dspSettings";
+
+/// Benchmark length for `benchmark_dsp_stages`
+const BENCHMARK_SECONDS: u32 = 5;
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct DspStageConfig {
+    pub stage: DspStageKind,
+    pub enabled: bool,
+}
+
+/// Stage lists in processing order. Disabled stages stay listed so the UI keeps their
+/// position; stages missing from a list don't run.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DspSettings {
+    pub microphone: Vec<DspStageConfig>,
+    pub system: Vec<DspStageConfig>,
+}
+
+impl DspSettings {
+    pub fn chain(&self, source: DspSource) -> &[DspStageConfig] {
+        match source {
+            DspSource::Microphone => &self.microphone,
+            DspSource::System => &self.system,
+        }
+    }
+
+    /// Enabled stages of a source, in order
+    pub fn enabled_stages(&self, source: DspSource) -> Vec<DspStageKind> {
+        self.chain(source)
+            .iter()
+            .filter(|config| config.enabled)
+            .map(|config| config.stage)
+            .collect()
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        for source in [DspSource::Microphone, DspSource::System] {
+            let mut seen = HashSet::new();
+            if let Some(duplicate) = self.chain(source).iter().find(|config| !seen.insert(config.stage)) {
+                return Err(format!("{:?} is listed twice in the {:?} chain", duplicate.stage, source));
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Default for DspSettings {
+    /// The processing used before the chain was configurable: microphone audio is
+    /// high-passed and loudness-normalized (RNNoise behind its flag), system audio is raw
+    fn default() -> Self {
+        let chain = |enabled: &[DspStageKind]| {
+            DspStageKind::ALL
+                .into_iter()
+                .map(|stage| DspStageConfig { stage, enabled: enabled.contains(&stage) })
+                .collect()
+        };
+        let mut microphone = vec![DspStageKind::HighPass, DspStageKind::Normalize];
+        if super::ffmpeg_mixer::RNNOISE_APPLY_ENABLED {
+            microphone.push(DspStageKind::Denoise);
+        }
+
+        Self {
+            microphone: chain(&microphone),
+            system: chain(&[]),
+        }
+    }
+}
+
+static DSP_SETTINGS: LazyLock<Mutex<DspSettings>> = LazyLock::new(Default::default);
+
+/// Get the DSP settings used for new recordings
+pub fn current() -> DspSettings {
+    DSP_SETTINGS
+        .lock()
+        .map(|settings| settings.clone())
+        .unwrap_or_default()
+}
+
+fn set_current(settings: DspSettings) {
+    if let Ok(mut current) = DSP_SETTINGS.lock() {
+        *current = settings;
+    }
+}
+
+/// Load persisted settings into the global settings (called on startup)
+pub fn load_dsp_settings<R: Runtime>(app: &AppHandle<R>) {
+    if let Some(settings) =
+        load_store_setting(app, STORE_KEY, "DSP settings", DspSettings::validate)
+    {
+        info!("🎛️ Loaded DSP settings: mic={:?}, system={:?}",
+              settings.enabled_stages(DspSource::Microphone), settings.enabled_stages(DspSource::System));
+        set_current(settings);
+    }
+}
+
+#[tauri::command]
+pub async fn get_dsp_settings() -> Result<DspSettings, String> {
+    Ok(current())
+}
+
+#[tauri::command]
+pub async fn set_dsp_settings<R: Runtime>(
+    app: AppHandle<R>,
+    settings: DspSettings,
+) -> Result<(), String> {
+    settings.validate()?;
+    save_store_setting(&app, STORE_KEY, "DSP settings", &settings)?;
+
+    info!("🎛️ DSP settings updated (next recording): mic={:?}, system={:?}",
+          settings.enabled_stages(DspSource::Microphone), settings.enabled_stages(DspSource::System));
+    set_current(settings);
+    Ok(())
+}
+
+/// Measured per-stage CPU cost of the current (or last) recording
+#[tauri::command]
+pub async fn get_dsp_stage_costs() -> Result<Vec<DspStageCost>, String> {
+    Ok(dsp_chain::stage_costs())
+}
+
+/// Per-stage CPU cost on synthetic audio, without recording
+#[tauri::command]
+pub async fn benchmark_dsp_stages() -> Result<Vec<DspStageCost>, String> {
+    tokio::task::spawn_blocking(|| dsp_chain::benchmark_stages(48000, BENCHMARK_SECONDS))
+        .await
+        .map_err(|e| format!("DSP benchmark failed: {}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_matches_previous_processing() {
+        let settings = DspSettings::default();
+        assert!(settings.validate().is_ok());
+        assert_eq!(
+            settings.enabled_stages(DspSource::Microphone),
+            vec![DspStageKind::HighPass, DspStageKind::Normalize]
+        );
+        assert!(settings.enabled_stages(DspSource::System).is_empty());
+
+        let mut duplicated = settings.clone();
+        duplicated.system.push(DspStageConfig { stage: DspStageKind::HighPass, enabled: false });
+        assert!(duplicated.validate().is_err());
+    }
+}
@@ -1,4 +1,4 @@
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use anyhow::{Result, anyhow};
 use log::{info, warn, error};
 use super::encode::encode_single_audio;
@@ -22,6 +22,8 @@ pub struct IncrementalAudioSaver {
     checkpoint_count: u32,
     checkpoints_dir: PathBuf,
     meeting_folder: PathBuf,
+    /// Name of the merged file in the meeting folder
+    output_file: String,
     sample_rate: u32,
 }
 
@@ -45,10 +47,29 @@ impl IncrementalAudioSaver {
             checkpoint_count: 0,
             checkpoints_dir,
             meeting_folder,
+            output_file: "audio.mp4".to_string(),
             sample_rate,
         })
     }
 
+    /// Saver for an extra track of the recording (e.g. system audio only), with its own
+    /// checkpoints directory, merged into `output_file` in the meeting folder
+    pub fn for_track(
+        meeting_folder: PathBuf,
+        sample_rate: u32,
+        checkpoints_dir_name: &str,
+        output_file: &str,
+    ) -> Result<Self> {
+        let checkpoints_dir = meeting_folder.join(checkpoints_dir_name);
+        std::fs::create_dir_all(&checkpoints_dir)?;
+
+        Ok(Self {
+            checkpoints_dir,
+            output_file: output_file.to_string(),
+            ..Self::new(meeting_folder, sample_rate)?
+        })
+    }
+
     /// Add an audio chunk to the buffer
     /// Automatically saves a checkpoint when buffer reaches 30 seconds
     pub fn add_chunk(&mut self, chunk: AudioChunk) -> Result<()> {
@@ -129,7 +150,7 @@ impl IncrementalAudioSaver {
         }
 
         // Merge all checkpoints using FFmpeg concat
-        let final_audio_path = self.meeting_folder.join("audio.mp4");
+        let final_audio_path = self.meeting_folder.join(&self.output_file);
         self.merge_checkpoints(&final_audio_path).await?;
 
         // Clean up checkpoints directory
@@ -149,10 +170,7 @@ impl IncrementalAudioSaver {
     async fn merge_checkpoints(&self, output: &PathBuf) -> Result<()> {
         info!("Merging {} checkpoints into final audio file...", self.checkpoint_count);
 
-        // Create concat list file for FFmpeg
-        let list_file = self.checkpoints_dir.join("concat_list.txt");
-        let mut list_content = String::new();
-
+        let mut checkpoints = Vec::with_capacity(self.checkpoint_count as usize);
         for i in 0..self.checkpoint_count {
             let checkpoint_path = self.checkpoints_dir
                 .join(format!("audio_chunk_{:03}.mp4", i));
@@ -161,61 +179,10 @@ impl IncrementalAudioSaver {
             if !checkpoint_path.exists() {
                 return Err(anyhow!("Checkpoint file missing: {}", checkpoint_path.display()));
             }
-
-            // Use absolute path for FFmpeg (required for safe mode)
-            let abs_path = checkpoint_path.canonicalize()?;
-            list_content.push_str(&format!("file '{}'\n", abs_path.display()));
-        }
-
-        std::fs::write(&list_file, list_content)?;
-
-        #[cfg(target_os = "macos")]
-        let ffmpeg_path = find_ffmpeg_path()
-            .ok_or_else(|| anyhow!("FFmpeg not found. Please install FFmpeg to finalize recordings."))?;
-        
-        #[cfg(not(target_os = "macos"))]
-        let ffmpeg_path = "ffmpeg";  // Assume ffmpeg is in PATH on Windows/Linux
-        info!("Using FFmpeg at: {:?}", ffmpeg_path);
-
-        // Run FFmpeg concat command
-        // Using concat demuxer with copy codec for fast merging (no re-encoding)
-        
-        let mut command = std::process::Command::new(ffmpeg_path);
-        
-        command.args(&[
-            "-f", "concat",          // Use concat demuxer
-            "-safe", "0",            // Allow absolute paths
-            "-i", list_file.to_str().unwrap(),
-            "-c", "copy",            // Copy codec - no re-encoding!
-            "-y",                    // Overwrite output file
-            output.to_str().unwrap()
-        ]);
-
-        // Hide console window on Windows to prevent CMD popup during finalization
-        #[cfg(target_os = "windows")]
-        {
-            use std::os::windows::process::CommandExt;
-            const CREATE_NO_WINDOW: u32 = 0x08000000;
-            command.creation_flags(CREATE_NO_WINDOW);
-        }
-
-        let ffmpeg_output = command.output()?;
-
-        if !ffmpeg_output.status.success() {
-            let stderr = String::from_utf8_lossy(&ffmpeg_output.stderr);
-            error!("FFmpeg merge failed: {}", stderr);
-            return Err(anyhow!("FFmpeg concat failed: {}", stderr));
-        }
-
-        // Verify output file was created
-        if !output.exists() {
-            return Err(anyhow!("Merged audio file was not created: {}", output.display()));
+            checkpoints.push(checkpoint_path);
         }
 
-        info!("✅ Successfully merged {} checkpoints → {}",
-              self.checkpoint_count, output.display());
-
-        Ok(())
+        merge_checkpoint_files(&self.checkpoints_dir, &checkpoints, output)
     }
 
     /// Get the meeting folder path
@@ -229,6 +196,116 @@ impl IncrementalAudioSaver {
     }
 }
 
+/// Checkpoint files left in a meeting folder's `.checkpoints/`, in recording order
+pub fn list_checkpoints(meeting_folder: &Path) -> Vec<PathBuf> {
+    let mut checkpoints: Vec<PathBuf> = std::fs::read_dir(meeting_folder.join(".checkpoints"))
+        .map(|entries| {
+            entries
+                .filter_map(|e| e.ok())
+                .map(|e| e.path())
+                .filter(|p| {
+                    p.file_name()
+                        .map(|n| n.to_string_lossy())
+                        .is_some_and(|n| n.starts_with("audio_chunk_") && n.ends_with(".mp4"))
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+    // Zero-padded names sort in recording order
+    checkpoints.sort();
+    checkpoints
+}
+
+/// Merge the checkpoints of a recording that was never finalized (app crash, power loss)
+/// into `audio.mp4` and remove them. A truncated last checkpoint is dropped if FFmpeg
+/// can't read it.
+pub fn recover_checkpoints(meeting_folder: &Path) -> Result<PathBuf> {
+    let checkpoints_dir = meeting_folder.join(".checkpoints");
+    let mut checkpoints = list_checkpoints(meeting_folder);
+    if checkpoints.is_empty() {
+        return Err(anyhow!("No audio checkpoints to recover in {}", meeting_folder.display()));
+    }
+
+    let output = meeting_folder.join("audio.mp4");
+    if let Err(e) = merge_checkpoint_files(&checkpoints_dir, &checkpoints, &output) {
+        if checkpoints.len() == 1 {
+            return Err(e);
+        }
+        warn!("Merging all checkpoints failed ({}), retrying without the last one", e);
+        checkpoints.pop();
+        merge_checkpoint_files(&checkpoints_dir, &checkpoints, &output)?;
+    }
+
+    if let Err(e) = std::fs::remove_dir_all(&checkpoints_dir) {
+        warn!("Failed to clean up checkpoints directory: {}", e);
+    }
+    info!("✅ Recovered {} checkpoints → {}", checkpoints.len(), output.display());
+    Ok(output)
+}
+
+/// Concatenate checkpoint files into `output` with FFmpeg's concat demuxer (no re-encoding)
+fn merge_checkpoint_files(checkpoints_dir: &Path, checkpoints: &[PathBuf], output: &Path) -> Result<()> {
+    // Create concat list file for FFmpeg
+    let list_file = checkpoints_dir.join("concat_list.txt");
+    let mut list_content = String::new();
+
+    for checkpoint_path in checkpoints {
+        // Use absolute path for FFmpeg (required for safe mode)
+        let abs_path = checkpoint_path.canonicalize()?;
+        list_content.push_str(&format!("file '{}'\n", abs_path.display()));
+    }
+
+    std::fs::write(&list_file, list_content)?;
+
+    #[cfg(target_os = "macos")]
+    let ffmpeg_path = find_ffmpeg_path()
+        .ok_or_else(|| anyhow!("FFmpeg not found. Please install FFmpeg to finalize recordings."))?;
+
+    #[cfg(not(target_os = "macos"))]
+    let ffmpeg_path = "ffmpeg";  // Assume ffmpeg is in PATH on Windows/Linux
+    info!("Using FFmpeg at: {:?}", ffmpeg_path);
+
+    // Run FFmpeg concat command
+    // Using concat demuxer with copy codec for fast merging (no re-encoding)
+
+    let mut command = std::process::Command::new(ffmpeg_path);
+
+    command.args(&[
+        "-f", "concat",          // Use concat demuxer
+        "-safe", "0",            // Allow absolute paths
+        "-i", list_file.to_str().unwrap(),
+        "-c", "copy",            // Copy codec - no re-encoding!
+        "-y",                    // Overwrite output file
+        output.to_str().unwrap()
+    ]);
+
+    // Hide console window on Windows to prevent CMD popup during finalization
+    #[cfg(target_os = "windows")]
+    {
+        use std::os::windows::process::CommandExt;
+        const CREATE_NO_WINDOW: u32 = 0x08000000;
+        command.creation_flags(CREATE_NO_WINDOW);
+    }
+
+    let ffmpeg_output = command.output()?;
+
+    if !ffmpeg_output.status.success() {
+        let stderr = String::from_utf8_lossy(&ffmpeg_output.stderr);
+        error!("FFmpeg merge failed: {}", stderr);
+        return Err(anyhow!("FFmpeg concat failed: {}", stderr));
+    }
+
+    // Verify output file was created
+    if !output.exists() {
+        return Err(anyhow!("Merged audio file was not created: {}", output.display()));
+    }
+
+    info!("✅ Successfully merged {} checkpoints → {}",
+          checkpoints.len(), output.display());
+
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1,8 +1,16 @@
 // src/audio/mod.rs
+//
+// Recording settings (vad_settings, dsp_settings, recording_preferences and
+// audio_v2::settings) are persisted in the store plugin and kept in a global that a
+// recording reads once when it starts, so changes apply from the next recording on.
+// `load_store_setting` / `save_store_setting` at the bottom do the store part.
 pub mod audio_processing;
 pub mod encode;
 pub mod ffmpeg;
+pub mod decoder;
 pub mod vad;
+pub mod vad_settings;
+pub mod vad_calibration;
 
 // Modularized device management
 pub mod devices;
@@ -23,10 +31,13 @@ pub mod recording_commands;
 pub mod recording_preferences;
 pub mod recording_saver;
 pub mod incremental_saver;  // NEW: Incremental audio saving with checkpoints
+pub mod recovery;  // Recovery of interrupted recordings from checkpoints
 pub mod level_monitor;
 pub mod simple_level_monitor;
 pub mod buffer_pool;
 pub mod post_processor;
+pub mod dsp_chain;  // Configurable per-source preprocessing chain
+pub mod dsp_settings;
 pub mod hardware_detector;
 pub mod async_logger;
 pub mod batch_processor;
@@ -67,7 +78,7 @@ pub use system_audio_commands::{
 };
 
 // Export new simplified components
-pub use recording_state::{RecordingState, AudioChunk, ProcessedAudioChunk, AudioError, DeviceType as RecordingDeviceType};
+pub use recording_state::{RecordingState, AudioChunk, ProcessedAudioChunk, AudioError, DeviceType as RecordingDeviceType, TranscriptionChannelMode};
 pub use pipeline::{AudioPipelineManager};
 pub use stream::{AudioStreamManager};
 pub use recording_manager::{RecordingManager};
@@ -99,4 +110,69 @@ pub use diagnostics::{
 pub use ffmpeg_mixer::{FFmpegAudioMixer, BufferStats, RNNOISE_APPLY_ENABLED};
 
 pub use vad::{extract_speech_16k};
+pub use vad_settings::{VadPreset, VadSettings};
 
+
+const SETTINGS_STORE_FILE: &str = "store.json";
+
+/// Reads a setting saved with `save_store_setting` (called on startup). Returns None,
+/// with a warning, when it is missing, unparsable or fails `validate`.
+pub(crate) fn load_store_setting<T, R>(
+    app: &tauri::AppHandle<R>,
+    key: &str,
+    label: &str,
+    validate: impl Fn(&T) -> Result<(), String>,
+) -> Option<T>
+where
+    T: serde::de::DeserializeOwned + std::fmt::Debug,
+    R: tauri::Runtime,
+{
+    use tauri_plugin_store::StoreExt;
+
+    let store = match app.store(SETTINGS_STORE_FILE) {
+        Ok(store) => store,
+        Err(e) => {
+            log::warn!("Failed to open store for {}: {}", label, e);
+            return None;
+        }
+    };
+    let value = store.get(key)?;
+
+    match serde_json::from_value::<T>(value) {
+        Ok(setting) => match validate(&setting) {
+            Ok(()) => Some(setting),
+            Err(e) => {
+                log::warn!("Ignoring invalid stored {} ({}): {:?}", label, e, setting);
+                None
+            }
+        },
+        Err(e) => {
+            log::warn!("Failed to parse stored {}: {}", label, e);
+            None
+        }
+    }
+}
+
+/// Persists an already validated setting under `key`
+pub(crate) fn save_store_setting<T, R>(
+    app: &tauri::AppHandle<R>,
+    key: &str,
+    label: &str,
+    setting: &T,
+) -> Result<(), String>
+where
+    T: serde::Serialize,
+    R: tauri::Runtime,
+{
+    use tauri_plugin_store::StoreExt;
+
+    let store = app
+        .store(SETTINGS_STORE_FILE)
+        .map_err(|e| format!("Failed to open store: {}", e))?;
+    let value = serde_json::to_value(setting)
+        .map_err(|e| format!("Failed to serialize {}: {}", label, e))?;
+    store.set(key, value);
+    store
+        .save()
+        .map_err(|e| format!("Failed to save {}: {}", label, e))
+}
@@ -9,9 +9,12 @@ use super::batch_processor::AudioMetricsBatcher;
 use rubato::{Resampler, SincFixedIn, SincInterpolationParameters, SincInterpolationType, WindowFunction};
 
 use super::devices::AudioDevice;
-use super::recording_state::{AudioChunk, AudioError, RecordingState, DeviceType};
-use super::audio_processing::{audio_to_mono, LoudnessNormalizer, NoiseSuppressionProcessor, HighPassFilter};
-use super::vad::{ContinuousVadProcessor};
+use super::recording_state::{AudioChunk, AudioError, RecordingState, DeviceType, TranscriptionChannelMode};
+use super::audio_processing::audio_to_mono;
+use super::dsp_chain::{DspChain, DspSource};
+use super::vad::{ContinuousVadProcessor, SpeechSegment};
+use crate::audio_v2::compatibility::{AudioEngineKind, LegacyBridge, MixEngine, MixEngines, MixedWindow};
+use crate::audio_v2::sync::SyncTracker;
 
 /// Ring buffer for synchronized audio mixing
 /// Accumulates samples from mic and system streams until we have aligned windows
@@ -189,6 +192,61 @@ impl ProfessionalAudioMixer {
     }
 }
 
+/// The legacy mixing stage (ring buffer + summing mixer) behind the audio_v2 engine switch
+pub(crate) struct LegacyMixEngine {
+    ring_buffer: AudioMixerRingBuffer,
+    mixer: ProfessionalAudioMixer,
+    sample_rate: u32,
+    // Output position of the next window, for sync tracking only
+    emitted_samples: u64,
+    sync_tracker: SyncTracker,
+}
+
+impl LegacyMixEngine {
+    pub(crate) fn new(sample_rate: u32) -> Self {
+        Self {
+            ring_buffer: AudioMixerRingBuffer::new(sample_rate),
+            mixer: ProfessionalAudioMixer::new(sample_rate),
+            sample_rate,
+            emitted_samples: 0,
+            sync_tracker: SyncTracker::default(),
+        }
+    }
+}
+
+impl MixEngine for LegacyMixEngine {
+    fn kind(&self) -> AudioEngineKind {
+        AudioEngineKind::Legacy
+    }
+
+    fn add_samples(&mut self, device_type: DeviceType, timestamp: f64, samples: Vec<f32>) {
+        // Timestamps are not used for mixing; they only measure how far apart the sources end up
+        let buffered = match device_type {
+            DeviceType::Microphone => self.ring_buffer.mic_buffer.len(),
+            DeviceType::System => self.ring_buffer.system_buffer.len(),
+        };
+        let capture_end = (timestamp.max(0.0) * self.sample_rate as f64).round() as u64;
+        self.sync_tracker.record(
+            &device_type,
+            self.emitted_samples + buffered as u64,
+            capture_end.saturating_sub(samples.len() as u64),
+        );
+
+        self.ring_buffer.add_samples(device_type, samples);
+    }
+
+    fn next_window(&mut self) -> Option<MixedWindow> {
+        let (mic_window, sys_window) = self.ring_buffer.extract_window()?;
+        let mixed = self.mixer.mix_window(&mic_window, &sys_window);
+        self.emitted_samples += mixed.len() as u64;
+        Some(MixedWindow { mic: mic_window, system: sys_window, mixed })
+    }
+
+    fn sync_accuracy_ms(&self) -> f64 {
+        self.sync_tracker.accuracy_ms(self.sample_rate)
+    }
+}
+
 /// Simplified audio capture without broadcast channels
 #[derive(Clone)]
 pub struct AudioCapture {
@@ -205,11 +263,8 @@ pub struct AudioCapture {
     // Buffering for variable-size chunks → fixed-size resampler input
     resampler_input_buffer: Arc<std::sync::Mutex<Vec<f32>>>,
     resampler_chunk_size: usize,  // Fixed chunk size for resampler (512 samples)
-    // Audio enhancement processors (microphone only)
-    noise_suppressor: Arc<std::sync::Mutex<Option<NoiseSuppressionProcessor>>>,
-    high_pass_filter: Arc<std::sync::Mutex<Option<HighPassFilter>>>,
-    // EBU R128 normalizer for microphone audio (per-device, stateful)
-    normalizer: Arc<std::sync::Mutex<Option<LoudnessNormalizer>>>,
+    // Configurable preprocessing (high-pass, denoise, AGC, normalize, limiter), per source
+    dsp_chain: Arc<std::sync::Mutex<DspChain>>,
     // Note: Using global recording timestamp for synchronization
 }
 
@@ -265,52 +320,17 @@ impl AudioCapture {
             );
         }
 
-        // Initialize audio enhancement processors for MICROPHONE ONLY
-        // System audio doesn't need enhancement (already clean)
-        let (noise_suppressor, high_pass_filter, normalizer) = if matches!(device_type, DeviceType::Microphone) {
-            // Initialize noise suppression (RNNoise) at 48kHz - CONDITIONAL based on flag
-            let ns = if super::ffmpeg_mixer::RNNOISE_APPLY_ENABLED {
-                match NoiseSuppressionProcessor::new(TARGET_SAMPLE_RATE) {
-                    Ok(processor) => {
-                        info!("✅ RNNoise noise suppression ENABLED for microphone '{}' (10-15 dB reduction)", device.name);
-                        Some(processor)
-                    }
-                    Err(e) => {
-                        warn!("⚠️ Failed to create noise suppressor: {}, continuing without noise suppression", e);
-                        None
-                    }
-                }
-            } else {
-                info!("ℹ️ RNNoise noise suppression DISABLED for microphone '{}' (flag: RNNOISE_APPLY_ENABLED=false)", device.name);
-                info!("   Whisper handles noise well internally - RNNoise is optional");
-                None
-            };
-
-            // Initialize high-pass filter (removes rumble below 80 Hz)
-            let hpf = {
-                let filter = HighPassFilter::new(TARGET_SAMPLE_RATE, 80.0);
-                info!("✅ High-pass filter initialized for microphone '{}' (cutoff: 80 Hz)", device.name);
-                Some(filter)
-            };
-
-            // Initialize EBU R128 normalizer (professional loudness standard)
-            let norm = match LoudnessNormalizer::new(1, TARGET_SAMPLE_RATE) {
-                Ok(normalizer) => {
-                    info!("✅ EBU R128 normalizer initialized for microphone '{}' (target: -23 LUFS)", device.name);
-                    Some(normalizer)
-                }
-                Err(e) => {
-                    warn!("⚠️ Failed to create normalizer for microphone: {}, normalization disabled", e);
-                    None
-                }
-            };
-
-            (ns, hpf, norm)
-        } else {
-            // System audio: no enhancement needed
-            info!("ℹ️ System audio '{}' captured raw (no enhancement)", device.name);
-            (None, None, None)
-        };
+        // Build the preprocessing chain for this source from the DSP settings
+        // (default: high-pass + EBU R128 normalization on the mic, system audio raw)
+        let source = DspSource::from(&device_type);
+        let dsp_chain = DspChain::new(
+            source,
+            &super::dsp_settings::current().enabled_stages(source),
+            TARGET_SAMPLE_RATE,
+        );
+        if dsp_chain.is_empty() {
+            info!("ℹ️ {:?} audio '{}' captured raw (no DSP stages enabled)", device_type, device.name);
+        }
 
         // CRITICAL FIX: Initialize persistent resampler to preserve energy across chunks
         // Creating a new resampler per chunk causes energy amplification and incorrect output sizes
@@ -375,9 +395,7 @@ impl AudioCapture {
             resampler: Arc::new(std::sync::Mutex::new(resampler)),
             resampler_input_buffer: Arc::new(std::sync::Mutex::new(Vec::with_capacity(RESAMPLER_CHUNK_SIZE * 2))),
             resampler_chunk_size: RESAMPLER_CHUNK_SIZE,
-            noise_suppressor: Arc::new(std::sync::Mutex::new(noise_suppressor)),
-            high_pass_filter: Arc::new(std::sync::Mutex::new(high_pass_filter)),
-            normalizer: Arc::new(std::sync::Mutex::new(normalizer)),
+            dsp_chain: Arc::new(std::sync::Mutex::new(dsp_chain)),
             // Using global recording time for sync
         }
     }
@@ -508,65 +526,18 @@ impl AudioCapture {
             }
         }
 
-        // AUDIO ENHANCEMENT PIPELINE (Microphone Only)
-        // Processing order is critical: high-pass → noise suppression → normalization
-        // This ensures noise is removed before being amplified by the normalizer
-        if matches!(self.device_type, DeviceType::Microphone) {
-            // STEP 1: Apply high-pass filter to remove low-frequency rumble (< 80 Hz)
-            if let Ok(mut hpf_lock) = self.high_pass_filter.lock() {
-                if let Some(ref mut filter) = *hpf_lock {
-                    mono_data = filter.process(&mono_data);
-                }
-            }
-
-            // STEP 2: Apply RNNoise noise suppression (10-15 dB reduction) - CONDITIONAL
-            if super::ffmpeg_mixer::RNNOISE_APPLY_ENABLED {
-                if let Ok(mut ns_lock) = self.noise_suppressor.lock() {
-                    if let Some(ref mut suppressor) = *ns_lock {
-                        let before_len = mono_data.len();
-                        mono_data = suppressor.process(&mono_data);
-                        let after_len = mono_data.len();
-
-                        // CRITICAL MONITORING: Track buffer health
-                        let chunk_id = self.chunk_counter.load(std::sync::atomic::Ordering::SeqCst);
-                        if chunk_id % 100 == 0 {
-                            let buffered = suppressor.buffered_samples();
-                            let length_delta = (before_len as i32 - after_len as i32).abs();
-
-                            debug!("🔇 Noise suppression health: in={}, out={}, delta={}, buffered={}, RMS={:.4}",
-                                   before_len, after_len, length_delta, buffered,
-                                   if !mono_data.is_empty() {
-                                       (mono_data.iter().map(|&x| x * x).sum::<f32>() / mono_data.len() as f32).sqrt()
-                                   } else { 0.0 });
-
-                            // WARN if accumulating samples (potential latency buildup)
-                            if buffered > 1000 {
-                                warn!("⚠️ RNNoise accumulating samples: {} buffered (potential latency issue!)",
-                                      buffered);
-                            }
-
-                            // WARN if significant length mismatch
-                            if length_delta > 50 {
-                                warn!("⚠️ RNNoise length mismatch: input={} output={} (delta={})",
-                                      before_len, after_len, length_delta);
-                            }
-                        }
-                    }
-                }
-            }
-
-            // STEP 3: Apply EBU R128 normalization (professional loudness standard)
-            if let Ok(mut normalizer_lock) = self.normalizer.lock() {
-                if let Some(ref mut normalizer) = *normalizer_lock {
-                    mono_data = normalizer.normalize_loudness(&mono_data);
-
-                    // Log normalization occasionally for debugging
-                    let chunk_id = self.chunk_counter.load(std::sync::atomic::Ordering::SeqCst);
-                    if chunk_id % 200 == 0 && !mono_data.is_empty() {
-                        let rms = (mono_data.iter().map(|&x| x * x).sum::<f32>() / mono_data.len() as f32).sqrt();
-                        let peak = mono_data.iter().map(|&x| x.abs()).fold(0.0f32, f32::max);
-                        debug!("🎤 After normalization chunk {}: RMS={:.4}, Peak={:.4}", chunk_id, rms, peak);
-                    }
+        // AUDIO ENHANCEMENT PIPELINE: the source's DSP chain, in the user's order
+        // (each stage is timed; see dsp_chain.rs for the published per-stage costs)
+        if let Ok(mut chain) = self.dsp_chain.lock() {
+            if !chain.is_empty() {
+                mono_data = chain.process(mono_data);
+
+                // Log levels after processing occasionally for debugging
+                let chunk_id = self.chunk_counter.load(std::sync::atomic::Ordering::SeqCst);
+                if chunk_id % 200 == 0 && !mono_data.is_empty() {
+                    let rms = (mono_data.iter().map(|&x| x * x).sum::<f32>() / mono_data.len() as f32).sqrt();
+                    let peak = mono_data.iter().map(|&x| x.abs()).fold(0.0f32, f32::max);
+                    debug!("🎤 [{:?}] After DSP chunk {}: RMS={:.4}, Peak={:.4}", self.device_type, chunk_id, rms, peak);
                 }
             }
         }
@@ -681,7 +652,11 @@ pub struct AudioPipeline {
     receiver: mpsc::UnboundedReceiver<AudioChunk>,
     transcription_sender: mpsc::UnboundedSender<AudioChunk>,
     state: Arc<RecordingState>,
+    // Mixed mode: VAD over the mixed stream. Dual mode: VAD over the microphone only
     vad_processor: ContinuousVadProcessor,
+    // Dual mode only: dedicated VAD for system audio so remote speech is segmented separately
+    system_vad_processor: Option<ContinuousVadProcessor>,
+    channel_mode: TranscriptionChannelMode,
     sample_rate: u32,
     chunk_id_counter: u64,
     // Performance optimization: reduce logging frequency
@@ -689,11 +664,12 @@ pub struct AudioPipeline {
     processed_chunks: u64,
     // Smart batching for audio metrics
     metrics_batcher: Option<AudioMetricsBatcher>,
-    // PROFESSIONAL AUDIO MIXING: Ring buffer + RMS-based mixer
-    ring_buffer: AudioMixerRingBuffer,
-    mixer: ProfessionalAudioMixer,
+    // Mixing stage: legacy ring buffer + mixer, or the audio_v2 engine (selected per recording)
+    engines: MixEngines,
     // Recording sender for pre-mixed audio
     recording_sender_for_mixed: Option<mpsc::UnboundedSender<AudioChunk>>,
+    // Recording sender for the system audio alone (dual-channel mode)
+    recording_sender_for_system: Option<mpsc::UnboundedSender<AudioChunk>>,
 }
 
 impl AudioPipeline {
@@ -707,7 +683,9 @@ impl AudioPipeline {
         mic_device_kind: super::device_detection::InputDeviceKind,
         system_device_name: String,
         system_device_kind: super::device_detection::InputDeviceKind,
-    ) -> Self {
+        channel_mode: TranscriptionChannelMode,
+        engine_bridge: LegacyBridge,
+    ) -> Result<Self> {
         // Log device characteristics for adaptive buffering
         info!("🎛️ AudioPipeline initializing with device characteristics:");
         info!("   Mic: '{}' ({:?}) - Buffer: {:?}",
@@ -719,36 +697,56 @@ impl AudioPipeline {
         // For now, we log it for monitoring and potential optimization
         let _ = (mic_device_name, mic_device_kind, system_device_name, system_device_kind);
 
-        // Create VAD processor with balanced redemption time for speech accumulation
-        // The VAD processor now handles 48kHz->16kHz resampling internally
-        // This bridges natural pauses without excessive fragmentation
-        // For mac os core audio, 900ms, for windows 400ms seems good
-
-        let redemption_time = if cfg!(target_os = "macos") { 400 } else { 400 };
+        // Create VAD processor with the user's VAD settings (thresholds, redemption time,
+        // padding), read once per recording. The VAD processor handles 48kHz->16kHz
+        // resampling internally.
+        let vad_settings = super::vad_settings::current();
+        info!("🎚️ VAD settings: preset={:?}, thresholds={:.2}/{:.2}, redemption={}ms",
+              vad_settings.preset, vad_settings.positive_speech_threshold,
+              vad_settings.negative_speech_threshold, vad_settings.redemption_time_ms);
 
-        let vad_processor = match ContinuousVadProcessor::new(sample_rate, redemption_time) {
+        let vad_processor = match ContinuousVadProcessor::new(sample_rate, &vad_settings) {
             Ok(processor) => {
                 info!("VAD-driven pipeline: VAD segments will be sent directly to Whisper (no time-based accumulation)");
                 processor
             }
             Err(e) => {
                 error!("Failed to create VAD processor: {}", e);
-                panic!("VAD processor creation failed: {}", e);
+                return Err(anyhow::anyhow!("VAD processor creation failed: {}", e));
+            }
+        };
+
+        // Dual-channel mode: system audio gets its own VAD session so segment
+        // boundaries (and speaker tags) never blend the two sources
+        let system_vad_processor = match channel_mode {
+            TranscriptionChannelMode::Mixed => None,
+            TranscriptionChannelMode::Dual => {
+                match ContinuousVadProcessor::new(sample_rate, &vad_settings) {
+                    Ok(processor) => {
+                        info!("Dual-channel pipeline: mic and system audio are segmented and transcribed separately");
+                        Some(processor)
+                    }
+                    Err(e) => {
+                        error!("Failed to create system VAD processor: {}", e);
+                        return Err(anyhow::anyhow!("System VAD processor creation failed: {}", e));
+                    }
+                }
             }
         };
 
-        // Initialize professional audio mixing components
-        let ring_buffer = AudioMixerRingBuffer::new(sample_rate);
-        let mixer = ProfessionalAudioMixer::new(sample_rate);
+        // Initialize the mixing engine(s) selected in the audio engine settings
+        let engines = engine_bridge.create_engines(sample_rate);
 
         // Note: target_chunk_duration_ms is ignored - VAD controls segmentation now
         let _ = target_chunk_duration_ms;
 
-        Self {
+        Ok(Self {
             receiver,
             transcription_sender,
             state,
             vad_processor,
+            system_vad_processor,
+            channel_mode,
             sample_rate,
             chunk_id_counter: 0,
             // Performance optimization: reduce logging frequency
@@ -756,11 +754,10 @@ impl AudioPipeline {
             processed_chunks: 0,
             // Initialize metrics batcher for smart batching
             metrics_batcher: Some(AudioMetricsBatcher::new()),
-            // Initialize professional audio mixing
-            ring_buffer,
-            mixer,
+            engines,
             recording_sender_for_mixed: None,  // Will be set by manager
-        }
+            recording_sender_for_system: None,
+        })
     }
 
     /// Run the VAD-driven audio processing pipeline
@@ -814,69 +811,19 @@ impl AudioPipeline {
                         self.last_summary_time = std::time::Instant::now();
                     }
 
-                    // STEP 1: Add raw audio to ring buffer for mixing
+                    // STEP 1: Add raw audio to the mixing engine
                     // Microphone audio is already normalized at capture level (AudioCapture)
                     // System audio remains raw
-                    self.ring_buffer.add_samples(chunk.device_type.clone(), chunk.data);
-
-                    // STEP 2: Mix audio in fixed windows when both streams have sufficient data
-                    while self.ring_buffer.can_mix() {
-                        if let Some((mic_window, sys_window)) = self.ring_buffer.extract_window() {
-                            // Simple mixing without aggressive ducking
-                            let mixed_clean = self.mixer.mix_window(&mic_window, &sys_window);
-
-                            // NO POST-GAIN NEEDED: Microphone already normalized by EBU R128 to -23 LUFS
-                            // This is broadcast-standard loudness (Netflix/YouTube/Spotify level)
-                            // System audio at natural levels
-                            // Previous 2x gain was causing excessive limiting/distortion
-                            let mixed_with_gain = mixed_clean;
-
-                            // STEP 3: Send mixed audio for transcription (VAD + Whisper)
-                            match self.vad_processor.process_audio(&mixed_with_gain) {
-                                Ok(speech_segments) => {
-                                    for segment in speech_segments {
-                                        let duration_ms = segment.end_timestamp_ms - segment.start_timestamp_ms;
-
-                                        if segment.samples.len() >= 800 {  // Minimum 50ms at 16kHz - matches Parakeet capability
-                                            info!("📤 Sending VAD segment: {:.1}ms, {} samples",
-                                                  duration_ms, segment.samples.len());
-
-                                            let transcription_chunk = AudioChunk {
-                                                data: segment.samples,
-                                                sample_rate: 16000,
-                                                timestamp: segment.start_timestamp_ms / 1000.0,
-                                                chunk_id: self.chunk_id_counter,
-                                                device_type: DeviceType::Microphone,  // Mixed audio
-                                            };
-
-                                            if let Err(e) = self.transcription_sender.send(transcription_chunk) {
-                                                warn!("Failed to send VAD segment: {}", e);
-                                            } else {
-                                                self.chunk_id_counter += 1;
-                                            }
-                                        } else {
-                                            debug!("⏭️ Dropping short VAD segment: {:.1}ms ({} samples < 800)",
-                                                   duration_ms, segment.samples.len());
-                                        }
-                                    }
-                                }
-                                Err(e) => {
-                                    warn!("⚠️ VAD error: {}", e);
-                                }
-                            }
+                    // Hybrid mode: the shadow engine mixes a copy for quality comparison only
+                    if let Some(shadow) = self.engines.shadow.as_mut() {
+                        shadow.add_samples(chunk.device_type.clone(), chunk.timestamp, chunk.data.clone());
+                        while shadow.next_window().is_some() {}
+                    }
+                    self.engines.primary.add_samples(chunk.device_type.clone(), chunk.timestamp, chunk.data);
 
-                            // STEP 4: Send mixed audio for recording (WAV file)
-                            if let Some(ref sender) = self.recording_sender_for_mixed {
-                                let recording_chunk = AudioChunk {
-                                    data: mixed_with_gain.clone(),
-                                    sample_rate: self.sample_rate,
-                                    timestamp: chunk.timestamp,
-                                    chunk_id: self.chunk_id_counter,
-                                    device_type: DeviceType::Microphone,  // Mixed audio
-                                };
-                                let _ = sender.send(recording_chunk);
-                            }
-                        }
+                    // STEP 2: Mix audio in windows once the engine has enough data
+                    while let Some(window) = self.engines.primary.next_window() {
+                        self.process_mixed_window(window, chunk.timestamp);
                     }
                 }
                 Ok(None) => {
@@ -893,51 +840,155 @@ impl AudioPipeline {
         // Flush any remaining VAD segments
         self.flush_remaining_audio()?;
 
+        // Quality metrics of this recording, per engine
+        self.engines.publish_metrics();
+
         info!("VAD-driven audio pipeline ended");
         Ok(())
     }
 
+    /// Send one mixed window to transcription (VAD) and to the recording
+    fn process_mixed_window(&mut self, window: MixedWindow, timestamp: f64) {
+        let MixedWindow { mic: mic_window, system: sys_window, mixed: mixed_clean } = window;
+
+        // NO POST-GAIN NEEDED: Microphone already normalized by EBU R128 to -23 LUFS
+        // This is broadcast-standard loudness (Netflix/YouTube/Spotify level)
+        // System audio at natural levels
+        // Previous 2x gain was causing excessive limiting/distortion
+        let mixed_with_gain = mixed_clean;
+
+        // STEP 3: Send speech for transcription (VAD + Whisper)
+        // Mixed mode segments the blended stream; dual mode keeps each source separate
+        match self.channel_mode {
+            TranscriptionChannelMode::Mixed => {
+                match self.vad_processor.process_audio(&mixed_with_gain) {
+                    Ok(speech_segments) => {
+                        self.send_speech_segments(speech_segments, DeviceType::Microphone, false);
+                    }
+                    Err(e) => {
+                        warn!("⚠️ VAD error: {}", e);
+                    }
+                }
+            }
+            TranscriptionChannelMode::Dual => {
+                match self.vad_processor.process_audio(&mic_window) {
+                    Ok(speech_segments) => {
+                        self.send_speech_segments(speech_segments, DeviceType::Microphone, false);
+                    }
+                    Err(e) => {
+                        warn!("⚠️ Mic VAD error: {}", e);
+                    }
+                }
+
+                let system_result = self.system_vad_processor
+                    .as_mut()
+                    .map(|vad| vad.process_audio(&sys_window));
+                match system_result {
+                    Some(Ok(speech_segments)) => {
+                        self.send_speech_segments(speech_segments, DeviceType::System, false);
+                    }
+                    Some(Err(e)) => {
+                        warn!("⚠️ System VAD error: {}", e);
+                    }
+                    None => {}
+                }
+
+                if let Some(ref sender) = self.recording_sender_for_system {
+                    let _ = sender.send(AudioChunk {
+                        data: sys_window,
+                        sample_rate: self.sample_rate,
+                        timestamp,
+                        chunk_id: self.chunk_id_counter,
+                        device_type: DeviceType::System,
+                    });
+                }
+            }
+        }
+
+        // STEP 4: Send mixed audio for recording (WAV file)
+        if let Some(ref sender) = self.recording_sender_for_mixed {
+            let recording_chunk = AudioChunk {
+                data: mixed_with_gain,
+                sample_rate: self.sample_rate,
+                timestamp,
+                chunk_id: self.chunk_id_counter,
+                device_type: DeviceType::Microphone,  // Mixed audio
+            };
+            let _ = sender.send(recording_chunk);
+        }
+    }
+
     fn flush_remaining_audio(&mut self) -> Result<()> {
         info!("Flushing remaining audio from pipeline (processed {} chunks)", self.processed_chunks);
 
+        // Partial last window still buffered in the engine (the legacy engine drops it)
+        if let Some(shadow) = self.engines.shadow.as_mut() {
+            shadow.flush();
+        }
+        if let Some(window) = self.engines.primary.flush() {
+            self.process_mixed_window(window, self.state.get_recording_duration().unwrap_or(0.0));
+        }
+
         // Flush any remaining audio from VAD processor and send segments to transcription
         match self.vad_processor.flush() {
             Ok(final_segments) => {
-                for segment in final_segments {
-                    let duration_ms = segment.end_timestamp_ms - segment.start_timestamp_ms;
-
-                    // Send segments >= 50ms (800 samples at 16kHz) - matches main pipeline filter
-                    if segment.samples.len() >= 800 {
-                        info!("📤 Sending final VAD segment to Whisper: {:.1}ms duration, {} samples",
-                              duration_ms, segment.samples.len());
-
-                        let transcription_chunk = AudioChunk {
-                            data: segment.samples,
-                            sample_rate: 16000,
-                            timestamp: segment.start_timestamp_ms / 1000.0,
-                            chunk_id: self.chunk_id_counter,
-                            device_type: DeviceType::Microphone,
-                        };
-
-                        if let Err(e) = self.transcription_sender.send(transcription_chunk) {
-                            warn!("Failed to send final VAD segment: {}", e);
-                        } else {
-                            self.chunk_id_counter += 1;
-                        }
-                    } else {
-                        info!("⏭️ Skipping short final segment: {:.1}ms ({} samples < 800)",
-                              duration_ms, segment.samples.len());
-                    }
-                }
+                self.send_speech_segments(final_segments, DeviceType::Microphone, true);
             }
             Err(e) => {
                 warn!("Failed to flush VAD processor: {}", e);
             }
         }
 
+        // Dual-channel mode: flush the system audio VAD as well
+        let system_result = self.system_vad_processor.as_mut().map(|vad| vad.flush());
+        match system_result {
+            Some(Ok(final_segments)) => {
+                self.send_speech_segments(final_segments, DeviceType::System, true);
+            }
+            Some(Err(e)) => {
+                warn!("Failed to flush system VAD processor: {}", e);
+            }
+            None => {}
+        }
+
         Ok(())
     }
 
+    /// Send VAD speech segments to the transcription queue, tagged with their source
+    /// Segments shorter than 50ms (800 samples at 16kHz) are dropped - matches Parakeet capability
+    fn send_speech_segments(&mut self, segments: Vec<SpeechSegment>, device_type: DeviceType, is_final: bool) {
+        for segment in segments {
+            let duration_ms = segment.end_timestamp_ms - segment.start_timestamp_ms;
+
+            if segment.samples.len() >= 800 {
+                if is_final {
+                    info!("📤 Sending final {:?} VAD segment to Whisper: {:.1}ms duration, {} samples",
+                          device_type, duration_ms, segment.samples.len());
+                } else {
+                    info!("📤 Sending {:?} VAD segment: {:.1}ms, {} samples",
+                          device_type, duration_ms, segment.samples.len());
+                }
+
+                let transcription_chunk = AudioChunk {
+                    data: segment.samples,
+                    sample_rate: 16000,
+                    timestamp: segment.start_timestamp_ms / 1000.0,
+                    chunk_id: self.chunk_id_counter,
+                    device_type: device_type.clone(),
+                };
+
+                if let Err(e) = self.transcription_sender.send(transcription_chunk) {
+                    warn!("Failed to send VAD segment: {}", e);
+                } else {
+                    self.chunk_id_counter += 1;
+                }
+            } else {
+                debug!("⏭️ Dropping short {:?} VAD segment: {:.1}ms ({} samples < 800)",
+                       device_type, duration_ms, segment.samples.len());
+            }
+        }
+    }
+
 }
 
 /// Simple audio pipeline manager
@@ -962,10 +1013,13 @@ impl AudioPipelineManager {
         target_chunk_duration_ms: u32,
         sample_rate: u32,
         recording_sender: Option<mpsc::UnboundedSender<AudioChunk>>,
+        system_recording_sender: Option<mpsc::UnboundedSender<AudioChunk>>,
         mic_device_name: String,
         mic_device_kind: super::device_detection::InputDeviceKind,
         system_device_name: String,
         system_device_kind: super::device_detection::InputDeviceKind,
+        channel_mode: TranscriptionChannelMode,
+        engine_bridge: LegacyBridge,
     ) -> Result<()> {
         // Log device information for adaptive buffering
         info!("🎙️ Starting pipeline with device info:");
@@ -989,11 +1043,14 @@ impl AudioPipelineManager {
             mic_device_kind,
             system_device_name,
             system_device_kind,
-        );
+            channel_mode,
+            engine_bridge,
+        )?;
 
         // CRITICAL FIX: Connect recording sender to receive pre-mixed audio
         // This ensures both mic AND system audio are captured in recordings
         pipeline.recording_sender_for_mixed = recording_sender;
+        pipeline.recording_sender_for_system = system_recording_sender;
 
         let handle = tokio::spawn(async move {
             pipeline.run().await
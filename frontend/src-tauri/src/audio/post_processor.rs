@@ -2,6 +2,15 @@ use std::sync::Arc;
 use tokio::sync::mpsc;
 use anyhow::Result;
 use log::{info, warn, error};
+use crate::audio::transcription::WordTiming;
+use crate::vocabulary::Vocabulary;
+
+/// Largest share of a term's letters that may differ in a near-miss spelling
+const MAX_EDIT_RATIO: f32 = 0.25;
+
+/// Shorter terms (letters and digits only) are fixed only when they match apart from case,
+/// spacing and punctuation: too many ordinary words are one edit away from them
+const MIN_FUZZY_TERM_CHARS: usize = 5;
 
 /// Post-processing request for transcript text
 #[derive(Debug, Clone)]
@@ -132,13 +141,14 @@ impl PostProcessor {
         let normalized = Self::normalize_text(&cleaned);
 
         // Step 4: Apply contextual improvements (if not partial)
-        let final_text = if !request.is_partial {
+        let improved = if !request.is_partial {
             Self::apply_contextual_improvements(&normalized)
         } else {
             normalized
         };
 
-        Ok(final_text)
+        // Custom vocabulary is corrected once, by the transcription workers
+        Ok(improved)
     }
 
     /// Clean repetitive text patterns (same as whisper_engine but moved to background)
@@ -273,4 +283,295 @@ impl Default for PostProcessor {
     fn default() -> Self {
         Self::new()
     }
+}
+
+/// A term as matched against transcribed words
+struct MatchTerm<'a> {
+    term: &'a str,
+    letters: Vec<char>,
+    consonants: Vec<char>,
+    word_count: usize,
+}
+
+/// `len` words starting at `start` that spell `term`
+struct VocabularyMatch<'a> {
+    start: usize,
+    len: usize,
+    term: &'a str,
+}
+
+/// Lowercase letters and digits of `text`
+fn match_letters(text: &str) -> Vec<char> {
+    text.chars()
+        .filter(|c| c.is_alphanumeric())
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+/// Rough sound of a spelling: its letters without vowels and doubled letters. Speech
+/// recognition mostly gets vowels wrong ("meetly", "petrava"); a different consonant
+/// ("march" for "Marco") is usually a different word.
+fn consonant_skeleton(letters: &[char]) -> Vec<char> {
+    let mut skeleton: Vec<char> = letters
+        .iter()
+        .copied()
+        .filter(|c| !matches!(c, 'a' | 'e' | 'i' | 'o' | 'u' | 'y'))
+        .collect();
+    skeleton.dedup();
+    skeleton
+}
+
+fn edit_distance(a: &[char], b: &[char]) -> usize {
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    let mut current = vec![0; b.len() + 1];
+    for (i, ca) in a.iter().enumerate() {
+        current[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(ca != cb);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        std::mem::swap(&mut previous, &mut current);
+    }
+    previous[b.len()]
+}
+
+/// Finds runs of words that are near-miss spellings of vocabulary terms. A run has as many
+/// words as the term, give or take one, so "kuber netes" can become "Kubernetes".
+/// Near misses must keep the first letter and the consonants and stay within
+/// `MAX_EDIT_RATIO` edits.
+fn find_vocabulary_matches<'a>(words: &[&str], terms: &'a [MatchTerm<'a>]) -> Vec<VocabularyMatch<'a>> {
+    let mut matches = Vec::new();
+    let mut i = 0;
+    while i < words.len() {
+        // (distance, len, term) of the closest match starting at word i
+        let mut best: Option<(usize, usize, &MatchTerm)> = None;
+        for term in terms {
+            let min_len = term.word_count.saturating_sub(1).max(1);
+            let max_len = (term.word_count + 1).min(words.len() - i);
+            let max_edits = if term.letters.len() >= MIN_FUZZY_TERM_CHARS {
+                (term.letters.len() as f32 * MAX_EDIT_RATIO) as usize
+            } else {
+                0
+            };
+            for len in min_len..=max_len {
+                let letters = match_letters(&words[i..i + len].concat());
+                if letters.is_empty() || letters[0] != term.letters[0] {
+                    continue;
+                }
+                if letters.len().abs_diff(term.letters.len()) > max_edits {
+                    continue;
+                }
+                let distance = edit_distance(&letters, &term.letters);
+                if distance > 0 && consonant_skeleton(&letters) != term.consonants {
+                    continue;
+                }
+                let better = match best {
+                    None => true,
+                    Some((d, l, _)) => distance < d || (distance == d && len > l),
+                };
+                if distance <= max_edits && better {
+                    best = Some((distance, len, term));
+                }
+            }
+        }
+
+        match best {
+            Some((_, len, term)) => {
+                matches.push(VocabularyMatch { start: i, len, term: term.term });
+                i += len;
+            }
+            None => i += 1,
+        }
+    }
+    matches
+}
+
+fn match_terms(vocabulary: &Vocabulary) -> Vec<MatchTerm<'_>> {
+    vocabulary
+        .terms()
+        .iter()
+        .map(|term| {
+            let letters = match_letters(term);
+            MatchTerm {
+                term,
+                consonants: consonant_skeleton(&letters),
+                letters,
+                word_count: term.split_whitespace().count(),
+            }
+        })
+        .filter(|term| !term.letters.is_empty())
+        .collect()
+}
+
+/// `term` with the punctuation around the matched words kept ("meetly," -> "Meetily,")
+fn spell_term(term: &str, first_word: &str, last_word: &str) -> String {
+    let prefix: String = first_word.chars().take_while(|c| !c.is_alphanumeric()).collect();
+    let suffix: String = last_word
+        .chars()
+        .rev()
+        .take_while(|c| !c.is_alphanumeric())
+        .collect::<Vec<_>>()
+        .into_iter()
+        .rev()
+        .collect();
+    format!("{}{}{}", prefix, term, suffix)
+}
+
+/// Byte ranges of the whitespace-separated words of `text`
+fn word_ranges(text: &str) -> Vec<(usize, usize)> {
+    let mut ranges = Vec::new();
+    let mut start = None;
+    for (i, c) in text.char_indices() {
+        match (c.is_whitespace(), start) {
+            (true, Some(s)) => {
+                ranges.push((s, i));
+                start = None;
+            }
+            (false, None) => start = Some(i),
+            _ => {}
+        }
+    }
+    if let Some(s) = start {
+        ranges.push((s, text.len()));
+    }
+    ranges
+}
+
+/// Replaces near-miss spellings of custom vocabulary terms in a transcript. Only the
+/// matched words change; the whitespace around them (line breaks included) is kept.
+pub fn correct_vocabulary(text: &str, vocabulary: &Vocabulary) -> String {
+    if vocabulary.is_empty() {
+        return text.to_string();
+    }
+    let terms = match_terms(vocabulary);
+    let ranges = word_ranges(text);
+    let words: Vec<&str> = ranges.iter().map(|&(start, end)| &text[start..end]).collect();
+    let matches = find_vocabulary_matches(&words, &terms);
+    if matches.is_empty() {
+        return text.to_string();
+    }
+
+    let mut corrected = String::with_capacity(text.len());
+    let mut next = 0;
+    for m in matches {
+        let (start, _) = ranges[m.start];
+        let (_, end) = ranges[m.start + m.len - 1];
+        corrected.push_str(&text[next..start]);
+        corrected.push_str(&spell_term(m.term, words[m.start], words[m.start + m.len - 1]));
+        next = end;
+    }
+    corrected.push_str(&text[next..]);
+    corrected
+}
+
+/// Applies the same corrections to word timings. Words that together spell one term are
+/// merged into a single entry spanning them.
+pub fn correct_vocabulary_words(words: Vec<WordTiming>, vocabulary: &Vocabulary) -> Vec<WordTiming> {
+    if vocabulary.is_empty() || words.is_empty() {
+        return words;
+    }
+    let terms = match_terms(vocabulary);
+    let texts: Vec<&str> = words.iter().map(|w| w.word.as_str()).collect();
+    let matches: Vec<(usize, usize, String)> = find_vocabulary_matches(&texts, &terms)
+        .into_iter()
+        .map(|m| {
+            let spelled = spell_term(m.term, texts[m.start], texts[m.start + m.len - 1]);
+            (m.start, m.len, spelled)
+        })
+        .collect();
+    if matches.is_empty() {
+        return words;
+    }
+
+    let mut corrected = Vec::with_capacity(words.len());
+    let mut words = words.into_iter().enumerate().peekable();
+    for (start, len, spelled) in matches {
+        while let Some((_, word)) = words.next_if(|(index, _)| *index < start) {
+            corrected.push(word);
+        }
+        let run: Vec<WordTiming> = words.by_ref().take(len).map(|(_, word)| word).collect();
+        let (Some(first), Some(last)) = (run.first(), run.last()) else {
+            continue;
+        };
+        corrected.push(WordTiming {
+            word: spelled,
+            start: first.start,
+            end: last.end,
+            // The lowest score of the run: the words still deserve a second look
+            probability: run.iter().filter_map(|w| w.probability).reduce(f32::min),
+        });
+    }
+    corrected.extend(words.map(|(_, word)| word));
+    corrected
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn vocabulary(terms: &[&str]) -> Vocabulary {
+        Vocabulary::new(terms.iter().map(|t| t.to_string()))
+    }
+
+    #[test]
+    fn test_correct_vocabulary() {
+        let vocabulary = vocabulary(&["Meetily", "Kubernetes", "Anya Petrova", "gRPC"]);
+
+        assert_eq!(
+            correct_vocabulary("We deployed meetly on cooper netes.", &vocabulary),
+            "We deployed Meetily on cooper netes."
+        );
+        assert_eq!(
+            correct_vocabulary("ask anya petrava about the kuber netes cluster", &vocabulary),
+            "ask Anya Petrova about the Kubernetes cluster"
+        );
+        // Short terms only match exactly (apart from case and punctuation)
+        assert_eq!(correct_vocabulary("the grpc api, not grp", &vocabulary), "the gRPC api, not grp");
+        // Ordinary words are left alone
+        assert_eq!(
+            correct_vocabulary("The meeting is on Monday.", &vocabulary),
+            "The meeting is on Monday."
+        );
+        assert_eq!(correct_vocabulary("meetly", &Vocabulary::default()), "meetly");
+    }
+
+    #[test]
+    fn test_correct_vocabulary_keeps_layout_and_real_words() {
+        let vocabulary = vocabulary(&["Meetily", "Marco"]);
+
+        // Whitespace outside the corrected words is untouched
+        assert_eq!(
+            correct_vocabulary("Try  meetly\nnow.", &vocabulary),
+            "Try  Meetily\nnow."
+        );
+        assert_eq!(correct_vocabulary("a  b\n", &vocabulary), "a  b\n");
+        // One edit away, but a different consonant: a different word
+        assert_eq!(
+            correct_vocabulary("We start in March with marco.", &vocabulary),
+            "We start in March with Marco."
+        );
+    }
+
+    #[test]
+    fn test_correct_vocabulary_words() {
+        let word = |text: &str, start: f64, probability: f32| WordTiming {
+            word: text.to_string(),
+            start,
+            end: start + 0.5,
+            probability: Some(probability),
+        };
+        let words = vec![
+            word("the", 0.0, 0.9),
+            word("kuber", 0.5, 0.4),
+            word("netes,", 1.0, 0.3),
+            word("cluster", 1.5, 0.9),
+        ];
+
+        let corrected = correct_vocabulary_words(words, &vocabulary(&["Kubernetes"]));
+        assert_eq!(corrected.len(), 3);
+        assert_eq!(corrected[1].word, "Kubernetes,");
+        assert_eq!((corrected[1].start, corrected[1].end), (0.5, 1.5));
+        assert_eq!(corrected[1].probability, Some(0.3));
+        assert_eq!(corrected[2].word, "cluster");
+    }
 }
\ No newline at end of file
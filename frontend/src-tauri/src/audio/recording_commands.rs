@@ -77,6 +77,9 @@ pub async fn start_recording_with_meeting_name<R: Runtime>(
         return Err("Recording already in progress".to_string());
     }
 
+    // Held until IS_RECORDING is set so an offline job can't swap the model meanwhile
+    let _engine_claim = transcription::EngineClaim::try_acquire("start recording")?;
+
     // Validate that transcription models are available before starting recording
     info!("🔍 Validating transcription model availability before starting recording...");
     if let Err(validation_error) = transcription::validate_transcription_model_ready(&app).await {
@@ -110,6 +113,11 @@ pub async fn start_recording_with_meeting_name<R: Runtime>(
     });
     manager.set_meeting_name(Some(effective_meeting_name));
 
+    // Apply transcription channel mode (mixed stream vs dual-channel speaker attribution)
+    let channel_mode = super::recording_preferences::get_transcription_channel_mode();
+    info!("🎚️ Transcription channel mode: {:?}", channel_mode);
+    manager.set_transcription_channel_mode(channel_mode);
+
     // Set up error callback
     let app_for_error = app.clone();
     manager.set_error_callback(move |error| {
@@ -134,7 +142,7 @@ pub async fn start_recording_with_meeting_name<R: Runtime>(
     reset_speech_detected_flag(); // Reset for new recording session
 
     // Start optimized parallel transcription task and store handle
-    let task_handle = transcription::start_transcription_task(app.clone(), transcription_receiver);
+    let task_handle = transcription::start_transcription_task(app.clone(), transcription_receiver, channel_mode);
     {
         let mut global_task = TRANSCRIPTION_TASK.lock().unwrap();
         *global_task = Some(task_handle);
@@ -159,6 +167,8 @@ pub async fn start_recording_with_meeting_name<R: Runtime>(
                     display_time: update.timestamp.clone(), // Use wall-clock timestamp for display
                     confidence: update.confidence,
                     sequence_id: update.sequence_id,
+                    source: Some(update.source.clone()),
+                    words: update.words.clone(),
                 };
 
                 // Save to recording manager
@@ -216,6 +226,9 @@ pub async fn start_recording_with_devices_and_meeting<R: Runtime>(
         return Err("Recording already in progress".to_string());
     }
 
+    // Held until IS_RECORDING is set so an offline job can't swap the model meanwhile
+    let _engine_claim = transcription::EngineClaim::try_acquire("start recording")?;
+
     // Validate that transcription models are available before starting recording
     info!("🔍 Validating transcription model availability before starting recording...");
     if let Err(validation_error) = transcription::validate_transcription_model_ready(&app).await {
@@ -265,6 +278,11 @@ pub async fn start_recording_with_devices_and_meeting<R: Runtime>(
     });
     manager.set_meeting_name(Some(effective_meeting_name));
 
+    // Apply transcription channel mode (mixed stream vs dual-channel speaker attribution)
+    let channel_mode = super::recording_preferences::get_transcription_channel_mode();
+    info!("🎚️ Transcription channel mode: {:?}", channel_mode);
+    manager.set_transcription_channel_mode(channel_mode);
+
     // Set up error callback
     let app_for_error = app.clone();
     manager.set_error_callback(move |error| {
@@ -289,7 +307,7 @@ pub async fn start_recording_with_devices_and_meeting<R: Runtime>(
     reset_speech_detected_flag(); // Reset for new recording session
 
     // Start optimized parallel transcription task and store handle
-    let task_handle = transcription::start_transcription_task(app.clone(), transcription_receiver);
+    let task_handle = transcription::start_transcription_task(app.clone(), transcription_receiver, channel_mode);
     {
         let mut global_task = TRANSCRIPTION_TASK.lock().unwrap();
         *global_task = Some(task_handle);
@@ -314,6 +332,8 @@ pub async fn start_recording_with_devices_and_meeting<R: Runtime>(
                     display_time: update.timestamp.clone(), // Use wall-clock timestamp for display
                     confidence: update.confidence,
                     sequence_id: update.sequence_id,
+                    source: Some(update.source.clone()),
+                    words: update.words.clone(),
                 };
 
                 // Save to recording manager
@@ -510,6 +530,9 @@ pub async fn stop_recording<R: Runtime>(
                 warn!("⚠️ No Parakeet engine found to unload model");
             }
         }
+        Some(provider) if crate::audio::transcription::cloud_provider::is_cloud_provider(provider) => {
+            info!("☁️ Cloud transcription provider '{}' - no local model to unload", provider);
+        }
         _ => {
             // Default to Whisper
             info!("🎤 Unloading Whisper model...");
@@ -10,11 +10,13 @@ use super::devices::get_safe_recording_devices_macos;
 
 #[cfg(not(target_os = "macos"))]
 use super::devices::{default_input_device, default_output_device};
-use super::recording_state::{RecordingState, AudioChunk, DeviceType as RecordingDeviceType};
+use super::recording_state::{RecordingState, AudioChunk, DeviceType as RecordingDeviceType, TranscriptionChannelMode};
 use super::pipeline::AudioPipelineManager;
 use super::stream::AudioStreamManager;
 use super::recording_saver::RecordingSaver;
 use super::device_monitor::{AudioDeviceMonitor, DeviceEvent, DeviceMonitorType};
+use crate::audio_v2::compatibility::LegacyBridge;
+use crate::audio_v2::settings as audio_engine_settings;
 
 /// Stream manager type enumeration
 pub enum StreamManagerType {
@@ -29,6 +31,7 @@ pub struct RecordingManager {
     recording_saver: RecordingSaver,
     device_monitor: Option<AudioDeviceMonitor>,
     device_event_receiver: Option<mpsc::UnboundedReceiver<DeviceEvent>>,
+    channel_mode: TranscriptionChannelMode,
 }
 
 // SAFETY: RecordingManager contains types that we've marked as Send
@@ -49,6 +52,7 @@ impl RecordingManager {
             recording_saver: RecordingSaver::new(),
             device_monitor: Some(device_monitor),
             device_event_receiver: Some(device_event_receiver),
+            channel_mode: TranscriptionChannelMode::default(),
         }
     }
 
@@ -68,6 +72,11 @@ impl RecordingManager {
         // CRITICAL FIX: Create recording sender for pre-mixed audio from pipeline
         // Pipeline will mix mic + system audio professionally and send to this channel
         let recording_sender = self.recording_saver.start_accumulation();
+        // Dual-channel recordings also keep the system audio alone, for speaker diarization
+        let system_recording_sender = match self.channel_mode {
+            TranscriptionChannelMode::Dual => self.recording_saver.start_system_track(),
+            TranscriptionChannelMode::Mixed => None,
+        };
 
         // Start recording state first
         self.state.start_recording()?;
@@ -96,7 +105,7 @@ impl RecordingManager {
             system_device.as_ref().map(|d| d.name.clone())
         );
 
-        // Start the audio processing pipeline with FFmpeg adaptive mixer
+        // Start the audio processing pipeline with the mixing engine from the audio engine settings
         // Pipeline will: 1) Mix mic+system audio with adaptive buffering, 2) Send mixed to recording_sender,
         // 3) Apply VAD and send speech segments to transcription
         self.pipeline_manager.start(
@@ -105,10 +114,14 @@ impl RecordingManager {
             0, // Ignored - using dynamic sizing internally
             48000, // 48kHz sample rate
             Some(recording_sender), // CRITICAL: Pass recording sender to receive pre-mixed audio
+            system_recording_sender,
             mic_name,
             mic_kind,
             sys_name,
             sys_kind,
+            self.channel_mode,
+            LegacyBridge::from_settings(&audio_engine_settings::current())
+                .with_meeting_folder(self.recording_saver.get_meeting_folder().cloned()),
         )?;
 
         // Give the pipeline a moment to fully initialize before starting streams
@@ -413,6 +426,17 @@ impl RecordingManager {
         self.recording_saver.set_meeting_name(name);
     }
 
+    /// Set whether mic and system audio are transcribed as separate channels
+    /// Must be called before the recording starts
+    pub fn set_transcription_channel_mode(&mut self, mode: TranscriptionChannelMode) {
+        self.channel_mode = mode;
+    }
+
+    /// Get the transcription channel mode for this recording session
+    pub fn get_transcription_channel_mode(&self) -> TranscriptionChannelMode {
+        self.channel_mode
+    }
+
     /// Add a structured transcript segment to be saved later
     pub fn add_transcript_segment(&self, segment: super::recording_saver::TranscriptSegment) {
         self.recording_saver.add_transcript_segment(segment);
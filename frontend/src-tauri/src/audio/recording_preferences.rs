@@ -1,8 +1,12 @@
 use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
+use std::sync::Mutex;
 use tauri::{AppHandle, Runtime};
+use tauri_plugin_store::StoreExt;
 use log::{info, warn};
 
+use crate::audio::recording_state::TranscriptionChannelMode;
+
 #[cfg(target_os = "macos")]
 use log::error;
 use anyhow::Result;
@@ -18,6 +22,62 @@ pub struct RecordingPreferences {
     #[cfg(target_os = "macos")]
     #[serde(default)]
     pub system_audio_backend: Option<String>,
+    /// Mixed (single stream) or dual-channel (mic = "You", system = "Remote") transcription
+    #[serde(default)]
+    pub transcription_channel_mode: TranscriptionChannelMode,
+}
+
+const STORE_FILE: &str = "store.json";
+const CHANNEL_MODE_STORE_KEY: &str = "transcriptionChannelMode";
+
+static TRANSCRIPTION_CHANNEL_MODE: Mutex<TranscriptionChannelMode> =
+    Mutex::new(TranscriptionChannelMode::Mixed);
+
+/// Get the transcription channel mode used for new recordings
+pub fn get_transcription_channel_mode() -> TranscriptionChannelMode {
+    TRANSCRIPTION_CHANNEL_MODE
+        .lock()
+        .map(|mode| *mode)
+        .unwrap_or_default()
+}
+
+/// Set the transcription channel mode used for new recordings
+pub fn set_transcription_channel_mode(mode: TranscriptionChannelMode) {
+    if let Ok(mut current) = TRANSCRIPTION_CHANNEL_MODE.lock() {
+        *current = mode;
+    }
+}
+
+/// Load the persisted channel mode into the global setting (called on startup)
+pub fn load_transcription_channel_mode<R: Runtime>(app: &AppHandle<R>) {
+    let store = match app.store(STORE_FILE) {
+        Ok(store) => store,
+        Err(e) => {
+            warn!("Failed to open store for transcription channel mode: {}", e);
+            return;
+        }
+    };
+    let Some(value) = store.get(CHANNEL_MODE_STORE_KEY) else {
+        return;
+    };
+
+    match serde_json::from_value::<TranscriptionChannelMode>(value) {
+        Ok(mode) => {
+            info!("🎚️ Loaded transcription channel mode: {:?}", mode);
+            set_transcription_channel_mode(mode);
+        }
+        Err(e) => warn!("Failed to parse stored transcription channel mode: {}", e),
+    }
+}
+
+fn save_transcription_channel_mode<R: Runtime>(app: &AppHandle<R>, mode: TranscriptionChannelMode) -> Result<()> {
+    let store = app
+        .store(STORE_FILE)
+        .map_err(|e| anyhow::anyhow!("Failed to open store: {}", e))?;
+    store.set(CHANNEL_MODE_STORE_KEY, serde_json::to_value(mode)?);
+    store
+        .save()
+        .map_err(|e| anyhow::anyhow!("Failed to save transcription channel mode: {}", e))
 }
 
 impl Default for RecordingPreferences {
@@ -28,6 +88,7 @@ impl Default for RecordingPreferences {
             file_format: "mp4".to_string(),
             #[cfg(target_os = "macos")]
             system_audio_backend: Some("coreaudio".to_string()),
+            transcription_channel_mode: TranscriptionChannelMode::default(),
         }
     }
 }
@@ -93,7 +154,7 @@ pub async fn load_recording_preferences<R: Runtime>(
     // Try to load from Tauri store, fallback to defaults
     // For now, return defaults - can be enhanced to use tauri-plugin-store
     #[cfg(target_os = "macos")]
-    let prefs = {
+    let mut prefs = {
         let mut p = RecordingPreferences::default();
         let backend = crate::audio::capture::get_current_backend();
         p.system_audio_backend = Some(backend.to_string());
@@ -101,16 +162,18 @@ pub async fn load_recording_preferences<R: Runtime>(
     };
 
     #[cfg(not(target_os = "macos"))]
-    let prefs = RecordingPreferences::default();
+    let mut prefs = RecordingPreferences::default();
+
+    prefs.transcription_channel_mode = get_transcription_channel_mode();
 
-    info!("Loaded recording preferences: save_folder={:?}, auto_save={}, format={}",
-          prefs.save_folder, prefs.auto_save, prefs.file_format);
+    info!("Loaded recording preferences: save_folder={:?}, auto_save={}, format={}, channel_mode={:?}",
+          prefs.save_folder, prefs.auto_save, prefs.file_format, prefs.transcription_channel_mode);
     Ok(prefs)
 }
 
 /// Save recording preferences to store
 pub async fn save_recording_preferences<R: Runtime>(
-    _app: &AppHandle<R>,
+    app: &AppHandle<R>,
     preferences: &RecordingPreferences,
 ) -> Result<()> {
     // For now, just log - can be enhanced to use tauri-plugin-store
@@ -126,6 +189,11 @@ pub async fn save_recording_preferences<R: Runtime>(
         }
     }
 
+    // Persist the channel mode and make it the mode of the next recording
+    info!("Setting transcription channel mode to: {:?}", preferences.transcription_channel_mode);
+    save_transcription_channel_mode(app, preferences.transcription_channel_mode)?;
+    set_transcription_channel_mode(preferences.transcription_channel_mode);
+
     // Ensure the directory exists
     ensure_recordings_directory(&preferences.save_folder)?;
 
@@ -1,3 +1,4 @@
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::{Arc, Mutex};
 use tokio::sync::Mutex as AsyncMutex;
 use anyhow::Result;
@@ -5,12 +6,21 @@ use log::{info, warn, error};
 use tauri::{AppHandle, Runtime, Emitter};
 use tokio::sync::mpsc;
 use serde::{Serialize, Deserialize};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 use super::recording_state::AudioChunk;
 use super::recording_preferences::load_recording_preferences;
 use super::audio_processing::create_meeting_folder;
 use super::incremental_saver::IncrementalAudioSaver;
+use super::transcription::WordTiming;
+use crate::audio_v2::compatibility::{self, AudioQualityMetrics};
+
+/// System audio alone, saved next to the mixed recording in dual-channel mode
+pub const SYSTEM_AUDIO_FILE: &str = "system_audio.mp4";
+const SYSTEM_CHECKPOINTS_DIR: &str = ".checkpoints_system";
+
+/// How often new transcript segments are written to transcripts.json during a recording
+const TRANSCRIPT_FLUSH_INTERVAL: std::time::Duration = std::time::Duration::from_secs(5);
 
 /// Structured transcript segment for JSON export
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -21,8 +31,16 @@ pub struct TranscriptSegment {
     pub audio_end_time: f64,   // Seconds from recording start
     pub duration: f64,          // Segment duration in seconds
     pub display_time: String,   // Formatted time for display like "[02:15]"
-    pub confidence: f32,
+    /// Decoder confidence (0-1), absent for providers without scores
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub confidence: Option<f32>,
     pub sequence_id: u64,
+    /// Speaker tag: "You" (mic) / "Remote" (system) in dual-channel mode, "Audio" when mixed
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source: Option<String>,
+    /// Word-level timings (recording-relative seconds)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub words: Vec<WordTiming>,
 }
 
 /// Meeting metadata structure
@@ -39,6 +57,9 @@ pub struct MeetingMetadata {
     pub transcript_file: String,
     pub sample_rate: u32,
     pub status: String,  // "recording", "completed", "error"
+    /// Mixing engine quality metrics (one entry per engine, two in hybrid mode)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub audio_quality: Vec<AudioQualityMetrics>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -47,13 +68,88 @@ pub struct DeviceInfo {
     pub system_audio: Option<String>,
 }
 
+/// Path of the recording inside a meeting folder, as named by its metadata.json
+/// (imported meetings may keep their original container). Falls back to `audio.mp4`.
+pub fn meeting_audio_path(folder: &Path) -> PathBuf {
+    let audio_file = std::fs::read_to_string(folder.join("metadata.json"))
+        .ok()
+        .and_then(|content| serde_json::from_str::<MeetingMetadata>(&content).ok())
+        .map(|metadata| metadata.audio_file)
+        .filter(|name| !name.is_empty())
+        .unwrap_or_else(|| "audio.mp4".to_string());
+    folder.join(audio_file)
+}
+
+/// Path of the system-audio track inside a meeting folder (only dual-channel recordings
+/// have one)
+pub fn meeting_system_audio_path(folder: &Path) -> PathBuf {
+    folder.join(SYSTEM_AUDIO_FILE)
+}
+
+/// Write transcript segments to `folder/transcripts.json` (atomic write with temp file)
+pub fn write_transcripts_file(folder: &Path, segments: &[TranscriptSegment]) -> Result<()> {
+    info!("Writing {} transcript segments to JSON", segments.len());
+
+    let transcript_path = folder.join("transcripts.json");
+    let temp_path = folder.join(".transcripts.json.tmp");
+
+    // Create JSON structure
+    let json = serde_json::json!({
+        "version": "1.0",
+        "segments": segments,
+        "last_updated": chrono::Utc::now().to_rfc3339(),
+        "total_segments": segments.len()
+    });
+
+    // Serialize to pretty JSON string
+    let json_string = serde_json::to_string_pretty(&json)
+        .map_err(|e| {
+            error!("Failed to serialize transcripts to JSON: {}", e);
+            anyhow::anyhow!("JSON serialization failed: {}", e)
+        })?;
+
+    // Encrypted at rest when encryption is enabled and unlocked
+    let contents = crate::encryption::seal_bytes(json_string.into_bytes())
+        .map_err(|e| anyhow::anyhow!("Failed to encrypt transcripts: {}", e))?;
+
+    // Write to temp file with error handling
+    std::fs::write(&temp_path, &contents)
+        .map_err(|e| {
+            error!("Failed to write transcript temp file to {}: {}", temp_path.display(), e);
+            anyhow::anyhow!("Failed to write temp file: {}", e)
+        })?;
+
+    // Verify temp file was written correctly
+    if !temp_path.exists() {
+        error!("Temp transcript file does not exist after write: {}", temp_path.display());
+        return Err(anyhow::anyhow!("Temp file verification failed"));
+    }
+
+    // Atomic rename
+    std::fs::rename(&temp_path, &transcript_path)
+        .map_err(|e| {
+            error!("Failed to rename transcript file from {} to {}: {}",
+                   temp_path.display(), transcript_path.display(), e);
+            anyhow::anyhow!("Failed to rename transcript file: {}", e)
+        })?;
+
+    info!("✅ Successfully wrote transcripts.json with {} segments", segments.len());
+    Ok(())
+}
+
 /// New recording saver using incremental saving strategy
 pub struct RecordingSaver {
     incremental_saver: Option<Arc<AsyncMutex<IncrementalAudioSaver>>>,
+    /// System audio alone (dual-channel mode)
+    system_track_saver: Option<Arc<AsyncMutex<IncrementalAudioSaver>>>,
     meeting_folder: Option<PathBuf>,
     meeting_name: Option<String>,
     metadata: Option<MeetingMetadata>,
     transcript_segments: Arc<Mutex<Vec<TranscriptSegment>>>,
+    /// Set when segments changed since the last transcripts.json write
+    transcripts_dirty: Arc<AtomicBool>,
+    /// Serializes transcripts.json writes so an older snapshot never overwrites a newer one
+    transcripts_write_lock: Arc<Mutex<()>>,
     chunk_receiver: Option<mpsc::UnboundedReceiver<AudioChunk>>,
     is_saving: Arc<Mutex<bool>>,
 }
@@ -62,10 +158,13 @@ impl RecordingSaver {
     pub fn new() -> Self {
         Self {
             incremental_saver: None,
+            system_track_saver: None,
             meeting_folder: None,
             meeting_name: None,
             metadata: None,
             transcript_segments: Arc::new(Mutex::new(Vec::new())),
+            transcripts_dirty: Arc::new(AtomicBool::new(false)),
+            transcripts_write_lock: Arc::new(Mutex::new(())),
             chunk_receiver: None,
             is_saving: Arc::new(Mutex::new(false)),
         }
@@ -93,7 +192,7 @@ impl RecordingSaver {
     }
 
     /// Add or update a structured transcript segment (upserts based on sequence_id)
-    /// Also saves incrementally to disk
+    /// The flush task started by `start_accumulation` writes it to disk within a few seconds
     pub fn add_transcript_segment(&self, segment: TranscriptSegment) {
         if let Ok(mut segments) = self.transcript_segments.lock() {
             // Check if segment with same sequence_id exists (update it)
@@ -111,12 +210,7 @@ impl RecordingSaver {
             error!("Failed to lock transcript segments for adding segment {}", segment.id);
         }
 
-        // NEW: Save incrementally to disk
-        if let Some(folder) = &self.meeting_folder {
-            if let Err(e) = self.write_transcripts_json(folder) {
-                warn!("Failed to write incremental transcript update: {}", e);
-            }
-        }
+        self.transcripts_dirty.store(true, Ordering::SeqCst);
     }
 
     /// Legacy method for backward compatibility - converts text to basic segment
@@ -128,8 +222,10 @@ impl RecordingSaver {
             audio_end_time: 0.0,
             duration: 0.0,
             display_time: "[00:00]".to_string(),
-            confidence: 1.0,
+            confidence: None,
             sequence_id: 0,
+            source: None,
+            words: Vec::new(),
         };
         self.add_transcript_segment(segment);
     }
@@ -193,9 +289,77 @@ impl RecordingSaver {
             *is_saving = true;
         }
 
+        // Periodically flush transcript segments so they survive a crash
+        if let Some(folder) = self.meeting_folder.clone() {
+            let is_saving = self.is_saving.clone();
+            let dirty = self.transcripts_dirty.clone();
+            let segments = self.transcript_segments.clone();
+            let write_lock = self.transcripts_write_lock.clone();
+            tokio::spawn(async move {
+                let mut interval = tokio::time::interval(TRANSCRIPT_FLUSH_INTERVAL);
+                loop {
+                    interval.tick().await;
+                    if !is_saving.lock().map(|s| *s).unwrap_or(false) {
+                        break;
+                    }
+                    if !dirty.swap(false, Ordering::SeqCst) {
+                        continue;
+                    }
+                    let (folder, segments, write_lock) =
+                        (folder.clone(), segments.clone(), write_lock.clone());
+                    let written = tokio::task::spawn_blocking(move || {
+                        let _guard = write_lock
+                            .lock()
+                            .map_err(|_| anyhow::anyhow!("Transcript write lock poisoned"))?;
+                        let snapshot = segments
+                            .lock()
+                            .map(|segments| segments.clone())
+                            .map_err(|_| anyhow::anyhow!("Failed to lock transcript segments"))?;
+                        write_transcripts_file(&folder, &snapshot)
+                    })
+                    .await;
+                    if !matches!(written, Ok(Ok(()))) {
+                        warn!("Failed to flush transcript segments, will retry");
+                        dirty.store(true, Ordering::SeqCst);
+                    }
+                }
+                info!("Transcript flush task ended");
+            });
+        }
+
         sender
     }
 
+    /// Start saving the system audio alone next to the mixed recording (dual-channel mode),
+    /// so speaker diarization only hears the remote side. None without a meeting folder.
+    pub fn start_system_track(&mut self) -> Option<mpsc::UnboundedSender<AudioChunk>> {
+        let folder = self.meeting_folder.clone()?;
+        let saver = match IncrementalAudioSaver::for_track(folder, 48000, SYSTEM_CHECKPOINTS_DIR, SYSTEM_AUDIO_FILE) {
+            Ok(saver) => Arc::new(AsyncMutex::new(saver)),
+            Err(e) => {
+                warn!("Failed to set up the system audio track: {}", e);
+                return None;
+            }
+        };
+        self.system_track_saver = Some(saver.clone());
+
+        let (sender, mut receiver) = mpsc::unbounded_channel::<AudioChunk>();
+        let is_saving = self.is_saving.clone();
+        tokio::spawn(async move {
+            while let Some(chunk) = receiver.recv().await {
+                if !is_saving.lock().map(|s| *s).unwrap_or(false) {
+                    break;
+                }
+                if let Err(e) = saver.lock().await.add_chunk(chunk) {
+                    error!("Failed to add chunk to the system audio track: {}", e);
+                }
+            }
+            info!("System audio track task ended");
+        });
+        info!("🎧 Saving the system audio as a separate track");
+        Some(sender)
+    }
+
     /// Initialize meeting folder structure and metadata
     fn initialize_meeting_folder(&mut self, meeting_name: &str) -> Result<()> {
         // Load preferences to get base recordings folder
@@ -223,6 +387,7 @@ impl RecordingSaver {
             transcript_file: "transcripts.json".to_string(),
             sample_rate: 48000,
             status: "recording".to_string(),
+            audio_quality: Vec::new(),
         };
 
         // Write initial metadata.json
@@ -249,6 +414,11 @@ impl RecordingSaver {
 
     /// Write transcripts.json to disk (atomic write with temp file and validation)
     fn write_transcripts_json(&self, folder: &PathBuf) -> Result<()> {
+        let _guard = self
+            .transcripts_write_lock
+            .lock()
+            .map_err(|_| anyhow::anyhow!("Transcript write lock poisoned"))?;
+
         // Clone segments to avoid holding lock during I/O
         let segments_clone = if let Ok(segments) = self.transcript_segments.lock() {
             segments.clone()
@@ -257,49 +427,8 @@ impl RecordingSaver {
             return Err(anyhow::anyhow!("Failed to lock transcript segments"));
         };
 
-        info!("Writing {} transcript segments to JSON", segments_clone.len());
-
-        let transcript_path = folder.join("transcripts.json");
-        let temp_path = folder.join(".transcripts.json.tmp");
-
-        // Create JSON structure
-        let json = serde_json::json!({
-            "version": "1.0",
-            "segments": segments_clone,
-            "last_updated": chrono::Utc::now().to_rfc3339(),
-            "total_segments": segments_clone.len()
-        });
-
-        // Serialize to pretty JSON string
-        let json_string = serde_json::to_string_pretty(&json)
-            .map_err(|e| {
-                error!("Failed to serialize transcripts to JSON: {}", e);
-                anyhow::anyhow!("JSON serialization failed: {}", e)
-            })?;
-
-        // Write to temp file with error handling
-        std::fs::write(&temp_path, &json_string)
-            .map_err(|e| {
-                error!("Failed to write transcript temp file to {}: {}", temp_path.display(), e);
-                anyhow::anyhow!("Failed to write temp file: {}", e)
-            })?;
-
-        // Verify temp file was written correctly
-        if !temp_path.exists() {
-            error!("Temp transcript file does not exist after write: {}", temp_path.display());
-            return Err(anyhow::anyhow!("Temp file verification failed"));
-        }
-
-        // Atomic rename
-        std::fs::rename(&temp_path, &transcript_path)
-            .map_err(|e| {
-                error!("Failed to rename transcript file from {} to {}: {}",
-                       temp_path.display(), transcript_path.display(), e);
-                anyhow::anyhow!("Failed to rename transcript file: {}", e)
-            })?;
-
-        info!("✅ Successfully wrote transcripts.json with {} segments", segments_clone.len());
-        Ok(())
+        self.transcripts_dirty.store(false, Ordering::SeqCst);
+        write_transcripts_file(folder, &segments_clone)
     }
 
     // in frontend/src-tauri/src/audio/recording_saver.rs
@@ -367,6 +496,14 @@ impl RecordingSaver {
             return Err("No incremental saver initialized".to_string());
         };
 
+        // The system track is optional: diarization reports recordings without one
+        if let Some(saver_arc) = &self.system_track_saver {
+            match saver_arc.lock().await.finalize().await {
+                Ok(path) => info!("✅ Finalized system audio track: {}", path.display()),
+                Err(e) => warn!("Failed to finalize the system audio track: {}", e),
+            }
+        }
+
         // Save final transcripts.json with validation
         if let Some(folder) = &self.meeting_folder {
             if let Err(e) = self.write_transcripts_json(folder) {
@@ -387,6 +524,8 @@ impl RecordingSaver {
         if let (Some(folder), Some(mut metadata)) = (&self.meeting_folder, self.metadata.clone()) {
             metadata.status = "completed".to_string();
             metadata.completed_at = Some(chrono::Utc::now().to_rfc3339());
+            // The pipeline has stopped, so its engines have published their metrics
+            metadata.audio_quality = compatibility::quality_metrics_for_folder(folder);
 
             // Use actual recording duration from RecordingState (more accurate than transcript segments)
             // Falls back to last transcript segment if duration not provided
@@ -406,6 +545,10 @@ impl RecordingSaver {
             info!("✅ Metadata updated with duration: {:?}s", metadata.duration_seconds);
         }
 
+        if let Some(folder) = &self.meeting_folder {
+            crate::encryption::seal_meeting_folder(folder).await;
+        }
+
         // Emit save event with audio and transcript paths
         let save_event = serde_json::json!({
             "audio_file": final_audio_path.to_string_lossy(),
@@ -3,6 +3,7 @@ use std::sync::{Arc, Mutex};
 use std::time::Instant;
 use tokio::sync::mpsc;
 use anyhow::Result;
+use serde::{Deserialize, Serialize};
 
 use super::devices::AudioDevice;
 use super::buffer_pool::AudioBufferPool;
@@ -14,6 +15,28 @@ pub enum DeviceType {
     System,
 }
 
+impl DeviceType {
+    /// Speaker tag used for transcript segments in dual-channel mode
+    pub fn speaker_label(&self) -> &'static str {
+        match self {
+            DeviceType::Microphone => "You",
+            DeviceType::System => "Remote",
+        }
+    }
+}
+
+/// How captured sources are fed to VAD and transcription
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TranscriptionChannelMode {
+    /// Mic and system audio are mixed into one stream before VAD (single speaker tag)
+    #[default]
+    Mixed,
+    /// Mic and system audio each get their own VAD and transcription queue,
+    /// so segments can be tagged "You" (mic) or "Remote" (system)
+    Dual,
+}
+
 /// Audio chunk with metadata for processing
 #[derive(Debug, Clone)]
 pub struct AudioChunk {
@@ -0,0 +1,493 @@
+// audio/recovery.rs
+//
+// Recovery of recordings that never finished (app crash, power loss). Such folders still
+// have `status: "recording"` in metadata.json and/or unmerged audio checkpoints; their
+// transcript is whatever the periodic flush wrote to transcripts.json. Recovering merges
+// the checkpoints, optionally transcribes speech the live transcript missed, and saves
+// the meeting.
+
+use crate::audio::decoder::{decode_audio_file_16k_mono, TARGET_SAMPLE_RATE};
+use crate::audio::incremental_saver::{list_checkpoints, recover_checkpoints};
+use crate::audio::recording_preferences::load_recording_preferences;
+use crate::audio::recording_saver::{
+    meeting_audio_path, write_transcripts_file, MeetingMetadata, TranscriptSegment,
+};
+use crate::audio::transcription::batch::{
+    detect_speech_segments, display_timestamp, transcribe_speech_segments,
+};
+use crate::audio::transcription::engine::{
+    get_or_init_transcription_engine, validate_transcription_model_ready,
+};
+use crate::audio::vad::SpeechSegment;
+use crate::database::repositories::meeting::{MeetingsRepository, NewMeeting};
+use crate::database::repositories::transcript::TranscriptsRepository;
+use crate::export::import::read_meeting_folder;
+use crate::state::AppState;
+use crate::vocabulary::Vocabulary;
+use chrono::{DateTime, Utc};
+use log::{info, warn};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use tauri::{AppHandle, Emitter, Runtime};
+
+/// Speech segments that overlap the saved transcript by less than this share are re-transcribed
+const MIN_COVERED_SHARE: f64 = 0.5;
+
+/// Status of a folder whose recovery the user declined
+pub const DISCARDED_STATUS: &str = "discarded";
+
+/// A meeting folder whose recording was never finalized
+#[derive(Debug, Clone, Serialize)]
+pub struct InterruptedRecording {
+    pub folder_path: String,
+    pub meeting_name: Option<String>,
+    pub created_at: Option<String>,
+    /// Audio checkpoints that were never merged
+    pub checkpoint_count: usize,
+    /// Segments flushed to transcripts.json before the interruption
+    pub transcript_segments: usize,
+    /// Whether a merged recording already exists in the folder
+    pub has_audio: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RecoveryResult {
+    pub meeting_id: String,
+    pub meeting_name: String,
+    pub folder_path: String,
+    pub segment_count: usize,
+    /// Segments added by transcribing audio the live transcript missed
+    pub retranscribed_segments: usize,
+    pub audio_recovered: bool,
+    pub duration_seconds: Option<f64>,
+}
+
+fn emit_progress<R: Runtime>(
+    app: &AppHandle<R>,
+    folder_path: &str,
+    stage: &str,
+    processed: usize,
+    total: usize,
+) {
+    let percentage = if total > 0 {
+        (processed as f64 / total as f64 * 100.0) as u32
+    } else {
+        0
+    };
+    let _ = app.emit(
+        "recording-recovery-progress",
+        serde_json::json!({
+            "folder_path": folder_path,
+            "stage": stage,
+            "processed": processed,
+            "total": total,
+            "percentage": percentage,
+        }),
+    );
+}
+
+fn read_json(path: &Path) -> Option<serde_json::Value> {
+    crate::encryption::read_to_string(path)
+        .ok()
+        .and_then(|content| serde_json::from_str(&content).ok())
+}
+
+/// Describes `folder` if it holds an interrupted recording: metadata.json still says
+/// "recording", or audio checkpoints were left behind
+pub fn inspect_interrupted(folder: &Path) -> Option<InterruptedRecording> {
+    let metadata_path = folder.join("metadata.json");
+    if !metadata_path.is_file() {
+        return None;
+    }
+    // Read leniently: the crash may have happened while metadata.json was being written
+    let metadata = read_json(&metadata_path).unwrap_or_default();
+    let field = |name: &str| {
+        metadata
+            .get(name)
+            .and_then(|v| v.as_str())
+            .filter(|v| !v.is_empty())
+            .map(str::to_string)
+    };
+
+    let status = field("status");
+    let checkpoint_count = list_checkpoints(folder).len();
+    if status.as_deref() == Some(DISCARDED_STATUS)
+        || (status.as_deref() != Some("recording") && checkpoint_count == 0)
+    {
+        return None;
+    }
+
+    let transcript_segments = read_json(&folder.join("transcripts.json"))
+        .and_then(|json| json.get("segments").and_then(|s| s.as_array()).map(Vec::len))
+        .unwrap_or(0);
+
+    Some(InterruptedRecording {
+        folder_path: folder.to_string_lossy().to_string(),
+        meeting_name: field("meeting_name"),
+        created_at: field("created_at"),
+        checkpoint_count,
+        transcript_segments,
+        has_audio: meeting_audio_path(folder).is_file(),
+    })
+}
+
+/// Interrupted recordings directly inside `root`, oldest first. `active_folder` (the
+/// recording in progress, if any) is left out.
+pub fn find_interrupted_recordings(root: &Path, active_folder: Option<&Path>) -> Vec<InterruptedRecording> {
+    let Ok(entries) = std::fs::read_dir(root) else {
+        return Vec::new();
+    };
+    let active = active_folder.and_then(|p| p.canonicalize().ok());
+
+    let mut folders: Vec<PathBuf> = entries
+        .filter_map(Result::ok)
+        .map(|e| e.path())
+        .filter(|p| p.is_dir())
+        .filter(|p| active.is_none() || p.canonicalize().ok() != active)
+        .collect();
+    folders.sort();
+    folders.iter().filter_map(|p| inspect_interrupted(p)).collect()
+}
+
+/// Speech segments whose time range is mostly not covered by `covered` (start, end) ranges
+fn uncovered_speech(speech: Vec<SpeechSegment>, covered: &[(f64, f64)]) -> Vec<SpeechSegment> {
+    speech
+        .into_iter()
+        .filter(|segment| {
+            let start = segment.start_timestamp_ms / 1000.0;
+            let end = segment.end_timestamp_ms / 1000.0;
+            let length = end - start;
+            if length <= 0.0 {
+                return false;
+            }
+            let overlap: f64 = covered
+                .iter()
+                .map(|&(s, e)| (end.min(e) - start.max(s)).max(0.0))
+                .sum();
+            overlap / length < MIN_COVERED_SHARE
+        })
+        .collect()
+}
+
+fn read_saved_segments(folder: &Path, transcript_file: &str) -> Vec<TranscriptSegment> {
+    #[derive(Deserialize)]
+    struct SavedTranscripts {
+        segments: Vec<TranscriptSegment>,
+    }
+    crate::encryption::read_to_string(&folder.join(transcript_file))
+        .ok()
+        .and_then(|content| match serde_json::from_str::<SavedTranscripts>(&content) {
+            Ok(saved) => Some(saved.segments),
+            Err(e) => {
+                warn!("Unreadable {} in {}: {}", transcript_file, folder.display(), e);
+                None
+            }
+        })
+        .unwrap_or_default()
+}
+
+fn write_metadata<T: Serialize>(folder: &Path, metadata: &T) -> Result<(), String> {
+    let json = serde_json::to_string_pretty(metadata).map_err(|e| e.to_string())?;
+    let temp_path = folder.join(".metadata.json.tmp");
+    std::fs::write(&temp_path, json)
+        .and_then(|_| std::fs::rename(&temp_path, folder.join("metadata.json")))
+        .map_err(|e| format!("Failed to write metadata.json: {}", e))
+}
+
+/// Folder of the recording in progress, which scans must skip
+pub(crate) async fn active_recording_folder() -> Option<PathBuf> {
+    crate::audio::recording_commands::get_meeting_folder_path()
+        .await
+        .ok()
+        .flatten()
+        .map(PathBuf::from)
+}
+
+async fn recordings_root<R: Runtime>(app: &AppHandle<R>) -> Result<PathBuf, String> {
+    Ok(load_recording_preferences(app)
+        .await
+        .map_err(|e| format!("Failed to load recording preferences: {}", e))?
+        .save_folder)
+}
+
+/// Scans the recordings folder for interrupted recordings
+pub async fn scan_interrupted_recordings<R: Runtime>(
+    app: &AppHandle<R>,
+) -> Result<Vec<InterruptedRecording>, String> {
+    let root = recordings_root(app).await?;
+    let active = active_recording_folder().await;
+    tokio::task::spawn_blocking(move || find_interrupted_recordings(&root, active.as_deref()))
+        .await
+        .map_err(|e| format!("Recovery scan failed: {}", e))
+}
+
+/// Lists recordings that were interrupted before they could be saved
+#[tauri::command]
+pub async fn api_list_interrupted_recordings<R: Runtime>(
+    app: AppHandle<R>,
+) -> Result<Vec<InterruptedRecording>, String> {
+    scan_interrupted_recordings(&app).await
+}
+
+/// Recovers an interrupted recording: merges its audio checkpoints, transcribes speech
+/// the live transcript missed (when `retranscribe` is set) and saves the meeting.
+#[tauri::command]
+pub async fn api_recover_recording<R: Runtime>(
+    app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    folder_path: String,
+    retranscribe: Option<bool>,
+    language: Option<String>,
+) -> Result<RecoveryResult, String> {
+    let retranscribe = retranscribe.unwrap_or(false);
+    info!("🩹 Recovering interrupted recording {} (re-transcribe: {})", folder_path, retranscribe);
+
+    // Re-transcription shares the live engine, and the active folder must not be touched
+    if crate::audio::recording_commands::is_recording().await {
+        return Err("Cannot recover a recording while another recording is in progress".to_string());
+    }
+
+    let folder = PathBuf::from(&folder_path);
+    let interrupted = inspect_interrupted(&folder)
+        .ok_or_else(|| format!("{} is not an interrupted recording", folder_path))?;
+
+    let metadata_content = std::fs::read_to_string(folder.join("metadata.json"))
+        .map_err(|e| format!("Failed to read metadata.json: {}", e))?;
+    let mut metadata: MeetingMetadata = serde_json::from_str(&metadata_content)
+        .map_err(|e| format!("Invalid metadata.json: {}", e))?;
+    let recording_start = DateTime::parse_from_rfc3339(&metadata.created_at)
+        .ok()
+        .map(|t| t.with_timezone(&Utc));
+
+    if retranscribe {
+        validate_transcription_model_ready(&app).await?;
+    }
+
+    // 1. Audio
+    if interrupted.checkpoint_count > 0 {
+        emit_progress(&app, &folder_path, "merging_audio", 0, interrupted.checkpoint_count);
+        let merge_folder = folder.clone();
+        match tokio::task::spawn_blocking(move || recover_checkpoints(&merge_folder)).await {
+            Ok(Ok(output)) => {
+                metadata.audio_file = output
+                    .file_name()
+                    .map(|n| n.to_string_lossy().to_string())
+                    .unwrap_or_else(|| "audio.mp4".to_string());
+            }
+            // The transcript is still worth saving; the checkpoints stay for another try
+            Ok(Err(e)) => warn!("Failed to merge audio checkpoints of {}: {}", folder_path, e),
+            Err(e) => warn!("Checkpoint merge task failed for {}: {}", folder_path, e),
+        }
+    }
+    let audio_path = folder.join(&metadata.audio_file);
+    let audio_recovered = audio_path.is_file();
+
+    // 2. Transcript flushed during the recording, plus speech it missed
+    let mut segments = read_saved_segments(&folder, &metadata.transcript_file);
+    segments.retain(|s| !s.text.trim().is_empty());
+    let mut retranscribed_segments = 0;
+    let mut duration_seconds = None;
+
+    if retranscribe && audio_recovered {
+        emit_progress(&app, &folder_path, "decoding", 0, 0);
+        let decode_path = audio_path.clone();
+        let samples = tokio::task::spawn_blocking(move || decode_audio_file_16k_mono(&decode_path))
+            .await
+            .map_err(|e| format!("Decoding task failed: {}", e))?
+            .map_err(|e| format!("Failed to decode recovered audio: {}", e))?;
+        duration_seconds = Some(samples.len() as f64 / TARGET_SAMPLE_RATE as f64);
+
+        emit_progress(&app, &folder_path, "detecting_speech", 0, 0);
+        let covered: Vec<(f64, f64)> = segments
+            .iter()
+            .map(|s| (s.audio_start_time, s.audio_end_time))
+            .collect();
+        let missing = uncovered_speech(detect_speech_segments(samples).await?, &covered);
+        info!("🩹 {} speech segments are missing from the saved transcript", missing.len());
+
+        if !missing.is_empty() {
+            let engine = get_or_init_transcription_engine(&app).await?;
+            let language = language.or_else(crate::get_language_preference_internal);
+            let vocabulary = Vocabulary::load(state.db_manager.pool(), None).await;
+            let transcribed = transcribe_speech_segments(
+                &engine,
+                missing,
+                language,
+                &vocabulary,
+                |done, total| emit_progress(&app, &folder_path, "transcribing", done, total),
+            )
+            .await?;
+
+            let mut next_sequence = segments.iter().map(|s| s.sequence_id + 1).max().unwrap_or(0);
+            retranscribed_segments = transcribed.len();
+            for batch in transcribed {
+                segments.push(TranscriptSegment {
+                    id: format!("seg_{}", next_sequence),
+                    display_time: display_timestamp(recording_start, batch.audio_start_time),
+                    text: batch.text,
+                    audio_start_time: batch.audio_start_time,
+                    audio_end_time: batch.audio_end_time,
+                    duration: batch.audio_end_time - batch.audio_start_time,
+                    confidence: batch.confidence,
+                    sequence_id: next_sequence,
+                    source: None,
+                    words: batch.words,
+                });
+                next_sequence += 1;
+            }
+            segments.sort_by(|a, b| a.audio_start_time.total_cmp(&b.audio_start_time));
+        }
+    }
+
+    if segments.is_empty() && !audio_recovered {
+        return Err("Nothing to recover: no transcript and no audio".to_string());
+    }
+
+    // 3. Files, then the database
+    emit_progress(&app, &folder_path, "saving", 0, 0);
+    write_transcripts_file(&folder, &segments)
+        .map_err(|e| format!("Failed to write transcripts.json: {}", e))?;
+
+    let pool = state.db_manager.pool();
+    let existing = MeetingsRepository::find_existing(
+        pool,
+        metadata.meeting_id.as_deref(),
+        Some(folder_path.as_str()),
+    )
+    .await
+    .map_err(|e| format!("Failed to look up meeting: {}", e))?;
+    let meeting_id = existing
+        .clone()
+        .unwrap_or_else(|| format!("meeting-{}", uuid::Uuid::new_v4()));
+
+    let duration_seconds = duration_seconds.or_else(|| {
+        segments
+            .iter()
+            .map(|s| s.audio_end_time)
+            .max_by(f64::total_cmp)
+    });
+    metadata.meeting_id = Some(meeting_id.clone());
+    metadata.status = "completed".to_string();
+    metadata.completed_at = Some(Utc::now().to_rfc3339());
+    metadata.duration_seconds = duration_seconds;
+    write_metadata(&folder, &metadata)?;
+
+    // Same conversion as an import of the folder (display timestamps, empty segments)
+    let meeting = read_meeting_folder(&folder)?;
+    let saved = match &existing {
+        Some(id) => TranscriptsRepository::replace_transcripts(pool, id, &meeting.transcripts).await,
+        None => {
+            MeetingsRepository::insert_meeting(
+                pool,
+                NewMeeting {
+                    id: &meeting_id,
+                    title: &meeting.title,
+                    created_at: meeting.created_at,
+                    updated_at: Utc::now(),
+                    folder_path: Some(folder_path.as_str()),
+                    transcripts: &meeting.transcripts,
+                    summary: None,
+                },
+                false,
+            )
+            .await
+        }
+    };
+    // metadata.json is already complete, so a later rescan can still restore the meeting
+    saved.map_err(|e| format!("Failed to save recovered meeting: {}", e))?;
+    crate::encryption::seal_meeting_folder(&folder).await;
+
+    let result = RecoveryResult {
+        meeting_id,
+        meeting_name: meeting.title,
+        folder_path,
+        segment_count: meeting.transcripts.len(),
+        retranscribed_segments,
+        audio_recovered,
+        duration_seconds,
+    };
+    info!(
+        "✅ Recovered meeting {} ({} segments, {} re-transcribed)",
+        result.meeting_id, result.segment_count, result.retranscribed_segments
+    );
+    let _ = app.emit("recording-recovered", &result);
+    Ok(result)
+}
+
+/// Stops offering recovery for a folder. Nothing is deleted: the status becomes
+/// "discarded" and the checkpoints stay on disk for a manual recovery.
+#[tauri::command]
+pub async fn api_dismiss_interrupted_recording(folder_path: String) -> Result<(), String> {
+    let folder = PathBuf::from(&folder_path);
+    let content = std::fs::read_to_string(folder.join("metadata.json"))
+        .map_err(|e| format!("Failed to read metadata.json: {}", e))?;
+    let mut metadata: serde_json::Value =
+        serde_json::from_str(&content).map_err(|e| format!("Invalid metadata.json: {}", e))?;
+    metadata["status"] = serde_json::Value::from(DISCARDED_STATUS);
+    write_metadata(&folder, &metadata)?;
+    info!("Dismissed interrupted recording {}", folder_path);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn speech(start: f64, end: f64) -> SpeechSegment {
+        SpeechSegment {
+            samples: Vec::new(),
+            start_timestamp_ms: start * 1000.0,
+            end_timestamp_ms: end * 1000.0,
+            confidence: 0.9,
+        }
+    }
+
+    #[test]
+    fn test_uncovered_speech() {
+        let covered = [(0.0, 10.0), (20.0, 25.0)];
+        let missing = uncovered_speech(
+            vec![speech(1.0, 9.0), speech(8.0, 14.0), speech(24.0, 30.0), speech(40.0, 45.0)],
+            &covered,
+        );
+        let starts: Vec<f64> = missing.iter().map(|s| s.start_timestamp_ms / 1000.0).collect();
+        assert_eq!(starts, vec![8.0, 24.0, 40.0]);
+    }
+
+    #[test]
+    fn test_find_interrupted_recordings() {
+        let root = std::env::temp_dir().join(format!("meetily-recovery-test-{}", uuid::Uuid::new_v4()));
+        let crashed = root.join("Standup_2025-11-03_09-00");
+        let finished = root.join("Retro_2025-11-04_15-00");
+        let leftover = root.join("Planning_2025-11-05_10-00");
+        let active = root.join("Sync_2025-11-06_11-00");
+        for folder in [&crashed, &finished, &leftover, &active] {
+            std::fs::create_dir_all(folder).unwrap();
+        }
+        std::fs::write(
+            crashed.join("metadata.json"),
+            r#"{"meeting_name":"Standup","status":"recording"}"#,
+        )
+        .unwrap();
+        std::fs::write(
+            crashed.join("transcripts.json"),
+            r#"{"segments":[{"text":"a"},{"text":"b"}]}"#,
+        )
+        .unwrap();
+        std::fs::write(finished.join("metadata.json"), r#"{"status":"completed"}"#).unwrap();
+        // Completed, but a checkpoint was never merged
+        std::fs::write(leftover.join("metadata.json"), r#"{"status":"completed"}"#).unwrap();
+        std::fs::create_dir_all(leftover.join(".checkpoints")).unwrap();
+        std::fs::write(leftover.join(".checkpoints/audio_chunk_000.mp4"), b"").unwrap();
+        std::fs::write(active.join("metadata.json"), r#"{"status":"recording"}"#).unwrap();
+
+        let found = find_interrupted_recordings(&root, Some(&active));
+        std::fs::remove_dir_all(&root).unwrap();
+
+        assert_eq!(found.len(), 2);
+        assert_eq!(found[0].meeting_name, None);
+        assert_eq!(found[0].checkpoint_count, 1);
+        assert_eq!(found[1].meeting_name.as_deref(), Some("Standup"));
+        assert_eq!(found[1].transcript_segments, 2);
+        assert!(!found[1].has_audio);
+    }
+}
@@ -0,0 +1,204 @@
+// audio/transcription/batch.rs
+//
+// Offline (batch) transcription of complete audio files: VAD segmentation with
+// get_speech_chunks followed by sequential transcription of every speech segment.
+
+use super::engine::TranscriptionEngine;
+use super::provider::{TranscriptionError, WordTiming};
+use crate::api::TranscriptSegment;
+use crate::audio::post_processor::{correct_vocabulary, correct_vocabulary_words};
+use crate::audio::vad::{get_speech_chunks, SpeechSegment};
+use crate::audio::vad_settings;
+use crate::vocabulary::Vocabulary;
+use chrono::{DateTime, Utc};
+use log::{info, warn};
+
+/// Longer speech segments are split so every engine call stays within model limits
+const MAX_SEGMENT_SECONDS: usize = 30;
+
+/// Segments shorter than this are skipped (matches the live pipeline's minimum)
+const MIN_SEGMENT_SAMPLES: usize = 800;
+
+const SAMPLE_RATE: usize = 16000;
+
+/// One transcribed speech segment of an offline job (times are file-relative seconds)
+#[derive(Debug, Clone)]
+pub struct BatchSegment {
+    pub text: String,
+    pub audio_start_time: f64,
+    pub audio_end_time: f64,
+    pub confidence: Option<f32>,
+    pub words: Vec<WordTiming>,
+}
+
+impl BatchSegment {
+    /// Convert into an API transcript segment ready to be saved
+    pub fn into_transcript_segment(
+        self,
+        index: usize,
+        recording_start: Option<DateTime<Utc>>,
+    ) -> TranscriptSegment {
+        TranscriptSegment {
+            id: format!("seg_{}", index),
+            timestamp: display_timestamp(recording_start, self.audio_start_time),
+            text: self.text,
+            audio_start_time: Some(self.audio_start_time),
+            audio_end_time: Some(self.audio_end_time),
+            duration: Some(self.audio_end_time - self.audio_start_time),
+            source: None,
+            speaker: None,
+            confidence: self.confidence,
+            words: self.words,
+        }
+    }
+}
+
+/// Run VAD over a whole 16kHz mono file with the user's VAD settings (same as the live
+/// pipeline) and return speech segments (split to at most `MAX_SEGMENT_SECONDS`).
+/// CPU-bound, runs on the blocking pool.
+pub async fn detect_speech_segments(samples_16k: Vec<f32>) -> Result<Vec<SpeechSegment>, String> {
+    let settings = vad_settings::current();
+    let segments = tokio::task::spawn_blocking(move || {
+        get_speech_chunks(&samples_16k, &settings)
+    })
+    .await
+    .map_err(|e| format!("Speech detection task failed: {}", e))?
+    .map_err(|e| format!("Speech detection failed: {}", e))?;
+
+    Ok(segments
+        .into_iter()
+        .flat_map(split_long_segment)
+        .filter(|s| s.samples.len() >= MIN_SEGMENT_SAMPLES)
+        .collect())
+}
+
+/// Transcribe VAD speech segments one after another, biased towards and corrected with
+/// `vocabulary`. `on_progress(done, total)` is called after each segment.
+pub async fn transcribe_speech_segments<F>(
+    engine: &TranscriptionEngine,
+    segments: Vec<SpeechSegment>,
+    language: Option<String>,
+    vocabulary: &Vocabulary,
+    mut on_progress: F,
+) -> Result<Vec<BatchSegment>, String>
+where
+    F: FnMut(usize, usize),
+{
+    let total = segments.len();
+    let mut results = Vec::with_capacity(total);
+
+    info!(
+        "📼 Batch transcription of {} speech segments with {}",
+        total,
+        engine.provider_name()
+    );
+
+    let initial_prompt = vocabulary.initial_prompt();
+    for (index, segment) in segments.into_iter().enumerate() {
+        let start = segment.start_timestamp_ms / 1000.0;
+        let end = segment.end_timestamp_ms / 1000.0;
+
+        match engine
+            .transcribe(segment.samples, language.clone(), initial_prompt.as_deref())
+            .await
+        {
+            Ok(result) => {
+                let text = correct_vocabulary(result.text.trim(), vocabulary);
+                if !text.is_empty() {
+                    results.push(BatchSegment {
+                        text,
+                        audio_start_time: start,
+                        audio_end_time: end,
+                        confidence: result.confidence,
+                        words: correct_vocabulary_words(result.words.unwrap_or_default(), vocabulary)
+                            .into_iter()
+                            .map(|w| w.offset_by(start))
+                            .collect(),
+                    });
+                }
+            }
+            Err(TranscriptionError::AudioTooShort { .. }) => {}
+            Err(TranscriptionError::ModelNotLoaded) => {
+                return Err(TranscriptionError::ModelNotLoaded.to_string());
+            }
+            Err(e) => {
+                warn!(
+                    "Batch transcription failed for segment {:.1}s-{:.1}s: {}",
+                    start, end, e
+                );
+            }
+        }
+
+        on_progress(index + 1, total);
+    }
+
+    Ok(results)
+}
+
+/// Split a VAD segment longer than `MAX_SEGMENT_SECONDS` into consecutive pieces
+fn split_long_segment(segment: SpeechSegment) -> Vec<SpeechSegment> {
+    let max_samples = MAX_SEGMENT_SECONDS * SAMPLE_RATE;
+    if segment.samples.len() <= max_samples {
+        return vec![segment];
+    }
+
+    segment
+        .samples
+        .chunks(max_samples)
+        .enumerate()
+        .map(|(i, piece)| {
+            let start_ms =
+                segment.start_timestamp_ms + (i * max_samples) as f64 * 1000.0 / SAMPLE_RATE as f64;
+            SpeechSegment {
+                samples: piece.to_vec(),
+                start_timestamp_ms: start_ms,
+                end_timestamp_ms: start_ms + piece.len() as f64 * 1000.0 / SAMPLE_RATE as f64,
+                confidence: segment.confidence,
+            }
+        })
+        .collect()
+}
+
+/// Display timestamp for an offline segment: wall-clock time ("14:30:05", UTC like the
+/// live worker) when the recording start is known, otherwise recording time ("[02:15]").
+pub fn display_timestamp(recording_start: Option<DateTime<Utc>>, offset_seconds: f64) -> String {
+    match recording_start {
+        Some(start) => {
+            let at = start + chrono::Duration::milliseconds((offset_seconds * 1000.0) as i64);
+            at.format("%H:%M:%S").to_string()
+        }
+        None => {
+            let total_seconds = offset_seconds.max(0.0).floor() as u64;
+            format!("[{:02}:{:02}]", total_seconds / 60, total_seconds % 60)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_split_long_segment() {
+        let segment = SpeechSegment {
+            samples: vec![0.0; 70 * SAMPLE_RATE],
+            start_timestamp_ms: 5000.0,
+            end_timestamp_ms: 75000.0,
+            confidence: 0.9,
+        };
+
+        let pieces = split_long_segment(segment);
+        assert_eq!(pieces.len(), 3);
+        assert_eq!(pieces[0].start_timestamp_ms, 5000.0);
+        assert_eq!(pieces[1].start_timestamp_ms, 35000.0);
+        assert_eq!(pieces[2].end_timestamp_ms, 75000.0);
+    }
+
+    #[test]
+    fn test_display_timestamp() {
+        let start = Utc.with_ymd_and_hms(2025, 1, 1, 14, 30, 0).unwrap();
+        assert_eq!(display_timestamp(Some(start), 65.4), "14:31:05");
+        assert_eq!(display_timestamp(None, 135.9), "[02:15]");
+    }
+}
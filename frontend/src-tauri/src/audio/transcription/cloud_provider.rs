@@ -0,0 +1,203 @@
+// audio/transcription/cloud_provider.rs
+//
+// Shared pieces of the cloud transcription providers (Groq, OpenAI, Deepgram):
+// provider registry, WAV encoding of chunks and HTTP error mapping.
+
+use super::deepgram_provider::DeepgramProvider;
+use super::openai_compatible_provider::OpenAICompatibleProvider;
+use super::provider::{TranscriptionError, TranscriptionProvider};
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Providers whose transcription runs on a remote API (keys stored in `transcript_settings`)
+pub const CLOUD_PROVIDERS: &[&str] = &["groq", "openai", "deepgram"];
+
+pub const GROQ_BASE_URL: &str = "https://api.groq.com/openai/v1";
+pub const OPENAI_BASE_URL: &str = "https://api.openai.com/v1";
+pub const DEEPGRAM_BASE_URL: &str = "https://api.deepgram.com/v1";
+
+/// Upper bound for a single chunk upload + transcription round-trip
+pub const REQUEST_TIMEOUT: Duration = Duration::from_secs(60);
+
+pub fn is_cloud_provider(provider: &str) -> bool {
+    CLOUD_PROVIDERS.contains(&provider)
+}
+
+/// Build the cloud provider for a saved transcript config.
+/// Fails when the provider is unknown or the API key is missing.
+pub fn create_cloud_provider(
+    provider: &str,
+    model: &str,
+    api_key: Option<&str>,
+) -> Result<Arc<dyn TranscriptionProvider>, String> {
+    let api_key = api_key
+        .map(str::trim)
+        .filter(|key| !key.is_empty())
+        .ok_or_else(|| format!("No API key configured for transcription provider '{}'", provider))?
+        .to_string();
+
+    if model.trim().is_empty() {
+        return Err(format!("No model configured for transcription provider '{}'", provider));
+    }
+
+    let provider: Arc<dyn TranscriptionProvider> = match provider {
+        "groq" => Arc::new(OpenAICompatibleProvider::new(
+            "Groq",
+            GROQ_BASE_URL,
+            &api_key,
+            model,
+        )?),
+        "openai" => Arc::new(OpenAICompatibleProvider::new(
+            "OpenAI",
+            OPENAI_BASE_URL,
+            &api_key,
+            model,
+        )?),
+        "deepgram" => Arc::new(DeepgramProvider::new(DEEPGRAM_BASE_URL, &api_key, model)?),
+        other => {
+            return Err(format!(
+                "Transcription provider '{}' is not supported",
+                other
+            ))
+        }
+    };
+    Ok(provider)
+}
+
+pub(crate) fn build_http_client() -> Result<reqwest::Client, String> {
+    reqwest::Client::builder()
+        .timeout(REQUEST_TIMEOUT)
+        .build()
+        .map_err(|e| format!("Failed to create HTTP client: {}", e))
+}
+
+/// Encode 16kHz mono f32 samples as a 16-bit PCM WAV file
+pub fn encode_wav(samples: &[f32], sample_rate: u32) -> Vec<u8> {
+    let data_len = (samples.len() * 2) as u32;
+    let mut wav = Vec::with_capacity(44 + data_len as usize);
+
+    wav.extend_from_slice(b"RIFF");
+    wav.extend_from_slice(&(36 + data_len).to_le_bytes());
+    wav.extend_from_slice(b"WAVE");
+    wav.extend_from_slice(b"fmt ");
+    wav.extend_from_slice(&16u32.to_le_bytes()); // fmt chunk size
+    wav.extend_from_slice(&1u16.to_le_bytes()); // PCM
+    wav.extend_from_slice(&1u16.to_le_bytes()); // mono
+    wav.extend_from_slice(&sample_rate.to_le_bytes());
+    wav.extend_from_slice(&(sample_rate * 2).to_le_bytes()); // byte rate
+    wav.extend_from_slice(&2u16.to_le_bytes()); // block align
+    wav.extend_from_slice(&16u16.to_le_bytes()); // bits per sample
+    wav.extend_from_slice(b"data");
+    wav.extend_from_slice(&data_len.to_le_bytes());
+
+    for sample in samples {
+        let value = (sample.clamp(-1.0, 1.0) * i16::MAX as f32) as i16;
+        wav.extend_from_slice(&value.to_le_bytes());
+    }
+    wav
+}
+
+/// Turn a failed HTTP response into a transcription error with a readable message
+pub(crate) async fn error_from_response(
+    provider: &str,
+    response: reqwest::Response,
+) -> TranscriptionError {
+    let status = response.status();
+    let body = response.text().await.unwrap_or_default();
+    let detail = match status.as_u16() {
+        401 | 403 => "invalid API key".to_string(),
+        429 => "rate limit exceeded".to_string(),
+        _ => body.chars().take(300).collect(),
+    };
+    TranscriptionError::EngineFailed(format!("{} API error ({}): {}", provider, status, detail))
+}
+
+/// Language hint for remote APIs: `None` for automatic detection
+pub(crate) fn language_hint(language: Option<&str>) -> Option<&str> {
+    match language {
+        Some("auto") | Some("auto-translate") | Some("") | None => None,
+        Some(lang) => Some(lang),
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod test_server {
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpListener;
+
+    /// Minimal one-shot HTTP server: answers the first request with `status` and a JSON
+    /// `body` and hands back the raw request (headers + body) for assertions.
+    pub async fn serve_once(
+        status: u16,
+        body: &'static str,
+    ) -> (String, tokio::task::JoinHandle<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+
+        let handle = tokio::spawn(async move {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            let mut request = Vec::new();
+            let mut buf = [0u8; 8192];
+            loop {
+                let n = socket.read(&mut buf).await.unwrap();
+                if n == 0 {
+                    break;
+                }
+                request.extend_from_slice(&buf[..n]);
+                if request_complete(&request) {
+                    break;
+                }
+            }
+
+            let response = format!(
+                "HTTP/1.1 {} Mock\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                status,
+                body.len(),
+                body
+            );
+            socket.write_all(response.as_bytes()).await.unwrap();
+            String::from_utf8_lossy(&request).to_string()
+        });
+
+        (url, handle)
+    }
+
+    fn request_complete(request: &[u8]) -> bool {
+        let text = String::from_utf8_lossy(request);
+        let Some(header_end) = text.find("\r\n\r\n") else {
+            return false;
+        };
+        let content_length = text[..header_end]
+            .lines()
+            .find_map(|line| {
+                let (name, value) = line.split_once(':')?;
+                name.eq_ignore_ascii_case("content-length")
+                    .then(|| value.trim().parse::<usize>().ok())
+                    .flatten()
+            })
+            .unwrap_or(0);
+        request.len() >= header_end + 4 + content_length
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encode_wav_header() {
+        let wav = encode_wav(&[0.0, 1.0, -1.0], 16000);
+        assert_eq!(&wav[0..4], b"RIFF");
+        assert_eq!(&wav[8..12], b"WAVE");
+        assert_eq!(wav.len(), 44 + 6);
+        assert_eq!(i16::from_le_bytes([wav[46], wav[47]]), i16::MAX);
+    }
+
+    #[test]
+    fn test_create_cloud_provider_requires_key() {
+        assert!(create_cloud_provider("groq", "whisper-large-v3", None).is_err());
+        assert!(create_cloud_provider("groq", "whisper-large-v3", Some("  ")).is_err());
+        assert!(create_cloud_provider("elevenLabs", "model", Some("key")).is_err());
+        assert!(create_cloud_provider("deepgram", "nova-2", Some("key")).is_ok());
+    }
+}
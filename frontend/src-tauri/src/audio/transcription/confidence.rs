@@ -0,0 +1,169 @@
+// audio/transcription/confidence.rs
+//
+// Confidence scores from decoder probabilities, and the "low-confidence regions" reviewers
+// use to find parts of a meeting that were probably mis-transcribed.
+//
+// A segment's confidence is the geometric mean of its token probabilities (exp of the mean
+// log-probability), so one very unlikely token pulls the score down much more than in an
+// arithmetic mean. A word's probability is the mean of its tokens' probabilities.
+
+use serde::Serialize;
+
+/// Floor for probabilities before taking logs (a token with p = 0 would zero the segment)
+const MIN_PROBABILITY: f32 = 1e-6;
+
+/// Segments closer than this (seconds) are merged into one region
+const REGION_MERGE_GAP: f64 = 2.0;
+
+pub const DEFAULT_SEGMENT_THRESHOLD: f32 = 0.6;
+pub const DEFAULT_WORD_THRESHOLD: f32 = 0.4;
+
+/// Geometric mean of token probabilities, `None` without tokens
+pub fn sequence_confidence(probabilities: impl IntoIterator<Item = f32>) -> Option<f32> {
+    let (sum, count) = probabilities
+        .into_iter()
+        .filter(|p| p.is_finite())
+        .fold((0.0f64, 0usize), |(sum, count), p| {
+            (sum + (p.clamp(MIN_PROBABILITY, 1.0) as f64).ln(), count + 1)
+        });
+    if count == 0 {
+        return None;
+    }
+    Some((sum / count as f64).exp() as f32)
+}
+
+/// Softmax probability of the highest logit (the token a greedy decoder picks)
+pub fn argmax_probability(logits: &[f32]) -> Option<(usize, f32)> {
+    let (index, &max) = logits
+        .iter()
+        .enumerate()
+        .max_by(|(_, a), (_, b)| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal))?;
+    let sum: f32 = logits.iter().map(|&l| (l - max).exp()).sum();
+    Some((index, 1.0 / sum))
+}
+
+/// Confidence data of one stored segment
+#[derive(Debug, Clone)]
+pub struct SegmentConfidence {
+    pub transcript_id: String,
+    pub text: String,
+    pub start_time: f64,
+    pub end_time: f64,
+    pub confidence: Option<f32>,
+    /// (word, probability) in order
+    pub words: Vec<(String, Option<f32>)>,
+}
+
+/// A stretch of a meeting likely to contain transcription errors
+#[derive(Debug, Clone, Serialize)]
+pub struct LowConfidenceRegion {
+    pub start_time: f64,
+    pub end_time: f64,
+    pub transcript_ids: Vec<String>,
+    /// Lowest segment confidence in the region (None if only individual words were flagged)
+    pub confidence: Option<f32>,
+    /// Words below the word threshold, in order
+    pub uncertain_words: Vec<String>,
+    pub text: String,
+}
+
+/// Groups segments below `segment_threshold`, or containing words below `word_threshold`,
+/// into regions. `segments` must be ordered by start time. Segments without scores are
+/// never flagged.
+pub fn find_low_confidence_regions(
+    segments: &[SegmentConfidence],
+    segment_threshold: f32,
+    word_threshold: f32,
+) -> Vec<LowConfidenceRegion> {
+    let mut regions: Vec<LowConfidenceRegion> = Vec::new();
+
+    for segment in segments {
+        let low_segment = segment.confidence.is_some_and(|c| c < segment_threshold);
+        let uncertain_words: Vec<String> = segment
+            .words
+            .iter()
+            .filter(|(_, p)| p.is_some_and(|p| p < word_threshold))
+            .map(|(word, _)| word.clone())
+            .collect();
+        if !low_segment && uncertain_words.is_empty() {
+            continue;
+        }
+        let confidence = segment.confidence.filter(|_| low_segment);
+
+        match regions.last_mut() {
+            Some(region) if segment.start_time - region.end_time <= REGION_MERGE_GAP => {
+                region.end_time = region.end_time.max(segment.end_time);
+                region.transcript_ids.push(segment.transcript_id.clone());
+                region.confidence = match (region.confidence, confidence) {
+                    (Some(a), Some(b)) => Some(a.min(b)),
+                    (a, b) => a.or(b),
+                };
+                region.uncertain_words.extend(uncertain_words);
+                if !segment.text.is_empty() {
+                    region.text.push(' ');
+                    region.text.push_str(&segment.text);
+                }
+            }
+            _ => regions.push(LowConfidenceRegion {
+                start_time: segment.start_time,
+                end_time: segment.end_time,
+                transcript_ids: vec![segment.transcript_id.clone()],
+                confidence,
+                uncertain_words,
+                text: segment.text.clone(),
+            }),
+        }
+    }
+    regions
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn segment(id: &str, start: f64, confidence: Option<f32>, words: &[(&str, f32)]) -> SegmentConfidence {
+        SegmentConfidence {
+            transcript_id: id.to_string(),
+            text: id.to_string(),
+            start_time: start,
+            end_time: start + 3.0,
+            confidence,
+            words: words.iter().map(|(w, p)| (w.to_string(), Some(*p))).collect(),
+        }
+    }
+
+    #[test]
+    fn test_sequence_confidence_is_geometric_mean() {
+        assert_eq!(sequence_confidence(Vec::<f32>::new()), None);
+        assert!((sequence_confidence(vec![0.9, 0.9]).unwrap() - 0.9).abs() < 1e-5);
+        // One unlikely token dominates: arithmetic mean would be 0.5
+        let mixed = sequence_confidence(vec![0.99, 0.01]).unwrap();
+        assert!((mixed - (0.99f32 * 0.01).sqrt()).abs() < 1e-5);
+        assert!(sequence_confidence(vec![0.0]).unwrap() > 0.0);
+
+        let (index, p) = argmax_probability(&[1.0, 3.0, 1.0]).unwrap();
+        assert_eq!(index, 1);
+        let expected = 3.0f32.exp() / (2.0 * 1.0f32.exp() + 3.0f32.exp());
+        assert!((p - expected).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_find_low_confidence_regions() {
+        let segments = vec![
+            segment("ok", 0.0, Some(0.9), &[("fine", 0.95)]),
+            segment("low", 10.0, Some(0.4), &[("mumble", 0.2)]),
+            // Starts 1s after "low" ends: merged
+            segment("word", 14.0, Some(0.8), &[("clear", 0.9), ("Kubernetes", 0.3)]),
+            segment("unscored", 18.0, None, &[]),
+            segment("later", 40.0, Some(0.5), &[]),
+        ];
+
+        let regions = find_low_confidence_regions(&segments, 0.6, 0.4);
+        assert_eq!(regions.len(), 2);
+        assert_eq!(regions[0].transcript_ids, vec!["low", "word"]);
+        assert_eq!((regions[0].start_time, regions[0].end_time), (10.0, 17.0));
+        assert_eq!(regions[0].confidence, Some(0.4));
+        assert_eq!(regions[0].uncertain_words, vec!["mumble", "Kubernetes"]);
+        assert_eq!(regions[1].transcript_ids, vec!["later"]);
+    }
+}
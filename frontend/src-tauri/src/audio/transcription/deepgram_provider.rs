@@ -0,0 +1,211 @@
+// audio/transcription/deepgram_provider.rs
+//
+// Transcription provider for Deepgram's prerecorded audio API (`POST /listen`).
+
+use super::cloud_provider::{build_http_client, encode_wav, error_from_response, language_hint};
+use super::provider::{TranscriptionError, TranscriptionProvider, TranscriptResult, WordTiming};
+use async_trait::async_trait;
+use log::debug;
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize)]
+struct ListenResponse {
+    results: ListenResults,
+}
+
+#[derive(Debug, Deserialize)]
+struct ListenResults {
+    channels: Vec<ListenChannel>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ListenChannel {
+    alternatives: Vec<ListenAlternative>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ListenAlternative {
+    transcript: String,
+    #[serde(default)]
+    confidence: Option<f32>,
+    #[serde(default)]
+    words: Vec<ListenWord>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ListenWord {
+    word: String,
+    #[serde(default)]
+    punctuated_word: Option<String>,
+    start: f64,
+    end: f64,
+    #[serde(default)]
+    confidence: Option<f32>,
+}
+
+/// Deepgram prerecorded transcription provider
+pub struct DeepgramProvider {
+    base_url: String,
+    api_key: String,
+    model: String,
+    client: reqwest::Client,
+}
+
+impl DeepgramProvider {
+    pub fn new(base_url: &str, api_key: &str, model: &str) -> Result<Self, String> {
+        Ok(Self {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            api_key: api_key.to_string(),
+            model: model.to_string(),
+            client: build_http_client()?,
+        })
+    }
+}
+
+#[async_trait]
+impl TranscriptionProvider for DeepgramProvider {
+    async fn transcribe(
+        &self,
+        audio: Vec<f32>,
+        language: Option<String>,
+    ) -> std::result::Result<TranscriptResult, TranscriptionError> {
+        let url = format!("{}/listen", self.base_url);
+
+        let mut query: Vec<(&str, String)> = vec![
+            ("model", self.model.clone()),
+            ("smart_format", "true".to_string()),
+            ("punctuate", "true".to_string()),
+        ];
+        match language_hint(language.as_deref()) {
+            Some(lang) => query.push(("language", lang.to_string())),
+            None => query.push(("detect_language", "true".to_string())),
+        }
+
+        debug!("Deepgram transcription request: {} ({} samples)", url, audio.len());
+
+        let response = self
+            .client
+            .post(&url)
+            .query(&query)
+            .header("Authorization", format!("Token {}", self.api_key))
+            .header("Content-Type", "audio/wav")
+            .body(encode_wav(&audio, 16000))
+            .send()
+            .await
+            .map_err(|e| TranscriptionError::EngineFailed(format!("Deepgram request failed: {}", e)))?;
+
+        if !response.status().is_success() {
+            return Err(error_from_response("Deepgram", response).await);
+        }
+
+        let body: ListenResponse = response.json().await.map_err(|e| {
+            TranscriptionError::EngineFailed(format!("Invalid Deepgram response: {}", e))
+        })?;
+
+        Ok(parse_response(body))
+    }
+
+    async fn is_model_loaded(&self) -> bool {
+        // Nothing to load locally; the key was validated when the provider was created
+        true
+    }
+
+    async fn get_current_model(&self) -> Option<String> {
+        Some(self.model.clone())
+    }
+
+    fn provider_name(&self) -> &'static str {
+        "Deepgram"
+    }
+}
+
+fn parse_response(body: ListenResponse) -> TranscriptResult {
+    let Some(best) = body
+        .results
+        .channels
+        .into_iter()
+        .next()
+        .and_then(|channel| channel.alternatives.into_iter().next())
+    else {
+        return TranscriptResult {
+            text: String::new(),
+            confidence: None,
+            is_partial: false,
+            words: None,
+        };
+    };
+
+    let words = best
+        .words
+        .into_iter()
+        .map(|w| WordTiming {
+            word: w.punctuated_word.unwrap_or(w.word),
+            start: w.start,
+            end: w.end,
+            probability: w.confidence,
+        })
+        .collect();
+
+    TranscriptResult {
+        text: best.transcript.trim().to_string(),
+        confidence: best.confidence,
+        is_partial: false,
+        words: Some(words),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::audio::transcription::cloud_provider::test_server::serve_once;
+
+    const LISTEN_RESPONSE: &str = r#"{
+        "metadata": {"request_id": "abc"},
+        "results": {"channels": [{"alternatives": [{
+            "transcript": "hello there",
+            "confidence": 0.97,
+            "words": [
+                {"word": "hello", "punctuated_word": "Hello", "start": 0.1, "end": 0.4, "confidence": 0.99},
+                {"word": "there", "punctuated_word": "there.", "start": 0.5, "end": 0.8, "confidence": 0.95}
+            ]
+        }]}]}
+    }"#;
+
+    #[tokio::test]
+    async fn test_transcribe_against_mock_server() {
+        let (url, server) = serve_once(200, LISTEN_RESPONSE).await;
+        let provider = DeepgramProvider::new(&url, "dg-key", "nova-2").unwrap();
+
+        let result = provider
+            .transcribe(vec![0.0; 16000], Some("fr".to_string()))
+            .await
+            .unwrap();
+
+        assert_eq!(result.text, "hello there");
+        assert_eq!(result.confidence, Some(0.97));
+        let words = result.words.unwrap();
+        assert_eq!(words[0].word, "Hello");
+        assert_eq!(words[1].end, 0.8);
+        assert_eq!(words[1].probability, Some(0.95));
+
+        let request = server.await.unwrap();
+        assert!(request.starts_with("POST /listen?model=nova-2"));
+        assert!(request.contains("language=fr"));
+        assert!(request.to_lowercase().contains("authorization: token dg-key"));
+        assert!(request.to_lowercase().contains("content-type: audio/wav"));
+    }
+
+    #[tokio::test]
+    async fn test_auto_language_enables_detection() {
+        let (url, server) = serve_once(200, r#"{"results": {"channels": []}}"#).await;
+        let provider = DeepgramProvider::new(&url, "dg-key", "nova-2").unwrap();
+
+        let result = provider
+            .transcribe(vec![0.0; 16000], Some("auto".to_string()))
+            .await
+            .unwrap();
+
+        assert!(result.text.is_empty());
+        assert!(server.await.unwrap().contains("detect_language=true"));
+    }
+}
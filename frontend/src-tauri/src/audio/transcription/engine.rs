@@ -2,11 +2,51 @@
 //
 // TranscriptionEngine enum and model initialization/validation logic.
 
-use super::provider::TranscriptionProvider;
+use super::cloud_provider::{create_cloud_provider, is_cloud_provider};
+use super::parakeet_provider::{parakeet_confidence, parakeet_word_timings};
+use super::provider::{TranscriptResult, TranscriptionError, TranscriptionProvider};
+use crate::database::repositories::setting::SettingsRepository;
+use crate::state::AppState;
 use log::{info, warn};
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
 use tauri::{AppHandle, Manager, Runtime};
 
+// ============================================================================
+// SHARED ENGINE CLAIM
+// ============================================================================
+
+// Set while an offline job uses the shared engines or a recording is starting
+static ENGINES_CLAIMED: AtomicBool = AtomicBool::new(false);
+
+/// Exclusive claim on the shared Whisper/Parakeet engines, released on drop.
+///
+/// Offline jobs (re-transcription, import) hold it for their whole run and recording
+/// start holds it until `IS_RECORDING` is set, so a job never swaps the model under
+/// a live recording and a recording never starts in the middle of a job.
+pub struct EngineClaim(());
+
+impl EngineClaim {
+    pub fn try_acquire(purpose: &str) -> Result<Self, String> {
+        if ENGINES_CLAIMED
+            .compare_exchange(false, true, Ordering::SeqCst, Ordering::SeqCst)
+            .is_err()
+        {
+            return Err(format!(
+                "Cannot {} while a meeting is being transcribed in the background",
+                purpose
+            ));
+        }
+        Ok(Self(()))
+    }
+}
+
+impl Drop for EngineClaim {
+    fn drop(&mut self) {
+        ENGINES_CLAIMED.store(false, Ordering::SeqCst);
+    }
+}
+
 // ============================================================================
 // TRANSCRIPTION ENGINE ENUM
 // ============================================================================
@@ -45,16 +85,53 @@ impl TranscriptionEngine {
             Self::Provider(provider) => provider.provider_name(),
         }
     }
+
+    /// Transcribe 16kHz mono audio with whichever engine this is.
+    /// Used by offline batch jobs; the live worker keeps its own per-engine handling.
+    /// `initial_prompt` is only understood by Whisper.
+    pub async fn transcribe(
+        &self,
+        audio: Vec<f32>,
+        language: Option<String>,
+        initial_prompt: Option<&str>,
+    ) -> Result<TranscriptResult, TranscriptionError> {
+        match self {
+            Self::Whisper(engine) => engine
+                .transcribe_audio_detailed(audio, language, initial_prompt)
+                .await
+                .map(|result| TranscriptResult {
+                    text: result.text.trim().to_string(),
+                    confidence: Some(result.confidence),
+                    is_partial: result.is_partial,
+                    words: Some(result.words),
+                })
+                .map_err(|e| TranscriptionError::EngineFailed(e.to_string())),
+            Self::Parakeet(engine) => {
+                let audio_duration = audio.len() as f64 / 16000.0;
+                engine
+                    .transcribe_audio_with_timestamps(audio)
+                    .await
+                    .map(|result| TranscriptResult {
+                        text: result.text.trim().to_string(),
+                        confidence: parakeet_confidence(&result),
+                        is_partial: false,
+                        words: Some(parakeet_word_timings(&result, audio_duration)),
+                    })
+                    .map_err(|e| TranscriptionError::EngineFailed(e.to_string()))
+            }
+            Self::Provider(provider) => provider.transcribe(audio, language).await,
+        }
+    }
 }
 
 // ============================================================================
 // MODEL VALIDATION AND INITIALIZATION
 // ============================================================================
 
-/// Validate that transcription models (Whisper or Parakeet) are ready before starting recording
-pub async fn validate_transcription_model_ready<R: Runtime>(app: &AppHandle<R>) -> Result<(), String> {
-    // Check transcript configuration to determine which engine to validate
-    let config = match crate::api::api::api_get_transcript_config(
+/// Transcript provider configuration with the decrypted API key of cloud providers
+/// (the API command only returns a masked key). Defaults to localWhisper.
+async fn load_transcript_config<R: Runtime>(app: &AppHandle<R>) -> crate::api::api::TranscriptConfig {
+    let mut config = match crate::api::api::api_get_transcript_config(
         app.clone(),
         app.clone().state(),
         None,
@@ -63,7 +140,7 @@ pub async fn validate_transcription_model_ready<R: Runtime>(app: &AppHandle<R>)
     {
         Ok(Some(config)) => {
             info!(
-                "📝 Found transcript config - provider: {}, model: {}",
+                "📝 Transcript config - provider: {}, model: {}",
                 config.provider, config.model
             );
             config
@@ -86,6 +163,30 @@ pub async fn validate_transcription_model_ready<R: Runtime>(app: &AppHandle<R>)
         }
     };
 
+    if is_cloud_provider(&config.provider) {
+        let state = app.state::<AppState>();
+        config.api_key = match SettingsRepository::get_transcript_api_key(
+            state.db_manager.pool(),
+            &config.provider,
+        )
+        .await
+        {
+            Ok(key) => key,
+            Err(e) => {
+                warn!("⚠️ Failed to load {} API key: {}", config.provider, e);
+                None
+            }
+        };
+    }
+    config
+}
+
+/// Validate that transcription models (Whisper or Parakeet) are ready before starting recording,
+/// or that a cloud provider (Groq, OpenAI, Deepgram) has an API key and model configured
+pub async fn validate_transcription_model_ready<R: Runtime>(app: &AppHandle<R>) -> Result<(), String> {
+    // Check transcript configuration to determine which engine to validate
+    let config = load_transcript_config(app).await;
+
     // Validate based on provider
     match config.provider.as_str() {
         "localWhisper" => {
@@ -135,10 +236,23 @@ pub async fn validate_transcription_model_ready<R: Runtime>(app: &AppHandle<R>)
                 }
             }
         }
+        provider if is_cloud_provider(provider) => {
+            info!("🔍 Validating {} cloud transcription config...", provider);
+            match create_cloud_provider(provider, &config.model, config.api_key.as_deref()) {
+                Ok(_) => {
+                    info!("✅ {} transcription ready with model {}", provider, config.model);
+                    Ok(())
+                }
+                Err(e) => {
+                    warn!("❌ {} transcription config invalid: {}", provider, e);
+                    Err(e)
+                }
+            }
+        }
         other => {
             warn!("❌ Unsupported transcription provider for local recording: {}", other);
             Err(format!(
-                "Provider '{}' is not supported for local transcription. Please select 'localWhisper' or 'parakeet'.",
+                "Provider '{}' is not supported for transcription. Please select 'localWhisper', 'parakeet', 'groq', 'openai' or 'deepgram'.",
                 other
             ))
         }
@@ -150,37 +264,7 @@ pub async fn get_or_init_transcription_engine<R: Runtime>(
     app: &AppHandle<R>,
 ) -> Result<TranscriptionEngine, String> {
     // Get provider configuration from API
-    let config = match crate::api::api::api_get_transcript_config(
-        app.clone(),
-        app.clone().state(),
-        None,
-    )
-    .await
-    {
-        Ok(Some(config)) => {
-            info!(
-                "📝 Transcript config - provider: {}, model: {}",
-                config.provider, config.model
-            );
-            config
-        }
-        Ok(None) => {
-            info!("📝 No transcript config found, defaulting to localWhisper");
-            crate::api::api::TranscriptConfig {
-                provider: "localWhisper".to_string(),
-                model: "large-v3".to_string(),
-                api_key: None,
-            }
-        }
-        Err(e) => {
-            warn!("⚠️ Failed to get transcript config: {}, defaulting to localWhisper", e);
-            crate::api::api::TranscriptConfig {
-                provider: "localWhisper".to_string(),
-                model: "large-v3".to_string(),
-                api_key: None,
-            }
-        }
-    };
+    let config = load_transcript_config(app).await;
 
     // Initialize the appropriate engine based on provider
     match config.provider.as_str() {
@@ -212,6 +296,11 @@ pub async fn get_or_init_transcription_engine<R: Runtime>(
                 }
             }
         }
+        provider if is_cloud_provider(provider) => {
+            info!("☁️ Initializing {} cloud transcription ({})", provider, config.model);
+            let provider = create_cloud_provider(provider, &config.model, config.api_key.as_deref())?;
+            Ok(TranscriptionEngine::Provider(provider))
+        }
         "localWhisper" | _ => {
             info!("🎤 Initializing Whisper transcription engine");
             let whisper_engine = get_or_init_whisper(app).await?;
@@ -385,31 +474,7 @@ pub async fn get_or_init_whisper<R: Runtime>(
 
     match model_info {
         Some(model) => {
-            match model.status {
-                crate::whisper_engine::ModelStatus::Available => {
-                    info!("Loading model: {}", model_to_load);
-                    engine
-                        .load_model(&model_to_load)
-                        .await
-                        .map_err(|e| format!("Failed to load model '{}': {}", model_to_load, e))?;
-                    info!("✅ Model '{}' loaded successfully", model_to_load);
-                }
-                crate::whisper_engine::ModelStatus::Missing => {
-                    return Err(format!(
-                        "Model '{}' is not downloaded. Please download it first from the settings.",
-                        model_to_load
-                    ));
-                }
-                crate::whisper_engine::ModelStatus::Downloading { progress } => {
-                    return Err(format!("Model '{}' is currently downloading ({}%). Please wait for it to complete.", model_to_load, progress));
-                }
-                crate::whisper_engine::ModelStatus::Error(ref err) => {
-                    return Err(format!("Model '{}' has an error: {}. Please check the model or try downloading it again.", model_to_load, err));
-                }
-                crate::whisper_engine::ModelStatus::Corrupted { .. } => {
-                    return Err(format!("Model '{}' is corrupted. Please delete it and download again from the settings.", model_to_load));
-                }
-            }
+            load_whisper_model_with_status(&engine, model).await?;
         }
         None => {
             // Check if we have any available models and try to load the first one
@@ -441,3 +506,137 @@ pub async fn get_or_init_whisper<R: Runtime>(
 
     Ok(engine)
 }
+
+/// Load a discovered Whisper model, turning non-available statuses into user-facing errors
+async fn load_whisper_model_with_status(
+    engine: &crate::whisper_engine::WhisperEngine,
+    model: &crate::whisper_engine::ModelInfo,
+) -> Result<(), String> {
+    let model_to_load = &model.name;
+    match model.status {
+        crate::whisper_engine::ModelStatus::Available => {
+            info!("Loading model: {}", model_to_load);
+            engine
+                .load_model(model_to_load)
+                .await
+                .map_err(|e| format!("Failed to load model '{}': {}", model_to_load, e))?;
+            info!("✅ Model '{}' loaded successfully", model_to_load);
+            Ok(())
+        }
+        crate::whisper_engine::ModelStatus::Missing => Err(format!(
+            "Model '{}' is not downloaded. Please download it first from the settings.",
+            model_to_load
+        )),
+        crate::whisper_engine::ModelStatus::Downloading { progress } => Err(format!(
+            "Model '{}' is currently downloading ({}%). Please wait for it to complete.",
+            model_to_load, progress
+        )),
+        crate::whisper_engine::ModelStatus::Error(ref err) => Err(format!(
+            "Model '{}' has an error: {}. Please check the model or try downloading it again.",
+            model_to_load, err
+        )),
+        crate::whisper_engine::ModelStatus::Corrupted { .. } => Err(format!(
+            "Model '{}' is corrupted. Please delete it and download again from the settings.",
+            model_to_load
+        )),
+    }
+}
+
+/// Get a transcription engine with an explicitly chosen provider and model loaded,
+/// independent of the saved transcript config. Used by offline batch jobs
+/// (e.g. re-transcribing a saved meeting with a larger model). Cloud providers use
+/// the saved API key of that provider.
+///
+/// Note: this switches the model of the shared engine instance; callers must hold an
+/// `EngineClaim` and check that no recording is running.
+pub async fn get_or_init_transcription_engine_for_model<R: Runtime>(
+    app: &AppHandle<R>,
+    provider: &str,
+    model: &str,
+) -> Result<TranscriptionEngine, String> {
+    match provider {
+        "localWhisper" => {
+            if let Err(e) = crate::whisper_engine::commands::whisper_init().await {
+                return Err(format!("Failed to initialize Whisper engine: {}", e));
+            }
+            let engine = {
+                let guard = crate::whisper_engine::commands::WHISPER_ENGINE
+                    .lock()
+                    .unwrap();
+                guard
+                    .as_ref()
+                    .cloned()
+                    .ok_or("Failed to get initialized engine")?
+            };
+
+            if engine.is_model_loaded().await
+                && engine.get_current_model().await.as_deref() == Some(model)
+            {
+                info!("✅ Whisper model '{}' already loaded", model);
+                return Ok(TranscriptionEngine::Whisper(engine));
+            }
+
+            let models = engine
+                .discover_models()
+                .await
+                .map_err(|e| format!("Failed to discover models: {}", e))?;
+            let model_info = models
+                .iter()
+                .find(|m| m.name == model)
+                .ok_or_else(|| format!("Whisper model '{}' not found", model))?;
+
+            if engine.is_model_loaded().await {
+                engine.unload_model().await;
+            }
+            load_whisper_model_with_status(&engine, model_info).await?;
+            Ok(TranscriptionEngine::Whisper(engine))
+        }
+        "parakeet" => {
+            if let Err(e) = crate::parakeet_engine::commands::parakeet_init().await {
+                return Err(format!("Failed to initialize Parakeet engine: {}", e));
+            }
+            let engine = {
+                let guard = crate::parakeet_engine::commands::PARAKEET_ENGINE
+                    .lock()
+                    .unwrap();
+                guard
+                    .as_ref()
+                    .cloned()
+                    .ok_or("Parakeet engine not initialized")?
+            };
+
+            if engine.is_model_loaded().await
+                && engine.get_current_model().await.as_deref() == Some(model)
+            {
+                info!("✅ Parakeet model '{}' already loaded", model);
+                return Ok(TranscriptionEngine::Parakeet(engine));
+            }
+
+            // Discovery refreshes the model registry that load_model looks up
+            engine
+                .discover_models()
+                .await
+                .map_err(|e| format!("Failed to discover Parakeet models: {}", e))?;
+            engine
+                .load_model(model)
+                .await
+                .map_err(|e| format!("Failed to load Parakeet model '{}': {}", model, e))?;
+            info!("✅ Parakeet model '{}' loaded successfully", model);
+            Ok(TranscriptionEngine::Parakeet(engine))
+        }
+        provider if is_cloud_provider(provider) => {
+            let state = app.state::<AppState>();
+            let api_key =
+                SettingsRepository::get_transcript_api_key(state.db_manager.pool(), provider)
+                    .await
+                    .map_err(|e| format!("Failed to load {} API key: {}", provider, e))?;
+            info!("☁️ Using {} cloud transcription ({})", provider, model);
+            let provider = create_cloud_provider(provider, model, api_key.as_deref())?;
+            Ok(TranscriptionEngine::Provider(provider))
+        }
+        other => Err(format!(
+            "Provider '{}' is not supported for offline transcription. Please select 'localWhisper', 'parakeet', 'groq', 'openai' or 'deepgram'.",
+            other
+        )),
+    }
+}
@@ -0,0 +1,322 @@
+// audio/transcription/import.rs
+//
+// Import of an external audio file (anything symphonia or the ffmpeg sidecar can decode)
+// as a new meeting: the recording is copied into a regular meeting folder, VAD-segmented
+// and transcribed with the configured engine.
+
+use super::batch::{detect_speech_segments, transcribe_speech_segments, BatchSegment};
+use super::engine::{
+    get_or_init_transcription_engine, validate_transcription_model_ready, EngineClaim,
+};
+use crate::audio::audio_processing::{create_meeting_folder, resample};
+use crate::audio::decoder::{decode_audio_file, TARGET_SAMPLE_RATE};
+use crate::audio::encode::encode_single_audio;
+use crate::audio::recording_preferences::get_default_recordings_folder;
+use crate::audio::recording_saver::{self, DeviceInfo, MeetingMetadata};
+use crate::database::repositories::transcript::TranscriptsRepository;
+use crate::state::AppState;
+use crate::vocabulary::Vocabulary;
+use anyhow::Result;
+use log::{info, warn};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use tauri::{AppHandle, Emitter, Runtime};
+
+/// Containers that are stored as-is; everything else is re-encoded to AAC/MP4
+const PASSTHROUGH_EXTENSIONS: &[&str] = &["mp4", "m4a"];
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImportResult {
+    pub meeting_id: String,
+    pub meeting_name: String,
+    pub folder_path: String,
+    pub segment_count: usize,
+    pub duration_seconds: f64,
+}
+
+fn emit_progress<R: Runtime>(
+    app: &AppHandle<R>,
+    file_path: &str,
+    stage: &str,
+    processed: usize,
+    total: usize,
+) {
+    let percentage = if total > 0 {
+        (processed as f64 / total as f64 * 100.0) as u32
+    } else {
+        0
+    };
+    let _ = app.emit(
+        "import-progress",
+        serde_json::json!({
+            "file_path": file_path,
+            "stage": stage,
+            "processed": processed,
+            "total": total,
+            "percentage": percentage,
+        }),
+    );
+}
+
+/// Import an audio file as a new meeting and transcribe it with the configured engine.
+/// The meeting name defaults to the file name.
+#[tauri::command]
+pub async fn import_audio_file<R: Runtime>(
+    app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    file_path: String,
+    meeting_name: Option<String>,
+    language: Option<String>,
+) -> Result<ImportResult, String> {
+    info!("📥 Importing audio file: {}", file_path);
+
+    // The import shares the live transcription engine
+    let _claim = EngineClaim::try_acquire("import audio")?;
+    if crate::audio::recording_commands::is_recording().await {
+        return Err("Cannot import audio while a recording is in progress".to_string());
+    }
+
+    let source = PathBuf::from(&file_path);
+    if !source.is_file() {
+        return Err(format!("Audio file not found: {}", file_path));
+    }
+
+    validate_transcription_model_ready(&app).await?;
+
+    let meeting_name = meeting_name
+        .map(|name| name.trim().to_string())
+        .filter(|name| !name.is_empty())
+        .or_else(|| source.file_stem().map(|s| s.to_string_lossy().to_string()))
+        .unwrap_or_else(|| "Imported Meeting".to_string());
+
+    let folder = create_meeting_folder(&get_default_recordings_folder(), &meeting_name)
+        .map_err(|e| format!("Failed to create meeting folder: {}", e))?;
+
+    let result = import_into_folder(
+        &app,
+        &state,
+        &file_path,
+        &source,
+        &folder,
+        &meeting_name,
+        language,
+    )
+    .await;
+
+    match result {
+        Ok(result) => {
+            info!(
+                "✅ Imported '{}' as meeting {} ({} segments)",
+                meeting_name, result.meeting_id, result.segment_count
+            );
+            let _ = app.emit("import-complete", &result);
+            Ok(result)
+        }
+        Err(e) => {
+            // Don't leave a half-imported folder behind
+            if let Err(cleanup_err) = std::fs::remove_dir_all(&folder) {
+                warn!(
+                    "Failed to remove meeting folder {} after failed import: {}",
+                    folder.display(),
+                    cleanup_err
+                );
+            }
+            let _ = app.emit(
+                "import-error",
+                serde_json::json!({ "file_path": file_path, "error": e }),
+            );
+            Err(e)
+        }
+    }
+}
+
+async fn import_into_folder<R: Runtime>(
+    app: &AppHandle<R>,
+    state: &tauri::State<'_, AppState>,
+    file_path: &str,
+    source: &Path,
+    folder: &Path,
+    meeting_name: &str,
+    language: Option<String>,
+) -> Result<ImportResult, String> {
+    // Decode, keep a copy of the recording in the meeting folder, resample for the engines
+    emit_progress(app, file_path, "decoding", 0, 0);
+    let (source_owned, folder_owned) = (source.to_path_buf(), folder.to_path_buf());
+    let (audio_file, samples, duration_seconds) = tokio::task::spawn_blocking(move || {
+        let decoded = decode_audio_file(&source_owned)
+            .map_err(|e| format!("Failed to decode audio file: {}", e))?;
+        let duration = decoded.duration_seconds();
+        let audio_file = store_recording(
+            &source_owned,
+            &folder_owned,
+            &decoded.samples,
+            decoded.sample_rate,
+        )
+        .map_err(|e| format!("Failed to copy audio into meeting folder: {}", e))?;
+
+        let samples = if decoded.sample_rate == TARGET_SAMPLE_RATE {
+            decoded.samples
+        } else {
+            resample(&decoded.samples, decoded.sample_rate, TARGET_SAMPLE_RATE)
+                .map_err(|e| format!("Failed to resample audio: {}", e))?
+        };
+        Ok::<_, String>((audio_file, samples, duration))
+    })
+    .await
+    .map_err(|e| format!("Decoding task failed: {}", e))??;
+
+    emit_progress(app, file_path, "detecting_speech", 0, 0);
+    let speech_segments = detect_speech_segments(samples).await?;
+    if speech_segments.is_empty() {
+        return Err("No speech detected in the audio file".to_string());
+    }
+
+    let engine = get_or_init_transcription_engine(app).await?;
+    let language = language.or_else(crate::get_language_preference_internal);
+    let vocabulary = Vocabulary::load(state.db_manager.pool(), None).await;
+    let segments = transcribe_speech_segments(
+        &engine,
+        speech_segments,
+        language,
+        &vocabulary,
+        |done, total| emit_progress(app, file_path, "transcribing", done, total),
+    )
+    .await?;
+
+    emit_progress(app, file_path, "saving", 0, 0);
+    write_transcripts_json(folder, &segments)
+        .map_err(|e| format!("Failed to write transcripts.json: {}", e))?;
+
+    let api_segments: Vec<_> = segments
+        .into_iter()
+        .enumerate()
+        .map(|(i, s)| s.into_transcript_segment(i, None))
+        .collect();
+
+    let folder_path = folder.to_string_lossy().to_string();
+    let meeting_id = TranscriptsRepository::save_transcript(
+        state.db_manager.pool(),
+        meeting_name,
+        &api_segments,
+        Some(folder_path.clone()),
+    )
+    .await
+    .map_err(|e| format!("Failed to save meeting: {}", e))?;
+
+    let now = chrono::Utc::now().to_rfc3339();
+    let metadata = MeetingMetadata {
+        version: "1.0".to_string(),
+        meeting_id: Some(meeting_id.clone()),
+        meeting_name: Some(meeting_name.to_string()),
+        created_at: now.clone(),
+        completed_at: Some(now),
+        duration_seconds: Some(duration_seconds),
+        devices: DeviceInfo {
+            microphone: None,
+            system_audio: None,
+        },
+        audio_file,
+        transcript_file: "transcripts.json".to_string(),
+        sample_rate: TARGET_SAMPLE_RATE,
+        status: "completed".to_string(),
+        audio_quality: Vec::new(),
+    };
+    if let Err(e) = write_json_atomic(folder, "metadata.json", &metadata) {
+        // The meeting is already saved; a missing metadata.json only affects recovery tools
+        warn!("Failed to write metadata.json for imported meeting {}: {}", meeting_id, e);
+    }
+    crate::encryption::seal_meeting_folder(folder).await;
+
+    Ok(ImportResult {
+        meeting_id,
+        meeting_name: meeting_name.to_string(),
+        folder_path,
+        segment_count: api_segments.len(),
+        duration_seconds,
+    })
+}
+
+/// Place the recording in the meeting folder and return its file name.
+/// MP4/M4A files are copied as `audio.mp4`; other formats are re-encoded from the decoded
+/// samples, falling back to a verbatim copy (`audio.<ext>`) if FFmpeg is unavailable.
+fn store_recording(source: &Path, folder: &Path, samples: &[f32], sample_rate: u32) -> Result<String> {
+    let extension = source
+        .extension()
+        .map(|e| e.to_string_lossy().to_lowercase())
+        .unwrap_or_default();
+
+    let target = folder.join("audio.mp4");
+    if PASSTHROUGH_EXTENSIONS.contains(&extension.as_str()) {
+        std::fs::copy(source, &target)?;
+        return Ok("audio.mp4".to_string());
+    }
+
+    let bytes: Vec<u8> = samples.iter().flat_map(|s| s.to_le_bytes()).collect();
+    match encode_single_audio(&bytes, sample_rate, 1, &target) {
+        Ok(()) => Ok("audio.mp4".to_string()),
+        Err(e) => {
+            warn!("Re-encoding imported audio failed ({}), copying original file", e);
+            let _ = std::fs::remove_file(&target);
+            let file_name = imported_file_name(&extension);
+            std::fs::copy(source, folder.join(&file_name))?;
+            Ok(file_name)
+        }
+    }
+}
+
+fn imported_file_name(extension: &str) -> String {
+    if extension.is_empty() {
+        "audio".to_string()
+    } else {
+        format!("audio.{}", extension)
+    }
+}
+
+/// Write transcripts.json in the same layout as live recordings
+fn write_transcripts_json(folder: &Path, segments: &[BatchSegment]) -> Result<()> {
+    let saved: Vec<recording_saver::TranscriptSegment> = segments
+        .iter()
+        .enumerate()
+        .map(|(i, s)| recording_saver::TranscriptSegment {
+            id: format!("seg_{}", i),
+            text: s.text.clone(),
+            audio_start_time: s.audio_start_time,
+            audio_end_time: s.audio_end_time,
+            duration: s.audio_end_time - s.audio_start_time,
+            display_time: super::batch::display_timestamp(None, s.audio_start_time),
+            confidence: s.confidence,
+            sequence_id: i as u64,
+            source: None,
+            words: s.words.clone(),
+        })
+        .collect();
+
+    let json = serde_json::json!({
+        "version": "1.0",
+        "segments": saved,
+        "last_updated": chrono::Utc::now().to_rfc3339(),
+        "total_segments": saved.len()
+    });
+    write_json_atomic(folder, "transcripts.json", &json)
+}
+
+/// Atomic JSON write (temp file + rename)
+fn write_json_atomic<T: Serialize>(folder: &Path, file_name: &str, value: &T) -> Result<()> {
+    let path = folder.join(file_name);
+    let temp_path = folder.join(format!(".{}.tmp", file_name));
+
+    std::fs::write(&temp_path, serde_json::to_string_pretty(value)?)?;
+    std::fs::rename(&temp_path, &path)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_imported_file_name() {
+        assert_eq!(imported_file_name("wav"), "audio.wav");
+        assert_eq!(imported_file_name(""), "audio");
+    }
+}
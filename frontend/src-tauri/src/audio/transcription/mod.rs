@@ -1,21 +1,34 @@
 // audio/transcription/mod.rs
 //
-// Transcription module: Provider abstraction, engine management, and worker pool.
+// Transcription module: Provider abstraction (local and cloud), engine management, and worker pool.
 
 pub mod provider;
+pub mod confidence;
 pub mod whisper_provider;
 pub mod parakeet_provider;
+pub mod cloud_provider;
+pub mod openai_compatible_provider;
+pub mod deepgram_provider;
 pub mod engine;
 pub mod worker;
+pub mod batch;
+pub mod retranscribe;
+pub mod import;
 
 // Re-export commonly used types
-pub use provider::{TranscriptionError, TranscriptionProvider, TranscriptResult};
+pub use provider::{
+    TokenTiming, TranscriptionError, TranscriptionProvider, TranscriptResult, WordTiming,
+};
 pub use whisper_provider::WhisperProvider;
 pub use parakeet_provider::ParakeetProvider;
+pub use openai_compatible_provider::OpenAICompatibleProvider;
+pub use deepgram_provider::DeepgramProvider;
 pub use engine::{
     TranscriptionEngine,
+    EngineClaim,
     validate_transcription_model_ready,
     get_or_init_transcription_engine,
+    get_or_init_transcription_engine_for_model,
     get_or_init_whisper
 };
 pub use worker::{
@@ -0,0 +1,227 @@
+// audio/transcription/openai_compatible_provider.rs
+//
+// Transcription provider for Whisper-compatible `/audio/transcriptions` endpoints
+// (OpenAI, Groq and any server implementing the same API).
+
+use super::cloud_provider::{build_http_client, encode_wav, error_from_response, language_hint};
+use super::provider::{TranscriptionError, TranscriptionProvider, TranscriptResult, WordTiming};
+use async_trait::async_trait;
+use log::debug;
+use reqwest::multipart::{Form, Part};
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize)]
+struct TranscriptionResponse {
+    text: String,
+    #[serde(default)]
+    words: Option<Vec<ResponseWord>>,
+    #[serde(default)]
+    segments: Option<Vec<ResponseSegment>>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ResponseWord {
+    word: String,
+    start: f64,
+    end: f64,
+}
+
+#[derive(Debug, Deserialize)]
+struct ResponseSegment {
+    #[serde(default)]
+    avg_logprob: Option<f64>,
+}
+
+/// Whisper-compatible HTTP transcription provider
+pub struct OpenAICompatibleProvider {
+    name: &'static str,
+    base_url: String,
+    api_key: String,
+    model: String,
+    client: reqwest::Client,
+}
+
+impl OpenAICompatibleProvider {
+    pub fn new(name: &'static str, base_url: &str, api_key: &str, model: &str) -> Result<Self, String> {
+        Ok(Self {
+            name,
+            base_url: base_url.trim_end_matches('/').to_string(),
+            api_key: api_key.to_string(),
+            model: model.to_string(),
+            client: build_http_client()?,
+        })
+    }
+
+    /// `gpt-4o-*-transcribe` models only return plain JSON (no word timestamps)
+    fn supports_verbose_json(&self) -> bool {
+        !self.model.starts_with("gpt-4o")
+    }
+}
+
+#[async_trait]
+impl TranscriptionProvider for OpenAICompatibleProvider {
+    async fn transcribe(
+        &self,
+        audio: Vec<f32>,
+        language: Option<String>,
+    ) -> std::result::Result<TranscriptResult, TranscriptionError> {
+        // "auto-translate" maps onto the translations endpoint (always English output)
+        let translate = language.as_deref() == Some("auto-translate");
+        let endpoint = if translate { "translations" } else { "transcriptions" };
+        let url = format!("{}/audio/{}", self.base_url, endpoint);
+
+        let file = Part::bytes(encode_wav(&audio, 16000))
+            .file_name("audio.wav")
+            .mime_str("audio/wav")
+            .map_err(|e| TranscriptionError::EngineFailed(e.to_string()))?;
+
+        let mut form = Form::new()
+            .part("file", file)
+            .text("model", self.model.clone())
+            .text("temperature", "0");
+
+        if self.supports_verbose_json() {
+            form = form.text("response_format", "verbose_json");
+            if !translate {
+                form = form.text("timestamp_granularities[]", "word");
+            }
+        } else {
+            form = form.text("response_format", "json");
+        }
+
+        if !translate {
+            if let Some(lang) = language_hint(language.as_deref()) {
+                form = form.text("language", lang.to_string());
+            }
+        }
+
+        debug!("{} transcription request: {} ({} samples)", self.name, url, audio.len());
+
+        let response = self
+            .client
+            .post(&url)
+            .bearer_auth(&self.api_key)
+            .multipart(form)
+            .send()
+            .await
+            .map_err(|e| TranscriptionError::EngineFailed(format!("{} request failed: {}", self.name, e)))?;
+
+        if !response.status().is_success() {
+            return Err(error_from_response(self.name, response).await);
+        }
+
+        let body: TranscriptionResponse = response.json().await.map_err(|e| {
+            TranscriptionError::EngineFailed(format!("Invalid {} response: {}", self.name, e))
+        })?;
+
+        Ok(parse_response(body))
+    }
+
+    async fn is_model_loaded(&self) -> bool {
+        // Nothing to load locally; the key was validated when the provider was created
+        true
+    }
+
+    async fn get_current_model(&self) -> Option<String> {
+        Some(self.model.clone())
+    }
+
+    fn provider_name(&self) -> &'static str {
+        self.name
+    }
+}
+
+fn parse_response(body: TranscriptionResponse) -> TranscriptResult {
+    // Mean segment log-probability -> probability, as a rough confidence score
+    let confidence = body.segments.as_ref().and_then(|segments| {
+        let logprobs: Vec<f64> = segments.iter().filter_map(|s| s.avg_logprob).collect();
+        if logprobs.is_empty() {
+            None
+        } else {
+            let mean = logprobs.iter().sum::<f64>() / logprobs.len() as f64;
+            Some(mean.exp().clamp(0.0, 1.0) as f32)
+        }
+    });
+
+    let words = body.words.map(|words| {
+        words
+            .into_iter()
+            .map(|w| WordTiming {
+                word: w.word.trim().to_string(),
+                start: w.start,
+                end: w.end,
+                probability: None,
+            })
+            .filter(|w| !w.word.is_empty())
+            .collect()
+    });
+
+    TranscriptResult {
+        text: body.text.trim().to_string(),
+        confidence,
+        is_partial: false,
+        words,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::audio::transcription::cloud_provider::test_server::serve_once;
+
+    const VERBOSE_RESPONSE: &str = r#"{
+        "text": " Hello world.",
+        "words": [
+            {"word": "Hello", "start": 0.0, "end": 0.4},
+            {"word": "world.", "start": 0.5, "end": 0.9}
+        ],
+        "segments": [{"avg_logprob": -0.1}]
+    }"#;
+
+    #[tokio::test]
+    async fn test_transcribe_against_mock_server() {
+        let (url, server) = serve_once(200, VERBOSE_RESPONSE).await;
+        let provider = OpenAICompatibleProvider::new("Groq", &url, "test-key", "whisper-large-v3").unwrap();
+
+        let result = provider
+            .transcribe(vec![0.0; 16000], Some("en".to_string()))
+            .await
+            .unwrap();
+
+        assert_eq!(result.text, "Hello world.");
+        assert!((result.confidence.unwrap() - (-0.1f64).exp() as f32).abs() < 1e-4);
+        let words = result.words.unwrap();
+        assert_eq!(words.len(), 2);
+        assert_eq!(words[1].word, "world.");
+
+        let request = server.await.unwrap();
+        assert!(request.starts_with("POST /audio/transcriptions"));
+        assert!(request.to_lowercase().contains("authorization: bearer test-key"));
+        assert!(request.contains("whisper-large-v3"));
+        assert!(request.contains("verbose_json"));
+    }
+
+    #[tokio::test]
+    async fn test_auto_translate_uses_translations_endpoint() {
+        let (url, server) = serve_once(200, r#"{"text": "Hi"}"#).await;
+        let provider = OpenAICompatibleProvider::new("OpenAI", &url, "key", "whisper-1").unwrap();
+
+        let result = provider
+            .transcribe(vec![0.0; 16000], Some("auto-translate".to_string()))
+            .await
+            .unwrap();
+
+        assert_eq!(result.text, "Hi");
+        assert!(result.words.is_none());
+        assert!(server.await.unwrap().starts_with("POST /audio/translations"));
+    }
+
+    #[tokio::test]
+    async fn test_http_error_is_reported() {
+        let (url, _server) = serve_once(401, r#"{"error": {"message": "bad key"}}"#).await;
+        let provider = OpenAICompatibleProvider::new("OpenAI", &url, "key", "whisper-1").unwrap();
+
+        let err = provider.transcribe(vec![0.0; 16000], None).await.unwrap_err();
+        assert!(err.to_string().contains("invalid API key"));
+    }
+}
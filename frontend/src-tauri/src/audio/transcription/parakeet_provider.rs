@@ -2,11 +2,34 @@
 //
 // Parakeet transcription provider implementation.
 
-use super::provider::{TranscriptionError, TranscriptionProvider, TranscriptResult};
+use super::confidence::sequence_confidence;
+use super::provider::{
+    token_timings_from_starts, words_from_tokens, TranscriptionError, TranscriptionProvider,
+    TranscriptResult, WordTiming,
+};
 use async_trait::async_trait;
 use log::warn;
 use std::sync::Arc;
 
+/// Convert Parakeet per-token start times into word timings (seconds, chunk-relative)
+pub fn parakeet_word_timings(
+    result: &crate::parakeet_engine::TimestampedResult,
+    audio_duration: f64,
+) -> Vec<WordTiming> {
+    let tokens = token_timings_from_starts(
+        &result.tokens,
+        &result.timestamps,
+        &result.probabilities,
+        audio_duration,
+    );
+    words_from_tokens(&tokens)
+}
+
+/// Confidence of a Parakeet result from its token probabilities
+pub fn parakeet_confidence(result: &crate::parakeet_engine::TimestampedResult) -> Option<f32> {
+    sequence_confidence(result.probabilities.iter().copied())
+}
+
 /// Parakeet transcription provider (wraps ParakeetEngine)
 pub struct ParakeetProvider {
     engine: Arc<crate::parakeet_engine::ParakeetEngine>,
@@ -33,11 +56,13 @@ impl TranscriptionProvider for ParakeetProvider {
             );
         }
 
-        match self.engine.transcribe_audio(audio).await {
-            Ok(text) => Ok(TranscriptResult {
-                text: text.trim().to_string(),
-                confidence: None, // Parakeet doesn't provide confidence scores
+        let audio_duration = audio.len() as f64 / 16000.0;
+        match self.engine.transcribe_audio_with_timestamps(audio).await {
+            Ok(result) => Ok(TranscriptResult {
+                text: result.text.trim().to_string(),
+                confidence: parakeet_confidence(&result),
                 is_partial: false, // Parakeet doesn't provide partial results
+                words: Some(parakeet_word_timings(&result, audio_duration)),
             }),
             Err(e) => Err(TranscriptionError::EngineFailed(e.to_string())),
         }
@@ -4,6 +4,7 @@
 // transcription engines (Whisper, Parakeet, future providers).
 
 use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
 
 // ============================================================================
 // TRANSCRIPTION PROVIDER TRAIT & ERROR TYPES
@@ -43,6 +44,122 @@ pub struct TranscriptResult {
     pub text: String,
     pub confidence: Option<f32>, // None if provider doesn't support confidence scores
     pub is_partial: bool,
+    pub words: Option<Vec<WordTiming>>, // None if provider doesn't support word timestamps
+}
+
+/// Start/end time of a single word, in seconds.
+/// Relative to the transcribed chunk inside providers; recording-relative once emitted.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WordTiming {
+    pub word: String,
+    pub start: f64,
+    pub end: f64,
+    /// Mean probability of the word's tokens (None if the engine doesn't report it)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub probability: Option<f32>,
+}
+
+impl WordTiming {
+    /// Shift the timing by `offset` seconds (chunk-relative -> recording-relative)
+    pub fn offset_by(mut self, offset: f64) -> Self {
+        self.start += offset;
+        self.end += offset;
+        self
+    }
+}
+
+/// Timing of a single sub-word token as reported by an engine
+#[derive(Debug, Clone)]
+pub struct TokenTiming {
+    pub text: String,
+    pub start: f64,
+    pub end: f64,
+    pub probability: Option<f32>,
+}
+
+/// Build token timings from per-token start times (Parakeet style).
+/// Each token ends where the next one starts; the last one ends at `total_duration`.
+/// `probabilities` may be empty if the engine didn't report them.
+pub fn token_timings_from_starts(
+    tokens: &[String],
+    starts: &[f32],
+    probabilities: &[f32],
+    total_duration: f64,
+) -> Vec<TokenTiming> {
+    let count = tokens.len().min(starts.len());
+    (0..count)
+        .map(|i| {
+            let start = starts[i] as f64;
+            let end = if i + 1 < count {
+                starts[i + 1] as f64
+            } else {
+                total_duration
+            };
+            TokenTiming {
+                text: tokens[i].clone(),
+                start,
+                end: end.max(start),
+                probability: probabilities.get(i).copied(),
+            }
+        })
+        .collect()
+}
+
+/// Group sub-word tokens into words.
+/// Both Whisper (BPE) and Parakeet (SentencePiece) mark the first token of a word
+/// with a leading space; every other token is glued onto the current word.
+pub fn words_from_tokens(tokens: &[TokenTiming]) -> Vec<WordTiming> {
+    // Words with the probabilities of their tokens
+    let mut words: Vec<(WordTiming, Vec<f32>)> = Vec::new();
+    let mut current: Option<(WordTiming, Vec<f32>)> = None;
+
+    for token in tokens {
+        // A bare whitespace token just closes the current word
+        if token.text.trim().is_empty() {
+            if let Some(word) = current.take() {
+                words.push(word);
+            }
+            continue;
+        }
+
+        let starts_word = token.text.starts_with(char::is_whitespace);
+        match current.as_mut() {
+            Some((word, probabilities)) if !starts_word => {
+                word.word.push_str(&token.text);
+                word.end = token.end.max(word.end);
+                probabilities.extend(token.probability);
+            }
+            _ => {
+                if let Some(word) = current.take() {
+                    words.push(word);
+                }
+                current = Some((
+                    WordTiming {
+                        word: token.text.trim_start().to_string(),
+                        start: token.start,
+                        end: token.end,
+                        probability: None,
+                    },
+                    token.probability.into_iter().collect(),
+                ));
+            }
+        }
+    }
+    if let Some(word) = current {
+        words.push(word);
+    }
+
+    words
+        .into_iter()
+        .filter(|(w, _)| !w.word.is_empty())
+        .map(|(mut word, probabilities)| {
+            if !probabilities.is_empty() {
+                word.probability =
+                    Some(probabilities.iter().sum::<f32>() / probabilities.len() as f32);
+            }
+            word
+        })
+        .collect()
 }
 
 /// Trait for transcription providers (Whisper, Parakeet, future providers)
@@ -71,3 +188,64 @@ pub trait TranscriptionProvider: Send + Sync {
     /// Get the provider name (for logging/debugging)
     fn provider_name(&self) -> &'static str;
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn token(text: &str, start: f64, end: f64) -> TokenTiming {
+        TokenTiming {
+            text: text.to_string(),
+            start,
+            end,
+            probability: None,
+        }
+    }
+
+    #[test]
+    fn test_words_from_tokens_groups_subwords() {
+        let tokens = vec![
+            token(" Hel", 0.0, 0.2),
+            token("lo", 0.2, 0.4),
+            token(" world", 0.5, 0.9),
+            token(".", 0.9, 1.0),
+        ];
+
+        let words = words_from_tokens(&tokens);
+        assert_eq!(words.len(), 2);
+        assert_eq!(words[0].word, "Hello");
+        assert_eq!((words[0].start, words[0].end), (0.0, 0.4));
+        assert_eq!(words[1].word, "world.");
+        assert_eq!((words[1].start, words[1].end), (0.5, 1.0));
+    }
+
+    #[test]
+    fn test_token_timings_from_starts() {
+        let tokens = vec![" a".to_string(), "b".to_string(), " c".to_string()];
+        let timings = token_timings_from_starts(&tokens, &[0.0, 0.16, 0.48], &[0.9, 0.5, 0.8], 1.0);
+
+        assert_eq!(timings.len(), 3);
+        assert!((timings[0].end - 0.16).abs() < 1e-6);
+        assert!((timings[2].end - 1.0).abs() < 1e-6);
+
+        let words = words_from_tokens(&timings);
+        assert_eq!(words.len(), 2);
+        assert_eq!(words[0].word, "ab");
+        assert_eq!(words[1].word, "c");
+        // Mean of the word's token probabilities
+        assert!((words[0].probability.unwrap() - 0.7).abs() < 1e-6);
+        assert_eq!(words[1].probability, Some(0.8));
+    }
+
+    #[test]
+    fn test_word_timing_offset() {
+        let word = WordTiming {
+            word: "hi".to_string(),
+            start: 0.5,
+            end: 1.0,
+            probability: None,
+        }
+        .offset_by(10.0);
+        assert_eq!((word.start, word.end), (10.5, 11.0));
+    }
+}
@@ -0,0 +1,285 @@
+// audio/transcription/retranscribe.rs
+//
+// Re-transcription of a saved meeting from its recorded audio with a
+// different provider/model (e.g. record live with `base`, re-run with `large-v3`).
+
+use super::batch::{detect_speech_segments, transcribe_speech_segments};
+use super::engine::{get_or_init_transcription_engine_for_model, EngineClaim, TranscriptionEngine};
+use crate::api::TranscriptSegment;
+use crate::audio::decoder::decode_audio_file_16k_mono;
+use crate::audio::recording_saver::{meeting_audio_path, MeetingMetadata};
+use crate::database::models::Transcript;
+use crate::database::repositories::{
+    meeting::MeetingsRepository, transcript::TranscriptsRepository,
+};
+use crate::state::AppState;
+use crate::vocabulary::Vocabulary;
+use chrono::{DateTime, Utc};
+use log::{info, warn};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use tauri::{AppHandle, Emitter, Runtime};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RetranscriptionResult {
+    pub meeting_id: String,
+    pub provider: String,
+    pub model: String,
+    pub segment_count: usize,
+}
+
+fn emit_progress<R: Runtime>(
+    app: &AppHandle<R>,
+    meeting_id: &str,
+    stage: &str,
+    processed: usize,
+    total: usize,
+) {
+    let percentage = if total > 0 {
+        (processed as f64 / total as f64 * 100.0) as u32
+    } else {
+        0
+    };
+    let _ = app.emit(
+        "retranscription-progress",
+        serde_json::json!({
+            "meeting_id": meeting_id,
+            "stage": stage,
+            "processed": processed,
+            "total": total,
+            "percentage": percentage,
+        }),
+    );
+}
+
+/// Re-transcribe a saved meeting from its recording and replace its transcript segments.
+/// Speaker source tags and diarized speaker labels are carried over from the previous
+/// transcript by time overlap.
+#[tauri::command]
+pub async fn retranscribe_meeting<R: Runtime>(
+    app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    meeting_id: String,
+    provider: String,
+    model: String,
+    language: Option<String>,
+) -> Result<RetranscriptionResult, String> {
+    info!(
+        "🔁 Re-transcribing meeting {} with {} / {}",
+        meeting_id, provider, model
+    );
+
+    // Switching models of the shared engines would disrupt live transcription. The claim
+    // keeps a recording from starting until the previous model is restored.
+    let _claim = EngineClaim::try_acquire("re-transcribe")?;
+    if crate::audio::recording_commands::is_recording().await {
+        return Err("Cannot re-transcribe while a recording is in progress".to_string());
+    }
+
+    let pool = state.db_manager.pool();
+
+    let folder_path = MeetingsRepository::get_meeting_folder_path(pool, &meeting_id)
+        .await
+        .map_err(|e| format!("Failed to load meeting: {}", e))?
+        .ok_or_else(|| "Meeting has no recording folder".to_string())?;
+    let folder = PathBuf::from(folder_path);
+    let audio_path = meeting_audio_path(&folder);
+    if !audio_path.exists() {
+        return Err(format!("Recording not found: {}", audio_path.display()));
+    }
+
+    let previous = TranscriptsRepository::get_transcripts_for_meeting(pool, &meeting_id)
+        .await
+        .map_err(|e| format!("Failed to load transcripts: {}", e))?;
+
+    // Decode + VAD
+    emit_progress(&app, &meeting_id, "decoding", 0, 0);
+    let samples = tokio::task::spawn_blocking(move || decode_audio_file_16k_mono(&audio_path))
+        .await
+        .map_err(|e| format!("Decoding task failed: {}", e))?
+        .map_err(|e| format!("Failed to decode recording: {}", e))?;
+
+    emit_progress(&app, &meeting_id, "detecting_speech", 0, 0);
+    let speech_segments = detect_speech_segments(samples).await?;
+    if speech_segments.is_empty() {
+        return Err("No speech detected in the recording".to_string());
+    }
+
+    // Load the requested model, remembering what was loaded so it can be restored
+    let previous_model = currently_loaded_model(&provider).await;
+    let engine = get_or_init_transcription_engine_for_model(&app, &provider, &model).await?;
+
+    let language = language.or_else(crate::get_language_preference_internal);
+    let vocabulary = Vocabulary::load(pool, Some(&meeting_id)).await;
+    let batch_result = transcribe_speech_segments(
+        &engine,
+        speech_segments,
+        language,
+        &vocabulary,
+        |done, total| emit_progress(&app, &meeting_id, "transcribing", done, total),
+    )
+    .await;
+
+    restore_model(&app, &provider, &model, previous_model).await;
+    let segments = batch_result?;
+
+    emit_progress(&app, &meeting_id, "saving", 0, 0);
+    let recording_start = read_recording_start(&folder);
+    let mut new_segments: Vec<TranscriptSegment> = segments
+        .into_iter()
+        .enumerate()
+        .map(|(i, s)| s.into_transcript_segment(i, recording_start))
+        .collect();
+    carry_over_labels(&mut new_segments, &previous);
+
+    TranscriptsRepository::replace_transcripts(pool, &meeting_id, &new_segments)
+        .await
+        .map_err(|e| format!("Failed to save new transcript: {}", e))?;
+
+    info!(
+        "✅ Re-transcription of meeting {} complete: {} segments (was {})",
+        meeting_id,
+        new_segments.len(),
+        previous.len()
+    );
+
+    let result = RetranscriptionResult {
+        meeting_id,
+        provider,
+        model,
+        segment_count: new_segments.len(),
+    };
+    let _ = app.emit("retranscription-complete", &result);
+
+    Ok(result)
+}
+
+/// Model currently loaded in the shared engine of `provider`, if any
+async fn currently_loaded_model(provider: &str) -> Option<String> {
+    let engine = match provider {
+        "localWhisper" => crate::whisper_engine::commands::WHISPER_ENGINE
+            .lock()
+            .unwrap()
+            .as_ref()
+            .cloned()
+            .map(TranscriptionEngine::Whisper),
+        "parakeet" => crate::parakeet_engine::commands::PARAKEET_ENGINE
+            .lock()
+            .unwrap()
+            .as_ref()
+            .cloned()
+            .map(TranscriptionEngine::Parakeet),
+        _ => None,
+    }?;
+
+    if engine.is_model_loaded().await {
+        engine.get_current_model().await
+    } else {
+        None
+    }
+}
+
+/// Reload the model that was active before the job (best effort)
+async fn restore_model<R: Runtime>(
+    app: &AppHandle<R>,
+    provider: &str,
+    used_model: &str,
+    previous_model: Option<String>,
+) {
+    if let Some(previous) = previous_model {
+        if previous != used_model {
+            if let Err(e) =
+                get_or_init_transcription_engine_for_model(app, provider, &previous).await
+            {
+                warn!("Failed to restore previous model '{}': {}", previous, e);
+            }
+        }
+    }
+}
+
+/// Recording start time from the meeting folder's metadata.json, if available
+fn read_recording_start(folder: &Path) -> Option<DateTime<Utc>> {
+    let content = std::fs::read_to_string(folder.join("metadata.json")).ok()?;
+    let metadata: MeetingMetadata = serde_json::from_str(&content).ok()?;
+    DateTime::parse_from_rfc3339(&metadata.created_at)
+        .ok()
+        .map(|dt| dt.with_timezone(&Utc))
+}
+
+/// Copy source tags and speaker labels from the previous transcript onto the new
+/// segments, picking the previous segment with the largest time overlap.
+fn carry_over_labels(segments: &mut [TranscriptSegment], previous: &[Transcript]) {
+    for segment in segments.iter_mut() {
+        let (Some(start), Some(end)) = (segment.audio_start_time, segment.audio_end_time) else {
+            continue;
+        };
+
+        let best = previous
+            .iter()
+            .filter_map(|p| {
+                let (p_start, p_end) = (p.audio_start_time?, p.audio_end_time?);
+                let overlap = end.min(p_end) - start.max(p_start);
+                (overlap > 0.0).then_some((overlap, p))
+            })
+            .max_by(|a, b| a.0.total_cmp(&b.0));
+
+        if let Some((_, p)) = best {
+            segment.source = p.source.clone();
+            segment.speaker = p.speaker.clone();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn previous_row(start: f64, end: f64, source: &str, speaker: Option<&str>) -> Transcript {
+        Transcript {
+            id: format!("t-{}", start),
+            meeting_id: "m".to_string(),
+            transcript: String::new(),
+            timestamp: String::new(),
+            summary: None,
+            action_items: None,
+            key_points: None,
+            audio_start_time: Some(start),
+            audio_end_time: Some(end),
+            duration: Some(end - start),
+            source: Some(source.to_string()),
+            speaker: speaker.map(|s| s.to_string()),
+            confidence: None,
+        }
+    }
+
+    fn new_segment(start: f64, end: f64) -> TranscriptSegment {
+        TranscriptSegment {
+            id: String::new(),
+            text: "text".to_string(),
+            timestamp: String::new(),
+            audio_start_time: Some(start),
+            audio_end_time: Some(end),
+            duration: Some(end - start),
+            source: None,
+            speaker: None,
+            confidence: None,
+            words: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_carry_over_labels_by_overlap() {
+        let previous = vec![
+            previous_row(0.0, 4.0, "You", None),
+            previous_row(4.0, 10.0, "Remote", Some("Speaker 2")),
+        ];
+        let mut segments = vec![new_segment(0.5, 3.0), new_segment(3.5, 9.0), new_segment(20.0, 25.0)];
+
+        carry_over_labels(&mut segments, &previous);
+
+        assert_eq!(segments[0].source.as_deref(), Some("You"));
+        assert_eq!(segments[1].source.as_deref(), Some("Remote"));
+        assert_eq!(segments[1].speaker.as_deref(), Some("Speaker 2"));
+        assert_eq!(segments[2].source, None);
+    }
+}
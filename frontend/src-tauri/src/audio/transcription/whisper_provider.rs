@@ -24,15 +24,18 @@ impl TranscriptionProvider for WhisperProvider {
         audio: Vec<f32>,
         language: Option<String>,
     ) -> std::result::Result<TranscriptResult, TranscriptionError> {
+        // Trait callers can't pass a vocabulary; use the user's list
+        let initial_prompt = crate::vocabulary::active().initial_prompt();
         match self
             .engine
-            .transcribe_audio_with_confidence(audio, language)
+            .transcribe_audio_detailed(audio, language, initial_prompt.as_deref())
             .await
         {
-            Ok((text, confidence, is_partial)) => Ok(TranscriptResult {
-                text: text.trim().to_string(),
-                confidence: Some(confidence),
-                is_partial,
+            Ok(result) => Ok(TranscriptResult {
+                text: result.text.trim().to_string(),
+                confidence: Some(result.confidence),
+                is_partial: result.is_partial,
+                words: Some(result.words),
             }),
             Err(e) => Err(TranscriptionError::EngineFailed(e.to_string())),
         }
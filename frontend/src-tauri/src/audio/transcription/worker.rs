@@ -3,7 +3,10 @@
 // Parallel transcription worker pool and chunk processing logic.
 
 use super::engine::TranscriptionEngine;
-use super::provider::TranscriptionError;
+use super::parakeet_provider::{parakeet_confidence, parakeet_word_timings};
+use super::provider::{TranscriptResult, TranscriptionError, WordTiming};
+use crate::audio::post_processor::{correct_vocabulary, correct_vocabulary_words};
+use crate::audio::recording_state::{DeviceType, TranscriptionChannelMode};
 use crate::audio::AudioChunk;
 use log::{error, info, warn};
 use serde::{Deserialize, Serialize};
@@ -31,20 +34,41 @@ pub struct TranscriptUpdate {
     pub sequence_id: u64,
     pub chunk_start_time: f64, // Legacy field, kept for compatibility
     pub is_partial: bool,
-    pub confidence: f32,
+    // Decoder confidence (0-1), absent for providers without scores
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub confidence: Option<f32>,
     // NEW: Recording-relative timestamps for playback sync
     pub audio_start_time: f64, // Seconds from recording start (e.g., 125.3)
     pub audio_end_time: f64,   // Seconds from recording start (e.g., 128.6)
     pub duration: f64,          // Segment duration in seconds (e.g., 3.3)
+    // Word-level timings, recording-relative (empty if the engine doesn't provide them)
+    #[serde(default)]
+    pub words: Vec<WordTiming>,
 }
 
 // NOTE: get_transcript_history and get_recording_meeting_name functions
 // have been moved to recording_commands.rs where they have access to RECORDING_MANAGER
 
+/// Source label emitted when mic and system audio are transcribed as one mixed stream
+const MIXED_SOURCE_LABEL: &str = "Audio";
+
+/// Resolve the transcript source label for a chunk
+/// Dual-channel mode tags mic segments "You" and system segments "Remote"
+fn source_label(channel_mode: TranscriptionChannelMode, device_type: &DeviceType) -> &'static str {
+    match channel_mode {
+        TranscriptionChannelMode::Mixed => MIXED_SOURCE_LABEL,
+        TranscriptionChannelMode::Dual => device_type.speaker_label(),
+    }
+}
+
 /// Optimized parallel transcription task ensuring ZERO chunk loss
+///
+/// In dual-channel mode each source gets its own work queue and worker, so mic and
+/// system segments are transcribed independently while staying ordered per source.
 pub fn start_transcription_task<R: Runtime>(
     app: AppHandle<R>,
     transcription_receiver: tokio::sync::mpsc::UnboundedReceiver<AudioChunk>,
+    channel_mode: TranscriptionChannelMode,
 ) -> tokio::task::JoinHandle<()> {
     tokio::spawn(async move {
         info!("🚀 Starting optimized parallel transcription task - guaranteeing zero chunk loss");
@@ -63,28 +87,42 @@ pub fn start_transcription_task<R: Runtime>(
             }
         };
 
+        // Pick up the user's custom vocabulary for this recording
+        crate::vocabulary::refresh_active(&app).await;
+
         // Create parallel workers for faster processing while preserving ALL chunks
-        const NUM_WORKERS: usize = 1; // Serial processing ensures transcripts emit in chronological order
-        let (work_sender, work_receiver) = tokio::sync::mpsc::unbounded_channel::<AudioChunk>();
-        let work_receiver = Arc::new(tokio::sync::Mutex::new(work_receiver));
+        // Serial processing per queue ensures transcripts emit in chronological order.
+        // Mixed mode: one queue. Dual mode: one queue per source (0 = mic, 1 = system).
+        let num_workers: usize = match channel_mode {
+            TranscriptionChannelMode::Mixed => 1,
+            TranscriptionChannelMode::Dual => 2,
+        };
+        let mut work_senders = Vec::with_capacity(num_workers);
+        let mut work_receivers = Vec::with_capacity(num_workers);
+        for _ in 0..num_workers {
+            let (work_sender, work_receiver) = tokio::sync::mpsc::unbounded_channel::<AudioChunk>();
+            work_senders.push(work_sender);
+            work_receivers.push(Arc::new(tokio::sync::Mutex::new(work_receiver)));
+        }
 
         // Track completion: AtomicU64 for chunks queued, AtomicU64 for chunks completed
         let chunks_queued = Arc::new(AtomicU64::new(0));
         let chunks_completed = Arc::new(AtomicU64::new(0));
         let input_finished = Arc::new(AtomicBool::new(false));
 
-        info!("📊 Starting {} transcription worker{} (serial mode for ordered emission)", NUM_WORKERS, if NUM_WORKERS == 1 { "" } else { "s" });
+        info!("📊 Starting {} transcription worker{} (serial mode for ordered emission, {:?} channels)",
+              num_workers, if num_workers == 1 { "" } else { "s" }, channel_mode);
 
         // Spawn worker tasks
         let mut worker_handles = Vec::new();
-        for worker_id in 0..NUM_WORKERS {
+        for (worker_id, work_receiver) in work_receivers.into_iter().enumerate() {
             let engine_clone = match &transcription_engine {
                 TranscriptionEngine::Whisper(e) => TranscriptionEngine::Whisper(e.clone()),
                 TranscriptionEngine::Parakeet(e) => TranscriptionEngine::Parakeet(e.clone()),
                 TranscriptionEngine::Provider(p) => TranscriptionEngine::Provider(p.clone()),
             };
             let app_clone = app.clone();
-            let work_receiver_clone = work_receiver.clone();
+            let work_receiver_clone = work_receiver;
             let chunks_completed_clone = chunks_completed.clone();
             let input_finished_clone = input_finished.clone();
             let chunks_queued_clone = chunks_queued.clone();
@@ -142,6 +180,7 @@ pub fn start_transcription_task<R: Runtime>(
 
                             let chunk_timestamp = chunk.timestamp;
                             let chunk_duration = chunk.data.len() as f64 / chunk.sample_rate as f64;
+                            let source = source_label(channel_mode, &chunk.device_type);
 
                             // Transcribe with provider-agnostic approach
                             match transcribe_chunk_with_provider(
@@ -151,25 +190,29 @@ pub fn start_transcription_task<R: Runtime>(
                             )
                             .await
                             {
-                                Ok((transcript, confidence_opt, is_partial)) => {
-                                    // Provider-aware confidence threshold
-                                    let confidence_threshold = match &engine_clone {
-                                        TranscriptionEngine::Whisper(_) | TranscriptionEngine::Provider(_) => 0.3,
-                                        TranscriptionEngine::Parakeet(_) => 0.0, // Parakeet has no confidence, accept all
-                                    };
+                                Ok(result) => {
+                                    let TranscriptResult {
+                                        text: transcript,
+                                        confidence: confidence_opt,
+                                        is_partial,
+                                        words,
+                                    } = result;
+
+                                    // Restore the spelling of custom vocabulary terms
+                                    let vocabulary = crate::vocabulary::active();
+                                    let transcript = correct_vocabulary(&transcript, &vocabulary);
+                                    let words = words.map(|words| correct_vocabulary_words(words, &vocabulary));
 
                                     let confidence_str = match confidence_opt {
                                         Some(c) => format!("{:.2}", c),
                                         None => "N/A".to_string(),
                                     };
 
-                                    info!("🔍 Worker {} transcription result: text='{}', confidence={}, partial={}, threshold={:.2}",
-                                          worker_id, transcript, confidence_str, is_partial, confidence_threshold);
+                                    info!("🔍 Worker {} transcription result: text='{}', confidence={}, partial={}",
+                                          worker_id, transcript, confidence_str, is_partial);
 
-                                    // Check confidence threshold (or accept if no confidence provided)
-                                    let meets_threshold = confidence_opt.map_or(true, |c| c >= confidence_threshold);
-
-                                    if !transcript.trim().is_empty() && meets_threshold {
+                                    // Low-confidence text is kept: the stored score flags it for review
+                                    if !transcript.trim().is_empty() {
                                         // PERFORMANCE: Only log transcription results, not every processing step
                                         info!("✅ Worker {} transcribed: {} (confidence: {}, partial: {})",
                                               worker_id, transcript, confidence_str, is_partial);
@@ -208,15 +251,20 @@ pub fn start_transcription_task<R: Runtime>(
                                         let update = TranscriptUpdate {
                                             text: transcript,
                                             timestamp: format_current_timestamp(), // Wall-clock for reference
-                                            source: "Audio".to_string(),
+                                            source: source.to_string(),
                                             sequence_id,
                                             chunk_start_time: chunk_timestamp, // Legacy compatibility
                                             is_partial,
-                                            confidence: confidence_opt.unwrap_or(0.85), // Default for providers without confidence
+                                            confidence: confidence_opt,
                                             // NEW: Recording-relative timestamps for sync
                                             audio_start_time,
                                             audio_end_time,
                                             duration: chunk_duration,
+                                            words: words
+                                                .unwrap_or_default()
+                                                .into_iter()
+                                                .map(|w| w.offset_by(chunk_timestamp))
+                                                .collect(),
                                         };
 
                                         if let Err(e) = app_clone.emit("transcript-update", &update)
@@ -321,15 +369,20 @@ pub fn start_transcription_task<R: Runtime>(
         }
 
         // Main dispatcher: receive chunks and distribute to workers
+        // Dual mode routes each chunk to the queue of its source
         let mut receiver = transcription_receiver;
         while let Some(chunk) = receiver.recv().await {
+            let queue_index = match (channel_mode, &chunk.device_type) {
+                (TranscriptionChannelMode::Dual, DeviceType::System) => 1,
+                _ => 0,
+            };
             let queued = chunks_queued.fetch_add(1, Ordering::SeqCst) + 1;
             info!(
-                "📥 Dispatching chunk {} to workers (total queued: {})",
-                chunk.chunk_id, queued
+                "📥 Dispatching chunk {} to worker {} (total queued: {})",
+                chunk.chunk_id, queue_index, queued
             );
 
-            if let Err(_) = work_sender.send(chunk) {
+            if let Err(_) = work_senders[queue_index].send(chunk) {
                 error!("❌ Failed to send chunk to workers - this should not happen!");
                 break;
             }
@@ -337,11 +390,11 @@ pub fn start_transcription_task<R: Runtime>(
 
         // Signal that input is finished
         input_finished.store(true, Ordering::SeqCst);
-        drop(work_sender); // Close the channel to signal workers
+        drop(work_senders); // Close the channels to signal workers
 
         let total_chunks_queued = chunks_queued.load(Ordering::SeqCst);
         info!("📭 Input finished with {} total chunks queued. Waiting for all {} workers to complete...",
-              total_chunks_queued, NUM_WORKERS);
+              total_chunks_queued, num_workers);
 
         // Emit final chunk count to frontend
         let _ = app.emit("transcription-queue-complete", serde_json::json!({
@@ -404,12 +457,12 @@ pub fn start_transcription_task<R: Runtime>(
 }
 
 /// Transcribe audio chunk using the appropriate provider (Whisper, Parakeet, or trait-based)
-/// Returns: (text, confidence Option, is_partial)
+/// Returns the unified result; word timings are relative to the chunk start
 async fn transcribe_chunk_with_provider<R: Runtime>(
     engine: &TranscriptionEngine,
     chunk: AudioChunk,
     app: &AppHandle<R>,
-) -> std::result::Result<(String, Option<f32>, bool), TranscriptionError> {
+) -> std::result::Result<TranscriptResult, TranscriptionError> {
     // Convert to 16kHz mono for transcription
     let transcription_data = if chunk.sample_rate != 16000 {
         crate::audio::audio_processing::resample_audio(&chunk.data, chunk.sample_rate, 16000)
@@ -445,25 +498,36 @@ async fn transcribe_chunk_with_provider<R: Runtime>(
     // Transcribe using the appropriate engine (with improved error handling)
     match engine {
         TranscriptionEngine::Whisper(whisper_engine) => {
-            // Get language preference from global state
+            // Get language preference and custom vocabulary from global state
             let language = crate::get_language_preference_internal();
+            let initial_prompt = crate::vocabulary::active().initial_prompt();
 
             match whisper_engine
-                .transcribe_audio_with_confidence(speech_samples, language)
+                .transcribe_audio_detailed(speech_samples, language, initial_prompt.as_deref())
                 .await
             {
-                Ok((text, confidence, is_partial)) => {
-                    let cleaned_text = text.trim().to_string();
+                Ok(result) => {
+                    let cleaned_text = result.text.trim().to_string();
                     if cleaned_text.is_empty() {
-                        return Ok((String::new(), Some(confidence), is_partial));
+                        return Ok(TranscriptResult {
+                            text: String::new(),
+                            confidence: Some(result.confidence),
+                            is_partial: result.is_partial,
+                            words: None,
+                        });
                     }
 
                     info!(
                         "Whisper transcription complete for chunk {}: '{}' (confidence: {:.2}, partial: {})",
-                        chunk.chunk_id, cleaned_text, confidence, is_partial
+                        chunk.chunk_id, cleaned_text, result.confidence, result.is_partial
                     );
 
-                    Ok((cleaned_text, Some(confidence), is_partial))
+                    Ok(TranscriptResult {
+                        text: cleaned_text,
+                        confidence: Some(result.confidence),
+                        is_partial: result.is_partial,
+                        words: Some(result.words),
+                    })
                 }
                 Err(e) => {
                     error!(
@@ -486,11 +550,17 @@ async fn transcribe_chunk_with_provider<R: Runtime>(
             }
         }
         TranscriptionEngine::Parakeet(parakeet_engine) => {
-            match parakeet_engine.transcribe_audio(speech_samples).await {
-                Ok(text) => {
-                    let cleaned_text = text.trim().to_string();
+            let audio_duration = speech_samples.len() as f64 / 16000.0;
+            match parakeet_engine.transcribe_audio_with_timestamps(speech_samples).await {
+                Ok(result) => {
+                    let cleaned_text = result.text.trim().to_string();
                     if cleaned_text.is_empty() {
-                        return Ok((String::new(), None, false));
+                        return Ok(TranscriptResult {
+                            text: String::new(),
+                            confidence: None,
+                            is_partial: false,
+                            words: None,
+                        });
                     }
 
                     info!(
@@ -498,8 +568,13 @@ async fn transcribe_chunk_with_provider<R: Runtime>(
                         chunk.chunk_id, cleaned_text
                     );
 
-                    // Parakeet doesn't provide confidence or partial results
-                    Ok((cleaned_text, None, false))
+                    // Parakeet doesn't provide partial results
+                    Ok(TranscriptResult {
+                        text: cleaned_text,
+                        confidence: parakeet_confidence(&result),
+                        is_partial: false,
+                        words: Some(parakeet_word_timings(&result, audio_duration)),
+                    })
                 }
                 Err(e) => {
                     error!(
@@ -529,7 +604,10 @@ async fn transcribe_chunk_with_provider<R: Runtime>(
                 Ok(result) => {
                     let cleaned_text = result.text.trim().to_string();
                     if cleaned_text.is_empty() {
-                        return Ok((String::new(), result.confidence, result.is_partial));
+                        return Ok(TranscriptResult {
+                            text: String::new(),
+                            ..result
+                        });
                     }
 
                     let confidence_str = match result.confidence {
@@ -546,7 +624,10 @@ async fn transcribe_chunk_with_provider<R: Runtime>(
                         result.is_partial
                     );
 
-                    Ok((cleaned_text, result.confidence, result.is_partial))
+                    Ok(TranscriptResult {
+                        text: cleaned_text,
+                        ..result
+                    })
                 }
                 Err(e) => {
                     error!(
@@ -594,3 +675,20 @@ fn format_recording_time(seconds: f64) -> String {
 
     format!("[{:02}:{:02}]", minutes, secs)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_source_label_mixed_mode() {
+        assert_eq!(source_label(TranscriptionChannelMode::Mixed, &DeviceType::Microphone), "Audio");
+        assert_eq!(source_label(TranscriptionChannelMode::Mixed, &DeviceType::System), "Audio");
+    }
+
+    #[test]
+    fn test_source_label_dual_mode() {
+        assert_eq!(source_label(TranscriptionChannelMode::Dual, &DeviceType::Microphone), "You");
+        assert_eq!(source_label(TranscriptionChannelMode::Dual, &DeviceType::System), "Remote");
+    }
+}
@@ -4,6 +4,8 @@ use log::{debug, info};
 use std::collections::VecDeque;
 use std::time::Duration;
 
+use super::vad_settings::VadSettings;
+
 /// Represents a complete speech segment detected by VAD
 #[derive(Debug, Clone)]
 pub struct SpeechSegment {
@@ -29,34 +31,25 @@ pub struct ContinuousVadProcessor {
 }
 
 impl ContinuousVadProcessor {
-    pub fn new(input_sample_rate: u32, redemption_time_ms: u32) -> Result<Self> {
+    pub fn new(input_sample_rate: u32, settings: &VadSettings) -> Result<Self> {
         // Silero VAD MUST use 16kHz - this is hardcoded requirement
         const VAD_SAMPLE_RATE: u32 = 16000;
 
-        // Use STRICT settings to prevent silence from reaching Whisper
         let mut config = VadConfig::default();
         config.sample_rate = VAD_SAMPLE_RATE as usize;
 
-        // CONTINUOUS SPEECH FIX: Tuned for capturing complete 5+ second utterances
-        // Previous: 0.55/0.40 with 400ms redemption was fragmenting speech into 40ms segments
-        // New: More lenient thresholds + longer redemption for continuous speech
-        config.positive_speech_threshold = 0.50;  // Silero default - good for continuous speech
-        config.negative_speech_threshold = 0.35;  // Silero default - allows natural pauses
-
-        // CRITICAL FIX: Removed redemption_time capping to support long continuous speech
-        // Previous: capped at 400ms, causing VAD to fragment 5-second speech into 40ms segments
-        // New: Use full redemption_time from pipeline (2000ms) to bridge natural pauses
-        config.redemption_time = Duration::from_millis(redemption_time_ms as u64);
-        config.pre_speech_pad = Duration::from_millis(300);   // Pre-speech padding for context
-        config.post_speech_pad = Duration::from_millis(400);  // Increased: more context at end
-
-        // CRITICAL FIX: Increased min_speech_time to prevent tiny 40ms fragments
-        // Previous: 100ms allowed too-short segments that Whisper rejects
-        // New: 250ms ensures segments are substantial enough for Whisper (>100ms requirement)
-        config.min_speech_time = Duration::from_millis(250);  // Prevent tiny fragments
+        // Thresholds, redemption time, padding and minimum speech time come from the
+        // user's VAD settings (see vad_settings.rs for the presets and their rationale)
+        config.positive_speech_threshold = settings.positive_speech_threshold;
+        config.negative_speech_threshold = settings.negative_speech_threshold;
+        config.redemption_time = Duration::from_millis(settings.redemption_time_ms as u64);
+        config.pre_speech_pad = Duration::from_millis(settings.pre_speech_pad_ms as u64);
+        config.post_speech_pad = Duration::from_millis(settings.post_speech_pad_ms as u64);
+        config.min_speech_time = Duration::from_millis(settings.min_speech_time_ms as u64);
 
-        debug!("Creating VAD session with: sample_rate={}Hz, redemption={}ms, min_speech={}ms, input_rate={}Hz",
-               VAD_SAMPLE_RATE, redemption_time_ms, 250, input_sample_rate);
+        debug!("Creating VAD session with: sample_rate={}Hz, thresholds={:.2}/{:.2}, redemption={}ms, min_speech={}ms, input_rate={}Hz",
+               VAD_SAMPLE_RATE, settings.positive_speech_threshold, settings.negative_speech_threshold,
+               settings.redemption_time_ms, settings.min_speech_time_ms, input_sample_rate);
 
         let session = VadSession::new(config)
             .map_err(|e| anyhow!("Failed to create VAD session: {:?}", e))?;
@@ -265,7 +258,7 @@ impl ContinuousVadProcessor {
 
 /// Legacy function for backward compatibility - now uses the optimized approach
 pub fn extract_speech_16k(samples_mono_16k: &[f32]) -> Result<Vec<f32>> {
-    let mut processor = ContinuousVadProcessor::new(16000, 400)?;
+    let mut processor = ContinuousVadProcessor::new(16000, &VadSettings::default())?;
 
     // Process all audio
     let mut all_segments = processor.process_audio(samples_mono_16k)?;
@@ -304,9 +297,9 @@ pub fn extract_speech_16k(samples_mono_16k: &[f32]) -> Result<Vec<f32>> {
 }
 
 /// Simple convenience function to get speech chunks from audio
-/// Uses the optimized ContinuousVadProcessor with configurable redemption time
-pub fn get_speech_chunks(samples_mono_16k: &[f32], redemption_time_ms: u32) -> Result<Vec<SpeechSegment>> {
-    let mut processor = ContinuousVadProcessor::new(16000, redemption_time_ms)?;
+/// Uses the optimized ContinuousVadProcessor with the given VAD settings
+pub fn get_speech_chunks(samples_mono_16k: &[f32], settings: &VadSettings) -> Result<Vec<SpeechSegment>> {
+    let mut processor = ContinuousVadProcessor::new(16000, settings)?;
 
     // Process all audio
     let mut segments = processor.process_audio(samples_mono_16k)?;
@@ -0,0 +1,267 @@
+// audio/vad_calibration.rs
+//
+// VAD calibration: records a 10-second sample from a microphone, measures the room's noise
+// floor and speech level, and suggests VAD settings. The suggestion interpolates between
+// the "quiet room" and "noisy office" presets by how noisy the room is; it is only
+// returned, the UI applies it with `set_vad_settings`. (The "lecture" preset is about the
+// speaking style, not the room, so calibration never suggests it.)
+
+use anyhow::{anyhow, Result};
+use cpal::traits::{DeviceTrait, StreamTrait};
+use cpal::{FromSample, Sample, SampleFormat, SizedSample};
+use log::{error, info};
+use serde::Serialize;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use super::audio_processing::{audio_to_mono, resample};
+use super::devices::{default_input_device, get_device_and_config, parse_audio_device, DeviceType};
+use super::vad::get_speech_chunks;
+use super::vad_settings::VadSettings;
+
+const CALIBRATION_SECONDS: u64 = 10;
+const SAMPLE_RATE: u32 = 16000;
+
+/// 30 ms analysis frames, the VAD's own frame size
+const FRAME_SAMPLES: usize = 480;
+
+/// Frames this far above the noise floor count as active (speech or loud noise)
+const ACTIVE_MARGIN_DB: f32 = 10.0;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LevelStats {
+    /// Level of the quietest frames (15th percentile), dBFS
+    pub noise_floor_db: f32,
+    /// Level of the loudest frames (95th percentile), dBFS
+    pub speech_level_db: f32,
+    /// Share of frames `ACTIVE_MARGIN_DB` above the noise floor
+    pub active_ratio: f32,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct VadCalibrationResult {
+    pub device_name: String,
+    pub noise_floor_db: f32,
+    pub speech_level_db: f32,
+    pub snr_db: f32,
+    pub suggested: VadSettings,
+    /// Speech segments the suggested settings find in the sample
+    pub detected_segments: usize,
+    pub detected_speech_seconds: f32,
+    /// Hints for the user (e.g. nobody spoke during calibration)
+    pub notes: Vec<String>,
+}
+
+/// Frame levels of 16kHz mono audio. None if the sample is shorter than one frame.
+pub fn measure_levels(samples_16k: &[f32]) -> Option<LevelStats> {
+    let mut levels: Vec<f32> = samples_16k
+        .chunks_exact(FRAME_SAMPLES)
+        .map(|frame| {
+            let rms = (frame.iter().map(|s| s * s).sum::<f32>() / frame.len() as f32).sqrt();
+            20.0 * rms.max(1e-6).log10()
+        })
+        .collect();
+    if levels.is_empty() {
+        return None;
+    }
+    levels.sort_by(|a, b| a.total_cmp(b));
+
+    let percentile = |p: f32| levels[((levels.len() - 1) as f32 * p).round() as usize];
+    let noise_floor_db = percentile(0.15);
+    let active = levels
+        .iter()
+        .filter(|&&level| level > noise_floor_db + ACTIVE_MARGIN_DB)
+        .count();
+
+    Some(LevelStats {
+        noise_floor_db,
+        speech_level_db: percentile(0.95),
+        active_ratio: active as f32 / levels.len() as f32,
+    })
+}
+
+/// Settings for a room with these levels: a low signal-to-noise ratio or a loud noise
+/// floor moves the suggestion from the quiet-room towards the noisy-office preset
+pub fn suggest_settings(stats: &LevelStats) -> VadSettings {
+    let snr_db = stats.speech_level_db - stats.noise_floor_db;
+    // 25 dB SNR or better is a quiet room, 10 dB or worse is as noisy as it gets
+    let snr_factor = ((25.0 - snr_db) / 15.0).clamp(0.0, 1.0);
+    // A -60 dBFS floor is a silent room, -40 dBFS is constant chatter or fan noise
+    let floor_factor = ((stats.noise_floor_db + 60.0) / 20.0).clamp(0.0, 1.0);
+    let noisiness = snr_factor.max(floor_factor);
+
+    let round_threshold = |value: f32| (value * 100.0).round() / 100.0;
+    let round_ms = |value: f32| ((value / 50.0).round() * 50.0) as u32;
+
+    let positive_speech_threshold = round_threshold(0.45 + 0.20 * noisiness);
+    VadSettings {
+        positive_speech_threshold,
+        negative_speech_threshold: round_threshold(positive_speech_threshold - 0.15),
+        redemption_time_ms: round_ms(400.0 + 500.0 * noisiness),
+        pre_speech_pad_ms: 300,
+        post_speech_pad_ms: round_ms(400.0 - 100.0 * noisiness),
+        min_speech_time_ms: round_ms(200.0 + 200.0 * noisiness),
+        preset: None,
+    }
+    .with_matching_preset()
+}
+
+/// Analyse a 16kHz mono sample: measure levels, suggest settings and check how the
+/// suggestion segments the sample. Runs the VAD, so call it off the async runtime.
+pub fn analyze_sample(device_name: String, samples_16k: &[f32]) -> Result<VadCalibrationResult> {
+    let stats = measure_levels(samples_16k).ok_or_else(|| anyhow!("The recorded sample is empty"))?;
+    let suggested = suggest_settings(&stats);
+
+    let segments = get_speech_chunks(samples_16k, &suggested)?;
+    let detected_speech_seconds = segments
+        .iter()
+        .map(|s| (s.end_timestamp_ms - s.start_timestamp_ms) as f32 / 1000.0)
+        .sum::<f32>();
+
+    let mut notes = Vec::new();
+    if stats.speech_level_db < -55.0 {
+        notes.push("The microphone level is very low. Check the input gain or the selected device.".to_string());
+    } else if stats.active_ratio < 0.1 || segments.is_empty() {
+        notes.push("Little speech was detected. Speak normally during calibration for a better suggestion.".to_string());
+    } else if detected_speech_seconds / (segments.len() as f32) < 1.0 {
+        notes.push("Speech is still split into short segments. Try a longer redemption time or the lecture preset.".to_string());
+    }
+
+    info!("🎚️ VAD calibration for '{}': noise floor {:.1} dBFS, speech {:.1} dBFS, {} segments -> {:?}",
+          device_name, stats.noise_floor_db, stats.speech_level_db, segments.len(), suggested);
+
+    Ok(VadCalibrationResult {
+        device_name,
+        noise_floor_db: stats.noise_floor_db,
+        speech_level_db: stats.speech_level_db,
+        snr_db: stats.speech_level_db - stats.noise_floor_db,
+        suggested,
+        detected_segments: segments.len(),
+        detected_speech_seconds,
+        notes,
+    })
+}
+
+fn build_capture_stream<T>(
+    device: &cpal::Device,
+    config: &cpal::StreamConfig,
+    captured: Arc<Mutex<Vec<f32>>>,
+) -> Result<cpal::Stream>
+where
+    T: SizedSample,
+    f32: FromSample<T>,
+{
+    let channels = config.channels;
+    let stream = device.build_input_stream(
+        config,
+        move |data: &[T], _: &cpal::InputCallbackInfo| {
+            let samples: Vec<f32> = data.iter().map(|&s| s.to_sample()).collect();
+            if let Ok(mut captured) = captured.lock() {
+                captured.extend(audio_to_mono(&samples, channels));
+            }
+        },
+        |err| error!("Calibration stream error: {}", err),
+        None,
+    )?;
+    Ok(stream)
+}
+
+/// Records `seconds` of mono audio from an input device. Blocks the calling thread.
+fn capture_sample(
+    device: &cpal::Device,
+    config: &cpal::SupportedStreamConfig,
+    seconds: u64,
+) -> Result<Vec<f32>> {
+    let captured = Arc::new(Mutex::new(Vec::new()));
+    let stream_config = config.config();
+    let stream = match config.sample_format() {
+        SampleFormat::F32 => build_capture_stream::<f32>(device, &stream_config, captured.clone())?,
+        SampleFormat::I16 => build_capture_stream::<i16>(device, &stream_config, captured.clone())?,
+        SampleFormat::I32 => build_capture_stream::<i32>(device, &stream_config, captured.clone())?,
+        SampleFormat::U16 => build_capture_stream::<u16>(device, &stream_config, captured.clone())?,
+        format => return Err(anyhow!("Unsupported sample format: {:?}", format)),
+    };
+
+    stream.play()?;
+    std::thread::sleep(Duration::from_secs(seconds));
+    drop(stream);
+
+    let samples = std::mem::take(&mut *captured.lock().map_err(|_| anyhow!("Capture buffer poisoned"))?);
+    Ok(samples)
+}
+
+/// Records a 10-second sample from the selected microphone (the default one if none is
+/// given) and suggests VAD settings for the room. The settings are not applied.
+#[tauri::command]
+pub async fn calibrate_vad(device_name: Option<String>) -> Result<VadCalibrationResult, String> {
+    if super::recording_commands::is_recording().await {
+        return Err("VAD calibration is not available while recording".to_string());
+    }
+
+    let device = match device_name.as_deref() {
+        Some(name) => parse_audio_device(name),
+        None => default_input_device(),
+    }
+    .map_err(|e| format!("Invalid microphone: {}", e))?;
+    if device.device_type != DeviceType::Input {
+        return Err(format!("'{}' is not a microphone", device.name));
+    }
+
+    let (cpal_device, config) = get_device_and_config(&device)
+        .await
+        .map_err(|e| format!("Failed to open microphone '{}': {}", device.name, e))?;
+    let input_rate = config.sample_rate().0;
+
+    info!("🎚️ Recording {}s VAD calibration sample from '{}'", CALIBRATION_SECONDS, device.name);
+    let device_name = device.name.clone();
+    tokio::task::spawn_blocking(move || {
+        let samples = capture_sample(&cpal_device, &config, CALIBRATION_SECONDS)?;
+        let samples_16k = resample(&samples, input_rate, SAMPLE_RATE)?;
+        analyze_sample(device_name, &samples_16k)
+    })
+    .await
+    .map_err(|e| format!("Calibration task failed: {}", e))?
+    .map_err(|e| format!("VAD calibration failed: {}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::audio::vad_settings::VadPreset;
+
+    /// One second of deterministic noise, with a 220 Hz tone in every other second
+    fn room_sample(noise_amplitude: f32, tone_amplitude: f32) -> Vec<f32> {
+        let mut seed: u32 = 12345;
+        (0..SAMPLE_RATE as usize * 6)
+            .map(|i| {
+                seed = seed.wrapping_mul(1_103_515_245).wrapping_add(12345);
+                let noise = ((seed >> 16) as f32 / 32768.0 - 1.0) * noise_amplitude;
+                let second = i / SAMPLE_RATE as usize;
+                let tone = if second % 2 == 1 {
+                    (i as f32 * 220.0 * std::f32::consts::TAU / SAMPLE_RATE as f32).sin() * tone_amplitude
+                } else {
+                    0.0
+                };
+                noise + tone
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_suggestions_follow_room_noise() {
+        let quiet = measure_levels(&room_sample(0.001, 0.3)).unwrap();
+        assert!(quiet.noise_floor_db < -60.0);
+        assert!((quiet.active_ratio - 0.5).abs() < 0.05);
+        assert_eq!(suggest_settings(&quiet).preset, Some(VadPreset::QuietRoom));
+
+        let noisy = measure_levels(&room_sample(0.1, 0.15)).unwrap();
+        assert_eq!(suggest_settings(&noisy).preset, Some(VadPreset::NoisyOffice));
+
+        let moderate = measure_levels(&room_sample(0.01, 0.1)).unwrap();
+        let settings = suggest_settings(&moderate);
+        assert!(settings.validate().is_ok());
+        assert!(settings.positive_speech_threshold > 0.45 && settings.positive_speech_threshold < 0.65);
+
+        assert_eq!(measure_levels(&[0.0; 100]), None);
+    }
+}
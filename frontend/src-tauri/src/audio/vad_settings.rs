@@ -0,0 +1,225 @@
+// audio/vad_settings.rs
+//
+// Tunable Silero VAD settings. Rooms differ: in a noisy open office the default thresholds
+// let background chatter open and close segments, fragmenting speech; in a lecture the
+// speaker pauses longer than the default redemption time. Offline transcription jobs
+// use the same settings as live recordings.
+
+use log::info;
+use serde::{Deserialize, Serialize};
+use std::sync::Mutex;
+use tauri::{AppHandle, Runtime};
+
+use super::{load_store_setting, save_store_setting};
+
+const STORE_KEY: &str = "vadSettings";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum VadPreset {
+    /// The settings used before VAD tuning was configurable
+    Standard,
+    QuietRoom,
+    NoisyOffice,
+    Lecture,
+}
+
+impl VadPreset {
+    pub const ALL: [VadPreset; 4] = [
+        VadPreset::Standard,
+        VadPreset::QuietRoom,
+        VadPreset::NoisyOffice,
+        VadPreset::Lecture,
+    ];
+
+    pub const fn settings(self) -> VadSettings {
+        match self {
+            VadPreset::Standard => VadSettings::STANDARD,
+            // Lower thresholds pick up soft-spoken participants; little noise to reject
+            VadPreset::QuietRoom => VadSettings {
+                positive_speech_threshold: 0.45,
+                negative_speech_threshold: 0.30,
+                redemption_time_ms: 400,
+                pre_speech_pad_ms: 300,
+                post_speech_pad_ms: 400,
+                min_speech_time_ms: 200,
+                preset: Some(VadPreset::QuietRoom),
+            },
+            // Higher thresholds reject background chatter; a longer redemption time bridges
+            // the frames where noise masks speech, so utterances are not split apart
+            VadPreset::NoisyOffice => VadSettings {
+                positive_speech_threshold: 0.65,
+                negative_speech_threshold: 0.50,
+                redemption_time_ms: 900,
+                pre_speech_pad_ms: 300,
+                post_speech_pad_ms: 300,
+                min_speech_time_ms: 400,
+                preset: Some(VadPreset::NoisyOffice),
+            },
+            // A single speaker with long pauses: keep sentences together
+            VadPreset::Lecture => VadSettings {
+                positive_speech_threshold: 0.50,
+                negative_speech_threshold: 0.35,
+                redemption_time_ms: 1500,
+                pre_speech_pad_ms: 400,
+                post_speech_pad_ms: 600,
+                min_speech_time_ms: 300,
+                preset: Some(VadPreset::Lecture),
+            },
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct VadSettings {
+    /// Speech probability that starts a segment
+    pub positive_speech_threshold: f32,
+    /// Speech probability below which a segment may end
+    pub negative_speech_threshold: f32,
+    /// Silence needed to end a segment; shorter pauses are bridged
+    pub redemption_time_ms: u32,
+    pub pre_speech_pad_ms: u32,
+    pub post_speech_pad_ms: u32,
+    /// Shorter speech is discarded as noise
+    pub min_speech_time_ms: u32,
+    /// Preset these values match, None for custom values
+    #[serde(default)]
+    pub preset: Option<VadPreset>,
+}
+
+impl VadSettings {
+    pub const STANDARD: VadSettings = VadSettings {
+        positive_speech_threshold: 0.50,
+        negative_speech_threshold: 0.35,
+        redemption_time_ms: 400,
+        pre_speech_pad_ms: 300,
+        post_speech_pad_ms: 400,
+        min_speech_time_ms: 250,
+        preset: Some(VadPreset::Standard),
+    };
+
+    pub fn validate(&self) -> Result<(), String> {
+        let (positive, negative) = (self.positive_speech_threshold, self.negative_speech_threshold);
+        if !(0.05..=0.95).contains(&positive) || !(0.05..=0.95).contains(&negative) {
+            return Err("Speech thresholds must be between 0.05 and 0.95".to_string());
+        }
+        if negative >= positive {
+            return Err("The negative speech threshold must be lower than the positive one".to_string());
+        }
+        if !(100..=5000).contains(&self.redemption_time_ms) {
+            return Err("Redemption time must be between 100 and 5000 ms".to_string());
+        }
+        if self.pre_speech_pad_ms > 2000 || self.post_speech_pad_ms > 2000 {
+            return Err("Speech padding must be at most 2000 ms".to_string());
+        }
+        if !(30..=2000).contains(&self.min_speech_time_ms) {
+            return Err("Minimum speech time must be between 30 and 2000 ms".to_string());
+        }
+        Ok(())
+    }
+
+    /// Recomputes `preset` from the values (edited values no longer match their preset)
+    pub fn with_matching_preset(mut self) -> Self {
+        self.preset = None;
+        self.preset = VadPreset::ALL
+            .into_iter()
+            .find(|preset| preset.settings().with_preset(None) == self);
+        self
+    }
+
+    fn with_preset(mut self, preset: Option<VadPreset>) -> Self {
+        self.preset = preset;
+        self
+    }
+}
+
+impl Default for VadSettings {
+    fn default() -> Self {
+        Self::STANDARD
+    }
+}
+
+static VAD_SETTINGS: Mutex<VadSettings> = Mutex::new(VadSettings::STANDARD);
+
+/// Get the VAD settings used for new recordings and offline transcription
+pub fn current() -> VadSettings {
+    VAD_SETTINGS
+        .lock()
+        .map(|settings| settings.clone())
+        .unwrap_or_default()
+}
+
+fn set_current(settings: VadSettings) {
+    if let Ok(mut current) = VAD_SETTINGS.lock() {
+        *current = settings;
+    }
+}
+
+/// Load persisted settings into the global settings (called on startup)
+pub fn load_vad_settings<R: Runtime>(app: &AppHandle<R>) {
+    if let Some(settings) =
+        load_store_setting(app, STORE_KEY, "VAD settings", VadSettings::validate)
+    {
+        let settings = settings.with_matching_preset();
+        info!("🎚️ Loaded VAD settings: {:?}", settings);
+        set_current(settings);
+    }
+}
+
+fn apply<R: Runtime>(app: &AppHandle<R>, settings: VadSettings) -> Result<VadSettings, String> {
+    settings.validate()?;
+    let settings = settings.with_matching_preset();
+    save_store_setting(app, STORE_KEY, "VAD settings", &settings)?;
+    info!("🎚️ VAD settings updated (next recording): {:?}", settings);
+    set_current(settings.clone());
+    Ok(settings)
+}
+
+#[tauri::command]
+pub async fn get_vad_settings() -> Result<VadSettings, String> {
+    Ok(current())
+}
+
+/// Saves custom settings. Returns them with `preset` set if they match one.
+#[tauri::command]
+pub async fn set_vad_settings<R: Runtime>(
+    app: AppHandle<R>,
+    settings: VadSettings,
+) -> Result<VadSettings, String> {
+    apply(&app, settings)
+}
+
+/// Settings of every preset, for the settings UI
+#[tauri::command]
+pub async fn get_vad_presets() -> Result<Vec<VadSettings>, String> {
+    Ok(VadPreset::ALL.into_iter().map(VadPreset::settings).collect())
+}
+
+#[tauri::command]
+pub async fn apply_vad_preset<R: Runtime>(
+    app: AppHandle<R>,
+    preset: VadPreset,
+) -> Result<VadSettings, String> {
+    apply(&app, preset.settings())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_presets_are_valid_and_recognized() {
+        for preset in VadPreset::ALL {
+            let settings = preset.settings();
+            assert!(settings.validate().is_ok(), "{:?}", preset);
+            assert_eq!(settings.clone().with_matching_preset().preset, Some(preset));
+        }
+
+        let mut custom = VadPreset::NoisyOffice.settings();
+        custom.redemption_time_ms = 1000;
+        assert_eq!(custom.clone().with_matching_preset().preset, None);
+
+        custom.negative_speech_threshold = custom.positive_speech_threshold;
+        assert!(custom.validate().is_err());
+    }
+}
@@ -1,190 +1,158 @@
 //! Compatibility layer between legacy and modern audio systems
-//! 
-//! This module provides a bridge that allows seamless switching between
-//! the old audio system and the new modern system.
+//!
+//! Both engines sit behind the `MixEngine` trait inside the recording pipeline.
+//! Capture (device streams, resampling, per-source DSP) and everything after
+//! mixing (VAD, transcription, the recording file) are shared, so switching the
+//! engine only changes how mic and system audio are aligned, mixed and leveled,
+//! and both engines see exactly the same input.
 
-use anyhow::Result;
-use std::sync::Arc;
-use tokio::sync::mpsc;
+use ebur128::{EbuR128, Mode};
+use log::{info, warn};
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
 
-use super::{ModernAudioSystem, AudioConfig};
-use crate::audio::recording_saver::RecordingSaver;
-use crate::audio::recording_state::{AudioChunk, RecordingState};
-
-/// Bridge between legacy and modern audio systems
-pub struct LegacyBridge {
-    legacy_saver: Option<RecordingSaver>,
-    modern_system: Option<ModernAudioSystem>,
-    mode: AudioMode,
-}
+use super::limiter::TruePeakLimiter;
+use super::mixer::{AudioMixer, MixingMode};
+use super::normalizer::AudioNormalizer;
+use super::sync::AudioSynchronizer;
+use super::AudioConfig;
+use crate::audio::pipeline::LegacyMixEngine;
+use crate::audio::recording_state::DeviceType;
 
 /// Audio system mode
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub enum AudioMode {
     /// Use the legacy audio system
+    #[default]
     Legacy,
     /// Use the modern audio system
     Modern,
-    /// Run both systems in parallel for comparison
+    /// Record with the legacy system, run the modern one alongside for comparison
     Hybrid,
 }
 
-impl LegacyBridge {
-    /// Create a new bridge with the specified mode
-    pub fn new(mode: AudioMode) -> Self {
-        Self {
-            legacy_saver: None,
-            modern_system: None,
-            mode,
-        }
+/// Engine that produced a set of quality metrics
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AudioEngineKind {
+    Legacy,
+    Modern,
+}
+
+/// One mixing window: the aligned source windows and their mix
+#[derive(Debug, Clone)]
+pub struct MixedWindow {
+    pub mic: Vec<f32>,
+    pub system: Vec<f32>,
+    pub mixed: Vec<f32>,
+}
+
+/// Aligns and mixes microphone and system audio for the recording pipeline
+pub trait MixEngine: Send {
+    fn kind(&self) -> AudioEngineKind;
+
+    /// Add a captured chunk (already resampled and preprocessed)
+    fn add_samples(&mut self, device_type: DeviceType, timestamp: f64, samples: Vec<f32>);
+
+    /// Next mixed window, if enough audio is buffered
+    fn next_window(&mut self) -> Option<MixedWindow>;
+
+    /// Audio still buffered at the end of the recording
+    fn flush(&mut self) -> Option<MixedWindow> {
+        None
     }
 
-    /// Initialize the bridge based on the current mode
-    pub async fn initialize(&mut self) -> Result<()> {
-        match self.mode {
-            AudioMode::Legacy => {
-                self.legacy_saver = Some(RecordingSaver::new());
-                log::info!("Initialized legacy audio system");
-            }
-            AudioMode::Modern => {
-                self.modern_system = Some(ModernAudioSystem::new());
-                if let Some(ref mut system) = self.modern_system {
-                    system.initialize().await?;
-                }
-                log::info!("Initialized modern audio system");
-            }
-            AudioMode::Hybrid => {
-                self.legacy_saver = Some(RecordingSaver::new());
-                self.modern_system = Some(ModernAudioSystem::new());
-                if let Some(ref mut system) = self.modern_system {
-                    system.initialize().await?;
-                }
-                log::info!("Initialized hybrid audio system (both legacy and modern)");
-            }
-        }
-        Ok(())
-    }
-
-    /// Start recording using the appropriate system
-    pub async fn start_recording<R: tauri::Runtime>(
-        &mut self,
-        app: &tauri::AppHandle<R>,
-    ) -> Result<mpsc::UnboundedSender<AudioChunk>> {
-        match self.mode {
-            AudioMode::Legacy => {
-                if let Some(ref mut saver) = self.legacy_saver {
-                    let sender = saver.start_accumulation();
-                    log::info!("Started recording with legacy system");
-                    Ok(sender)
-                } else {
-                    Err(anyhow::anyhow!("Legacy saver not initialized"))
-                }
-            }
-            AudioMode::Modern => {
-                if let Some(ref mut system) = self.modern_system {
-                    system.start_recording().await?;
-                    // TODO: Return a sender for the modern system
-                    // This will be implemented when we create the modern recorder
-                    Err(anyhow::anyhow!("Modern system sender not yet implemented"))
-                } else {
-                    Err(anyhow::anyhow!("Modern system not initialized"))
-                }
-            }
-            AudioMode::Hybrid => {
-                // Start both systems
-                let legacy_sender = if let Some(ref mut saver) = self.legacy_saver {
-                    let sender = saver.start_accumulation();
-                    log::info!("Started recording with legacy system");
-                    Some(sender)
-                } else {
-                    None
-                };
-
-                if let Some(ref mut system) = self.modern_system {
-                    system.start_recording().await?;
-                    log::info!("Started recording with modern system");
-                }
-
-                // For hybrid mode, we'll use the legacy sender for now
-                // In the future, we'll create a multiplexer that sends to both
-                legacy_sender.ok_or_else(|| anyhow::anyhow!("Failed to start legacy recording"))
-            }
-        }
+    /// Mean offset between microphone and system audio in milliseconds
+    fn sync_accuracy_ms(&self) -> f64;
+
+    fn mixing_mode(&self) -> Option<MixingMode> {
+        None
     }
+}
 
-    /// Stop recording and return the file path(s)
-    pub async fn stop_recording<R: tauri::Runtime>(
-        &mut self,
-        app: &tauri::AppHandle<R>,
-    ) -> Result<Option<String>> {
-        match self.mode {
-            AudioMode::Legacy => {
-                if let Some(ref mut saver) = self.legacy_saver {
-                    let result = saver.stop_and_save(app).await;
-                    log::info!("Stopped recording with legacy system");
-                    result.map_err(|e| anyhow::anyhow!("Legacy recording failed: {}", e))
-                } else {
-                    Err(anyhow::anyhow!("Legacy saver not initialized"))
-                }
-            }
-            AudioMode::Modern => {
-                if let Some(ref mut system) = self.modern_system {
-                    let result = system.stop_recording().await;
-                    log::info!("Stopped recording with modern system");
-                    result.map_err(|e| anyhow::anyhow!("Modern recording failed: {}", e))
-                } else {
-                    Err(anyhow::anyhow!("Modern system not initialized"))
-                }
-            }
-            AudioMode::Hybrid => {
-                // Stop both systems and return the modern system result
-                let legacy_result = if let Some(ref mut saver) = self.legacy_saver {
-                    saver.stop_and_save(app).await.ok()
-                } else {
-                    None
-                };
-
-                let modern_result = if let Some(ref mut system) = self.modern_system {
-                    system.stop_recording().await.ok()
-                } else {
-                    None
-                };
-
-                log::info!("Stopped recording with both systems");
-                
-                // For now, return the modern result if available, otherwise legacy
-                Ok(modern_result.or(legacy_result).flatten())
-            }
-        }
+/// The v2 stack: timestamp synchronization, level-aware mixing, EBU R128
+/// normalization and true peak limiting
+pub struct ModernMixEngine {
+    synchronizer: AudioSynchronizer,
+    mixer: AudioMixer,
+    normalizer: AudioNormalizer,
+    limiter: Option<TruePeakLimiter>,
+}
+
+/// Limiter ceiling: -1 dBTP
+const TRUE_PEAK_LIMIT: f32 = 0.891;
+const LIMITER_LOOKAHEAD_MS: usize = 5;
+
+impl ModernMixEngine {
+    pub fn new(config: &AudioConfig) -> anyhow::Result<Self> {
+        Ok(Self {
+            synchronizer: AudioSynchronizer::new(config.target_sample_rate, config.sync_tolerance_ms),
+            mixer: AudioMixer::new(config.mixing_mode),
+            normalizer: AudioNormalizer::new(config.normalization_target_lufs, config.target_sample_rate)?,
+            limiter: config
+                .enable_true_peak_limiting
+                .then(|| TruePeakLimiter::new(config.target_sample_rate, LIMITER_LOOKAHEAD_MS)),
+        })
     }
 
-    /// Get the current mode
-    pub fn mode(&self) -> &AudioMode {
-        &self.mode
+    fn mix(&mut self, mic: Vec<f32>, system: Vec<f32>) -> MixedWindow {
+        let mixed = self.mixer.mix(&mic, &system);
+        let normalized = self.normalizer.normalize(&mixed);
+        let mixed = match self.limiter.as_mut() {
+            Some(limiter) => limiter.process_block(&normalized, TRUE_PEAK_LIMIT),
+            None => normalized,
+        };
+        MixedWindow { mic, system, mixed }
     }
+}
 
-    /// Switch to a different mode
-    pub async fn switch_mode(&mut self, new_mode: AudioMode) -> Result<()> {
-        log::info!("Switching audio mode from {:?} to {:?}", self.mode, new_mode);
-        self.mode = new_mode;
-        self.initialize().await
+impl MixEngine for ModernMixEngine {
+    fn kind(&self) -> AudioEngineKind {
+        AudioEngineKind::Modern
     }
 
-    /// Get audio quality metrics (only available in modern mode)
-    pub fn get_quality_metrics(&self) -> Option<AudioQualityMetrics> {
-        match self.mode {
-            AudioMode::Modern | AudioMode::Hybrid => {
-                // TODO: Implement quality metrics
-                Some(AudioQualityMetrics::default())
-            }
-            AudioMode::Legacy => None,
-        }
+    fn add_samples(&mut self, device_type: DeviceType, timestamp: f64, samples: Vec<f32>) {
+        self.synchronizer.add_samples(device_type, timestamp, &samples);
+    }
+
+    fn next_window(&mut self) -> Option<MixedWindow> {
+        let chunk = self.synchronizer.next_window()?;
+        Some(self.mix(chunk.mic, chunk.system))
+    }
+
+    fn flush(&mut self) -> Option<MixedWindow> {
+        let chunk = self.synchronizer.flush()?;
+        Some(self.mix(chunk.mic, chunk.system))
+    }
+
+    fn sync_accuracy_ms(&self) -> f64 {
+        self.synchronizer.sync_accuracy_ms()
+    }
+
+    fn mixing_mode(&self) -> Option<MixingMode> {
+        Some(*self.mixer.mixing_mode())
     }
 }
 
-/// Audio quality metrics for monitoring
-#[derive(Debug, Clone, Default)]
+/// Audio quality metrics of one engine over one recording
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AudioQualityMetrics {
+    /// Shared by the engines of one recording, so hybrid rows can be paired
+    pub session_id: String,
+    /// Folder of the recording (None when it had no meeting folder)
+    pub meeting_folder: Option<String>,
+    pub engine: AudioEngineKind,
+    /// Mode the recording ran in
+    pub audio_mode: AudioMode,
+    /// Whether this engine's output was recorded and transcribed (false for the hybrid shadow)
+    pub is_primary: bool,
+    pub mixing_mode: Option<MixingMode>,
+    pub recorded_at: chrono::DateTime<chrono::Utc>,
+    pub duration_seconds: f64,
     /// Sync accuracy in milliseconds
     pub sync_accuracy_ms: f64,
     /// Peak level (0.0 to 1.0)
@@ -193,10 +161,284 @@ pub struct AudioQualityMetrics {
     pub rms_level: f32,
     /// LUFS level for EBU R128 compliance
     pub lufs_level: f64,
-    /// True peak level
+    /// True peak level (linear, can exceed 1.0)
     pub true_peak_level: f32,
     /// Number of clipping events
     pub clipping_events: u32,
+    /// Mixing time as a share of the audio duration
+    pub cpu_percent: f32,
+}
+
+/// Samples at or above this level count as clipped
+const CLIP_THRESHOLD: f32 = 0.999;
+/// Reported loudness of silent recordings
+const SILENCE_LUFS: f64 = -70.0;
+
+/// Measures an engine's mixed output and the time spent mixing
+struct QualityMeter {
+    sample_rate: u32,
+    loudness: Option<EbuR128>,
+    samples: u64,
+    sum_squares: f64,
+    peak: f32,
+    clipping_events: u32,
+    in_clip: bool,
+    processing_time: Duration,
+}
+
+impl QualityMeter {
+    fn new(sample_rate: u32) -> Self {
+        let loudness = match EbuR128::new(1, sample_rate, Mode::I | Mode::TRUE_PEAK) {
+            Ok(loudness) => Some(loudness),
+            Err(e) => {
+                warn!("Failed to create EBU R128 meter for quality metrics: {}", e);
+                None
+            }
+        };
+        Self {
+            sample_rate,
+            loudness,
+            samples: 0,
+            sum_squares: 0.0,
+            peak: 0.0,
+            clipping_events: 0,
+            in_clip: false,
+            processing_time: Duration::ZERO,
+        }
+    }
+
+    fn measure(&mut self, mixed: &[f32]) {
+        if let Some(loudness) = self.loudness.as_mut() {
+            let _ = loudness.add_frames_f32(mixed);
+        }
+        for &sample in mixed {
+            let level = sample.abs();
+            self.sum_squares += (sample * sample) as f64;
+            self.peak = self.peak.max(level);
+
+            // A run of clipped samples is one event
+            let clipped = level >= CLIP_THRESHOLD;
+            if clipped && !self.in_clip {
+                self.clipping_events += 1;
+            }
+            self.in_clip = clipped;
+        }
+        self.samples += mixed.len() as u64;
+    }
+
+    fn duration_seconds(&self) -> f64 {
+        self.samples as f64 / self.sample_rate as f64
+    }
+}
+
+/// A mix engine with its quality meter
+pub struct MeteredEngine {
+    engine: Box<dyn MixEngine>,
+    meter: QualityMeter,
+}
+
+impl MeteredEngine {
+    fn new(engine: Box<dyn MixEngine>, sample_rate: u32) -> Self {
+        Self {
+            engine,
+            meter: QualityMeter::new(sample_rate),
+        }
+    }
+
+    pub fn kind(&self) -> AudioEngineKind {
+        self.engine.kind()
+    }
+
+    pub fn add_samples(&mut self, device_type: DeviceType, timestamp: f64, samples: Vec<f32>) {
+        let started = Instant::now();
+        self.engine.add_samples(device_type, timestamp, samples);
+        self.meter.processing_time += started.elapsed();
+    }
+
+    pub fn next_window(&mut self) -> Option<MixedWindow> {
+        let started = Instant::now();
+        let window = self.engine.next_window();
+        self.meter.processing_time += started.elapsed();
+        if let Some(ref window) = window {
+            self.meter.measure(&window.mixed);
+        }
+        window
+    }
+
+    pub fn flush(&mut self) -> Option<MixedWindow> {
+        let window = self.engine.flush();
+        if let Some(ref window) = window {
+            self.meter.measure(&window.mixed);
+        }
+        window
+    }
+
+    fn metrics(&self, session: &MetricsSession, is_primary: bool) -> AudioQualityMetrics {
+        let meter = &self.meter;
+        let duration_seconds = meter.duration_seconds();
+        let lufs_level = meter
+            .loudness
+            .as_ref()
+            .and_then(|loudness| loudness.loudness_global().ok())
+            .filter(|lufs| lufs.is_finite())
+            .map_or(SILENCE_LUFS, |lufs| lufs.max(SILENCE_LUFS));
+        let true_peak_level = meter
+            .loudness
+            .as_ref()
+            .and_then(|loudness| loudness.true_peak(0).ok())
+            .map_or(meter.peak, |peak| peak as f32);
+
+        AudioQualityMetrics {
+            session_id: session.id.clone(),
+            meeting_folder: session.meeting_folder.clone(),
+            engine: self.kind(),
+            audio_mode: session.mode,
+            is_primary,
+            mixing_mode: self.engine.mixing_mode(),
+            recorded_at: session.started_at,
+            duration_seconds,
+            sync_accuracy_ms: self.engine.sync_accuracy_ms(),
+            peak_level: meter.peak,
+            rms_level: if meter.samples > 0 {
+                (meter.sum_squares / meter.samples as f64).sqrt() as f32
+            } else {
+                0.0
+            },
+            lufs_level,
+            true_peak_level,
+            clipping_events: meter.clipping_events,
+            cpu_percent: if duration_seconds > 0.0 {
+                (meter.processing_time.as_secs_f64() / duration_seconds * 100.0) as f32
+            } else {
+                0.0
+            },
+        }
+    }
+}
+
+/// Recording the engines belong to, stamped on their metrics
+struct MetricsSession {
+    id: String,
+    meeting_folder: Option<String>,
+    mode: AudioMode,
+    started_at: chrono::DateTime<chrono::Utc>,
+}
+
+/// Engines of one recording: the primary engine feeds transcription and the
+/// recording file; in hybrid mode the shadow engine mixes the same input and is
+/// only measured
+pub struct MixEngines {
+    session: MetricsSession,
+    pub primary: MeteredEngine,
+    pub shadow: Option<MeteredEngine>,
+}
+
+impl MixEngines {
+    pub fn mode(&self) -> AudioMode {
+        self.session.mode
+    }
+
+    /// Log and store the quality metrics of every engine (end of recording)
+    pub fn publish_metrics(&self) {
+        let engines = std::iter::once((&self.primary, true)).chain(self.shadow.iter().map(|shadow| (shadow, false)));
+        for (engine, is_primary) in engines {
+            let metrics = engine.metrics(&self.session, is_primary);
+            if metrics.duration_seconds == 0.0 {
+                continue;
+            }
+            info!("📊 {:?} engine ({:?} mode): {:.1}s, {:.1} LUFS, true peak {:.3}, {} clipping events, sync {:.1}ms, CPU {:.2}%",
+                  metrics.engine, metrics.audio_mode, metrics.duration_seconds, metrics.lufs_level,
+                  metrics.true_peak_level, metrics.clipping_events, metrics.sync_accuracy_ms, metrics.cpu_percent);
+            record_quality_metrics(metrics);
+        }
+    }
+}
+
+/// Bridge between legacy and modern audio systems
+pub struct LegacyBridge {
+    mode: AudioMode,
+    config: AudioConfig,
+    meeting_folder: Option<PathBuf>,
+}
+
+impl LegacyBridge {
+    /// Create a new bridge with the specified mode
+    pub fn new(mode: AudioMode) -> Self {
+        Self::with_config(mode, AudioConfig::default())
+    }
+
+    pub fn with_config(mode: AudioMode, config: AudioConfig) -> Self {
+        Self {
+            mode,
+            config,
+            meeting_folder: None,
+        }
+    }
+
+    /// Folder of the recording, so its quality metrics can be saved with it
+    pub fn with_meeting_folder(mut self, folder: Option<PathBuf>) -> Self {
+        self.meeting_folder = folder;
+        self
+    }
+
+    /// Bridge for the user's engine settings
+    pub fn from_settings(settings: &super::settings::AudioEngineSettings) -> Self {
+        Self::with_config(
+            settings.mode,
+            AudioConfig {
+                mixing_mode: settings.mixing_mode,
+                ..AudioConfig::default()
+            },
+        )
+    }
+
+    /// Get the current mode
+    pub fn mode(&self) -> AudioMode {
+        self.mode
+    }
+
+    pub fn config(&self) -> &AudioConfig {
+        &self.config
+    }
+
+    /// Engines for a new recording. Falls back to the legacy engine if the modern
+    /// one can't be created.
+    pub fn create_engines(&self, sample_rate: u32) -> MixEngines {
+        let config = AudioConfig {
+            target_sample_rate: sample_rate,
+            ..self.config.clone()
+        };
+        let legacy = || MeteredEngine::new(Box::new(LegacyMixEngine::new(sample_rate)), sample_rate);
+        let modern = || match ModernMixEngine::new(&config) {
+            Ok(engine) => Some(MeteredEngine::new(Box::new(engine), sample_rate)),
+            Err(e) => {
+                warn!("⚠️ Failed to create modern audio engine, using legacy: {}", e);
+                None
+            }
+        };
+
+        let (primary, shadow) = match self.mode {
+            AudioMode::Legacy => (legacy(), None),
+            AudioMode::Modern => (modern().unwrap_or_else(legacy), None),
+            AudioMode::Hybrid => (legacy(), modern()),
+        };
+        info!("🔀 Audio engine: {:?} mode, mixing with {:?}{}", self.mode, primary.kind(),
+              if shadow.is_some() { " (modern engine measured alongside)" } else { "" });
+
+        MixEngines {
+            session: MetricsSession {
+                id: uuid::Uuid::new_v4().to_string(),
+                meeting_folder: self
+                    .meeting_folder
+                    .as_ref()
+                    .map(|folder| folder.to_string_lossy().to_string()),
+                mode: self.mode,
+                started_at: chrono::Utc::now(),
+            },
+            primary,
+            shadow,
+        }
+    }
 }
 
 impl Default for LegacyBridge {
@@ -205,31 +447,90 @@ impl Default for LegacyBridge {
     }
 }
 
-/// Feature flag helper functions
-pub mod feature_flags {
-    /// Check if legacy audio is enabled
-    pub fn is_legacy_enabled() -> bool {
-        cfg!(feature = "legacy-audio")
-    }
+/// Metrics kept in memory (two engines per recording in hybrid mode)
+const MAX_QUALITY_HISTORY: usize = 40;
 
-    /// Check if modern audio is enabled
-    pub fn is_modern_enabled() -> bool {
-        cfg!(feature = "modern-audio")
+static QUALITY_HISTORY: Mutex<VecDeque<AudioQualityMetrics>> = Mutex::new(VecDeque::new());
+
+fn record_quality_metrics(metrics: AudioQualityMetrics) {
+    if let Ok(mut history) = QUALITY_HISTORY.lock() {
+        if history.len() >= MAX_QUALITY_HISTORY {
+            history.pop_front();
+        }
+        history.push_back(metrics);
     }
+}
+
+/// Quality metrics of recent recordings, oldest first
+pub fn quality_metrics_history() -> Vec<AudioQualityMetrics> {
+    QUALITY_HISTORY
+        .lock()
+        .map(|history| history.iter().cloned().collect())
+        .unwrap_or_default()
+}
+
+/// Quality metrics of the recording saved in `folder` (one entry per engine)
+pub fn quality_metrics_for_folder(folder: &Path) -> Vec<AudioQualityMetrics> {
+    let folder = folder.to_string_lossy();
+    quality_metrics_history()
+        .into_iter()
+        .filter(|metrics| metrics.meeting_folder.as_deref() == Some(folder.as_ref()))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hybrid_mode_measures_both_engines() {
+        let bridge = LegacyBridge::new(AudioMode::Hybrid);
+        let mut engines = bridge.create_engines(48000);
+        assert_eq!(engines.primary.kind(), AudioEngineKind::Legacy);
+        assert_eq!(engines.shadow.as_ref().map(MeteredEngine::kind), Some(AudioEngineKind::Modern));
 
-    /// Check if hybrid mode is enabled
-    pub fn is_hybrid_enabled() -> bool {
-        cfg!(feature = "hybrid-mode")
+        // Two seconds of 10ms chunks from both sources
+        for i in 1..=200u32 {
+            let timestamp = i as f64 * 0.01;
+            let tone: Vec<f32> = (0..480).map(|n| ((i * 480 + n) as f32 * 0.05).sin() * 0.3).collect();
+            for engine in std::iter::once(&mut engines.primary).chain(engines.shadow.as_mut()) {
+                engine.add_samples(DeviceType::Microphone, timestamp, tone.clone());
+                engine.add_samples(DeviceType::System, timestamp, vec![0.0; 480]);
+                while engine.next_window().is_some() {}
+            }
+        }
+
+        let legacy = engines.primary.metrics(&engines.session, true);
+        let modern = engines.shadow.as_ref().unwrap().metrics(&engines.session, false);
+        assert!(legacy.duration_seconds > 1.0 && modern.duration_seconds > 1.5);
+        assert!(modern.true_peak_level <= 1.0);
+        assert_eq!(modern.clipping_events, 0);
+        assert!(modern.sync_accuracy_ms < 1.0);
+        assert_eq!(modern.mixing_mode, Some(MixingMode::Professional));
+        assert_eq!(legacy.session_id, modern.session_id);
     }
 
-    /// Get the default audio mode based on feature flags
-    pub fn default_mode() -> super::AudioMode {
-        if is_hybrid_enabled() {
-            super::AudioMode::Hybrid
-        } else if is_modern_enabled() {
-            super::AudioMode::Modern
-        } else {
-            super::AudioMode::Legacy
+    #[test]
+    fn test_metrics_are_kept_per_meeting_folder() {
+        let folder = std::env::temp_dir().join(format!("meetily-metrics-test-{}", uuid::Uuid::new_v4()));
+        let bridge = LegacyBridge::new(AudioMode::Hybrid).with_meeting_folder(Some(folder.clone()));
+        let mut engines = bridge.create_engines(48000);
+        for i in 1..=100u32 {
+            let tone: Vec<f32> = (0..480).map(|n| ((i * 480 + n) as f32 * 0.05).sin() * 0.3).collect();
+            for engine in std::iter::once(&mut engines.primary).chain(engines.shadow.as_mut()) {
+                engine.add_samples(DeviceType::Microphone, i as f64 * 0.01, tone.clone());
+                engine.add_samples(DeviceType::System, i as f64 * 0.01, tone.clone());
+                while engine.next_window().is_some() {}
+            }
         }
+        for engine in std::iter::once(&mut engines.primary).chain(engines.shadow.as_mut()) {
+            engine.flush();
+        }
+        engines.publish_metrics();
+
+        let metrics = quality_metrics_for_folder(&folder);
+        assert_eq!(metrics.len(), 2);
+        assert!(metrics.iter().all(|m| m.session_id == metrics[0].session_id));
+        assert_eq!(metrics.iter().filter(|m| m.is_primary).count(), 1);
     }
 }
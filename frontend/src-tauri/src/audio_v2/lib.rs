@@ -1,128 +0,0 @@
-//! Modern audio system based on new architecture
-//! 
-//! This module provides a professional-grade audio processing system that replaces
-//! the legacy audio system while maintaining full backward compatibility.
-
-pub mod stream;
-pub mod mixer;
-pub mod normalizer;
-pub mod resampler;
-pub mod recorder;
-pub mod compatibility;
-pub mod sync;
-pub mod limiter;
-
-// Re-export main types for easy access
-pub use stream::{ModernAudioStream, ModernAudioStreamManager, ProcessedAudio, UnifiedAudioStream};
-pub use mixer::{AudioMixer, MixingMode, AudioLevelStats};
-pub use normalizer::AudioNormalizer;
-pub use resampler::DynamicResampler;
-pub use recorder::ModernRecorder;
-pub use compatibility::{LegacyBridge, AudioMode, AudioQualityMetrics};
-pub use sync::{AudioSynchronizer, SynchronizedChunk};
-pub use limiter::TruePeakLimiter;
-
-use anyhow::Result;
-use std::sync::Arc;
-
-/// Modern audio system configuration
-#[derive(Debug, Clone)]
-pub struct AudioConfig {
-    /// Target sample rate for processing
-    pub target_sample_rate: u32,
-    /// EBU R128 normalization target in LUFS
-    pub normalization_target_lufs: f64,
-    /// Sync tolerance in milliseconds
-    pub sync_tolerance_ms: u32,
-    /// Enable true peak limiting
-    pub enable_true_peak_limiting: bool,
-    /// Mixing mode for mic and system audio
-    pub mixing_mode: MixingMode,
-}
-
-/// Audio mixing modes
-#[derive(Debug, Clone)]
-pub enum MixingMode {
-    /// Fixed ratio mixing (legacy behavior)
-    Fixed { mic_ratio: f32, system_ratio: f32 },
-    /// Dynamic mixing based on audio levels
-    Dynamic,
-    /// Professional ducking and crossfading
-    Professional,
-}
-
-impl Default for AudioConfig {
-    fn default() -> Self {
-        Self {
-            target_sample_rate: 48000,
-            normalization_target_lufs: -23.0, // EBU R128 standard for speech
-            sync_tolerance_ms: 1, // 1ms tolerance for perfect sync
-            enable_true_peak_limiting: true,
-            mixing_mode: MixingMode::Professional,
-        }
-    }
-}
-
-/// Main entry point for the modern audio system
-pub struct ModernAudioSystem {
-    config: AudioConfig,
-    stream: Option<AudioStream>,
-    recorder: Option<ModernRecorder>,
-}
-
-impl ModernAudioSystem {
-    /// Create a new modern audio system with default configuration
-    pub fn new() -> Self {
-        Self {
-            config: AudioConfig::default(),
-            stream: None,
-            recorder: None,
-        }
-    }
-
-    /// Create a new modern audio system with custom configuration
-    pub fn with_config(config: AudioConfig) -> Self {
-        Self {
-            config,
-            stream: None,
-            recorder: None,
-        }
-    }
-
-    /// Initialize the audio system
-    pub async fn initialize(&mut self) -> Result<()> {
-        // TODO: Implement initialization
-        // This will be implemented in Phase 2
-        Ok(())
-    }
-
-    /// Start recording with the modern system
-    pub async fn start_recording(&mut self) -> Result<()> {
-        // TODO: Implement recording start
-        // This will be implemented in Phase 2
-        Ok(())
-    }
-
-    /// Stop recording and return the file path
-    pub async fn stop_recording(&mut self) -> Result<Option<String>> {
-        // TODO: Implement recording stop
-        // This will be implemented in Phase 2
-        Ok(None)
-    }
-
-    /// Get current configuration
-    pub fn config(&self) -> &AudioConfig {
-        &self.config
-    }
-
-    /// Update configuration
-    pub fn update_config(&mut self, config: AudioConfig) {
-        self.config = config;
-    }
-}
-
-impl Default for ModernAudioSystem {
-    fn default() -> Self {
-        Self::new()
-    }
-}
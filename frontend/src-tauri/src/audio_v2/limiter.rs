@@ -1,24 +1,111 @@
 //! True peak limiting
-//! 
-//! This module provides lookahead limiting to prevent clipping, exactly
-//! like new implementation.
+//!
+//! This module provides lookahead limiting to prevent clipping. Peaks are seen
+//! `lookahead` samples before they are played, so the gain ramps down ahead of
+//! them instead of clipping or reacting after the fact.
+
+use std::collections::VecDeque;
+
+/// Release time constant after the last peak has passed
+const RELEASE_MS: f32 = 150.0;
 
 /// True peak limiter with lookahead
 pub struct TruePeakLimiter {
-    // TODO: Implement in Phase 3
-    _placeholder: (),
+    delay_line: VecDeque<f32>,
+    lookahead_samples: usize,
+    gain: f32,
+    gain_step: f32,
+    ramp_remaining: usize,
+    target_gain: f32,
+    hold_remaining: usize,
+    release_coef: f32,
 }
 
 impl TruePeakLimiter {
     /// Create a new true peak limiter
     pub fn new(sample_rate: u32, lookahead_ms: usize) -> Self {
-        Self { _placeholder: () }
+        let lookahead_samples = (sample_rate as usize * lookahead_ms / 1000).max(1);
+        let release_samples = sample_rate as f32 * RELEASE_MS / 1000.0;
+
+        Self {
+            delay_line: VecDeque::from(vec![0.0; lookahead_samples]),
+            lookahead_samples,
+            gain: 1.0,
+            gain_step: 0.0,
+            ramp_remaining: 0,
+            target_gain: 1.0,
+            hold_remaining: 0,
+            release_coef: 1.0 - (-1.0 / release_samples).exp(),
+        }
     }
 
-    /// Process sample with true peak limiting
+    /// Process sample with true peak limiting. Output is delayed by the lookahead.
     pub fn process(&mut self, sample: f32, limit: f32) -> f32 {
-        // TODO: Implement lookahead limiting in Phase 3
-        // For now, return simple clipping
-        sample.max(-limit).min(limit)
+        let required_gain = if sample.abs() > limit { limit / sample.abs() } else { 1.0 };
+
+        if required_gain < 1.0 {
+            // Hold the reduction until this peak has left the delay line
+            self.hold_remaining = self.lookahead_samples;
+            if required_gain < self.target_gain.min(self.gain) {
+                // Reach the required gain by the time the peak is played
+                self.target_gain = required_gain;
+                self.ramp_remaining = self.lookahead_samples;
+                self.gain_step = (self.gain - required_gain) / self.lookahead_samples as f32;
+            }
+        }
+
+        if self.ramp_remaining > 0 {
+            self.gain -= self.gain_step;
+            self.ramp_remaining -= 1;
+        } else if self.hold_remaining > 0 {
+            self.hold_remaining -= 1;
+        } else if self.gain < 1.0 {
+            self.gain += (1.0 - self.gain) * self.release_coef;
+            self.target_gain = 1.0;
+        }
+
+        self.delay_line.push_back(sample);
+        let delayed = self.delay_line.pop_front().unwrap_or(0.0);
+
+        // Overlapping ramps can leave a sample slightly above the limit; never let it through
+        (delayed * self.gain).clamp(-limit, limit)
+    }
+
+    /// Process a block of samples
+    pub fn process_block(&mut self, samples: &[f32], limit: f32) -> Vec<f32> {
+        samples.iter().map(|&sample| self.process(sample, limit)).collect()
+    }
+
+    /// Output latency in samples
+    pub fn latency_samples(&self) -> usize {
+        self.lookahead_samples
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_limits_peaks_and_releases() {
+        let mut limiter = TruePeakLimiter::new(48000, 5);
+        let limit = 0.5;
+
+        // Quiet signal with a single loud burst in the middle
+        let input: Vec<f32> = (0..48000)
+            .map(|i| {
+                let tone = (i as f32 * 440.0 * std::f32::consts::TAU / 48000.0).sin();
+                if (20000..21000).contains(&i) { tone * 0.9 } else { tone * 0.2 }
+            })
+            .collect();
+        let output = limiter.process_block(&input, limit);
+
+        assert!(output.iter().all(|s| s.abs() <= limit));
+        // The burst is reduced to the limit, the quiet tail is back at full level
+        let latency = limiter.latency_samples();
+        let burst_peak = output[20000 + latency..21000 + latency].iter().fold(0.0f32, |m, s| m.max(s.abs()));
+        assert!(burst_peak > 0.45);
+        let tail_peak = output[40000..].iter().fold(0.0f32, |m, s| m.max(s.abs()));
+        assert!((tail_peak - 0.2).abs() < 0.01);
     }
 }
@@ -1,23 +1,24 @@
 //! Professional audio mixing
-//! 
+//!
 //! This module provides dynamic audio mixing capabilities based on real-time
 //! analysis, replacing the fixed 60%/40% mixing ratio.
 
-use anyhow::Result;
+use serde::{Deserialize, Serialize};
 use std::collections::VecDeque;
 
 /// Professional audio mixer with dynamic level analysis
 pub struct AudioMixer {
-    rms_analyzer: RmsAnalyzer,
+    mic_analyzer: RmsAnalyzer,
+    system_analyzer: RmsAnalyzer,
     ducking_processor: DuckingProcessor,
-    crossfade_processor: CrossfadeProcessor,
     mixing_mode: MixingMode,
     history_buffer: VecDeque<f32>,
     history_size: usize,
 }
 
 /// Audio mixing modes
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "mode", rename_all = "snake_case")]
 pub enum MixingMode {
     /// Fixed ratio mixing (legacy behavior)
     Fixed { mic_ratio: f32, system_ratio: f32 },
@@ -27,6 +28,23 @@ pub enum MixingMode {
     Professional,
 }
 
+impl MixingMode {
+    pub fn validate(&self) -> Result<(), String> {
+        if let MixingMode::Fixed { mic_ratio, system_ratio } = self {
+            if !(0.0..=1.0).contains(mic_ratio) || !(0.0..=1.0).contains(system_ratio) {
+                return Err("Fixed mixing ratios must be between 0.0 and 1.0".to_string());
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Default for MixingMode {
+    fn default() -> Self {
+        MixingMode::Professional
+    }
+}
+
 /// RMS analyzer for real-time audio level detection
 struct RmsAnalyzer {
     window_size: usize,
@@ -35,27 +53,27 @@ struct RmsAnalyzer {
 }
 
 /// Ducking processor for automatic level adjustment
+/// Lowers the mic while the system audio envelope is above the threshold, so remote
+/// speech isn't masked by keyboard and room noise picked up by the mic
 struct DuckingProcessor {
     threshold: f32,
-    attack_time: f32,
-    release_time: f32,
+    attack: f32,
+    release: f32,
+    envelope: f32,
     current_gain: f32,
-    target_gain: f32,
 }
 
-/// Crossfade processor for smooth transitions
-struct CrossfadeProcessor {
-    fade_length: usize,
-    fade_buffer: VecDeque<f32>,
-}
+/// Mic and system weights of the professional mode (after ducking)
+const PROFESSIONAL_MIC_WEIGHT: f32 = 0.6;
+const PROFESSIONAL_SYSTEM_WEIGHT: f32 = 0.4;
 
 impl AudioMixer {
     /// Create a new professional audio mixer
     pub fn new(mixing_mode: MixingMode) -> Self {
         Self {
-            rms_analyzer: RmsAnalyzer::new(1024), // 1024 sample window
-            ducking_processor: DuckingProcessor::new(0.1, 0.01, 0.1), // 10% threshold, 10ms attack, 100ms release
-            crossfade_processor: CrossfadeProcessor::new(256), // 256 sample crossfade
+            mic_analyzer: RmsAnalyzer::new(1024), // 1024 sample window
+            system_analyzer: RmsAnalyzer::new(1024),
+            ducking_processor: DuckingProcessor::new(0.1, 0.01, 0.0005), // 10% threshold, fast attack, slow release
             mixing_mode,
             history_buffer: VecDeque::with_capacity(2048),
             history_size: 2048,
@@ -71,37 +89,34 @@ impl AudioMixer {
             MixingMode::Fixed { mic_ratio, system_ratio } => {
                 // Fixed ratio mixing (legacy behavior)
                 for i in 0..max_len {
-                    let mic_sample = if i < mic.len() { mic[i] } else { 0.0 };
-                    let system_sample = if i < system.len() { system[i] } else { 0.0 };
+                    let mic_sample = mic.get(i).copied().unwrap_or(0.0);
+                    let system_sample = system.get(i).copied().unwrap_or(0.0);
                     mixed.push(mic_sample * mic_ratio + system_sample * system_ratio);
                 }
             }
             MixingMode::Dynamic => {
                 // Dynamic mixing based on real-time analysis
-                let mic_rms = self.rms_analyzer.analyze(mic);
-                let system_rms = self.rms_analyzer.analyze(system);
-                
-                let (mic_ratio, system_ratio) = self.calculate_dynamic_ratios(mic_rms, system_rms);
-                
+                let mic_rms = self.mic_analyzer.analyze(mic);
+                let system_rms = self.system_analyzer.analyze(system);
+
+                let (mic_ratio, system_ratio) = Self::calculate_dynamic_ratios(mic_rms, system_rms);
+
                 for i in 0..max_len {
-                    let mic_sample = if i < mic.len() { mic[i] } else { 0.0 };
-                    let system_sample = if i < system.len() { system[i] } else { 0.0 };
+                    let mic_sample = mic.get(i).copied().unwrap_or(0.0);
+                    let system_sample = system.get(i).copied().unwrap_or(0.0);
                     mixed.push(mic_sample * mic_ratio + system_sample * system_ratio);
                 }
             }
             MixingMode::Professional => {
-                // Professional mixing with ducking and crossfading
+                // Professional mixing with ducking and weighted summing
                 for i in 0..max_len {
-                    let mic_sample = if i < mic.len() { mic[i] } else { 0.0 };
-                    let system_sample = if i < system.len() { system[i] } else { 0.0 };
-                    
+                    let mic_sample = mic.get(i).copied().unwrap_or(0.0);
+                    let system_sample = system.get(i).copied().unwrap_or(0.0);
+
                     // Apply ducking
                     let ducked_mic = self.ducking_processor.process(mic_sample, system_sample);
-                    
-                    // Apply crossfade
-                    let crossfaded = self.crossfade_processor.process(ducked_mic, system_sample);
-                    
-                    mixed.push(crossfaded);
+
+                    mixed.push(ducked_mic * PROFESSIONAL_MIC_WEIGHT + system_sample * PROFESSIONAL_SYSTEM_WEIGHT);
                 }
             }
         }
@@ -118,7 +133,7 @@ impl AudioMixer {
     }
 
     /// Calculate dynamic mixing ratios based on RMS levels
-    fn calculate_dynamic_ratios(&self, mic_rms: f32, system_rms: f32) -> (f32, f32) {
+    fn calculate_dynamic_ratios(mic_rms: f32, system_rms: f32) -> (f32, f32) {
         if mic_rms == 0.0 && system_rms == 0.0 {
             return (0.5, 0.5); // Equal mix for silence
         }
@@ -133,7 +148,7 @@ impl AudioMixer {
 
         // Calculate ratios based on relative levels
         let total_level = mic_rms + system_rms;
-        let mic_ratio = (mic_rms / total_level).max(0.1).min(0.9); // Keep between 10% and 90%
+        let mic_ratio = (mic_rms / total_level).clamp(0.1, 0.9); // Keep between 10% and 90%
         let system_ratio = 1.0 - mic_ratio;
 
         (mic_ratio, system_ratio)
@@ -151,19 +166,18 @@ impl AudioMixer {
 
     /// Get audio level statistics
     pub fn get_level_stats(&self) -> AudioLevelStats {
-        let history: Vec<f32> = self.history_buffer.iter().cloned().collect();
-        let rms = if !history.is_empty() {
-            (history.iter().map(|&x| x * x).sum::<f32>() / history.len() as f32).sqrt()
+        let rms = if !self.history_buffer.is_empty() {
+            (self.history_buffer.iter().map(|&x| x * x).sum::<f32>() / self.history_buffer.len() as f32).sqrt()
         } else {
             0.0
         };
 
-        let peak = history.iter().map(|&x| x.abs()).fold(0.0f32, f32::max);
+        let peak = self.history_buffer.iter().map(|&x| x.abs()).fold(0.0f32, f32::max);
 
         AudioLevelStats {
             rms,
             peak,
-            samples_analyzed: history.len(),
+            samples_analyzed: self.history_buffer.len(),
         }
     }
 }
@@ -192,67 +206,36 @@ impl RmsAnalyzer {
         if self.buffer.is_empty() {
             0.0
         } else {
-            (self.sum_squares / self.buffer.len() as f32).sqrt()
+            // Running sums can drift slightly below zero
+            (self.sum_squares.max(0.0) / self.buffer.len() as f32).sqrt()
         }
     }
 }
 
 impl DuckingProcessor {
-    fn new(threshold: f32, attack_time: f32, release_time: f32) -> Self {
+    /// `attack` and `release` are per-sample smoothing factors (0-1)
+    fn new(threshold: f32, attack: f32, release: f32) -> Self {
         Self {
             threshold,
-            attack_time,
-            release_time,
+            attack,
+            release,
+            envelope: 0.0,
             current_gain: 1.0,
-            target_gain: 1.0,
         }
     }
 
     fn process(&mut self, mic_sample: f32, system_sample: f32) -> f32 {
-        let system_level = system_sample.abs();
-        
-        // Calculate target gain based on system level
-        if system_level > self.threshold {
-            // Duck the mic when system audio is loud
-            self.target_gain = 0.3; // Reduce mic to 30%
-        } else {
-            // Restore mic when system audio is quiet
-            self.target_gain = 1.0;
-        }
+        // Peak envelope of the system audio: instant rise, ~50ms decay at 48kHz
+        self.envelope = system_sample.abs().max(self.envelope * 0.9996);
 
-        // Smooth gain transitions
-        let gain_diff = self.target_gain - self.current_gain;
-        if gain_diff.abs() > 0.01 {
-            let step_size = if gain_diff > 0.0 {
-                self.attack_time
-            } else {
-                self.release_time
-            };
-            self.current_gain += gain_diff * step_size;
-        }
+        // Duck the mic to 30% while system audio is loud, restore it otherwise
+        let target_gain = if self.envelope > self.threshold { 0.3 } else { 1.0 };
 
-        mic_sample * self.current_gain
-    }
-}
+        // Smooth gain transitions: duck quickly, restore slowly
+        let step = if target_gain < self.current_gain { self.attack } else { self.release };
+        self.current_gain += (target_gain - self.current_gain) * step;
 
-impl CrossfadeProcessor {
-    fn new(fade_length: usize) -> Self {
-        Self {
-            fade_length,
-            fade_buffer: VecDeque::with_capacity(fade_length),
-        }
-    }
-
-    fn process(&mut self, mic_sample: f32, system_sample: f32) -> f32 {
-        // Simple crossfade implementation
-        // In a more sophisticated version, this would handle smooth transitions
-        // between different audio sources
-        
-        // For now, use a simple weighted average
-        let mic_weight = 0.6;
-        let system_weight = 0.4;
-        
-        mic_sample * mic_weight + system_sample * system_weight
+        mic_sample * self.current_gain
     }
 }
 
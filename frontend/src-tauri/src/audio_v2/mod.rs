@@ -0,0 +1,51 @@
+//! Modern audio system based on new architecture
+//!
+//! This module provides a professional-grade audio processing system that runs
+//! next to the legacy audio system. Only the mixing stage is switchable: capture
+//! always uses the legacy `audio::stream` devices, and recordings select the mixer
+//! at runtime through the `compatibility` bridge (see `settings` for the user's
+//! engine choice).
+
+pub mod mixer;
+pub mod normalizer;
+pub mod compatibility;
+pub mod sync;
+pub mod limiter;
+pub mod settings;
+
+// Re-export main types for easy access
+pub use mixer::{AudioMixer, MixingMode, AudioLevelStats};
+pub use normalizer::AudioNormalizer;
+pub use compatibility::{LegacyBridge, AudioMode, AudioQualityMetrics, MixEngine, MixEngines, MixedWindow};
+pub use sync::{AudioSynchronizer, SynchronizedChunk};
+pub use limiter::TruePeakLimiter;
+pub use settings::AudioEngineSettings;
+
+/// Modern audio system configuration
+#[derive(Debug, Clone)]
+pub struct AudioConfig {
+    /// Target sample rate for processing
+    pub target_sample_rate: u32,
+    /// EBU R128 normalization target in LUFS
+    pub normalization_target_lufs: f64,
+    /// Sync tolerance in milliseconds
+    pub sync_tolerance_ms: u32,
+    /// Enable true peak limiting
+    pub enable_true_peak_limiting: bool,
+    /// Mixing mode for mic and system audio
+    pub mixing_mode: MixingMode,
+}
+
+impl Default for AudioConfig {
+    fn default() -> Self {
+        Self {
+            target_sample_rate: 48000,
+            normalization_target_lufs: -23.0, // EBU R128 standard for speech
+            // Chunk timestamps are taken when audio reaches the pipeline, so smaller
+            // offsets are scheduling jitter rather than real gaps
+            sync_tolerance_ms: 50,
+            enable_true_peak_limiting: true,
+            mixing_mode: MixingMode::Professional,
+        }
+    }
+}
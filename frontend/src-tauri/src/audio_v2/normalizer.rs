@@ -1,36 +1,83 @@
 //! EBU R128 normalization
-//! 
-//! This module provides professional audio normalization using the EBU R128
-//! standard, replacing the inconsistent normalization approaches.
+//!
+//! This module provides loudness normalization using the EBU R128 standard.
+//! The gain follows the short-term (3 s) loudness of the signal, so it adapts
+//! when speakers or call volume change during a meeting. Until 3 s have been
+//! measured the momentary (400 ms) loudness is used, since the short-term
+//! window would still be mostly empty and overstate the gain.
 
 use anyhow::Result;
+use ebur128::{EbuR128, Mode};
+
+/// Short-term loudness below this is silence and doesn't move the gain
+const GATE_LUFS: f64 = -50.0;
+/// Gain limits (dB); quiet passages are not boosted into noise
+const MAX_GAIN_DB: f64 = 20.0;
+const MIN_GAIN_DB: f64 = -20.0;
+/// Time constant of gain changes
+const GAIN_SMOOTHING_MS: f32 = 500.0;
+const SHORT_TERM_WINDOW_MS: u64 = 3000;
+const MOMENTARY_WINDOW_MS: u64 = 400;
 
 /// Professional audio normalizer with EBU R128 compliance
 pub struct AudioNormalizer {
     target_lufs: f64,
-    // TODO: Add EBU R128 analyzer when dependencies are available
-    _placeholder: (),
+    meter: EbuR128,
+    sample_rate: u32,
+    measured_samples: u64,
+    gain: f32,
+    target_gain: f32,
+    smoothing_coef: f32,
 }
 
 impl AudioNormalizer {
     /// Create a new audio normalizer
-    pub fn new(target_lufs: f64) -> Self {
-        Self { 
+    pub fn new(target_lufs: f64, sample_rate: u32) -> Result<Self> {
+        let meter = EbuR128::new(1, sample_rate, Mode::M | Mode::S)
+            .map_err(|e| anyhow::anyhow!("Failed to create EBU R128 meter: {}", e))?;
+        let smoothing_samples = sample_rate as f32 * GAIN_SMOOTHING_MS / 1000.0;
+
+        Ok(Self {
             target_lufs,
-            _placeholder: (),
-        }
+            meter,
+            sample_rate,
+            measured_samples: 0,
+            gain: 1.0,
+            target_gain: 1.0,
+            smoothing_coef: 1.0 - (-1.0 / smoothing_samples).exp(),
+        })
     }
 
     /// Normalize audio to target LUFS level
     pub fn normalize(&mut self, audio: &[f32]) -> Vec<f32> {
-        // TODO: Implement EBU R128 normalization when ebur128 dependency is available
-        // For now, return simple normalization
-        let peak = audio.iter().map(|&x| x.abs()).fold(0.0f32, f32::max);
-        if peak > 0.0 {
-            let gain = 0.25 / peak; // Target -12dB peak
-            audio.iter().map(|&x| (x * gain).max(-1.0).min(1.0)).collect()
-        } else {
-            audio.to_vec()
+        if self.meter.add_frames_f32(audio).is_ok() {
+            self.measured_samples += audio.len() as u64;
+            let measured_ms = self.measured_samples * 1000 / self.sample_rate as u64;
+            let loudness = if measured_ms >= SHORT_TERM_WINDOW_MS {
+                self.meter.loudness_shortterm().ok()
+            } else if measured_ms >= MOMENTARY_WINDOW_MS {
+                self.meter.loudness_momentary().ok()
+            } else {
+                None
+            };
+
+            if let Some(loudness) = loudness.filter(|lufs| lufs.is_finite() && *lufs > GATE_LUFS) {
+                let gain_db = (self.target_lufs - loudness).clamp(MIN_GAIN_DB, MAX_GAIN_DB);
+                self.target_gain = 10f64.powf(gain_db / 20.0) as f32;
+            }
         }
+
+        audio
+            .iter()
+            .map(|&sample| {
+                self.gain += (self.target_gain - self.gain) * self.smoothing_coef;
+                sample * self.gain
+            })
+            .collect()
+    }
+
+    /// Current linear gain
+    pub fn gain(&self) -> f32 {
+        self.gain
     }
 }
@@ -0,0 +1,117 @@
+//! Audio engine settings
+//!
+//! Which engine mixes new recordings (legacy, modern, or legacy with the modern
+//! engine measured alongside) and how the modern engine mixes.
+
+use log::info;
+use serde::{Deserialize, Serialize};
+use std::sync::Mutex;
+use tauri::{AppHandle, Runtime};
+
+use super::compatibility::{self, AudioMode, AudioQualityMetrics};
+use super::mixer::MixingMode;
+use crate::audio::{load_store_setting, save_store_setting};
+
+const STORE_KEY: &str = "audioEngineSettings";
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct AudioEngineSettings {
+    pub mode: AudioMode,
+    /// Mixing of the modern engine (the legacy engine always sums both sources)
+    pub mixing_mode: MixingMode,
+}
+
+impl AudioEngineSettings {
+    pub const DEFAULT: AudioEngineSettings = AudioEngineSettings {
+        mode: AudioMode::Legacy,
+        mixing_mode: MixingMode::Professional,
+    };
+
+    pub fn validate(&self) -> Result<(), String> {
+        self.mixing_mode.validate()
+    }
+}
+
+impl Default for AudioEngineSettings {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+static AUDIO_ENGINE_SETTINGS: Mutex<AudioEngineSettings> = Mutex::new(AudioEngineSettings::DEFAULT);
+
+/// Get the engine settings used for new recordings
+pub fn current() -> AudioEngineSettings {
+    AUDIO_ENGINE_SETTINGS
+        .lock()
+        .map(|settings| *settings)
+        .unwrap_or_default()
+}
+
+fn set_current(settings: AudioEngineSettings) {
+    if let Ok(mut current) = AUDIO_ENGINE_SETTINGS.lock() {
+        *current = settings;
+    }
+}
+
+/// Load persisted settings into the global settings (called on startup)
+pub fn load_audio_engine_settings<R: Runtime>(app: &AppHandle<R>) {
+    if let Some(settings) = load_store_setting(
+        app,
+        STORE_KEY,
+        "audio engine settings",
+        AudioEngineSettings::validate,
+    ) {
+        info!("🔀 Loaded audio engine settings: {:?}", settings);
+        set_current(settings);
+    }
+}
+
+#[tauri::command]
+pub async fn get_audio_engine_settings() -> Result<AudioEngineSettings, String> {
+    Ok(current())
+}
+
+#[tauri::command]
+pub async fn set_audio_engine_settings<R: Runtime>(
+    app: AppHandle<R>,
+    settings: AudioEngineSettings,
+) -> Result<(), String> {
+    settings.validate()?;
+    save_store_setting(&app, STORE_KEY, "audio engine settings", &settings)?;
+
+    info!("🔀 Audio engine settings updated (next recording): {:?}", settings);
+    set_current(settings);
+    Ok(())
+}
+
+/// Quality metrics of recent recordings, per engine (oldest first). In hybrid
+/// mode each recording has an entry for both engines with the same session id.
+/// Saved recordings also keep theirs in metadata.json.
+#[tauri::command]
+pub async fn get_audio_quality_metrics() -> Result<Vec<AudioQualityMetrics>, String> {
+    Ok(compatibility::quality_metrics_history())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_settings_round_trip() {
+        let settings = AudioEngineSettings {
+            mode: AudioMode::Hybrid,
+            mixing_mode: MixingMode::Fixed { mic_ratio: 0.6, system_ratio: 0.4 },
+        };
+        let value = serde_json::to_value(settings).unwrap();
+        assert_eq!(value["mode"], "hybrid");
+        assert_eq!(value["mixing_mode"]["mode"], "fixed");
+        assert_eq!(serde_json::from_value::<AudioEngineSettings>(value).unwrap(), settings);
+
+        let invalid = AudioEngineSettings {
+            mixing_mode: MixingMode::Fixed { mic_ratio: 1.5, system_ratio: 0.4 },
+            ..settings
+        };
+        assert!(invalid.validate().is_err());
+    }
+}
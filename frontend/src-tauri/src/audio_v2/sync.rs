@@ -1,34 +1,255 @@
 //! Audio synchronization engine
-//! 
+//!
 //! This module provides timestamp-based synchronization to replace simple
-//! concatenation, ensuring perfect temporal alignment between streams.
+//! concatenation. Each source is placed on a shared sample timeline using the
+//! chunk timestamps (recording time at capture), so a stream that stalls or
+//! starts late is padded with silence where the gap happened instead of being
+//! pulled forward against the other source.
 
-use anyhow::Result;
-use std::time::Instant;
+use std::collections::VecDeque;
 
-/// Synchronized audio chunk
+use crate::audio::recording_state::DeviceType;
+
+/// Synchronized audio chunk: one window of both sources on the shared timeline
 #[derive(Debug, Clone)]
 pub struct SynchronizedChunk {
-    pub samples: Vec<f32>,
+    pub mic: Vec<f32>,
+    pub system: Vec<f32>,
+    /// Start of the window in seconds since the recording started
     pub timestamp: f64,
+    /// Window length in seconds
     pub duration: f64,
 }
 
-/// Audio synchronizer for perfect temporal alignment
+/// Window size of synchronized output
+const WINDOW_MS: u32 = 100;
+/// How long to wait for a lagging source before emitting its part as silence
+const MAX_LAG_MS: u32 = 500;
+/// Safety limit on buffered audio per source
+const MAX_BUFFER_MS: u32 = 5000;
+
+/// One source's samples, starting at timeline position `base`
+#[derive(Default)]
+struct SourceTimeline {
+    buffer: VecDeque<f32>,
+    base: u64,
+    started: bool,
+}
+
+impl SourceTimeline {
+    fn write_position(&self) -> u64 {
+        self.base + self.buffer.len() as u64
+    }
+
+    /// Samples available from `position` onwards (including leading silence)
+    fn available_from(&self, position: u64) -> u64 {
+        self.write_position().saturating_sub(position)
+    }
+
+    /// Take `len` samples starting at `start`; missing audio is silence
+    fn take(&mut self, start: u64, len: usize) -> Vec<f32> {
+        let mut window = Vec::with_capacity(len);
+        let lead = (self.base.saturating_sub(start) as usize).min(len);
+        window.resize(lead, 0.0);
+        let available = (len - lead).min(self.buffer.len());
+        window.extend(self.buffer.drain(..available));
+        window.resize(len, 0.0);
+        self.base = self.base.max(start + len as u64);
+        window
+    }
+}
+
+/// Tracks how far apart the two sources are on the output timeline, relative
+/// to when their audio was captured. Engines that ignore timestamps report it
+/// too, so their alignment can be compared.
+#[derive(Debug, Default)]
+pub struct SyncTracker {
+    mic_offset: Option<i64>,
+    system_offset: Option<i64>,
+    total_error_samples: f64,
+    measurements: u64,
+}
+
+impl SyncTracker {
+    /// Record where the first sample of a chunk lands on the output timeline
+    /// (`output_position`) versus when it was captured (`capture_position`)
+    pub fn record(&mut self, device_type: &DeviceType, output_position: u64, capture_position: u64) {
+        let offset = output_position as i64 - capture_position as i64;
+        match device_type {
+            DeviceType::Microphone => self.mic_offset = Some(offset),
+            DeviceType::System => self.system_offset = Some(offset),
+        }
+
+        if let (Some(mic), Some(system)) = (self.mic_offset, self.system_offset) {
+            self.total_error_samples += (mic - system).unsigned_abs() as f64;
+            self.measurements += 1;
+        }
+    }
+
+    /// Mean offset between the sources in milliseconds (0 with a single source)
+    pub fn accuracy_ms(&self, sample_rate: u32) -> f64 {
+        if self.measurements == 0 {
+            return 0.0;
+        }
+        self.total_error_samples / self.measurements as f64 / sample_rate as f64 * 1000.0
+    }
+}
+
+/// Audio synchronizer for temporal alignment of microphone and system audio
 pub struct AudioSynchronizer {
-    // TODO: Implement in Phase 4
-    _placeholder: (),
+    sample_rate: u32,
+    tolerance_samples: u64,
+    window_samples: usize,
+    max_lag_samples: u64,
+    max_buffer_samples: usize,
+    mic: SourceTimeline,
+    system: SourceTimeline,
+    /// Timeline position of the next window
+    emitted: u64,
+    tracker: SyncTracker,
+    inserted_gap_samples: u64,
 }
 
 impl AudioSynchronizer {
-    /// Create a new audio synchronizer
-    pub fn new(sync_tolerance_ms: u32) -> Self {
-        Self { _placeholder: () }
+    /// Create a new audio synchronizer. Timestamp offsets up to `sync_tolerance_ms`
+    /// are treated as scheduling jitter; larger gaps are filled with silence.
+    pub fn new(sample_rate: u32, sync_tolerance_ms: u32) -> Self {
+        let samples = |ms: u32| sample_rate as u64 * ms as u64 / 1000;
+        Self {
+            sample_rate,
+            tolerance_samples: samples(sync_tolerance_ms),
+            window_samples: samples(WINDOW_MS) as usize,
+            max_lag_samples: samples(MAX_LAG_MS),
+            max_buffer_samples: samples(MAX_BUFFER_MS) as usize,
+            mic: SourceTimeline::default(),
+            system: SourceTimeline::default(),
+            emitted: 0,
+            tracker: SyncTracker::default(),
+            inserted_gap_samples: 0,
+        }
     }
 
-    /// Synchronize audio streams
-    pub fn synchronize(&mut self) -> Result<Vec<SynchronizedChunk>> {
-        // TODO: Implement timestamp-based synchronization in Phase 4
-        Ok(vec![])
+    fn timeline(&mut self, device_type: &DeviceType) -> &mut SourceTimeline {
+        match device_type {
+            DeviceType::Microphone => &mut self.mic,
+            DeviceType::System => &mut self.system,
+        }
+    }
+
+    /// Add a chunk. `timestamp` is the recording time (seconds) when the chunk
+    /// was captured, i.e. the end of its audio.
+    pub fn add_samples(&mut self, device_type: DeviceType, timestamp: f64, samples: &[f32]) {
+        let capture_end = (timestamp.max(0.0) * self.sample_rate as f64).round() as u64;
+        let capture_start = capture_end.saturating_sub(samples.len() as u64);
+        let (emitted, tolerance, max_buffer) = (self.emitted, self.tolerance_samples, self.max_buffer_samples);
+
+        let timeline = self.timeline(&device_type);
+        if !timeline.started {
+            // First chunk of this source: place it where it was captured
+            timeline.started = true;
+            timeline.base = capture_start.max(emitted);
+        }
+
+        let mut gap = 0;
+        let write_position = timeline.write_position();
+        if capture_start > write_position + tolerance {
+            // The stream stalled: keep the gap so later audio stays aligned
+            gap = capture_start - write_position;
+            timeline.buffer.resize(timeline.buffer.len() + gap as usize, 0.0);
+        }
+        let output_position = timeline.write_position();
+        timeline.buffer.extend(samples.iter().copied());
+
+        let overflow = timeline.buffer.len().saturating_sub(max_buffer);
+        if overflow > 0 {
+            timeline.buffer.drain(..overflow);
+            timeline.base += overflow as u64;
+        }
+
+        self.inserted_gap_samples += gap;
+        self.tracker.record(&device_type, output_position, capture_start);
+    }
+
+    /// Next aligned window, once every running source has audio for it (or a
+    /// source has fallen more than the maximum lag behind)
+    pub fn next_window(&mut self) -> Option<SynchronizedChunk> {
+        let window = self.window_samples as u64;
+        let sources = [&self.mic, &self.system];
+        let running: Vec<u64> = sources
+            .iter()
+            .filter(|source| source.started)
+            .map(|source| source.available_from(self.emitted))
+            .collect();
+
+        let all_ready = !running.is_empty() && running.iter().all(|&available| available >= window);
+        let lead_exceeded = running.iter().any(|&available| available >= window + self.max_lag_samples);
+        if !all_ready && !lead_exceeded {
+            return None;
+        }
+
+        Some(self.take_window(self.window_samples))
+    }
+
+    /// Remaining buffered audio as one last window (end of recording)
+    pub fn flush(&mut self) -> Option<SynchronizedChunk> {
+        let remaining = self.mic.available_from(self.emitted).max(self.system.available_from(self.emitted));
+        if remaining == 0 {
+            return None;
+        }
+        Some(self.take_window(remaining as usize))
+    }
+
+    fn take_window(&mut self, len: usize) -> SynchronizedChunk {
+        let start = self.emitted;
+        let chunk = SynchronizedChunk {
+            mic: self.mic.take(start, len),
+            system: self.system.take(start, len),
+            timestamp: start as f64 / self.sample_rate as f64,
+            duration: len as f64 / self.sample_rate as f64,
+        };
+        self.emitted += len as u64;
+        chunk
+    }
+
+    /// Mean offset between microphone and system audio in milliseconds
+    pub fn sync_accuracy_ms(&self) -> f64 {
+        self.tracker.accuracy_ms(self.sample_rate)
+    }
+
+    /// Silence inserted for stream stalls, in seconds
+    pub fn inserted_gap_seconds(&self) -> f64 {
+        self.inserted_gap_samples as f64 / self.sample_rate as f64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stalled_source_is_padded_in_place() {
+        let mut sync = AudioSynchronizer::new(1000, 20);
+        // 10ms chunks at 1 kHz: mic runs continuously, system stalls from 100ms to 300ms
+        for i in 1..=40u32 {
+            let timestamp = i as f64 * 0.01;
+            sync.add_samples(DeviceType::Microphone, timestamp, &[0.5; 10]);
+            if !(11..=30).contains(&i) {
+                sync.add_samples(DeviceType::System, timestamp, &[0.25; 10]);
+            }
+        }
+
+        let mut system = Vec::new();
+        while let Some(chunk) = sync.next_window() {
+            assert_eq!(chunk.mic.len(), chunk.system.len());
+            system.extend(chunk.system);
+        }
+        system.extend(sync.flush().map(|chunk| chunk.system).unwrap_or_default());
+
+        assert_eq!(system.len(), 400);
+        assert!(system[..100].iter().all(|&s| s == 0.25));
+        assert!(system[100..300].iter().all(|&s| s == 0.0));
+        assert!(system[300..].iter().all(|&s| s == 0.25));
+        assert!((sync.inserted_gap_seconds() - 0.2).abs() < 1e-9);
+        assert!(sync.sync_accuracy_ms() < 1.0);
     }
 }
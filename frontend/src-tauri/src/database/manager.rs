@@ -1,3 +1,4 @@
+use sqlx::sqlite::SqliteConnectOptions;
 use sqlx::{migrate::MigrateDatabase, Result, Sqlite, SqlitePool, Transaction};
 use std::fs;
 use std::path::Path;
@@ -9,6 +10,24 @@ pub struct DatabaseManager {
 
 impl DatabaseManager {
     pub async fn new(tauri_db_path: &str, backend_db_path: &str) -> Result<Self> {
+        Self::new_with_key(tauri_db_path, backend_db_path, None).await
+    }
+
+    /// Connection options for the database at `path`. `key` is the SQLCipher key of an
+    /// encrypted database (see `crate::encryption`).
+    pub fn connect_options(path: &str, key: Option<&str>) -> SqliteConnectOptions {
+        let options = SqliteConnectOptions::new().filename(path);
+        match key {
+            Some(key) => options.pragma("key", format!("\"{}\"", key)),
+            None => options,
+        }
+    }
+
+    pub async fn new_with_key(
+        tauri_db_path: &str,
+        backend_db_path: &str,
+        key: Option<&str>,
+    ) -> Result<Self> {
         if let Some(parent_dir) = Path::new(tauri_db_path).parent() {
             if !parent_dir.exists() {
                 fs::create_dir_all(parent_dir).map_err(|e| sqlx::Error::Io(e))?;
@@ -29,7 +48,7 @@ impl DatabaseManager {
             }
         }
 
-        let pool = SqlitePool::connect(tauri_db_path).await?;
+        let pool = SqlitePool::connect_with(Self::connect_options(tauri_db_path, key)).await?;
 
         sqlx::migrate!("./migrations").run(&pool).await?;
 
@@ -41,6 +60,14 @@ impl DatabaseManager {
     // the current app dir, So the system detects legacy db and copy it and starts with that data
     // (Newly created .sqlite with the copied content from .db)
     pub async fn new_from_app_handle(app_handle: &tauri::AppHandle) -> Result<Self> {
+        Self::open_from_app_handle(app_handle, None).await
+    }
+
+    /// Like `new_from_app_handle`, for a database encrypted with SQLCipher key `key`
+    pub async fn open_from_app_handle(
+        app_handle: &tauri::AppHandle,
+        key: Option<&str>,
+    ) -> Result<Self> {
         // Resolve the app's data directory
         let app_data_dir = app_handle
             .path()
@@ -63,7 +90,16 @@ impl DatabaseManager {
         log::info!("Tauri DB path: {}", tauri_db_path);
         log::info!("Legacy backend DB path: {}", backend_db_path);
 
-        Self::new(&tauri_db_path, &backend_db_path).await
+        // API keys are encrypted with a per-install key stored next to the database
+        if let Err(e) = crate::secrets::init(&app_data_dir) {
+            log::error!("Failed to initialize secret store: {}", e);
+        }
+
+        let manager = Self::new_with_key(&tauri_db_path, &backend_db_path, key).await?;
+        if let Err(e) = crate::secrets::encrypt_migrated_secrets(manager.pool()).await {
+            log::error!("Failed to encrypt migrated API keys: {}", e);
+        }
+        Ok(manager)
     }
 
     /// Check if this is the first launch (sqlite database doesn't exist yet)
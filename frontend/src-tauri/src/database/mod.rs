@@ -1,5 +1,6 @@
 pub mod commands;
 pub mod manager;
 pub mod models;
+pub mod recovery;
 pub mod repositories;
 pub mod setup;
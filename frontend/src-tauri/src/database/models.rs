@@ -35,6 +35,25 @@ pub struct Transcript {
     pub audio_start_time: Option<f64>,
     pub audio_end_time: Option<f64>,
     pub duration: Option<f64>,
+    // Speaker source tag ("You" / "Remote" in dual-channel mode)
+    pub source: Option<String>,
+    // Diarized speaker label ("Speaker 1", ... or a user-assigned name)
+    pub speaker: Option<String>,
+    // Geometric mean of the decoder's token probabilities (None for providers without scores)
+    pub confidence: Option<f64>,
+}
+
+#[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
+pub struct TranscriptWord {
+    pub id: i64,
+    pub transcript_id: String,
+    pub meeting_id: String,
+    pub word_index: i64,
+    pub word: String,
+    // Recording-relative timestamps in seconds
+    pub start_time: f64,
+    pub end_time: f64,
+    pub probability: Option<f64>,
 }
 
 #[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
@@ -52,6 +71,66 @@ pub struct SummaryProcess {
     pub metadata: Option<String>, // JSON
 }
 
+#[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
+pub struct SummaryVersion {
+    pub id: String,
+    pub meeting_id: String,
+    pub version_number: i64,
+    pub source: String, // generated | edited | restored
+    pub template_id: Option<String>,
+    pub provider: Option<String>,
+    pub model: Option<String>,
+    pub custom_prompt: Option<String>,
+    pub restored_from: Option<String>,
+    pub result: String, // JSON
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    pub updated_at: chrono::DateTime<chrono::Utc>,
+}
+
+#[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
+pub struct MeetingChatMessage {
+    pub id: String,
+    pub meeting_id: String,
+    pub role: String, // user | assistant
+    pub content: String,
+    pub provider: Option<String>,
+    pub model: Option<String>,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+}
+
+#[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
+pub struct ActionItem {
+    pub id: String,
+    pub meeting_id: String,
+    pub task: String,
+    pub owner: Option<String>,
+    pub due_date: Option<String>, // YYYY-MM-DD
+    pub source_timestamp: Option<String>,
+    pub transcript_id: Option<String>,
+    pub status: String, // open | done
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    pub updated_at: chrono::DateTime<chrono::Utc>,
+    pub completed_at: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+/// Action item together with the title of its meeting, for cross-meeting lists
+#[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
+pub struct ActionItemWithMeeting {
+    #[sqlx(flatten)]
+    #[serde(flatten)]
+    pub item: ActionItem,
+    pub meeting_title: String,
+}
+
+/// A custom vocabulary term; `meeting_id` is None for the user's own list
+#[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
+pub struct VocabularyTerm {
+    pub id: i64,
+    pub meeting_id: Option<String>,
+    pub term: String,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+}
+
 #[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
 pub struct TranscriptChunk {
     pub meeting_id: String,
@@ -90,6 +169,16 @@ pub struct Setting {
     #[sqlx(rename = "ollamaEndpoint")]
     #[serde(rename = "ollamaEndpoint")]
     pub ollama_endpoint: Option<String>,
+    #[sqlx(rename = "customBaseUrl")]
+    #[serde(rename = "customBaseUrl")]
+    pub custom_base_url: Option<String>,
+    #[sqlx(rename = "customApiKey")]
+    #[serde(rename = "customApiKey")]
+    pub custom_api_key: Option<String>,
+    // Cleared by migration: the headers are secrets now (`summary.custom.headers`)
+    #[sqlx(rename = "customHeaders")]
+    #[serde(rename = "customHeaders")]
+    pub custom_headers: Option<String>,
 }
 
 #[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
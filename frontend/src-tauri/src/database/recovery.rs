@@ -0,0 +1,274 @@
+// database/recovery.rs
+//
+// Rebuilds the meetings index from the recordings folder. Every folder written by
+// `RecordingSaver` has a metadata.json and transcripts.json, so meetings can be restored
+// after the database was lost; rows whose folder no longer exists are reported.
+
+use crate::audio::recording_preferences::load_recording_preferences;
+use crate::audio::recovery::{
+    active_recording_folder, inspect_interrupted, InterruptedRecording, DISCARDED_STATUS,
+};
+use crate::database::repositories::meeting::MeetingsRepository;
+use crate::export::commands::{import_meeting, ImportStatus};
+use crate::export::import::{read_meeting_folder, DuplicatePolicy};
+use crate::state::AppState;
+use log::{error, info, warn};
+use serde::Serialize;
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+use tauri::{AppHandle, Runtime};
+
+/// A recording folder with no meeting row
+#[derive(Debug, Serialize)]
+pub struct MissingMeeting {
+    pub folder_path: String,
+    pub title: String,
+    /// Id of the recreated meeting; `None` in a dry run
+    pub restored_meeting_id: Option<String>,
+}
+
+/// A meeting row whose recording folder is gone
+#[derive(Debug, Serialize)]
+pub struct OrphanedMeeting {
+    pub meeting_id: String,
+    pub title: String,
+    pub folder_path: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct RescanFailure {
+    pub folder_path: String,
+    pub error: String,
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct RescanReport {
+    pub recordings_folder: String,
+    pub scanned_folders: usize,
+    pub already_indexed: usize,
+    pub missing: Vec<MissingMeeting>,
+    pub orphaned: Vec<OrphanedMeeting>,
+    pub interrupted: Vec<InterruptedRecording>,
+    pub failed: Vec<RescanFailure>,
+}
+
+/// What a meeting folder's metadata.json says about it
+struct FolderInfo {
+    path: PathBuf,
+    meeting_id: Option<String>,
+    status: Option<String>,
+}
+
+/// Meeting folders (sub-folders with a metadata.json) directly inside `root`, except the
+/// folder of the recording in progress
+fn scan_folders(root: &Path, active_folder: Option<&Path>) -> Result<Vec<FolderInfo>, String> {
+    let entries = std::fs::read_dir(root)
+        .map_err(|e| format!("Failed to read {}: {}", root.display(), e))?;
+    let active = active_folder.map(normalized);
+
+    let mut folders = Vec::new();
+    for entry in entries.filter_map(Result::ok) {
+        let path = entry.path();
+        let metadata_path = path.join("metadata.json");
+        if !path.is_dir() || !metadata_path.is_file() {
+            continue;
+        }
+        if active.as_ref() == Some(&normalized(&path)) {
+            continue;
+        }
+        // Read leniently: a half-written metadata.json still tells us the status
+        let metadata: serde_json::Value = std::fs::read_to_string(&metadata_path)
+            .ok()
+            .and_then(|content| serde_json::from_str(&content).ok())
+            .unwrap_or_default();
+        let field = |name: &str| {
+            metadata
+                .get(name)
+                .and_then(|v| v.as_str())
+                .filter(|v| !v.is_empty())
+                .map(str::to_string)
+        };
+        folders.push(FolderInfo {
+            // Extracted export bundles keep the id under "meeting"
+            meeting_id: field("meeting_id").or_else(|| {
+                metadata
+                    .pointer("/meeting/id")
+                    .and_then(|v| v.as_str())
+                    .map(str::to_string)
+            }),
+            status: field("status"),
+            path,
+        });
+    }
+    folders.sort_by(|a, b| a.path.cmp(&b.path));
+    Ok(folders)
+}
+
+fn normalized(path: &Path) -> PathBuf {
+    path.canonicalize().unwrap_or_else(|_| path.to_path_buf())
+}
+
+/// Reconciles the recordings folder with the meetings table. Folders without a meeting are
+/// restored (unless `dry_run`), rows whose folder is gone are reported as orphaned and
+/// folders of recordings that never finished are reported as interrupted.
+#[tauri::command]
+pub async fn api_rescan_recordings<R: Runtime>(
+    app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    dry_run: Option<bool>,
+) -> Result<RescanReport, String> {
+    let dry_run = dry_run.unwrap_or(false);
+    let pool = state.db_manager.pool();
+    let root = load_recording_preferences(&app)
+        .await
+        .map_err(|e| format!("Failed to load recording preferences: {}", e))?
+        .save_folder;
+    info!("🔎 Rescanning recordings in {} (dry run: {})", root.display(), dry_run);
+
+    let mut report = RescanReport {
+        recordings_folder: root.to_string_lossy().to_string(),
+        ..Default::default()
+    };
+
+    let meetings = MeetingsRepository::get_meetings(pool)
+        .await
+        .map_err(|e| e.to_string())?;
+    let known_ids: HashSet<String> = meetings.iter().map(|m| m.id.clone()).collect();
+    let mut known_folders: HashSet<PathBuf> = HashSet::new();
+    for meeting in &meetings {
+        let Some(folder_path) = meeting.folder_path.as_deref().filter(|p| !p.is_empty()) else {
+            continue;
+        };
+        let folder = Path::new(folder_path);
+        if folder.is_dir() {
+            known_folders.insert(normalized(folder));
+        } else {
+            report.orphaned.push(OrphanedMeeting {
+                meeting_id: meeting.id.clone(),
+                title: meeting.title.clone(),
+                folder_path: folder_path.to_string(),
+            });
+        }
+    }
+
+    let folders = if root.is_dir() {
+        let scan_root = root.clone();
+        let active = active_recording_folder().await;
+        tokio::task::spawn_blocking(move || scan_folders(&scan_root, active.as_deref()))
+            .await
+            .map_err(|e| format!("Rescan task failed: {}", e))??
+    } else {
+        warn!("Recordings folder {} does not exist", root.display());
+        Vec::new()
+    };
+    report.scanned_folders = folders.len();
+
+    for folder in folders {
+        let folder_path = folder.path.to_string_lossy().to_string();
+        let indexed = known_folders.contains(&normalized(&folder.path))
+            || folder.meeting_id.as_ref().is_some_and(|id| known_ids.contains(id));
+        if indexed {
+            report.already_indexed += 1;
+            continue;
+        }
+
+        // Interrupted recordings go through the recovery flow instead of a plain import
+        if let Some(interrupted) = inspect_interrupted(&folder.path) {
+            report.interrupted.push(interrupted);
+            continue;
+        }
+        if folder.status.as_deref() == Some(DISCARDED_STATUS) {
+            continue;
+        }
+
+        let path = folder.path.clone();
+        let imported = match tokio::task::spawn_blocking(move || read_meeting_folder(&path)).await {
+            Ok(result) => result,
+            Err(e) => Err(format!("Rescan task failed: {}", e)),
+        };
+        let imported = match imported {
+            Ok(imported) => imported,
+            Err(error) => {
+                report.failed.push(RescanFailure { folder_path, error });
+                continue;
+            }
+        };
+
+        if dry_run {
+            report.missing.push(MissingMeeting {
+                folder_path,
+                title: imported.title,
+                restored_meeting_id: None,
+            });
+            continue;
+        }
+
+        match import_meeting(pool, imported, &folder.path, &root, DuplicatePolicy::Skip).await {
+            Ok(result) => {
+                if result.status == ImportStatus::Skipped {
+                    report.already_indexed += 1;
+                } else {
+                    info!("♻️ Restored meeting {} from {}", result.meeting_id, folder_path);
+                    report.missing.push(MissingMeeting {
+                        folder_path,
+                        title: result.title,
+                        restored_meeting_id: Some(result.meeting_id),
+                    });
+                }
+            }
+            Err(error) => {
+                error!("❌ Failed to restore meeting from {}: {}", folder_path, error);
+                report.failed.push(RescanFailure { folder_path, error });
+            }
+        }
+    }
+
+    info!(
+        "🔎 Rescan finished: {} folders, {} indexed, {} missing, {} orphaned, {} interrupted, {} failed",
+        report.scanned_folders,
+        report.already_indexed,
+        report.missing.len(),
+        report.orphaned.len(),
+        report.interrupted.len(),
+        report.failed.len()
+    );
+    Ok(report)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scan_folders() {
+        let root = std::env::temp_dir().join(format!("meetily-rescan-test-{}", uuid::Uuid::new_v4()));
+        let finished = root.join("Standup_2025-11-03_09-00");
+        let interrupted = root.join("Retro_2025-11-04_15-00");
+        std::fs::create_dir_all(&finished).unwrap();
+        std::fs::create_dir_all(interrupted.join(".checkpoints")).unwrap();
+        std::fs::create_dir_all(root.join("not-a-meeting")).unwrap();
+        std::fs::write(
+            finished.join("metadata.json"),
+            r#"{"meeting_id":"meeting-1","meeting_name":"Standup","status":"completed"}"#,
+        )
+        .unwrap();
+        std::fs::write(interrupted.join("metadata.json"), r#"{"status":"recording"}"#).unwrap();
+        for i in 0..3 {
+            std::fs::write(interrupted.join(format!(".checkpoints/audio_chunk_{:03}.mp4", i)), b"").unwrap();
+        }
+
+        let folders = scan_folders(&root, None).unwrap();
+        // The recording in progress looks interrupted but must be left alone
+        let while_recording = scan_folders(&root, Some(&interrupted)).unwrap();
+        let interrupted = inspect_interrupted(&interrupted).unwrap();
+        std::fs::remove_dir_all(&root).unwrap();
+
+        assert_eq!(folders.len(), 2);
+        assert_eq!(folders[0].meeting_id, None);
+        assert_eq!(folders[0].status.as_deref(), Some("recording"));
+        assert_eq!(folders[1].meeting_id.as_deref(), Some("meeting-1"));
+        assert_eq!(interrupted.checkpoint_count, 3);
+        assert_eq!(while_recording.len(), 1);
+        assert_eq!(while_recording[0].meeting_id.as_deref(), Some("meeting-1"));
+    }
+}
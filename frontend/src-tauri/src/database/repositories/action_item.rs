@@ -0,0 +1,160 @@
+use crate::database::models::{ActionItem, ActionItemWithMeeting, Transcript};
+use chrono::Utc;
+use sqlx::SqlitePool;
+use tracing::info as log_info;
+use uuid::Uuid;
+
+/// An extracted action item to store
+#[derive(Debug, Clone, PartialEq)]
+pub struct NewActionItem {
+    pub task: String,
+    pub owner: Option<String>,
+    pub due_date: Option<String>,
+    pub source_timestamp: Option<String>,
+    pub transcript_id: Option<String>,
+}
+
+pub struct ActionItemsRepository;
+
+impl ActionItemsRepository {
+    /// Replaces the open items of a meeting with a fresh extraction. Items already marked
+    /// done are kept, and extracted items repeating a done task are not added again.
+    pub async fn replace_open_items(
+        pool: &SqlitePool,
+        meeting_id: &str,
+        items: &[NewActionItem],
+    ) -> Result<Vec<ActionItem>, sqlx::Error> {
+        let mut transaction = pool.begin().await?;
+
+        let done_tasks: Vec<String> = sqlx::query_scalar(
+            "SELECT task FROM action_items WHERE meeting_id = ? AND status = 'done'",
+        )
+        .bind(meeting_id)
+        .fetch_all(&mut *transaction)
+        .await?;
+        let done_tasks: Vec<String> = done_tasks.iter().map(|t| normalize_task(t)).collect();
+
+        sqlx::query("DELETE FROM action_items WHERE meeting_id = ? AND status = 'open'")
+            .bind(meeting_id)
+            .execute(&mut *transaction)
+            .await?;
+
+        let now = Utc::now();
+        let mut inserted = 0;
+        for item in items {
+            if done_tasks.contains(&normalize_task(&item.task)) {
+                continue;
+            }
+            sqlx::query(
+                r#"
+                INSERT INTO action_items (
+                    id, meeting_id, task, owner, due_date, source_timestamp, transcript_id,
+                    status, created_at, updated_at
+                )
+                VALUES (?, ?, ?, ?, ?, ?, ?, 'open', ?, ?)
+                "#,
+            )
+            .bind(Uuid::new_v4().to_string())
+            .bind(meeting_id)
+            .bind(&item.task)
+            .bind(&item.owner)
+            .bind(&item.due_date)
+            .bind(&item.source_timestamp)
+            .bind(&item.transcript_id)
+            .bind(now)
+            .bind(now)
+            .execute(&mut *transaction)
+            .await?;
+            inserted += 1;
+        }
+
+        transaction.commit().await?;
+        log_info!(
+            "Stored {} action items for meeting_id: {}",
+            inserted,
+            meeting_id
+        );
+
+        Self::list_for_meeting(pool, meeting_id).await
+    }
+
+    /// Action items of a meeting in extraction order
+    pub async fn list_for_meeting(
+        pool: &SqlitePool,
+        meeting_id: &str,
+    ) -> Result<Vec<ActionItem>, sqlx::Error> {
+        sqlx::query_as::<_, ActionItem>(
+            "SELECT * FROM action_items WHERE meeting_id = ? ORDER BY created_at, rowid",
+        )
+        .bind(meeting_id)
+        .fetch_all(pool)
+        .await
+    }
+
+    /// Open items across all meetings: dated items by due date first, then newest meetings
+    pub async fn list_open(pool: &SqlitePool) -> Result<Vec<ActionItemWithMeeting>, sqlx::Error> {
+        sqlx::query_as::<_, ActionItemWithMeeting>(
+            r#"
+            SELECT a.*, m.title AS meeting_title
+            FROM action_items a
+            JOIN meetings m ON m.id = a.meeting_id
+            WHERE a.status = 'open'
+            ORDER BY a.due_date IS NULL, a.due_date, m.created_at DESC, a.rowid
+            "#,
+        )
+        .fetch_all(pool)
+        .await
+    }
+
+    pub async fn get(pool: &SqlitePool, id: &str) -> Result<Option<ActionItem>, sqlx::Error> {
+        sqlx::query_as::<_, ActionItem>("SELECT * FROM action_items WHERE id = ?")
+            .bind(id)
+            .fetch_optional(pool)
+            .await
+    }
+
+    /// Transcript segment an item was extracted from
+    pub async fn get_source_transcript(
+        pool: &SqlitePool,
+        id: &str,
+    ) -> Result<Option<Transcript>, sqlx::Error> {
+        sqlx::query_as::<_, Transcript>(
+            "SELECT t.* FROM transcripts t JOIN action_items a ON a.transcript_id = t.id WHERE a.id = ?",
+        )
+        .bind(id)
+        .fetch_optional(pool)
+        .await
+    }
+
+    /// Sets the status ('open' or 'done'). Returns None for an unknown item.
+    pub async fn update_status(
+        pool: &SqlitePool,
+        id: &str,
+        status: &str,
+    ) -> Result<Option<ActionItem>, sqlx::Error> {
+        let now = Utc::now();
+        let completed_at = (status == "done").then_some(now);
+
+        let result = sqlx::query(
+            "UPDATE action_items SET status = ?, completed_at = ?, updated_at = ? WHERE id = ?",
+        )
+        .bind(status)
+        .bind(completed_at)
+        .bind(now)
+        .bind(id)
+        .execute(pool)
+        .await?;
+
+        if result.rows_affected() == 0 {
+            return Ok(None);
+        }
+        Self::get(pool, id).await
+    }
+}
+
+fn normalize_task(task: &str) -> String {
+    task.split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_lowercase()
+}
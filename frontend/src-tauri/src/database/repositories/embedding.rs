@@ -0,0 +1,145 @@
+use chrono::Utc;
+use sqlx::SqlitePool;
+
+/// A piece of text to store with its embedding
+pub struct NewEmbedding {
+    pub meeting_id: String,
+    pub kind: &'static str,
+    pub ref_id: String,
+    pub chunk_index: i64,
+    pub content: String,
+    pub vector: Vec<u8>,
+    pub dimensions: i64,
+}
+
+/// A stored embedding with the meeting context needed for citations
+#[derive(Debug, sqlx::FromRow)]
+pub struct StoredEmbedding {
+    pub meeting_id: String,
+    pub meeting_title: String,
+    pub meeting_created_at: String,
+    pub kind: String,
+    pub ref_id: String,
+    pub content: String,
+    pub vector: Vec<u8>,
+    pub timestamp: Option<String>,
+    pub audio_start_time: Option<f64>,
+}
+
+pub struct EmbeddingsRepository;
+
+impl EmbeddingsRepository {
+    /// Transcript segments without an embedding for `model`: (transcript_id, meeting_id, text)
+    pub async fn pending_transcripts(
+        pool: &SqlitePool,
+        model: &str,
+        limit: i64,
+    ) -> Result<Vec<(String, String, String)>, sqlx::Error> {
+        sqlx::query_as(
+            "SELECT t.id, t.meeting_id, t.transcript
+             FROM transcripts t
+             LEFT JOIN embeddings e ON e.kind = 'transcript' AND e.ref_id = t.id AND e.model = ?
+             WHERE e.id IS NULL AND length(trim(t.transcript)) > 0
+             LIMIT ?",
+        )
+        .bind(model)
+        .bind(limit)
+        .fetch_all(pool)
+        .await
+    }
+
+    /// Summaries without an embedding for `model`: (meeting_id, result JSON)
+    pub async fn pending_summaries(
+        pool: &SqlitePool,
+        model: &str,
+    ) -> Result<Vec<(String, String)>, sqlx::Error> {
+        sqlx::query_as(
+            "SELECT sp.meeting_id, sp.result
+             FROM summary_processes sp
+             WHERE sp.result IS NOT NULL
+               AND NOT EXISTS (
+                   SELECT 1 FROM embeddings e
+                   WHERE e.kind = 'summary' AND e.ref_id = sp.meeting_id AND e.model = ?
+               )",
+        )
+        .bind(model)
+        .fetch_all(pool)
+        .await
+    }
+
+    /// Number of pieces still waiting to be embedded for `model`
+    pub async fn count_pending(pool: &SqlitePool, model: &str) -> Result<i64, sqlx::Error> {
+        sqlx::query_scalar(
+            "SELECT
+                (SELECT COUNT(*) FROM transcripts t
+                 WHERE length(trim(t.transcript)) > 0 AND NOT EXISTS (
+                     SELECT 1 FROM embeddings e
+                     WHERE e.kind = 'transcript' AND e.ref_id = t.id AND e.model = ?))
+              + (SELECT COUNT(*) FROM summary_processes sp
+                 WHERE sp.result IS NOT NULL AND NOT EXISTS (
+                     SELECT 1 FROM embeddings e
+                     WHERE e.kind = 'summary' AND e.ref_id = sp.meeting_id AND e.model = ?))",
+        )
+        .bind(model)
+        .bind(model)
+        .fetch_one(pool)
+        .await
+    }
+
+    pub async fn insert_embeddings(
+        pool: &SqlitePool,
+        model: &str,
+        embeddings: &[NewEmbedding],
+    ) -> Result<(), sqlx::Error> {
+        let mut transaction = pool.begin().await?;
+        let now = Utc::now();
+
+        for embedding in embeddings {
+            sqlx::query(
+                "INSERT INTO embeddings (meeting_id, kind, ref_id, chunk_index, content, model, dimensions, vector, created_at)
+                 VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)
+                 ON CONFLICT(model, kind, ref_id, chunk_index) DO UPDATE SET
+                     content = excluded.content,
+                     dimensions = excluded.dimensions,
+                     vector = excluded.vector,
+                     created_at = excluded.created_at",
+            )
+            .bind(&embedding.meeting_id)
+            .bind(embedding.kind)
+            .bind(&embedding.ref_id)
+            .bind(embedding.chunk_index)
+            .bind(&embedding.content)
+            .bind(model)
+            .bind(embedding.dimensions)
+            .bind(&embedding.vector)
+            .bind(now)
+            .execute(&mut *transaction)
+            .await?;
+        }
+
+        transaction.commit().await?;
+        Ok(())
+    }
+
+    /// All embeddings of `model`, optionally limited to one meeting
+    pub async fn load_embeddings(
+        pool: &SqlitePool,
+        model: &str,
+        meeting_id: Option<&str>,
+    ) -> Result<Vec<StoredEmbedding>, sqlx::Error> {
+        sqlx::query_as::<_, StoredEmbedding>(
+            "SELECT e.meeting_id, m.title AS meeting_title, m.created_at AS meeting_created_at,
+                    e.kind, e.ref_id, e.content, e.vector, t.timestamp, t.audio_start_time
+             FROM embeddings e
+             JOIN meetings m ON m.id = e.meeting_id
+             LEFT JOIN transcripts t ON e.kind = 'transcript' AND t.id = e.ref_id
+             WHERE e.model = ?
+               AND (? IS NULL OR e.meeting_id = ?)",
+        )
+        .bind(model)
+        .bind(meeting_id)
+        .bind(meeting_id)
+        .fetch_all(pool)
+        .await
+    }
+}
@@ -1,9 +1,25 @@
-use crate::api::{MeetingDetails, MeetingTranscript};
-use crate::database::models::{MeetingModel, Transcript};
-use chrono::Utc;
+use crate::api::{MeetingDetails, MeetingTranscript, TranscriptSegment};
+use crate::audio::transcription::WordTiming;
+use crate::database::models::{MeetingModel, Transcript, TranscriptWord};
+use crate::database::repositories::transcript::{exclusive_date_bound, insert_segments};
+use serde_json::Value;
+use std::collections::HashMap;
+use chrono::{DateTime, Utc};
 use sqlx::{Connection, Error as SqlxError, SqliteConnection, SqlitePool};
 use tracing::{error, info};
 
+/// A complete meeting to insert with its own id and timestamps (used by imports)
+pub struct NewMeeting<'a> {
+    pub id: &'a str,
+    pub title: &'a str,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub folder_path: Option<&'a str>,
+    pub transcripts: &'a [TranscriptSegment],
+    /// Summary result (`{"markdown": ...}`), stored as a completed summary process
+    pub summary: Option<&'a Value>,
+}
+
 pub struct MeetingsRepository;
 
 impl MeetingsRepository {
@@ -15,6 +31,93 @@ impl MeetingsRepository {
         Ok(meetings)
     }
 
+    /// Meetings created between `date_from` and `date_to` (inclusive `YYYY-MM-DD` days), oldest first
+    pub async fn get_meetings_in_range(
+        pool: &SqlitePool,
+        date_from: &str,
+        date_to: &str,
+    ) -> Result<Vec<MeetingModel>, sqlx::Error> {
+        sqlx::query_as::<_, MeetingModel>(
+            "SELECT * FROM meetings
+             WHERE datetime(created_at) >= datetime(?) AND datetime(created_at) < datetime(?)
+             ORDER BY created_at",
+        )
+        .bind(date_from)
+        .bind(exclusive_date_bound(date_to))
+        .fetch_all(pool)
+        .await
+    }
+
+    /// Id of the meeting with the given id or recording folder, if one exists
+    pub async fn find_existing(
+        pool: &SqlitePool,
+        meeting_id: Option<&str>,
+        folder_path: Option<&str>,
+    ) -> Result<Option<String>, SqlxError> {
+        let row: Option<(String,)> = sqlx::query_as(
+            "SELECT id FROM meetings WHERE id = ? OR folder_path = ?
+             ORDER BY id = ? DESC LIMIT 1",
+        )
+        .bind(meeting_id)
+        .bind(folder_path)
+        .bind(meeting_id)
+        .fetch_optional(pool)
+        .await?;
+        Ok(row.map(|(id,)| id))
+    }
+
+    /// Inserts a meeting with its transcripts and summary in one transaction.
+    /// With `replace_existing`, a meeting with the same id and all its data is deleted first.
+    pub async fn insert_meeting(
+        pool: &SqlitePool,
+        meeting: NewMeeting<'_>,
+        replace_existing: bool,
+    ) -> Result<(), SqlxError> {
+        let mut transaction = pool.begin().await?;
+
+        if replace_existing {
+            delete_meeting_with_transaction(&mut *transaction, meeting.id).await?;
+        }
+
+        sqlx::query(
+            "INSERT INTO meetings (id, title, created_at, updated_at, folder_path) VALUES (?, ?, ?, ?, ?)",
+        )
+        .bind(meeting.id)
+        .bind(meeting.title)
+        .bind(meeting.created_at)
+        .bind(meeting.updated_at)
+        .bind(meeting.folder_path)
+        .execute(&mut *transaction)
+        .await?;
+
+        insert_segments(&mut *transaction, meeting.id, meeting.transcripts).await?;
+
+        if let Some(summary) = meeting.summary {
+            let result = serde_json::to_string(summary)
+                .map_err(|e| SqlxError::Protocol(format!("Failed to serialize summary: {}", e)))?;
+            let now = Utc::now();
+            sqlx::query(
+                "INSERT INTO summary_processes (meeting_id, status, created_at, updated_at, result, end_time)
+                 VALUES (?, 'completed', ?, ?, ?, ?)",
+            )
+            .bind(meeting.id)
+            .bind(now)
+            .bind(now)
+            .bind(result)
+            .bind(now)
+            .execute(&mut *transaction)
+            .await?;
+        }
+
+        transaction.commit().await?;
+        info!(
+            "Inserted meeting {} with {} transcript segments",
+            meeting.id,
+            meeting.transcripts.len()
+        );
+        Ok(())
+    }
+
     pub async fn delete_meeting(pool: &SqlitePool, meeting_id: &str) -> Result<bool, SqlxError> {
         if meeting_id.trim().is_empty() {
             return Err(SqlxError::Protocol(
@@ -80,18 +183,43 @@ impl MeetingsRepository {
                     .fetch_all(&mut *transaction)
                     .await?;
 
+            let words = sqlx::query_as::<_, TranscriptWord>(
+                "SELECT * FROM transcript_words WHERE meeting_id = ? ORDER BY transcript_id, word_index",
+            )
+            .bind(meeting_id)
+            .fetch_all(&mut *transaction)
+            .await?;
+
             transaction.commit().await?;
 
+            // Group word timings by transcript segment
+            let mut words_by_transcript: HashMap<String, Vec<WordTiming>> = HashMap::new();
+            for w in words {
+                words_by_transcript
+                    .entry(w.transcript_id)
+                    .or_default()
+                    .push(WordTiming {
+                        word: w.word,
+                        start: w.start_time,
+                        end: w.end_time,
+                        probability: w.probability.map(|p| p as f32),
+                    });
+            }
+
             // Convert Transcript to MeetingTranscript
             let meeting_transcripts = transcripts
                 .into_iter()
                 .map(|t| MeetingTranscript {
+                    words: words_by_transcript.remove(&t.id).unwrap_or_default(),
                     id: t.id,
                     text: t.transcript,
                     timestamp: t.timestamp,
                     audio_start_time: t.audio_start_time,
                     audio_end_time: t.audio_end_time,
                     duration: t.duration,
+                    source: t.source,
+                    speaker: t.speaker,
+                    confidence: t.confidence.map(|c| c as f32),
                 })
                 .collect::<Vec<_>>();
 
@@ -108,6 +236,23 @@ impl MeetingsRepository {
         }
     }
 
+    /// Returns the recording folder of a meeting, if it was recorded with folder-based storage.
+    pub async fn get_meeting_folder_path(
+        pool: &SqlitePool,
+        meeting_id: &str,
+    ) -> Result<Option<String>, SqlxError> {
+        let row: Option<(Option<String>,)> =
+            sqlx::query_as("SELECT folder_path FROM meetings WHERE id = ?")
+                .bind(meeting_id)
+                .fetch_optional(pool)
+                .await?;
+
+        match row {
+            Some((folder_path,)) => Ok(folder_path),
+            None => Err(SqlxError::RowNotFound),
+        }
+    }
+
     pub async fn update_meeting_title(
         pool: &SqlitePool,
         meeting_id: &str,
@@ -195,13 +340,48 @@ async fn delete_meeting_with_transaction(
         .execute(&mut *transaction)
         .await?;
 
-    // 2. Delete from summary_processes
+    // 2. Delete from summary_processes and cached chunk summaries
     sqlx::query("DELETE FROM summary_processes WHERE meeting_id = ?")
         .bind(meeting_id)
         .execute(&mut *transaction)
         .await?;
 
-    // 3. Delete from transcripts
+    sqlx::query("DELETE FROM summary_chunk_cache WHERE meeting_id = ?")
+        .bind(meeting_id)
+        .execute(&mut *transaction)
+        .await?;
+
+    sqlx::query("DELETE FROM summary_versions WHERE meeting_id = ?")
+        .bind(meeting_id)
+        .execute(&mut *transaction)
+        .await?;
+
+    sqlx::query("DELETE FROM embeddings WHERE meeting_id = ?")
+        .bind(meeting_id)
+        .execute(&mut *transaction)
+        .await?;
+
+    sqlx::query("DELETE FROM meeting_chat_messages WHERE meeting_id = ?")
+        .bind(meeting_id)
+        .execute(&mut *transaction)
+        .await?;
+
+    sqlx::query("DELETE FROM action_items WHERE meeting_id = ?")
+        .bind(meeting_id)
+        .execute(&mut *transaction)
+        .await?;
+
+    sqlx::query("DELETE FROM vocabulary_terms WHERE meeting_id = ?")
+        .bind(meeting_id)
+        .execute(&mut *transaction)
+        .await?;
+
+    // 3. Delete word timings, then transcripts
+    sqlx::query("DELETE FROM transcript_words WHERE meeting_id = ?")
+        .bind(meeting_id)
+        .execute(&mut *transaction)
+        .await?;
+
     sqlx::query("DELETE FROM transcripts WHERE meeting_id = ?")
         .bind(meeting_id)
         .execute(&mut *transaction)
@@ -0,0 +1,66 @@
+use crate::database::models::MeetingChatMessage;
+use chrono::Utc;
+use sqlx::SqlitePool;
+use uuid::Uuid;
+
+/// Conversation turns of the per-meeting chat
+pub struct MeetingChatRepository;
+
+impl MeetingChatRepository {
+    pub async fn add_message(
+        pool: &SqlitePool,
+        meeting_id: &str,
+        role: &str,
+        content: &str,
+        provider: Option<&str>,
+        model: Option<&str>,
+    ) -> Result<MeetingChatMessage, sqlx::Error> {
+        let message = MeetingChatMessage {
+            id: Uuid::new_v4().to_string(),
+            meeting_id: meeting_id.to_string(),
+            role: role.to_string(),
+            content: content.to_string(),
+            provider: provider.map(str::to_string),
+            model: model.map(str::to_string),
+            created_at: Utc::now(),
+        };
+
+        sqlx::query(
+            "INSERT INTO meeting_chat_messages (id, meeting_id, role, content, provider, model, created_at)
+             VALUES (?, ?, ?, ?, ?, ?, ?)",
+        )
+        .bind(&message.id)
+        .bind(&message.meeting_id)
+        .bind(&message.role)
+        .bind(&message.content)
+        .bind(&message.provider)
+        .bind(&message.model)
+        .bind(message.created_at)
+        .execute(pool)
+        .await?;
+
+        Ok(message)
+    }
+
+    /// All turns of a meeting's conversation, oldest first
+    pub async fn list_messages(
+        pool: &SqlitePool,
+        meeting_id: &str,
+    ) -> Result<Vec<MeetingChatMessage>, sqlx::Error> {
+        sqlx::query_as::<_, MeetingChatMessage>(
+            "SELECT * FROM meeting_chat_messages WHERE meeting_id = ? ORDER BY created_at, rowid",
+        )
+        .bind(meeting_id)
+        .fetch_all(pool)
+        .await
+    }
+
+    /// Deletes the conversation of a meeting, returning the number of removed turns
+    pub async fn clear(pool: &SqlitePool, meeting_id: &str) -> Result<u64, sqlx::Error> {
+        let result = sqlx::query("DELETE FROM meeting_chat_messages WHERE meeting_id = ?")
+            .bind(meeting_id)
+            .execute(pool)
+            .await?;
+        Ok(result.rows_affected())
+    }
+}
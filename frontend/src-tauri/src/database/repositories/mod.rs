@@ -1,5 +1,13 @@
+pub mod action_item;
+pub mod embedding;
 pub mod meeting;
+pub mod meeting_chat;
+pub mod secret;
 pub mod setting;
 pub mod summary;
+pub mod summary_chunk;
+pub mod summary_version;
 pub mod transcript;
 pub mod transcript_chunk;
+pub mod transcript_word;
+pub mod vocabulary;
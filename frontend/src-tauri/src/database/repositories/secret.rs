@@ -0,0 +1,48 @@
+use chrono::Utc;
+use sqlx::SqlitePool;
+
+/// Raw rows of the `secrets` table. Values are ciphertext; see `crate::secrets` for
+/// encryption and masking.
+pub struct SecretsRepository;
+
+impl SecretsRepository {
+    /// Stored (encrypted) value of a secret
+    pub async fn get(pool: &SqlitePool, name: &str) -> Result<Option<String>, sqlx::Error> {
+        sqlx::query_scalar("SELECT value FROM secrets WHERE name = ? AND encrypted = 1")
+            .bind(name)
+            .fetch_optional(pool)
+            .await
+    }
+
+    /// Inserts or replaces an encrypted value
+    pub async fn upsert(pool: &SqlitePool, name: &str, encrypted: &str) -> Result<(), sqlx::Error> {
+        sqlx::query(
+            "INSERT INTO secrets (name, value, encrypted, updated_at) VALUES (?, ?, 1, ?)
+             ON CONFLICT(name) DO UPDATE SET
+                value = excluded.value,
+                encrypted = 1,
+                updated_at = excluded.updated_at",
+        )
+        .bind(name)
+        .bind(encrypted)
+        .bind(Utc::now())
+        .execute(pool)
+        .await?;
+        Ok(())
+    }
+
+    pub async fn delete(pool: &SqlitePool, name: &str) -> Result<(), sqlx::Error> {
+        sqlx::query("DELETE FROM secrets WHERE name = ?")
+            .bind(name)
+            .execute(pool)
+            .await?;
+        Ok(())
+    }
+
+    /// (name, plaintext) of values that still need to be encrypted
+    pub async fn get_unencrypted(pool: &SqlitePool) -> Result<Vec<(String, String)>, sqlx::Error> {
+        sqlx::query_as("SELECT name, value FROM secrets WHERE encrypted = 0")
+            .fetch_all(pool)
+            .await
+    }
+}
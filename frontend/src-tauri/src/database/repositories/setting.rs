@@ -1,4 +1,5 @@
 use crate::database::models::{Setting, TranscriptSetting};
+use crate::secrets;
 use sqlx::SqlitePool;
 
 #[derive(serde::Deserialize, Debug)]
@@ -24,7 +25,7 @@ pub struct SaveTranscriptConfigRequest {
 pub struct SettingsRepository;
 
 // Transcript providers: localWhisper, deepgram, elevenLabs, groq, openai
-// Summary providers: openai, claude, ollama, groq, added openrouter
+// Summary providers: openai, claude, ollama, groq, added openrouter, custom (OpenAI-compatible)
 // NOTE: Handle data exclusion in the higher layer as this is database abstraction layer(using SELECT *)
 
 impl SettingsRepository {
@@ -66,61 +67,90 @@ impl SettingsRepository {
         Ok(())
     }
 
+    /// Encrypts and stores the API key of a summary provider. Masked values sent back
+    /// by the frontend are ignored.
     pub async fn save_api_key(
         pool: &SqlitePool,
         provider: &str,
         api_key: &str,
     ) -> std::result::Result<(), sqlx::Error> {
-        let api_key_column = match provider {
-            "openai" => "openaiApiKey",
-            "claude" => "anthropicApiKey",
-            "ollama" => "ollamaApiKey",
-            "groq" => "groqApiKey",
-            "openrouter" => "openRouterApiKey",
-            _ => {
-                return Err(sqlx::Error::Protocol(
-                    format!("Invalid provider: {}", provider).into(),
-                ))
-            }
-        };
+        let name = summary_secret_name(provider)?;
+        if secrets::is_masked(api_key) {
+            return Ok(());
+        }
+        secrets::set_secret(pool, name, api_key)
+            .await
+            .map_err(sqlx::Error::Protocol)
+    }
+
+    /// Decrypted API key of a summary provider. Only for making provider requests;
+    /// use `get_masked_api_key` for anything shown in the UI.
+    pub async fn get_api_key(
+        pool: &SqlitePool,
+        provider: &str,
+    ) -> std::result::Result<Option<String>, sqlx::Error> {
+        let name = summary_secret_name(provider)?;
+        secrets::get_secret(pool, name)
+            .await
+            .map_err(sqlx::Error::Protocol)
+    }
+
+    pub async fn get_masked_api_key(
+        pool: &SqlitePool,
+        provider: &str,
+    ) -> std::result::Result<Option<String>, sqlx::Error> {
+        let name = summary_secret_name(provider)?;
+        secrets::get_masked_secret(pool, name)
+            .await
+            .map_err(sqlx::Error::Protocol)
+    }
 
-        let query = format!(
+    /// Save the base URL of the custom OpenAI-compatible provider (its headers are secrets,
+    /// see `save_custom_headers`)
+    pub async fn save_custom_provider_config(
+        pool: &SqlitePool,
+        base_url: &str,
+    ) -> std::result::Result<(), sqlx::Error> {
+        sqlx::query(
             r#"
-            INSERT INTO settings (id, provider, model, whisperModel, "{}")
-            VALUES ('1', 'openai', 'gpt-4o-2024-11-20', 'large-v3', $1)
+            INSERT INTO settings (id, provider, model, whisperModel, customBaseUrl)
+            VALUES ('1', 'custom', '', 'large-v3', $1)
             ON CONFLICT(id) DO UPDATE SET
-                "{}" = $1
+                customBaseUrl = excluded.customBaseUrl
             "#,
-            api_key_column, api_key_column
-        );
-        sqlx::query(&query).bind(api_key).execute(pool).await?;
+        )
+        .bind(base_url)
+        .execute(pool)
+        .await?;
 
         Ok(())
     }
 
-    pub async fn get_api_key(
+    /// Encrypts and stores the extra headers (JSON object) of a summary provider. `None`
+    /// removes them.
+    pub async fn save_custom_headers(
         pool: &SqlitePool,
         provider: &str,
-    ) -> std::result::Result<Option<String>, sqlx::Error> {
-        let api_key_column = match provider {
-            "openai" => "openaiApiKey",
-            "ollama" => "ollamaApiKey",
-            "groq" => "groqApiKey",
-            "claude" => "anthropicApiKey",
-            "openrouter" => "openRouterApiKey",
-            _ => {
-                return Err(sqlx::Error::Protocol(
-                    format!("Invalid provider: {}", provider).into(),
-                ))
-            }
-        };
+        headers_json: Option<&str>,
+    ) -> std::result::Result<(), sqlx::Error> {
+        let name = summary_headers_secret_name(provider)?;
+        match headers_json {
+            Some(json) => secrets::set_secret(pool, name, json).await,
+            None => secrets::delete_secret(pool, name).await,
+        }
+        .map_err(sqlx::Error::Protocol)
+    }
 
-        let query = format!(
-            "SELECT {} FROM settings WHERE id = '1' LIMIT 1",
-            api_key_column
-        );
-        let api_key = sqlx::query_scalar(&query).fetch_optional(pool).await?;
-        Ok(api_key)
+    /// Decrypted extra headers (JSON object) of a summary provider. Mask the values before
+    /// returning them to the UI.
+    pub async fn get_custom_headers(
+        pool: &SqlitePool,
+        provider: &str,
+    ) -> std::result::Result<Option<String>, sqlx::Error> {
+        let name = summary_headers_secret_name(provider)?;
+        secrets::get_secret(pool, name)
+            .await
+            .map_err(sqlx::Error::Protocol)
     }
 
     pub async fn get_transcript_config(
@@ -158,88 +188,98 @@ impl SettingsRepository {
         Ok(())
     }
 
+    /// Encrypts and stores the API key of a transcription provider. Masked values sent back
+    /// by the frontend are ignored.
     pub async fn save_transcript_api_key(
         pool: &SqlitePool,
         provider: &str,
         api_key: &str,
     ) -> std::result::Result<(), sqlx::Error> {
-        let api_key_column = match provider {
-            "localWhisper" => "whisperApiKey",
-            "parakeet" => return Ok(()), // Parakeet doesn't need an API key, return early
-            "deepgram" => "deepgramApiKey",
-            "elevenLabs" => "elevenLabsApiKey",
-            "groq" => "groqApiKey",
-            "openai" => "openaiApiKey",
-            _ => {
-                return Err(sqlx::Error::Protocol(
-                    format!("Invalid provider: {}", provider).into(),
-                ))
-            }
+        // Parakeet doesn't need an API key
+        let Some(name) = transcript_secret_name(provider)? else {
+            return Ok(());
         };
-
-        let query = format!(
-            r#"
-            INSERT INTO transcript_settings (id, provider, model, "{}")
-            VALUES ('1', 'localWhisper', 'large-v3', $1)
-            ON CONFLICT(id) DO UPDATE SET
-                "{}" = $1
-            "#,
-            api_key_column, api_key_column
-        );
-        sqlx::query(&query).bind(api_key).execute(pool).await?;
-
-        Ok(())
+        if secrets::is_masked(api_key) {
+            return Ok(());
+        }
+        secrets::set_secret(pool, name, api_key)
+            .await
+            .map_err(sqlx::Error::Protocol)
     }
 
+    /// Decrypted API key of a transcription provider. Only for the transcription providers;
+    /// use `get_masked_transcript_api_key` for anything shown in the UI.
     pub async fn get_transcript_api_key(
         pool: &SqlitePool,
         provider: &str,
     ) -> std::result::Result<Option<String>, sqlx::Error> {
-        let api_key_column = match provider {
-            "localWhisper" => "whisperApiKey",
-            "parakeet" => return Ok(None), // Parakeet doesn't need an API key
-            "deepgram" => "deepgramApiKey",
-            "elevenLabs" => "elevenLabsApiKey",
-            "groq" => "groqApiKey",
-            "openai" => "openaiApiKey",
-            _ => {
-                return Err(sqlx::Error::Protocol(
-                    format!("Invalid provider: {}", provider).into(),
-                ))
-            }
+        let Some(name) = transcript_secret_name(provider)? else {
+            return Ok(None);
         };
+        secrets::get_secret(pool, name)
+            .await
+            .map_err(sqlx::Error::Protocol)
+    }
 
-        let query = format!(
-            "SELECT {} FROM transcript_settings WHERE id = '1' LIMIT 1",
-            api_key_column
-        );
-        let api_key = sqlx::query_scalar(&query).fetch_optional(pool).await?;
-        Ok(api_key)
+    pub async fn get_masked_transcript_api_key(
+        pool: &SqlitePool,
+        provider: &str,
+    ) -> std::result::Result<Option<String>, sqlx::Error> {
+        let Some(name) = transcript_secret_name(provider)? else {
+            return Ok(None);
+        };
+        secrets::get_masked_secret(pool, name)
+            .await
+            .map_err(sqlx::Error::Protocol)
     }
 
     pub async fn delete_api_key(
         pool: &SqlitePool,
         provider: &str,
     ) -> std::result::Result<(), sqlx::Error> {
-        let api_key_column = match provider {
-            "openai" => "openaiApiKey",
-            "ollama" => "ollamaApiKey",
-            "groq" => "groqApiKey",
-            "claude" => "anthropicApiKey",
-            "openrouter" => "openRouterApiKey",
-            _ => {
-                return Err(sqlx::Error::Protocol(
-                    format!("Invalid provider: {}", provider).into(),
-                ))
-            }
-        };
+        let name = summary_secret_name(provider)?;
+        secrets::delete_secret(pool, name)
+            .await
+            .map_err(sqlx::Error::Protocol)
+    }
+}
 
-        let query = format!(
-            "UPDATE settings SET {} = NULL WHERE id = '1'",
-            api_key_column
-        );
-        sqlx::query(&query).execute(pool).await?;
+/// Name of a summary provider's key in the secrets table
+fn summary_secret_name(provider: &str) -> std::result::Result<&'static str, sqlx::Error> {
+    match provider {
+        "openai" => Ok("summary.openai"),
+        "claude" => Ok("summary.claude"),
+        "ollama" => Ok("summary.ollama"),
+        "groq" => Ok("summary.groq"),
+        "openrouter" => Ok("summary.openrouter"),
+        "custom" => Ok("summary.custom"),
+        _ => Err(sqlx::Error::Protocol(
+            format!("Invalid provider: {}", provider).into(),
+        )),
+    }
+}
 
-        Ok(())
+/// Name of a summary provider's extra headers in the secrets table
+fn summary_headers_secret_name(provider: &str) -> std::result::Result<&'static str, sqlx::Error> {
+    match provider {
+        "custom" => Ok("summary.custom.headers"),
+        _ => Err(sqlx::Error::Protocol(
+            format!("Provider {} has no custom headers", provider).into(),
+        )),
+    }
+}
+
+/// Name of a transcription provider's key in the secrets table (None for Parakeet)
+fn transcript_secret_name(provider: &str) -> std::result::Result<Option<&'static str>, sqlx::Error> {
+    match provider {
+        "localWhisper" => Ok(Some("transcript.localWhisper")),
+        "parakeet" => Ok(None),
+        "deepgram" => Ok(Some("transcript.deepgram")),
+        "elevenLabs" => Ok(Some("transcript.elevenLabs")),
+        "groq" => Ok(Some("transcript.groq")),
+        "openai" => Ok(Some("transcript.openai")),
+        _ => Err(sqlx::Error::Protocol(
+            format!("Invalid provider: {}", provider).into(),
+        )),
     }
 }
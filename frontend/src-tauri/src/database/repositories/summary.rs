@@ -100,7 +100,8 @@ impl SummaryProcessesRepository {
                 updated_at = excluded.updated_at,
                 start_time = excluded.start_time,
                 result = NULL,
-                error = NULL
+                error = NULL,
+                metadata = NULL
             "#
         )
         .bind(meeting_id)
@@ -112,6 +113,34 @@ impl SummaryProcessesRepository {
         Ok(())
     }
 
+    /// Stores the latest streaming progress under `metadata.progress` so a reloaded
+    /// frontend can show the partial summary. Finished processes are left untouched.
+    pub async fn update_process_progress(
+        pool: &SqlitePool,
+        meeting_id: &str,
+        progress: &Value,
+    ) -> Result<(), sqlx::Error> {
+        let now = Utc::now();
+        let progress_str = serde_json::to_string(progress)
+            .map_err(|e| sqlx::Error::Protocol(format!("Failed to serialize progress: {}", e)))?;
+
+        sqlx::query(
+            r#"
+            UPDATE summary_processes
+            SET status = 'processing',
+                metadata = json_set(COALESCE(metadata, '{}'), '$.progress', json(?)),
+                updated_at = ?
+            WHERE meeting_id = ? AND status IN ('PENDING', 'processing')
+            "#,
+        )
+        .bind(progress_str)
+        .bind(now)
+        .bind(meeting_id)
+        .execute(pool)
+        .await?;
+        Ok(())
+    }
+
     pub async fn update_process_completed(
         pool: &SqlitePool,
         meeting_id: &str,
@@ -141,6 +170,27 @@ impl SummaryProcessesRepository {
         Ok(())
     }
 
+    /// Marks a process as cancelled by the user; cached chunk summaries are kept for resuming
+    pub async fn update_process_cancelled(
+        pool: &SqlitePool,
+        meeting_id: &str,
+    ) -> Result<(), sqlx::Error> {
+        let now = Utc::now();
+        sqlx::query(
+            r#"
+            UPDATE summary_processes
+            SET status = 'cancelled', error = NULL, updated_at = ?, end_time = ?
+            WHERE meeting_id = ?
+            "#,
+        )
+        .bind(now)
+        .bind(now)
+        .bind(meeting_id)
+        .execute(pool)
+        .await?;
+        Ok(())
+    }
+
     pub async fn update_process_failed(
         pool: &SqlitePool,
         meeting_id: &str,
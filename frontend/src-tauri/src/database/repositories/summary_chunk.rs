@@ -0,0 +1,60 @@
+use chrono::Utc;
+use sqlx::SqlitePool;
+
+/// Per-chunk summaries of multi-level summary runs, used to resume interrupted jobs
+pub struct SummaryChunkCacheRepository;
+
+impl SummaryChunkCacheRepository {
+    /// Returns the cached summary of a chunk if it was produced for the same fingerprint
+    pub async fn get_chunk(
+        pool: &SqlitePool,
+        meeting_id: &str,
+        chunk_index: usize,
+        fingerprint: &str,
+    ) -> Result<Option<String>, sqlx::Error> {
+        sqlx::query_scalar::<_, String>(
+            "SELECT summary FROM summary_chunk_cache WHERE meeting_id = ? AND chunk_index = ? AND chunk_fingerprint = ?",
+        )
+        .bind(meeting_id)
+        .bind(chunk_index as i64)
+        .bind(fingerprint)
+        .fetch_optional(pool)
+        .await
+    }
+
+    pub async fn save_chunk(
+        pool: &SqlitePool,
+        meeting_id: &str,
+        chunk_index: usize,
+        fingerprint: &str,
+        summary: &str,
+    ) -> Result<(), sqlx::Error> {
+        sqlx::query(
+            r#"
+            INSERT INTO summary_chunk_cache (meeting_id, chunk_index, chunk_fingerprint, summary, created_at)
+            VALUES (?, ?, ?, ?, ?)
+            ON CONFLICT(meeting_id, chunk_index) DO UPDATE SET
+                chunk_fingerprint = excluded.chunk_fingerprint,
+                summary = excluded.summary,
+                created_at = excluded.created_at
+            "#,
+        )
+        .bind(meeting_id)
+        .bind(chunk_index as i64)
+        .bind(fingerprint)
+        .bind(summary)
+        .bind(Utc::now())
+        .execute(pool)
+        .await?;
+        Ok(())
+    }
+
+    /// Drops the cache once a run has completed
+    pub async fn clear(pool: &SqlitePool, meeting_id: &str) -> Result<(), sqlx::Error> {
+        sqlx::query("DELETE FROM summary_chunk_cache WHERE meeting_id = ?")
+            .bind(meeting_id)
+            .execute(pool)
+            .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,258 @@
+use crate::database::models::SummaryVersion;
+use chrono::{Duration, Utc};
+use serde_json::Value;
+use sqlx::{SqliteConnection, SqlitePool};
+use tracing::info as log_info;
+use uuid::Uuid;
+
+/// Edits saved within this window of the previous edit update that version in place,
+/// so autosave doesn't produce a version per keystroke pause
+const EDIT_COALESCE_WINDOW_MINUTES: i64 = 5;
+
+/// Description of a version to append
+pub struct NewSummaryVersion<'a> {
+    pub meeting_id: &'a str,
+    pub source: &'a str,
+    pub template_id: Option<&'a str>,
+    pub provider: Option<&'a str>,
+    pub model: Option<&'a str>,
+    pub custom_prompt: Option<&'a str>,
+    pub restored_from: Option<&'a str>,
+    pub result: &'a Value,
+}
+
+pub struct SummaryVersionsRepository;
+
+impl SummaryVersionsRepository {
+    /// Appends a version with the next version number of the meeting
+    pub async fn add_version(
+        pool: &SqlitePool,
+        version: NewSummaryVersion<'_>,
+    ) -> Result<SummaryVersion, sqlx::Error> {
+        let mut transaction = pool.begin().await?;
+        let id = insert_version(&mut transaction, &version).await?;
+        let saved = fetch_version(&mut transaction, version.meeting_id, &id).await?;
+        transaction.commit().await?;
+
+        log_info!(
+            "Recorded summary version {} ({}) for meeting_id: {}",
+            saved.version_number,
+            saved.source,
+            saved.meeting_id
+        );
+        Ok(saved)
+    }
+
+    /// Records a user edit. Unchanged content is ignored and consecutive edits are
+    /// merged into one version; settings are inherited from the version being edited.
+    pub async fn record_edit(
+        pool: &SqlitePool,
+        meeting_id: &str,
+        result: &Value,
+    ) -> Result<(), sqlx::Error> {
+        let mut transaction = pool.begin().await?;
+        let latest = sqlx::query_as::<_, SummaryVersion>(
+            "SELECT * FROM summary_versions WHERE meeting_id = ? ORDER BY version_number DESC LIMIT 1",
+        )
+        .bind(meeting_id)
+        .fetch_optional(&mut *transaction)
+        .await?;
+
+        let result_str = to_json(result)?;
+        let now = Utc::now();
+
+        match latest {
+            Some(latest) if latest.result == result_str => {}
+            // Same markdown, e.g. the editor adding its block JSON to a fresh summary,
+            // or consecutive edits within one editing session
+            Some(latest)
+                if same_markdown(&latest.result, result)
+                    || (latest.source == "edited"
+                        && now - latest.updated_at
+                            < Duration::minutes(EDIT_COALESCE_WINDOW_MINUTES)) =>
+            {
+                sqlx::query("UPDATE summary_versions SET result = ?, updated_at = ? WHERE id = ?")
+                    .bind(&result_str)
+                    .bind(now)
+                    .bind(&latest.id)
+                    .execute(&mut *transaction)
+                    .await?;
+            }
+            latest => {
+                insert_version(
+                    &mut transaction,
+                    &NewSummaryVersion {
+                        meeting_id,
+                        source: "edited",
+                        template_id: latest.as_ref().and_then(|v| v.template_id.as_deref()),
+                        provider: latest.as_ref().and_then(|v| v.provider.as_deref()),
+                        model: latest.as_ref().and_then(|v| v.model.as_deref()),
+                        custom_prompt: latest.as_ref().and_then(|v| v.custom_prompt.as_deref()),
+                        restored_from: None,
+                        result,
+                    },
+                )
+                .await?;
+            }
+        }
+
+        transaction.commit().await?;
+        Ok(())
+    }
+
+    /// All versions of a meeting, newest first
+    pub async fn list_versions(
+        pool: &SqlitePool,
+        meeting_id: &str,
+    ) -> Result<Vec<SummaryVersion>, sqlx::Error> {
+        sqlx::query_as::<_, SummaryVersion>(
+            "SELECT * FROM summary_versions WHERE meeting_id = ? ORDER BY version_number DESC",
+        )
+        .bind(meeting_id)
+        .fetch_all(pool)
+        .await
+    }
+
+    pub async fn get_version(
+        pool: &SqlitePool,
+        meeting_id: &str,
+        version_id: &str,
+    ) -> Result<Option<SummaryVersion>, sqlx::Error> {
+        sqlx::query_as::<_, SummaryVersion>(
+            "SELECT * FROM summary_versions WHERE meeting_id = ? AND id = ?",
+        )
+        .bind(meeting_id)
+        .bind(version_id)
+        .fetch_optional(pool)
+        .await
+    }
+
+    /// Makes an earlier version current again. The restore is appended as a new version,
+    /// so the history stays linear and nothing is lost. Returns None for an unknown version.
+    pub async fn restore_version(
+        pool: &SqlitePool,
+        meeting_id: &str,
+        version_id: &str,
+    ) -> Result<Option<SummaryVersion>, sqlx::Error> {
+        let mut transaction = pool.begin().await?;
+
+        let Some(source) = sqlx::query_as::<_, SummaryVersion>(
+            "SELECT * FROM summary_versions WHERE meeting_id = ? AND id = ?",
+        )
+        .bind(meeting_id)
+        .bind(version_id)
+        .fetch_optional(&mut *transaction)
+        .await?
+        else {
+            transaction.rollback().await?;
+            return Ok(None);
+        };
+
+        let now = Utc::now();
+        sqlx::query(
+            "UPDATE summary_processes SET result = ?, status = 'completed', error = NULL, updated_at = ? WHERE meeting_id = ?",
+        )
+        .bind(&source.result)
+        .bind(now)
+        .bind(meeting_id)
+        .execute(&mut *transaction)
+        .await?;
+
+        sqlx::query("UPDATE meetings SET updated_at = ? WHERE id = ?")
+            .bind(now)
+            .bind(meeting_id)
+            .execute(&mut *transaction)
+            .await?;
+
+        let result: Value = serde_json::from_str(&source.result)
+            .map_err(|e| sqlx::Error::Protocol(format!("Invalid stored summary JSON: {}", e)))?;
+        let id = insert_version(
+            &mut transaction,
+            &NewSummaryVersion {
+                meeting_id,
+                source: "restored",
+                template_id: source.template_id.as_deref(),
+                provider: source.provider.as_deref(),
+                model: source.model.as_deref(),
+                custom_prompt: source.custom_prompt.as_deref(),
+                restored_from: Some(&source.id),
+                result: &result,
+            },
+        )
+        .await?;
+        let restored = fetch_version(&mut transaction, meeting_id, &id).await?;
+
+        transaction.commit().await?;
+
+        log_info!(
+            "Restored summary version {} as version {} for meeting_id: {}",
+            source.version_number,
+            restored.version_number,
+            meeting_id
+        );
+        Ok(Some(restored))
+    }
+}
+
+fn same_markdown(stored: &str, result: &Value) -> bool {
+    let stored_markdown = serde_json::from_str::<Value>(stored)
+        .ok()
+        .and_then(|v| v.get("markdown").cloned());
+    stored_markdown.is_some() && stored_markdown.as_ref() == result.get("markdown")
+}
+
+fn to_json(value: &Value) -> Result<String, sqlx::Error> {
+    serde_json::to_string(value)
+        .map_err(|e| sqlx::Error::Protocol(format!("Failed to serialize summary: {}", e)))
+}
+
+async fn insert_version(
+    conn: &mut SqliteConnection,
+    version: &NewSummaryVersion<'_>,
+) -> Result<String, sqlx::Error> {
+    let id = Uuid::new_v4().to_string();
+    let now = Utc::now();
+
+    sqlx::query(
+        r#"
+        INSERT INTO summary_versions (
+            id, meeting_id, version_number, source, template_id, provider, model,
+            custom_prompt, restored_from, result, created_at, updated_at
+        )
+        VALUES (
+            ?, ?, (SELECT COALESCE(MAX(version_number), 0) + 1 FROM summary_versions WHERE meeting_id = ?),
+            ?, ?, ?, ?, ?, ?, ?, ?, ?
+        )
+        "#,
+    )
+    .bind(&id)
+    .bind(version.meeting_id)
+    .bind(version.meeting_id)
+    .bind(version.source)
+    .bind(version.template_id)
+    .bind(version.provider)
+    .bind(version.model)
+    .bind(version.custom_prompt.filter(|p| !p.is_empty()))
+    .bind(version.restored_from)
+    .bind(to_json(version.result)?)
+    .bind(now)
+    .bind(now)
+    .execute(&mut *conn)
+    .await?;
+
+    Ok(id)
+}
+
+async fn fetch_version(
+    conn: &mut SqliteConnection,
+    meeting_id: &str,
+    version_id: &str,
+) -> Result<SummaryVersion, sqlx::Error> {
+    sqlx::query_as::<_, SummaryVersion>(
+        "SELECT * FROM summary_versions WHERE meeting_id = ? AND id = ?",
+    )
+    .bind(meeting_id)
+    .bind(version_id)
+    .fetch_one(&mut *conn)
+    .await
+}
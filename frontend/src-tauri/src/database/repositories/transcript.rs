@@ -1,6 +1,11 @@
 use crate::api::{TranscriptSearchResult, TranscriptSegment};
+use crate::audio::transcription::confidence::SegmentConfidence;
+use crate::database::models::Transcript;
+use crate::database::repositories::meeting::MeetingsRepository;
+use crate::database::repositories::transcript_word::TranscriptWordsRepository;
 use chrono::Utc;
-use sqlx::{Connection, Error as SqlxError, SqlitePool};
+use sqlx::{Connection, Error as SqlxError, SqliteConnection, SqlitePool};
+use std::collections::HashMap;
 use tracing::{error, info};
 use uuid::Uuid;
 
@@ -43,31 +48,14 @@ impl TranscriptsRepository {
 
         info!("Successfully created meeting with id: {}", meeting_id);
 
-        // 2. Save each transcript segment with audio timing fields
-        for segment in transcripts {
-            let transcript_id = format!("transcript-{}", Uuid::new_v4());
-            let result = sqlx::query(
-                "INSERT INTO transcripts (id, meeting_id, transcript, timestamp, audio_start_time, audio_end_time, duration)
-                 VALUES (?, ?, ?, ?, ?, ?, ?)"
-            )
-            .bind(&transcript_id)
-            .bind(&meeting_id)
-            .bind(&segment.text)
-            .bind(&segment.timestamp)
-            .bind(segment.audio_start_time)
-            .bind(segment.audio_end_time)
-            .bind(segment.duration)
-            .execute(&mut *transaction)
-            .await;
-
-            if let Err(e) = result {
-                error!(
-                    "Failed to save transcript segment for meeting {}: {}",
-                    meeting_id, e
-                );
-                transaction.rollback().await?;
-                return Err(e);
-            }
+        // 2. Save each transcript segment with audio timing fields, speaker tags and word timings
+        if let Err(e) = insert_segments(&mut *transaction, &meeting_id, transcripts).await {
+            error!(
+                "Failed to save transcript segments for meeting {}: {}",
+                meeting_id, e
+            );
+            transaction.rollback().await?;
+            return Err(e);
         }
 
         info!(
@@ -82,65 +70,547 @@ impl TranscriptsRepository {
         Ok(meeting_id)
     }
 
-    /// Searches for a query string within the transcripts.
-    /// It returns a list of matching transcripts with context.
+    /// Replaces every transcript segment (and word timing) of an existing meeting.
+    /// Used when a saved meeting is re-transcribed; runs in a single transaction.
+    pub async fn replace_transcripts(
+        pool: &SqlitePool,
+        meeting_id: &str,
+        transcripts: &[TranscriptSegment],
+    ) -> Result<(), SqlxError> {
+        let mut conn = pool.acquire().await?;
+        let mut transaction = conn.begin().await?;
+
+        let result: Result<(), SqlxError> = async {
+            sqlx::query("DELETE FROM transcript_words WHERE meeting_id = ?")
+                .bind(meeting_id)
+                .execute(&mut *transaction)
+                .await?;
+            sqlx::query("DELETE FROM transcripts WHERE meeting_id = ?")
+                .bind(meeting_id)
+                .execute(&mut *transaction)
+                .await?;
+            insert_segments(&mut *transaction, meeting_id, transcripts).await?;
+            sqlx::query("UPDATE meetings SET updated_at = ? WHERE id = ?")
+                .bind(Utc::now())
+                .bind(meeting_id)
+                .execute(&mut *transaction)
+                .await?;
+            Ok(())
+        }
+        .await;
+
+        if let Err(e) = result {
+            error!(
+                "Failed to replace transcripts for meeting {}: {}",
+                meeting_id, e
+            );
+            transaction.rollback().await?;
+            return Err(e);
+        }
+
+        transaction.commit().await?;
+        info!(
+            "Replaced transcripts of meeting {} with {} segments",
+            meeting_id,
+            transcripts.len()
+        );
+        Ok(())
+    }
+
+    /// Returns all transcript rows of a meeting ordered by their position in the recording.
+    pub async fn get_transcripts_for_meeting(
+        pool: &SqlitePool,
+        meeting_id: &str,
+    ) -> Result<Vec<Transcript>, SqlxError> {
+        sqlx::query_as::<_, Transcript>(
+            "SELECT * FROM transcripts WHERE meeting_id = ?
+             ORDER BY COALESCE(audio_start_time, 0), timestamp",
+        )
+        .bind(meeting_id)
+        .fetch_all(pool)
+        .await
+    }
+
+    /// Returns the confidence scores of a meeting's timed segments and their words, in
+    /// recording order.
+    pub async fn get_confidence_segments(
+        pool: &SqlitePool,
+        meeting_id: &str,
+    ) -> Result<Vec<SegmentConfidence>, SqlxError> {
+        let Some(meeting) = MeetingsRepository::get_meeting(pool, meeting_id).await? else {
+            return Ok(Vec::new());
+        };
+
+        let mut segments: Vec<SegmentConfidence> = meeting
+            .transcripts
+            .into_iter()
+            .filter_map(|t| {
+                let start_time = t.audio_start_time?;
+                Some(SegmentConfidence {
+                    words: t.words.into_iter().map(|w| (w.word, w.probability)).collect(),
+                    transcript_id: t.id,
+                    text: t.text,
+                    start_time,
+                    end_time: t.audio_end_time.unwrap_or(start_time),
+                    confidence: t.confidence,
+                })
+            })
+            .collect();
+        segments.sort_by(|a, b| a.start_time.total_cmp(&b.start_time));
+        Ok(segments)
+    }
+
+    /// Replaces the diarized speaker labels of a meeting.
+    /// All existing labels are cleared first so re-running diarization never leaves stale labels.
+    pub async fn update_speakers(
+        pool: &SqlitePool,
+        meeting_id: &str,
+        assignments: &[(String, String)],
+    ) -> Result<(), SqlxError> {
+        let mut conn = pool.acquire().await?;
+        let mut transaction = conn.begin().await?;
+
+        sqlx::query("UPDATE transcripts SET speaker = NULL WHERE meeting_id = ?")
+            .bind(meeting_id)
+            .execute(&mut *transaction)
+            .await?;
+
+        for (transcript_id, speaker) in assignments {
+            sqlx::query("UPDATE transcripts SET speaker = ? WHERE id = ? AND meeting_id = ?")
+                .bind(speaker)
+                .bind(transcript_id)
+                .bind(meeting_id)
+                .execute(&mut *transaction)
+                .await?;
+        }
+
+        transaction.commit().await?;
+        info!(
+            "Updated speaker labels for {} transcript segments of meeting {}",
+            assignments.len(),
+            meeting_id
+        );
+        Ok(())
+    }
+
+    /// Renames a speaker label across every transcript segment of a meeting.
+    /// Returns the number of segments that were updated.
+    pub async fn rename_speaker(
+        pool: &SqlitePool,
+        meeting_id: &str,
+        old_label: &str,
+        new_label: &str,
+    ) -> Result<u64, SqlxError> {
+        let result = sqlx::query(
+            "UPDATE transcripts SET speaker = ? WHERE meeting_id = ? AND speaker = ?",
+        )
+        .bind(new_label)
+        .bind(meeting_id)
+        .bind(old_label)
+        .execute(pool)
+        .await?;
+
+        Ok(result.rows_affected())
+    }
+
+    /// Full-text search over transcripts, summaries and meeting titles (FTS5, BM25 ranked).
+    ///
+    /// Supports `"exact phrases"`, `prefix*` terms and `AND` / `OR` / `NOT`; every matching
+    /// segment is returned as its own hit with a highlighted snippet.
     pub async fn search_transcripts(
         pool: &SqlitePool,
         query: &str,
+        filters: &SearchFilters,
     ) -> Result<Vec<TranscriptSearchResult>, SqlxError> {
-        if query.trim().is_empty() {
+        let Some(fts_query) = build_fts_query(query) else {
             return Ok(Vec::new());
+        };
+
+        let date_to = filters.date_to.as_deref().map(exclusive_date_bound);
+        let limit = filters.limit.unwrap_or(DEFAULT_SEARCH_LIMIT).clamp(1, 500);
+
+        let rows = sqlx::query_as::<_, SearchRow>(
+            "SELECT s.meeting_id, m.title, s.kind, s.ref_id,
+                    snippet(search_index, 0, ?, ?, '…', 24) AS snippet,
+                    bm25(search_index) AS rank,
+                    t.timestamp, t.audio_start_time, m.created_at
+             FROM search_index s
+             JOIN meetings m ON m.id = s.meeting_id
+             LEFT JOIN transcripts t ON s.kind = 'transcript' AND t.id = s.ref_id
+             WHERE search_index MATCH ?
+               AND (? IS NULL OR s.meeting_id = ?)
+               AND (? IS NULL OR datetime(m.created_at) >= datetime(?))
+               AND (? IS NULL OR datetime(m.created_at) < datetime(?))
+             ORDER BY rank
+             LIMIT ?",
+        )
+        .bind(HIGHLIGHT_START)
+        .bind(HIGHLIGHT_END)
+        .bind(&fts_query)
+        .bind(&filters.meeting_id)
+        .bind(&filters.meeting_id)
+        .bind(&filters.date_from)
+        .bind(&filters.date_from)
+        .bind(&date_to)
+        .bind(&date_to)
+        .bind(limit)
+        .fetch_all(pool)
+        .await;
+
+        let rows = match rows {
+            Ok(rows) => rows,
+            // Anything FTS5 still can't parse falls back to a plain substring search
+            Err(SqlxError::Database(e)) if e.message().contains("fts5") => {
+                error!("FTS query '{}' rejected ({}), falling back to LIKE search", fts_query, e);
+                return Self::search_transcripts_like(pool, query, filters, limit).await;
+            }
+            Err(e) => return Err(e),
+        };
+
+        // Seek positions of the matched words, when word timings are available
+        let word_start_times = match first_search_term(query) {
+            Some(term) => {
+                let transcript_ids: Vec<&str> = rows
+                    .iter()
+                    .filter(|row| row.kind == "transcript")
+                    .map(|row| row.ref_id.as_str())
+                    .collect();
+                TranscriptWordsRepository::find_word_start_times(pool, &transcript_ids, &term)
+                    .await?
+            }
+            None => HashMap::new(),
+        };
+
+        let mut results = Vec::with_capacity(rows.len());
+        for row in rows {
+            let word_start_time = match &row.kind[..] {
+                "transcript" => word_start_times.get(&row.ref_id).copied(),
+                _ => None,
+            };
+            let is_transcript = row.kind == "transcript";
+            results.push(TranscriptSearchResult {
+                id: row.meeting_id,
+                title: row.title,
+                match_context: strip_highlights(&row.snippet),
+                highlighted_context: row.snippet,
+                timestamp: row.timestamp.unwrap_or(row.created_at),
+                word_start_time,
+                kind: row.kind,
+                transcript_id: is_transcript.then_some(row.ref_id),
+                audio_start_time: row.audio_start_time,
+                rank: row.rank,
+            });
         }
 
-        let search_query = format!("%{}%", query.to_lowercase());
+        Ok(results)
+    }
 
-        let rows = sqlx::query_as::<_, (String, String, String, String)>(
-            "SELECT m.id, m.title, t.transcript, t.timestamp
+    /// Substring search used when FTS5 rejects a query
+    async fn search_transcripts_like(
+        pool: &SqlitePool,
+        query: &str,
+        filters: &SearchFilters,
+        limit: i64,
+    ) -> Result<Vec<TranscriptSearchResult>, SqlxError> {
+        let search_query = format!("%{}%", query.trim().to_lowercase());
+        let date_to = filters.date_to.as_deref().map(exclusive_date_bound);
+
+        let rows = sqlx::query_as::<_, (String, String, String, String, String, Option<f64>)>(
+            "SELECT m.id, m.title, t.id, t.transcript, t.timestamp, t.audio_start_time
              FROM meetings m
              JOIN transcripts t ON m.id = t.meeting_id
-             WHERE LOWER(t.transcript) LIKE ?",
+             WHERE LOWER(t.transcript) LIKE ?
+               AND (? IS NULL OR m.id = ?)
+               AND (? IS NULL OR datetime(m.created_at) >= datetime(?))
+               AND (? IS NULL OR datetime(m.created_at) < datetime(?))
+             LIMIT ?",
         )
         .bind(&search_query)
+        .bind(&filters.meeting_id)
+        .bind(&filters.meeting_id)
+        .bind(&filters.date_from)
+        .bind(&filters.date_from)
+        .bind(&date_to)
+        .bind(&date_to)
+        .bind(limit)
         .fetch_all(pool)
         .await?;
 
-        let results = rows
-            .into_iter()
-            .map(|(id, title, transcript, timestamp)| {
-                let match_context = Self::get_match_context(&transcript, query);
-                TranscriptSearchResult {
-                    id,
-                    title,
-                    match_context,
-                    timestamp,
-                }
-            })
-            .collect();
+        let mut results = Vec::with_capacity(rows.len());
+        let transcript_ids: Vec<&str> = rows.iter().map(|row| row.2.as_str()).collect();
+        let word_start_times =
+            TranscriptWordsRepository::find_word_start_times(pool, &transcript_ids, query).await?;
+
+        for (id, title, transcript_id, transcript, timestamp, audio_start_time) in rows {
+            let match_context = Self::get_match_context(&transcript, query);
+            let word_start_time = word_start_times.get(&transcript_id).copied();
+            results.push(TranscriptSearchResult {
+                id,
+                title,
+                highlighted_context: match_context.clone(),
+                match_context,
+                timestamp,
+                word_start_time,
+                kind: "transcript".to_string(),
+                transcript_id: Some(transcript_id),
+                audio_start_time,
+                rank: 0.0,
+            });
+        }
 
         Ok(results)
     }
 
     /// Helper function to extract a snippet of text around the first match of a query.
+    /// Works on characters, so multi-byte text is never split inside a code point.
     fn get_match_context(transcript: &str, query: &str) -> String {
-        let transcript_lower = transcript.to_lowercase();
-        let query_lower = query.to_lowercase();
+        let chars: Vec<char> = transcript.chars().collect();
+        let lower: Vec<char> = chars.iter().map(|c| c.to_lowercase().next().unwrap_or(*c)).collect();
+        let needle: Vec<char> = query
+            .chars()
+            .map(|c| c.to_lowercase().next().unwrap_or(c))
+            .collect();
 
-        match transcript_lower.find(&query_lower) {
+        let match_index = if needle.is_empty() {
+            None
+        } else {
+            lower.windows(needle.len()).position(|window| window == needle.as_slice())
+        };
+
+        match match_index {
             Some(match_index) => {
                 let start_index = match_index.saturating_sub(100);
-                let end_index = (match_index + query.len() + 100).min(transcript.len());
+                let end_index = (match_index + needle.len() + 100).min(chars.len());
 
                 let mut context = String::new();
                 if start_index > 0 {
                     context.push_str("...");
                 }
-                context.push_str(&transcript[start_index..end_index]);
-                if end_index < transcript.len() {
+                context.extend(&chars[start_index..end_index]);
+                if end_index < chars.len() {
                     context.push_str("...");
                 }
                 context
             }
-            None => transcript.chars().take(200).collect(), // Fallback to the start of the transcript
+            None => chars.iter().take(200).collect(), // Fallback to the start of the transcript
         }
     }
 }
+
+/// Markers around matched terms in `highlighted_context`
+const HIGHLIGHT_START: &str = "<mark>";
+const HIGHLIGHT_END: &str = "</mark>";
+const DEFAULT_SEARCH_LIMIT: i64 = 100;
+
+/// Optional restrictions of a transcript search
+#[derive(Debug, Default, Clone)]
+pub struct SearchFilters {
+    pub meeting_id: Option<String>,
+    /// Inclusive lower bound on the meeting's creation time (date or RFC 3339)
+    pub date_from: Option<String>,
+    /// Upper bound; a plain date includes that whole day
+    pub date_to: Option<String>,
+    pub limit: Option<i64>,
+}
+
+#[derive(sqlx::FromRow)]
+struct SearchRow {
+    meeting_id: String,
+    title: String,
+    kind: String,
+    ref_id: String,
+    snippet: String,
+    rank: f64,
+    timestamp: Option<String>,
+    audio_start_time: Option<f64>,
+    created_at: String,
+}
+
+/// Turn user input into an FTS5 query. Words are quoted so punctuation can't break the
+/// syntax; `"phrases"`, trailing `*` prefixes and upper-case `AND`/`OR`/`NOT` are kept.
+/// An operator with no term before or after it is searched as a plain word.
+/// Returns None when nothing searchable is left.
+fn build_fts_query(input: &str) -> Option<String> {
+    let mut parts: Vec<String> = Vec::new();
+    let mut last_was_operator = true; // no operator allowed at the start
+    let quote = |text: &str| format!("\"{}\"", text.replace('"', "\"\""));
+
+    let mut rest = input.trim();
+    while !rest.is_empty() {
+        let token = if let Some(after_quote) = rest.strip_prefix('"') {
+            // Phrase: up to the closing quote (or the end of the input)
+            let (phrase, remaining) = after_quote.split_once('"').unwrap_or((after_quote, ""));
+            rest = remaining;
+            let phrase = phrase.trim();
+            (!phrase.is_empty()).then(|| quote(phrase))
+        } else {
+            let end = rest
+                .find(|c: char| c.is_whitespace() || c == '"')
+                .unwrap_or(rest.len());
+            let word = &rest[..end];
+            rest = &rest[end..];
+
+            if matches!(word, "AND" | "OR" | "NOT") {
+                if parts.is_empty() {
+                    // Nothing to combine with: "NOT" is the word being searched for
+                    parts.push(quote(word));
+                    last_was_operator = false;
+                } else if !last_was_operator {
+                    parts.push(word.to_string());
+                    last_was_operator = true;
+                } else if let Some(previous) = parts.last_mut() {
+                    // "AND NOT" -> "NOT": the later operator wins
+                    *previous = word.to_string();
+                }
+                rest = rest.trim_start();
+                continue;
+            }
+
+            let (stem, prefix) = match word.strip_suffix('*') {
+                Some(stem) => (stem, true),
+                None => (word, false),
+            };
+            let stem = stem.trim_matches(|c: char| !c.is_alphanumeric());
+            (!stem.is_empty()).then(|| {
+                if prefix {
+                    format!("{}*", quote(stem))
+                } else {
+                    quote(stem)
+                }
+            })
+        };
+
+        if let Some(token) = token {
+            parts.push(token);
+            last_was_operator = false;
+        }
+        rest = rest.trim_start();
+    }
+
+    // A dangling operator at the end is a plain word too
+    if let Some(last) = parts
+        .last_mut()
+        .filter(|p| matches!(p.as_str(), "AND" | "OR" | "NOT"))
+    {
+        *last = quote(last);
+    }
+
+    (!parts.is_empty()).then(|| parts.join(" "))
+}
+
+/// First plain term of the query, used to locate the word timing of a hit
+fn first_search_term(query: &str) -> Option<String> {
+    query
+        .split(|c: char| c.is_whitespace() || c == '"')
+        .map(|w| w.trim_end_matches('*'))
+        .find(|w| !w.is_empty() && !matches!(*w, "AND" | "OR" | "NOT"))
+        .map(str::to_string)
+}
+
+fn strip_highlights(snippet: &str) -> String {
+    snippet.replace(HIGHLIGHT_START, "").replace(HIGHLIGHT_END, "")
+}
+
+/// `date_to` as an exclusive bound: a plain `YYYY-MM-DD` covers that whole day
+pub(crate) fn exclusive_date_bound(date_to: &str) -> String {
+    match chrono::NaiveDate::parse_from_str(date_to, "%Y-%m-%d") {
+        Ok(date) => date
+            .succ_opt()
+            .map(|next| next.format("%Y-%m-%d").to_string())
+            .unwrap_or_else(|| date_to.to_string()),
+        Err(_) => date_to.to_string(),
+    }
+}
+
+/// Inserts transcript segments (and their word timings) for a meeting on the given connection.
+pub(crate) async fn insert_segments(
+    conn: &mut SqliteConnection,
+    meeting_id: &str,
+    transcripts: &[TranscriptSegment],
+) -> Result<(), SqlxError> {
+    for segment in transcripts {
+        let transcript_id = format!("transcript-{}", Uuid::new_v4());
+        sqlx::query(
+            "INSERT INTO transcripts (id, meeting_id, transcript, timestamp, audio_start_time, audio_end_time, duration, source, speaker, confidence)
+             VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)"
+        )
+        .bind(&transcript_id)
+        .bind(meeting_id)
+        .bind(&segment.text)
+        .bind(&segment.timestamp)
+        .bind(segment.audio_start_time)
+        .bind(segment.audio_end_time)
+        .bind(segment.duration)
+        .bind(&segment.source)
+        .bind(&segment.speaker)
+        .bind(segment.confidence)
+        .execute(&mut *conn)
+        .await?;
+
+        TranscriptWordsRepository::insert_words(
+            &mut *conn,
+            meeting_id,
+            &transcript_id,
+            &segment.words,
+        )
+        .await?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_build_fts_query() {
+        assert_eq!(build_fts_query("budget").unwrap(), r#""budget""#);
+        assert_eq!(
+            build_fts_query(r#""action items" AND plan*"#).unwrap(),
+            r#""action items" AND "plan"*"#
+        );
+        assert_eq!(
+            build_fts_query("can't NOT C++").unwrap(),
+            r#""can't" NOT "C""#
+        );
+        assert_eq!(build_fts_query(r#"say "hi"#).unwrap(), r#""say" "hi""#);
+        assert_eq!(build_fts_query("budget AND NOT draft").unwrap(), r#""budget" NOT "draft""#);
+        assert!(build_fts_query("  ").is_none());
+        assert!(build_fts_query("???").is_none());
+    }
+
+    #[test]
+    fn test_build_fts_query_keeps_stray_operators_as_words() {
+        assert_eq!(build_fts_query("NOT budget").unwrap(), r#""NOT" "budget""#);
+        assert_eq!(build_fts_query("NOT").unwrap(), r#""NOT""#);
+        assert_eq!(build_fts_query("budget AND").unwrap(), r#""budget" "AND""#);
+        assert_eq!(
+            build_fts_query("OR can't NOT C++ AND").unwrap(),
+            r#""OR" "can't" NOT "C" "AND""#
+        );
+    }
+
+    #[test]
+    fn test_get_match_context_handles_multibyte_text() {
+        let transcript = format!("{}Größe des Budgets{}", "ü".repeat(150), "é".repeat(150));
+        let context = TranscriptsRepository::get_match_context(&transcript, "BUDGETS");
+        assert!(context.starts_with("..."));
+        assert!(context.ends_with("..."));
+        assert!(context.contains("Größe des Budgets"));
+
+        assert_eq!(TranscriptsRepository::get_match_context("日本語", "x"), "日本語");
+    }
+
+    #[test]
+    fn test_exclusive_date_bound() {
+        assert_eq!(exclusive_date_bound("2025-12-31"), "2026-01-01");
+        assert_eq!(exclusive_date_bound("2025-12-31T10:00:00Z"), "2025-12-31T10:00:00Z");
+    }
+
+    #[test]
+    fn test_first_search_term() {
+        assert_eq!(first_search_term(r#"NOT "road map" x"#).as_deref(), Some("road"));
+        assert_eq!(first_search_term("plan*").as_deref(), Some("plan"));
+        assert_eq!(first_search_term("AND"), None);
+    }
+}
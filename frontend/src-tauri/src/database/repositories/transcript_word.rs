@@ -0,0 +1,75 @@
+use crate::audio::transcription::WordTiming;
+use sqlx::{Error as SqlxError, SqliteConnection, SqlitePool};
+use std::collections::HashMap;
+
+pub struct TranscriptWordsRepository;
+
+impl TranscriptWordsRepository {
+    /// Inserts the word timings of one transcript segment.
+    /// Takes a connection so it can run inside the caller's transaction.
+    pub async fn insert_words(
+        conn: &mut SqliteConnection,
+        meeting_id: &str,
+        transcript_id: &str,
+        words: &[WordTiming],
+    ) -> Result<(), SqlxError> {
+        for (index, word) in words.iter().enumerate() {
+            sqlx::query(
+                "INSERT INTO transcript_words (transcript_id, meeting_id, word_index, word, start_time, end_time, probability)
+                 VALUES (?, ?, ?, ?, ?, ?, ?)",
+            )
+            .bind(transcript_id)
+            .bind(meeting_id)
+            .bind(index as i64)
+            .bind(&word.word)
+            .bind(word.start)
+            .bind(word.end)
+            .bind(word.probability)
+            .execute(&mut *conn)
+            .await?;
+        }
+        Ok(())
+    }
+
+    /// Finds, for each of the given segments, the start time of the first word matching
+    /// `query`, in one query. Only the first term of the query is matched (case-insensitive,
+    /// punctuation ignored); segments without a match or word timings are left out.
+    pub async fn find_word_start_times(
+        pool: &SqlitePool,
+        transcript_ids: &[&str],
+        query: &str,
+    ) -> Result<HashMap<String, f64>, SqlxError> {
+        let Some(term) = query.split_whitespace().next().map(normalize_word) else {
+            return Ok(HashMap::new());
+        };
+        if term.is_empty() || transcript_ids.is_empty() {
+            return Ok(HashMap::new());
+        }
+
+        let ids = serde_json::to_string(transcript_ids)
+            .map_err(|e| SqlxError::Protocol(e.to_string()))?;
+        let words: Vec<(String, String, f64)> = sqlx::query_as(
+            "SELECT transcript_id, word, start_time FROM transcript_words
+             WHERE transcript_id IN (SELECT value FROM json_each(?))
+             ORDER BY transcript_id, word_index",
+        )
+        .bind(ids)
+        .fetch_all(pool)
+        .await?;
+
+        let mut start_times = HashMap::new();
+        for (transcript_id, word, start) in words {
+            if !start_times.contains_key(&transcript_id) && normalize_word(&word).contains(&term) {
+                start_times.insert(transcript_id, start);
+            }
+        }
+        Ok(start_times)
+    }
+}
+
+fn normalize_word(word: &str) -> String {
+    word.chars()
+        .filter(|c| c.is_alphanumeric())
+        .flat_map(|c| c.to_lowercase())
+        .collect()
+}
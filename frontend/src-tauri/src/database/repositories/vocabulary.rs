@@ -0,0 +1,93 @@
+use crate::database::models::VocabularyTerm;
+use chrono::Utc;
+use sqlx::SqlitePool;
+use tracing::info as log_info;
+
+pub struct VocabularyRepository;
+
+impl VocabularyRepository {
+    /// Terms of one list (the user's list when `meeting_id` is None) in the order added
+    pub async fn list_terms(
+        pool: &SqlitePool,
+        meeting_id: Option<&str>,
+    ) -> Result<Vec<VocabularyTerm>, sqlx::Error> {
+        sqlx::query_as::<_, VocabularyTerm>(
+            "SELECT * FROM vocabulary_terms WHERE meeting_id IS ? ORDER BY id",
+        )
+        .bind(meeting_id)
+        .fetch_all(pool)
+        .await
+    }
+
+    /// Adds terms to a list, skipping ones it already has (ignoring case).
+    /// With `replace`, the list is emptied first. Returns the number of terms added.
+    pub async fn add_terms(
+        pool: &SqlitePool,
+        meeting_id: Option<&str>,
+        terms: &[String],
+        replace: bool,
+    ) -> Result<u64, sqlx::Error> {
+        let mut transaction = pool.begin().await?;
+
+        if replace {
+            sqlx::query("DELETE FROM vocabulary_terms WHERE meeting_id IS ?")
+                .bind(meeting_id)
+                .execute(&mut *transaction)
+                .await?;
+        }
+
+        let now = Utc::now();
+        let mut added = 0;
+        for term in terms {
+            let result = sqlx::query(
+                "INSERT OR IGNORE INTO vocabulary_terms (meeting_id, term, created_at) VALUES (?, ?, ?)",
+            )
+            .bind(meeting_id)
+            .bind(term)
+            .bind(now)
+            .execute(&mut *transaction)
+            .await?;
+            added += result.rows_affected();
+        }
+
+        transaction.commit().await?;
+        log_info!(
+            "Added {} vocabulary terms to the {} list",
+            added,
+            meeting_id.unwrap_or("user")
+        );
+        Ok(added)
+    }
+
+    /// Removes one term. Returns false for an unknown id.
+    pub async fn remove_term(pool: &SqlitePool, id: i64) -> Result<bool, sqlx::Error> {
+        let result = sqlx::query("DELETE FROM vocabulary_terms WHERE id = ?")
+            .bind(id)
+            .execute(pool)
+            .await?;
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// Empties a list. Returns the number of terms removed.
+    pub async fn clear_terms(pool: &SqlitePool, meeting_id: Option<&str>) -> Result<u64, sqlx::Error> {
+        let result = sqlx::query("DELETE FROM vocabulary_terms WHERE meeting_id IS ?")
+            .bind(meeting_id)
+            .execute(pool)
+            .await?;
+        Ok(result.rows_affected())
+    }
+
+    /// Terms to use when transcribing: the meeting's list (if any) first, then the user's
+    pub async fn terms_for_transcription(
+        pool: &SqlitePool,
+        meeting_id: Option<&str>,
+    ) -> Result<Vec<String>, sqlx::Error> {
+        sqlx::query_scalar(
+            "SELECT term FROM vocabulary_terms WHERE meeting_id IS NULL OR meeting_id = ?
+             ORDER BY meeting_id IS NULL, id",
+        )
+        .bind(meeting_id)
+        .fetch_all(pool)
+        .await
+    }
+}
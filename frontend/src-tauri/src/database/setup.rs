@@ -7,6 +7,12 @@ use crate::state::AppState;
 /// Initialize database on app startup
 /// Handles first launch detection and conditional initialization
 pub async fn initialize_database_on_startup(app: &AppHandle) -> Result<(), String> {
+    let app_data_dir = app
+        .path()
+        .app_data_dir()
+        .map_err(|e| format!("Failed to get app data dir: {}", e))?;
+    crate::encryption::init(&app_data_dir);
+
     // Check if this is the first launch (no database exists yet)
     let is_first_launch = DatabaseManager::is_first_launch(app)
         .await
@@ -24,6 +30,17 @@ pub async fn initialize_database_on_startup(app: &AppHandle) -> Result<(), Strin
                 .expect("Failed to emit first-launch-detected event");
             info!("Emitted first-launch-detected after delay");
         });
+    } else if crate::encryption::is_enabled() {
+        // Encrypted at rest - the database is opened by api_unlock_database
+        info!("🔒 Database is encrypted - waiting for the passphrase");
+
+        let app_handle = app.clone();
+        tauri::async_runtime::spawn(async move {
+            tokio::time::sleep(tokio::time::Duration::from_millis(500)).await;
+            if let Err(e) = app_handle.emit("database-locked", ()) {
+                log::error!("Failed to emit database-locked event: {}", e);
+            }
+        });
     } else {
         // Normal flow - initialize database immediately
         let db_manager = DatabaseManager::new_from_app_handle(app)
@@ -0,0 +1,130 @@
+/// Cosine similarity between two embeddings (0.0 if either is a zero vector)
+pub fn cosine_similarity(a: &[f32], b: &[f32]) -> f32 {
+    let dot: f32 = a.iter().zip(b).map(|(x, y)| x * y).sum();
+    let norm_a = a.iter().map(|x| x * x).sum::<f32>().sqrt();
+    let norm_b = b.iter().map(|x| x * x).sum::<f32>().sqrt();
+    if norm_a == 0.0 || norm_b == 0.0 {
+        return 0.0;
+    }
+    dot / (norm_a * norm_b)
+}
+
+/// Average-linkage agglomerative clustering over cosine similarity.
+///
+/// Clusters keep merging while the most similar pair is above `threshold`, or while
+/// there are more clusters than `max_speakers`. Returns one cluster index per embedding,
+/// numbered by order of first appearance (so the first speaker heard is cluster 0).
+pub fn cluster_embeddings(
+    embeddings: &[Vec<f32>],
+    threshold: f32,
+    max_speakers: Option<usize>,
+) -> Vec<usize> {
+    let n = embeddings.len();
+    if n == 0 {
+        return Vec::new();
+    }
+
+    // Pairwise similarity matrix, updated in place with the Lance-Williams formula
+    let mut sim = vec![vec![0.0f32; n]; n];
+    for i in 0..n {
+        for j in (i + 1)..n {
+            let s = cosine_similarity(&embeddings[i], &embeddings[j]);
+            sim[i][j] = s;
+            sim[j][i] = s;
+        }
+    }
+
+    let mut active: Vec<bool> = vec![true; n];
+    let mut sizes: Vec<usize> = vec![1; n];
+    let mut assignment: Vec<usize> = (0..n).collect();
+    let mut cluster_count = n;
+    let max_speakers = max_speakers.unwrap_or(usize::MAX).max(1);
+
+    while cluster_count > 1 {
+        let mut best: Option<(usize, usize, f32)> = None;
+        for i in 0..n {
+            if !active[i] {
+                continue;
+            }
+            for j in (i + 1)..n {
+                if active[j] && best.map_or(true, |(_, _, s)| sim[i][j] > s) {
+                    best = Some((i, j, sim[i][j]));
+                }
+            }
+        }
+
+        let Some((a, b, score)) = best else { break };
+        if score < threshold && cluster_count <= max_speakers {
+            break;
+        }
+
+        // Merge b into a
+        let (size_a, size_b) = (sizes[a] as f32, sizes[b] as f32);
+        for k in 0..n {
+            if active[k] && k != a && k != b {
+                let merged = (size_a * sim[a][k] + size_b * sim[b][k]) / (size_a + size_b);
+                sim[a][k] = merged;
+                sim[k][a] = merged;
+            }
+        }
+        sizes[a] += sizes[b];
+        active[b] = false;
+        assignment.iter_mut().filter(|c| **c == b).for_each(|c| *c = a);
+        cluster_count -= 1;
+    }
+
+    // Renumber clusters by first appearance
+    let mut order: Vec<usize> = Vec::new();
+    assignment
+        .into_iter()
+        .map(|c| match order.iter().position(|&o| o == c) {
+            Some(idx) => idx,
+            None => {
+                order.push(c);
+                order.len() - 1
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cosine_similarity() {
+        assert!((cosine_similarity(&[1.0, 0.0], &[1.0, 0.0]) - 1.0).abs() < 1e-6);
+        assert!(cosine_similarity(&[1.0, 0.0], &[0.0, 1.0]).abs() < 1e-6);
+        assert_eq!(cosine_similarity(&[0.0, 0.0], &[1.0, 0.0]), 0.0);
+    }
+
+    #[test]
+    fn test_cluster_two_speakers() {
+        let embeddings = vec![
+            vec![1.0, 0.05, 0.0],
+            vec![0.0, 1.0, 0.1],
+            vec![0.95, 0.1, 0.0],
+            vec![0.05, 0.9, 0.0],
+            vec![1.0, 0.0, 0.05],
+        ];
+
+        let labels = cluster_embeddings(&embeddings, 0.7, None);
+        assert_eq!(labels, vec![0, 1, 0, 1, 0]);
+    }
+
+    #[test]
+    fn test_cluster_respects_max_speakers() {
+        let embeddings = vec![vec![1.0, 0.0, 0.0], vec![0.0, 1.0, 0.0], vec![0.0, 0.0, 1.0]];
+
+        let unbounded = cluster_embeddings(&embeddings, 0.7, None);
+        assert_eq!(unbounded, vec![0, 1, 2]);
+
+        let bounded = cluster_embeddings(&embeddings, 0.7, Some(2));
+        assert_eq!(bounded.iter().max(), Some(&1));
+    }
+
+    #[test]
+    fn test_cluster_empty() {
+        assert!(cluster_embeddings(&[], 0.5, None).is_empty());
+    }
+}
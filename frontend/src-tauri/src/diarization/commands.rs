@@ -0,0 +1,276 @@
+use crate::audio::decoder::{decode_audio_file_16k_mono, slice_seconds, TARGET_SAMPLE_RATE};
+use crate::audio::recording_saver::meeting_system_audio_path;
+use crate::database::models::Transcript;
+use crate::database::repositories::{
+    meeting::MeetingsRepository, transcript::TranscriptsRepository,
+};
+use crate::diarization::{cluster_embeddings, SpeakerEmbeddingModel};
+use crate::state::AppState;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use tauri::{command, AppHandle, Emitter, Manager, Runtime};
+
+/// File name of the speaker embedding model inside the diarization models directory
+pub const SPEAKER_EMBEDDING_MODEL_FILE: &str = "speaker-embedding.onnx";
+
+/// Default cosine-similarity threshold above which two clusters are the same speaker
+const DEFAULT_SIMILARITY_THRESHOLD: f32 = 0.5;
+
+/// Segments shorter than this are too short for a reliable embedding and inherit
+/// the speaker of the closest embedded segment instead
+const MIN_EMBEDDING_SECONDS: f64 = 1.0;
+
+/// Source tag of system-audio segments in dual-channel recordings. Only these are
+/// diarized: microphone segments are always the local user, and mixed recordings
+/// can't tell the local user apart from remote speakers
+const SYSTEM_SOURCE: &str = "Remote";
+
+// Global diarization models directory path (set during app initialization)
+static MODELS_DIR: Mutex<Option<PathBuf>> = Mutex::new(None);
+
+// Loaded speaker embedding model, kept around between runs
+static SPEAKER_MODEL: Mutex<Option<SpeakerEmbeddingModel>> = Mutex::new(None);
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DiarizationResult {
+    pub meeting_id: String,
+    pub speakers: Vec<String>,
+    pub labeled_segments: usize,
+}
+
+/// Initialize the diarization models directory (`<app_data>/models/diarization`).
+/// This should be called during app setup.
+pub fn set_models_directory<R: Runtime>(app: &AppHandle<R>) {
+    let app_data_dir = app.path().app_data_dir().expect("Failed to get app data dir");
+
+    let models_dir = app_data_dir.join("models").join("diarization");
+
+    if !models_dir.exists() {
+        if let Err(e) = std::fs::create_dir_all(&models_dir) {
+            log::error!("Failed to create diarization models directory: {}", e);
+            return;
+        }
+    }
+
+    log::info!(
+        "Diarization models directory set to: {}",
+        models_dir.display()
+    );
+
+    let mut guard = MODELS_DIR.lock().unwrap();
+    *guard = Some(models_dir);
+}
+
+fn get_model_path() -> Option<PathBuf> {
+    MODELS_DIR
+        .lock()
+        .unwrap()
+        .as_ref()
+        .map(|dir| dir.join(SPEAKER_EMBEDDING_MODEL_FILE))
+}
+
+#[command]
+pub async fn diarization_is_model_available() -> Result<bool, String> {
+    Ok(get_model_path().map(|p| p.exists()).unwrap_or(false))
+}
+
+#[command]
+pub async fn diarization_get_models_directory() -> Result<String, String> {
+    MODELS_DIR
+        .lock()
+        .unwrap()
+        .as_ref()
+        .map(|dir| dir.to_string_lossy().to_string())
+        .ok_or_else(|| "Diarization models directory not initialized".to_string())
+}
+
+/// Cluster the system-audio segments of a recorded meeting into Speaker 1..N
+/// and store the labels on the transcript rows. Re-running replaces previous labels.
+#[command]
+pub async fn diarize_meeting<R: Runtime>(
+    app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    meeting_id: String,
+    similarity_threshold: Option<f32>,
+    max_speakers: Option<usize>,
+) -> Result<DiarizationResult, String> {
+    log::info!("🗣️ Starting speaker diarization for meeting {}", meeting_id);
+
+    let pool = state.db_manager.pool();
+
+    let folder_path = MeetingsRepository::get_meeting_folder_path(pool, &meeting_id)
+        .await
+        .map_err(|e| format!("Failed to load meeting: {}", e))?
+        .ok_or_else(|| "Meeting has no recording folder".to_string())?;
+    // Embeddings come from the system-audio track: in the mixed recording the local
+    // speaker (and mic bleed) overlaps remote speech and merges clusters
+    let audio_path = meeting_system_audio_path(&PathBuf::from(folder_path));
+    if !audio_path.exists() {
+        return Err(
+            "This recording has no separate system-audio track. Speaker diarization needs a \
+             recording made with separate channels"
+                .to_string(),
+        );
+    }
+
+    let model_path = get_model_path()
+        .ok_or_else(|| "Diarization models directory not initialized".to_string())?;
+    if !model_path.exists() {
+        return Err(format!(
+            "Speaker embedding model not found. Place {} in {}",
+            SPEAKER_EMBEDDING_MODEL_FILE,
+            model_path.parent().map(|p| p.display().to_string()).unwrap_or_default()
+        ));
+    }
+
+    let transcripts = TranscriptsRepository::get_transcripts_for_meeting(pool, &meeting_id)
+        .await
+        .map_err(|e| format!("Failed to load transcripts: {}", e))?;
+
+    if !transcripts.iter().any(|t| t.source.as_deref() == Some(SYSTEM_SOURCE)) {
+        return Err(
+            "Speaker diarization needs a recording made with separate channels: \
+             mixed recordings include the local speaker"
+                .to_string(),
+        );
+    }
+
+    let segments: Vec<Transcript> = transcripts
+        .into_iter()
+        .filter(|t| t.source.as_deref() == Some(SYSTEM_SOURCE))
+        .filter(|t| matches!((t.audio_start_time, t.audio_end_time), (Some(s), Some(e)) if e > s))
+        .collect();
+
+    if segments.is_empty() {
+        return Err("No timed system-audio segments to diarize".to_string());
+    }
+
+    let _ = app.emit(
+        "diarization-started",
+        serde_json::json!({ "meeting_id": meeting_id, "segments": segments.len() }),
+    );
+
+    let threshold = similarity_threshold.unwrap_or(DEFAULT_SIMILARITY_THRESHOLD);
+    let labels = tokio::task::spawn_blocking(move || {
+        diarize_segments(&audio_path, &model_path, &segments, threshold, max_speakers)
+    })
+    .await
+    .map_err(|e| format!("Diarization task failed: {}", e))??;
+
+    let mut speakers: Vec<String> = Vec::new();
+    for (_, speaker) in &labels {
+        if !speakers.contains(speaker) {
+            speakers.push(speaker.clone());
+        }
+    }
+
+    TranscriptsRepository::update_speakers(pool, &meeting_id, &labels)
+        .await
+        .map_err(|e| format!("Failed to save speaker labels: {}", e))?;
+
+    log::info!(
+        "✅ Diarization complete for meeting {}: {} speakers across {} segments",
+        meeting_id,
+        speakers.len(),
+        labels.len()
+    );
+
+    let result = DiarizationResult {
+        meeting_id,
+        speakers,
+        labeled_segments: labels.len(),
+    };
+    let _ = app.emit("diarization-complete", &result);
+
+    Ok(result)
+}
+
+/// Embed each segment and cluster them. Returns `(transcript_id, speaker_label)` pairs.
+fn diarize_segments(
+    audio_path: &Path,
+    model_path: &Path,
+    segments: &[Transcript],
+    threshold: f32,
+    max_speakers: Option<usize>,
+) -> Result<Vec<(String, String)>, String> {
+    let samples = decode_audio_file_16k_mono(audio_path)
+        .map_err(|e| format!("Failed to decode recording: {}", e))?;
+
+    let mut model_guard = SPEAKER_MODEL.lock().unwrap();
+    if model_guard.is_none() {
+        let model = SpeakerEmbeddingModel::new(model_path)
+            .map_err(|e| format!("Failed to load speaker embedding model: {}", e))?;
+        *model_guard = Some(model);
+    }
+    let model = model_guard.as_mut().unwrap();
+
+    // (segment index, embedding) for segments long enough to embed
+    let mut embedded: Vec<(usize, Vec<f32>)> = Vec::new();
+    for (idx, segment) in segments.iter().enumerate() {
+        let (start, end) = segment_span(segment);
+        if end - start < MIN_EMBEDDING_SECONDS {
+            continue;
+        }
+
+        let audio = slice_seconds(&samples, TARGET_SAMPLE_RATE, start, end);
+        match model.embed(audio) {
+            Ok(embedding) => embedded.push((idx, embedding)),
+            Err(e) => log::warn!("Skipping segment {} for diarization: {}", segment.id, e),
+        }
+    }
+
+    if embedded.is_empty() {
+        return Err("No segments long enough for speaker embedding".to_string());
+    }
+
+    let embeddings: Vec<Vec<f32>> = embedded.iter().map(|(_, e)| e.clone()).collect();
+    let clusters = cluster_embeddings(&embeddings, threshold, max_speakers);
+
+    // Short segments inherit the speaker of the nearest embedded segment in time
+    let labels = segments
+        .iter()
+        .map(|segment| {
+            let span = segment_span(segment);
+            let nearest = embedded
+                .iter()
+                .enumerate()
+                .min_by(|(_, (a, _)), (_, (b, _))| {
+                    time_gap(span, segment_span(&segments[*a]))
+                        .total_cmp(&time_gap(span, segment_span(&segments[*b])))
+                })
+                .map(|(pos, _)| clusters[pos])
+                .unwrap_or(0);
+            (segment.id.clone(), format!("Speaker {}", nearest + 1))
+        })
+        .collect();
+
+    Ok(labels)
+}
+
+/// Recording-relative `(start, end)` of a segment in seconds
+fn segment_span(segment: &Transcript) -> (f64, f64) {
+    (
+        segment.audio_start_time.unwrap_or(0.0),
+        segment.audio_end_time.unwrap_or(0.0),
+    )
+}
+
+/// Seconds between two spans (0 when they overlap)
+fn time_gap(a: (f64, f64), b: (f64, f64)) -> f64 {
+    (b.0 - a.1).max(a.0 - b.1).max(0.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_time_gap() {
+        assert_eq!(time_gap((0.0, 2.0), (5.0, 6.0)), 3.0);
+        assert_eq!(time_gap((5.0, 6.0), (0.0, 2.0)), 3.0);
+        assert_eq!(time_gap((0.0, 4.0), (3.0, 6.0)), 0.0);
+        // A long pause between list neighbours outweighs their adjacency
+        assert!(time_gap((10.0, 10.5), (0.0, 2.0)) > time_gap((10.0, 10.5), (11.0, 13.0)));
+    }
+}
@@ -0,0 +1,248 @@
+use ndarray::Array3;
+use ort::execution_providers::CPUExecutionProvider;
+use ort::inputs;
+use ort::session::builder::GraphOptimizationLevel;
+use ort::session::Session;
+use ort::value::TensorRef;
+use realfft::RealFftPlanner;
+use std::path::Path;
+
+const SAMPLE_RATE: f32 = 16000.0;
+const FRAME_LENGTH: usize = 400; // 25ms
+const FRAME_SHIFT: usize = 160; // 10ms
+const FFT_SIZE: usize = 512;
+const NUM_MEL_BINS: usize = 80;
+const LOW_FREQ: f32 = 20.0;
+const PREEMPHASIS: f32 = 0.97;
+
+/// Segments longer than this are trimmed around their centre before embedding
+const MAX_EMBEDDING_SECONDS: usize = 20;
+
+#[derive(thiserror::Error, Debug)]
+pub enum DiarizationError {
+    #[error("ORT error")]
+    Ort(#[from] ort::Error),
+    #[error("I/O error")]
+    Io(#[from] std::io::Error),
+    #[error("ndarray shape error")]
+    Shape(#[from] ndarray::ShapeError),
+    #[error("Speaker embedding model not found: {0}")]
+    ModelNotFound(String),
+    #[error("Model has no {0}")]
+    InvalidModel(String),
+    #[error("Audio segment too short for speaker embedding")]
+    SegmentTooShort,
+}
+
+/// Wrapper around an ONNX speaker-embedding model (WeSpeaker / 3D-Speaker style)
+/// that takes 80-dim log-mel filterbank features of shape `[batch, frames, 80]`
+/// and returns one embedding per batch item.
+pub struct SpeakerEmbeddingModel {
+    session: Session,
+    input_name: String,
+    output_name: String,
+    mel_filters: Vec<Vec<f32>>,
+    window: Vec<f32>,
+}
+
+impl SpeakerEmbeddingModel {
+    pub fn new<P: AsRef<Path>>(model_path: P) -> Result<Self, DiarizationError> {
+        let model_path = model_path.as_ref();
+        if !model_path.exists() {
+            return Err(DiarizationError::ModelNotFound(
+                model_path.display().to_string(),
+            ));
+        }
+
+        log::info!(
+            "Loading speaker embedding model from {}...",
+            model_path.display()
+        );
+
+        let session = Session::builder()?
+            .with_optimization_level(GraphOptimizationLevel::Level3)?
+            .with_execution_providers(vec![CPUExecutionProvider::default().build()])?
+            .commit_from_file(model_path)?;
+
+        let input_name = session
+            .inputs
+            .first()
+            .map(|i| i.name.clone())
+            .ok_or_else(|| DiarizationError::InvalidModel("inputs".to_string()))?;
+        let output_name = session
+            .outputs
+            .first()
+            .map(|o| o.name.clone())
+            .ok_or_else(|| DiarizationError::InvalidModel("outputs".to_string()))?;
+
+        log::info!(
+            "Speaker embedding model loaded: input={}, output={}",
+            input_name,
+            output_name
+        );
+
+        Ok(Self {
+            session,
+            input_name,
+            output_name,
+            mel_filters: mel_filterbank(),
+            window: povey_window(),
+        })
+    }
+
+    /// Compute an L2-normalised speaker embedding for 16kHz mono audio.
+    pub fn embed(&mut self, samples: &[f32]) -> Result<Vec<f32>, DiarizationError> {
+        let max_samples = MAX_EMBEDDING_SECONDS * SAMPLE_RATE as usize;
+        let samples = if samples.len() > max_samples {
+            let offset = (samples.len() - max_samples) / 2;
+            &samples[offset..offset + max_samples]
+        } else {
+            samples
+        };
+
+        let features = self.compute_fbank(samples);
+        if features.is_empty() {
+            return Err(DiarizationError::SegmentTooShort);
+        }
+
+        let num_frames = features.len();
+        let flat: Vec<f32> = features.into_iter().flatten().collect();
+        let input = Array3::from_shape_vec((1, num_frames, NUM_MEL_BINS), flat)?;
+
+        let inputs = inputs![
+            self.input_name.as_str() => TensorRef::from_array_view(input.view())?,
+        ];
+        let outputs = self.session.run(inputs)?;
+
+        let embedding: Vec<f32> = outputs
+            .get(self.output_name.as_str())
+            .ok_or_else(|| DiarizationError::InvalidModel(self.output_name.clone()))?
+            .try_extract_array::<f32>()?
+            .iter()
+            .copied()
+            .collect();
+
+        Ok(l2_normalize(embedding))
+    }
+
+    /// Kaldi-compatible 80-dim log-mel filterbank with per-utterance mean normalisation.
+    fn compute_fbank(&self, samples: &[f32]) -> Vec<Vec<f32>> {
+        if samples.len() < FRAME_LENGTH {
+            return Vec::new();
+        }
+
+        let num_frames = 1 + (samples.len() - FRAME_LENGTH) / FRAME_SHIFT;
+        let mut planner = RealFftPlanner::<f32>::new();
+        let fft = planner.plan_fft_forward(FFT_SIZE);
+        let mut buffer = fft.make_input_vec();
+        let mut spectrum = fft.make_output_vec();
+
+        let mut features = Vec::with_capacity(num_frames);
+        for i in 0..num_frames {
+            let start = i * FRAME_SHIFT;
+            // Models are trained on int16-scaled audio
+            let mut frame: Vec<f32> = samples[start..start + FRAME_LENGTH]
+                .iter()
+                .map(|s| s * 32768.0)
+                .collect();
+
+            let mean = frame.iter().sum::<f32>() / FRAME_LENGTH as f32;
+            frame.iter_mut().for_each(|s| *s -= mean);
+
+            for j in (1..FRAME_LENGTH).rev() {
+                frame[j] -= PREEMPHASIS * frame[j - 1];
+            }
+            frame[0] *= 1.0 - PREEMPHASIS;
+
+            buffer.iter_mut().for_each(|b| *b = 0.0);
+            for (j, s) in frame.iter().enumerate() {
+                buffer[j] = s * self.window[j];
+            }
+
+            if fft.process(&mut buffer, &mut spectrum).is_err() {
+                continue;
+            }
+
+            let power: Vec<f32> = spectrum.iter().map(|c| c.norm_sqr()).collect();
+            let mel: Vec<f32> = self
+                .mel_filters
+                .iter()
+                .map(|filter| {
+                    let energy: f32 = filter.iter().zip(&power).map(|(w, p)| w * p).sum();
+                    energy.max(f32::EPSILON).ln()
+                })
+                .collect();
+            features.push(mel);
+        }
+
+        // Cepstral mean normalisation
+        if !features.is_empty() {
+            let mut means = vec![0.0f32; NUM_MEL_BINS];
+            for frame in &features {
+                for (m, v) in means.iter_mut().zip(frame) {
+                    *m += v;
+                }
+            }
+            means.iter_mut().for_each(|m| *m /= features.len() as f32);
+            for frame in features.iter_mut() {
+                for (v, m) in frame.iter_mut().zip(&means) {
+                    *v -= m;
+                }
+            }
+        }
+
+        features
+    }
+}
+
+fn povey_window() -> Vec<f32> {
+    (0..FRAME_LENGTH)
+        .map(|i| {
+            let hann = 0.5
+                - 0.5 * (2.0 * std::f32::consts::PI * i as f32 / (FRAME_LENGTH - 1) as f32).cos();
+            hann.powf(0.85)
+        })
+        .collect()
+}
+
+fn hz_to_mel(hz: f32) -> f32 {
+    1127.0 * (1.0 + hz / 700.0).ln()
+}
+
+/// Triangular mel filters over the `FFT_SIZE / 2 + 1` power-spectrum bins.
+fn mel_filterbank() -> Vec<Vec<f32>> {
+    let num_bins = FFT_SIZE / 2 + 1;
+    let mel_low = hz_to_mel(LOW_FREQ);
+    let mel_high = hz_to_mel(SAMPLE_RATE / 2.0);
+    let mel_step = (mel_high - mel_low) / (NUM_MEL_BINS + 1) as f32;
+
+    (0..NUM_MEL_BINS)
+        .map(|m| {
+            let left = mel_low + m as f32 * mel_step;
+            let center = left + mel_step;
+            let right = center + mel_step;
+
+            (0..num_bins)
+                .map(|bin| {
+                    let hz = bin as f32 * SAMPLE_RATE / FFT_SIZE as f32;
+                    let mel = hz_to_mel(hz);
+                    if mel <= left || mel >= right {
+                        0.0
+                    } else if mel <= center {
+                        (mel - left) / (center - left)
+                    } else {
+                        (right - mel) / (right - center)
+                    }
+                })
+                .collect()
+        })
+        .collect()
+}
+
+fn l2_normalize(mut v: Vec<f32>) -> Vec<f32> {
+    let norm = v.iter().map(|x| x * x).sum::<f32>().sqrt();
+    if norm > 0.0 {
+        v.iter_mut().for_each(|x| *x /= norm);
+    }
+    v
+}
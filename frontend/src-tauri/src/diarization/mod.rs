@@ -0,0 +1,19 @@
+//! Offline speaker diarization for recorded meetings.
+//!
+//! Splits the remote side of a call into "Speaker 1", "Speaker 2", ... by embedding every
+//! system-audio transcript segment with a local ONNX speaker-embedding model and clustering
+//! the embeddings. Runs entirely on-device through the same ONNX Runtime used by Parakeet.
+//!
+//! # Module Structure
+//!
+//! - `embedding`: Fbank feature extraction and ONNX speaker-embedding model wrapper
+//! - `clustering`: Agglomerative clustering of embeddings into speakers
+//! - `commands`: Tauri command interface for frontend integration
+
+pub mod clustering;
+pub mod commands;
+pub mod embedding;
+
+pub use clustering::{cluster_embeddings, cosine_similarity};
+pub use commands::*;
+pub use embedding::{DiarizationError, SpeakerEmbeddingModel};
@@ -0,0 +1,354 @@
+use log::{error, info};
+use serde::Serialize;
+use sqlx::SqlitePool;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use tauri::{AppHandle, Emitter, Manager};
+
+use super::files::FileCipher;
+use super::keys::{self, DerivedKeys, EncryptionConfig};
+use crate::database::manager::DatabaseManager;
+use crate::state::AppState;
+
+const DATABASE_FILE: &str = "meeting_minutes.sqlite";
+
+/// Returned after enabling encryption: copies made before it are out of reach
+const EARLIER_COPIES_NOTICE: &str = "Earlier plaintext copies of your meetings, such as \
+    backups, filesystem snapshots or synced folders, are not encrypted. Delete them separately.";
+
+#[derive(Debug, Serialize)]
+pub struct EncryptionStatus {
+    /// This build includes SQLCipher (the `encryption` cargo feature)
+    pub available: bool,
+    pub enabled: bool,
+    /// Encryption is enabled and the passphrase has not been entered yet
+    pub locked: bool,
+}
+
+#[derive(Debug, Serialize)]
+pub struct EnableEncryptionResult {
+    pub notice: String,
+}
+
+fn data_dir() -> Result<PathBuf, String> {
+    super::app_data_dir().cloned().map_err(|e| e.to_string())
+}
+
+fn ensure_available() -> Result<(), String> {
+    if !cfg!(feature = "encryption") {
+        return Err("This build of Meetily doesn't include encryption at rest".to_string());
+    }
+    Ok(())
+}
+
+fn emit_progress(app: &AppHandle, stage: &str) {
+    let _ = app.emit("encryption-progress", serde_json::json!({ "stage": stage }));
+}
+
+/// Restarts the app once the command has answered. Locking and switching encryption on or
+/// off both need a fresh process: the database pool and keys live in managed state.
+fn restart_soon(app: &AppHandle) {
+    let app = app.clone();
+    tauri::async_runtime::spawn(async move {
+        tokio::time::sleep(tokio::time::Duration::from_millis(500)).await;
+        app.restart();
+    });
+}
+
+async fn ensure_not_recording() -> Result<(), String> {
+    if crate::audio::recording_commands::is_recording().await {
+        return Err("Stop the current recording first".to_string());
+    }
+    Ok(())
+}
+
+async fn unlock_keys(passphrase: String, config: EncryptionConfig) -> Result<DerivedKeys, String> {
+    // Argon2 deliberately takes a noticeable amount of CPU time
+    tokio::task::spawn_blocking(move || keys::unlock_keys(&passphrase, &config))
+        .await
+        .map_err(|e| format!("Key derivation task failed: {}", e))?
+}
+
+/// Encrypts the meeting folders under the recordings folder, skipping an active recording
+async fn seal_recordings(app: &AppHandle) {
+    let Some(cipher) = super::file_cipher() else {
+        return;
+    };
+    let recordings_dir = match crate::audio::recording_preferences::load_recording_preferences(app).await {
+        Ok(preferences) => preferences.save_folder,
+        Err(e) => {
+            error!("Failed to load recording preferences: {}", e);
+            return;
+        }
+    };
+    let active = crate::audio::recording_commands::get_meeting_folder_path()
+        .await
+        .ok()
+        .flatten()
+        .map(PathBuf::from);
+    let result = tokio::task::spawn_blocking(move || {
+        super::seal_recordings_blocking(&cipher, &recordings_dir, active.as_deref())
+    })
+    .await;
+    if let Err(e) = result {
+        error!("Recording encryption task failed: {}", e);
+    }
+}
+
+/// Copies the open database into `target` with SQLCipher key `key` ('' for plaintext)
+async fn export_database(pool: &SqlitePool, target: &Path, key: &str) -> Result<(), String> {
+    let _ = std::fs::remove_file(target);
+    let mut conn = pool.acquire().await.map_err(|e| e.to_string())?;
+    sqlx::query("ATTACH DATABASE ? AS export_target KEY ?")
+        .bind(target.to_string_lossy().to_string())
+        .bind(key)
+        .execute(&mut *conn)
+        .await
+        .map_err(|e| format!("Failed to create the new database: {}", e))?;
+    let exported = sqlx::query("SELECT sqlcipher_export('export_target')")
+        .execute(&mut *conn)
+        .await;
+    let detached = sqlx::query("DETACH DATABASE export_target")
+        .execute(&mut *conn)
+        .await;
+    exported.map_err(|e| format!("Failed to copy the database: {}", e))?;
+    detached.map_err(|e| format!("Failed to close the new database: {}", e))?;
+    Ok(())
+}
+
+/// Opens an exported database the way the next start will, and reads from it
+async fn verify_database(path: &Path, key: Option<&str>) -> Result<(), String> {
+    let options = DatabaseManager::connect_options(&path.to_string_lossy(), key);
+    let pool = SqlitePool::connect_with(options)
+        .await
+        .map_err(|e| format!("The new database can't be opened: {}", e))?;
+    let result: Result<i64, sqlx::Error> = sqlx::query_scalar("SELECT COUNT(*) FROM meetings")
+        .fetch_one(&pool)
+        .await;
+    pool.close().await;
+    result
+        .map(|_| ())
+        .map_err(|e| format!("The new database is unreadable: {}", e))
+}
+
+/// Overwrites a file with zeros before deleting it, so its old contents don't stay in free
+/// disk space. Copy-on-write filesystems and SSD wear levelling can still keep old blocks.
+pub(crate) fn overwrite_and_remove(path: &Path) -> io::Result<()> {
+    let mut remaining = std::fs::metadata(path)?.len();
+    let mut file = std::fs::OpenOptions::new().write(true).open(path)?;
+    let zeros = [0u8; 64 * 1024];
+    while remaining > 0 {
+        let len = remaining.min(zeros.len() as u64) as usize;
+        file.write_all(&zeros[..len])?;
+        remaining -= len as u64;
+    }
+    file.sync_all()?;
+    drop(file);
+    std::fs::remove_file(path)
+}
+
+/// Replaces the database file with `replacement`, restoring the original on failure
+fn swap_database(db_path: &Path, replacement: &Path) -> Result<(), String> {
+    let backup = db_path.with_extension("sqlite.bak");
+    std::fs::rename(db_path, &backup).map_err(|e| format!("Failed to move the database: {}", e))?;
+    // Journal files belong to the old file
+    for suffix in ["-wal", "-shm"] {
+        let journal = PathBuf::from(format!("{}{}", db_path.display(), suffix));
+        if journal.exists() {
+            let _ = overwrite_and_remove(&journal);
+        }
+    }
+    if let Err(e) = std::fs::rename(replacement, db_path) {
+        let _ = std::fs::rename(&backup, db_path);
+        return Err(format!("Failed to replace the database: {}", e));
+    }
+    if let Err(e) = overwrite_and_remove(&backup) {
+        error!("Failed to remove the old database {}: {}", backup.display(), e);
+    }
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn api_get_encryption_status(app: AppHandle) -> Result<EncryptionStatus, String> {
+    let enabled = super::is_enabled();
+    Ok(EncryptionStatus {
+        available: cfg!(feature = "encryption"),
+        enabled,
+        locked: enabled && app.try_state::<AppState>().is_none(),
+    })
+}
+
+/// Opens the encrypted database and recordings with the passphrase
+#[tauri::command]
+pub async fn api_unlock_database(app: AppHandle, passphrase: String) -> Result<(), String> {
+    if app.try_state::<AppState>().is_some() {
+        return Ok(());
+    }
+    ensure_available()?;
+    let config = keys::load_config(&data_dir()?)?.ok_or("Encryption is not enabled")?;
+    let derived = unlock_keys(passphrase, config).await?;
+
+    let db_manager = DatabaseManager::open_from_app_handle(&app, Some(&derived.sqlcipher_key()))
+        .await
+        .map_err(|e| format!("Failed to open the encrypted database: {}", e))?;
+    super::set_file_cipher(Some(FileCipher::new(&derived.files)));
+    app.manage(AppState { db_manager });
+    info!("🔓 Database unlocked");
+
+    app.emit("database-initialized", ())
+        .map_err(|e| format!("Failed to emit database-initialized event: {}", e))?;
+
+    // Meetings saved while encryption could not be applied (e.g. a crash mid-way) are
+    // encrypted now
+    let app_for_sweep = app.clone();
+    tauri::async_runtime::spawn(async move { seal_recordings(&app_for_sweep).await });
+    Ok(())
+}
+
+/// Forgets the keys and restarts into the locked state
+#[tauri::command]
+pub async fn api_lock_database(app: AppHandle) -> Result<(), String> {
+    if !super::is_enabled() {
+        return Err("Encryption is not enabled".to_string());
+    }
+    ensure_not_recording().await?;
+
+    super::set_file_cipher(None);
+    super::clear_decrypted_cache();
+    if let Some(state) = app.try_state::<AppState>() {
+        state.db_manager.pool().close().await;
+    }
+    info!("🔒 Database locked, restarting");
+    restart_soon(&app);
+    Ok(())
+}
+
+/// Encrypts the database and all recordings with a key derived from `passphrase`, then
+/// restarts locked. Existing data is converted; the plaintext database file is overwritten
+/// and deleted. Copies made earlier (backups, snapshots) are not touched.
+#[tauri::command]
+pub async fn api_enable_encryption(
+    app: AppHandle,
+    state: tauri::State<'_, AppState>,
+    passphrase: String,
+) -> Result<EnableEncryptionResult, String> {
+    if super::is_enabled() {
+        return Err("Encryption is already enabled".to_string());
+    }
+    ensure_available()?;
+    ensure_not_recording().await?;
+    let dir = data_dir()?;
+    let db_path = dir.join(DATABASE_FILE);
+    let encrypted_path = dir.join(format!("{}.encrypting", DATABASE_FILE));
+
+    emit_progress(&app, "deriving_key");
+    let (config, derived) = tokio::task::spawn_blocking(move || keys::create_config(&passphrase))
+        .await
+        .map_err(|e| format!("Key derivation task failed: {}", e))??;
+
+    emit_progress(&app, "encrypting_database");
+    let key = derived.sqlcipher_key();
+    let converted = async {
+        export_database(state.db_manager.pool(), &encrypted_path, &key).await?;
+        verify_database(&encrypted_path, Some(&key)).await
+    }
+    .await;
+    if let Err(e) = converted {
+        let _ = std::fs::remove_file(&encrypted_path);
+        return Err(e);
+    }
+
+    keys::save_config(&dir, &config)?;
+    state.db_manager.pool().close().await;
+    if let Err(e) = swap_database(&db_path, &encrypted_path) {
+        // The plaintext database is back in place; start again without encryption
+        let _ = std::fs::remove_file(dir.join(keys::CONFIG_FILE));
+        let _ = std::fs::remove_file(&encrypted_path);
+        restart_soon(&app);
+        return Err(e);
+    }
+    info!("🔐 Database encrypted");
+
+    emit_progress(&app, "encrypting_recordings");
+    super::set_file_cipher(Some(FileCipher::new(&derived.files)));
+    seal_recordings(&app).await;
+
+    super::set_file_cipher(None);
+    restart_soon(&app);
+    Ok(EnableEncryptionResult {
+        notice: EARLIER_COPIES_NOTICE.to_string(),
+    })
+}
+
+/// Decrypts all recordings and the database back to plaintext, then restarts
+#[tauri::command]
+pub async fn api_disable_encryption(
+    app: AppHandle,
+    state: tauri::State<'_, AppState>,
+    passphrase: String,
+) -> Result<(), String> {
+    let dir = data_dir()?;
+    let config = keys::load_config(&dir)?.ok_or("Encryption is not enabled")?;
+    ensure_available()?;
+    ensure_not_recording().await?;
+    let derived = unlock_keys(passphrase, config).await?;
+    let db_path = dir.join(DATABASE_FILE);
+    let plaintext_path = dir.join(format!("{}.decrypting", DATABASE_FILE));
+
+    // Recordings first: if this fails part-way everything is still readable with the key
+    emit_progress(&app, "decrypting_recordings");
+    let recordings_dir = crate::audio::recording_preferences::load_recording_preferences(&app)
+        .await
+        .map_err(|e| format!("Failed to load recording preferences: {}", e))?
+        .save_folder;
+    let cipher = FileCipher::new(&derived.files);
+    let unsealed = tokio::task::spawn_blocking(move || {
+        super::unseal_recordings_blocking(&cipher, &recordings_dir)
+    })
+    .await
+    .map_err(|e| format!("Recording decryption task failed: {}", e))??;
+    info!("🔓 Decrypted {} recording files", unsealed);
+    super::set_file_cipher(None);
+    super::clear_decrypted_cache();
+
+    emit_progress(&app, "decrypting_database");
+    let converted = async {
+        export_database(state.db_manager.pool(), &plaintext_path, "").await?;
+        verify_database(&plaintext_path, None).await
+    }
+    .await;
+    if let Err(e) = converted {
+        let _ = std::fs::remove_file(&plaintext_path);
+        return Err(e);
+    }
+
+    state.db_manager.pool().close().await;
+    if let Err(e) = swap_database(&db_path, &plaintext_path) {
+        // Still encrypted; the recordings are plaintext, which reads fine either way
+        let _ = std::fs::remove_file(&plaintext_path);
+        restart_soon(&app);
+        return Err(e);
+    }
+    std::fs::remove_file(dir.join(keys::CONFIG_FILE))
+        .map_err(|e| format!("Failed to remove encryption settings: {}", e))?;
+    info!("🔓 Encryption disabled");
+
+    restart_soon(&app);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_overwrite_and_remove() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("meeting_minutes.sqlite.bak");
+        std::fs::write(&path, vec![0x5a; 100_000]).unwrap();
+
+        overwrite_and_remove(&path).unwrap();
+        assert!(!path.exists());
+        assert!(overwrite_and_remove(&path).is_err());
+    }
+}
@@ -0,0 +1,200 @@
+// encryption/files.rs
+//
+// Streaming XChaCha20-Poly1305 for recordings and transcripts. Layout:
+//
+//   "MTLYENC1" | 16-byte random prefix | chunk*
+//   chunk = last flag (1 byte) | ciphertext length (u32 BE) | ciphertext
+//
+// Plaintext is cut into 1 MiB chunks. Chunk i uses nonce = prefix || i (u64 BE) and the last
+// flag as associated data, so reordered, dropped or truncated chunks fail authentication.
+
+use chacha20poly1305::aead::{Aead, KeyInit, Payload};
+use chacha20poly1305::{Key, XChaCha20Poly1305, XNonce};
+use rand::RngCore;
+use std::io::{self, Read, Write};
+use std::path::Path;
+
+pub const MAGIC: &[u8; 8] = b"MTLYENC1";
+const PREFIX_LEN: usize = 16;
+const CHUNK_SIZE: usize = 1024 * 1024;
+const TAG_LEN: usize = 16;
+
+pub struct FileCipher {
+    cipher: XChaCha20Poly1305,
+}
+
+fn invalid(message: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message.to_string())
+}
+
+/// Reads until `buf` is full or the reader is exhausted
+fn read_full(reader: &mut impl Read, buf: &mut [u8]) -> io::Result<usize> {
+    let mut filled = 0;
+    while filled < buf.len() {
+        match reader.read(&mut buf[filled..]) {
+            Ok(0) => break,
+            Ok(n) => filled += n,
+            Err(e) if e.kind() == io::ErrorKind::Interrupted => {}
+            Err(e) => return Err(e),
+        }
+    }
+    Ok(filled)
+}
+
+/// Whether `bytes` start with the encrypted file header
+pub fn is_encrypted(bytes: &[u8]) -> bool {
+    bytes.starts_with(MAGIC)
+}
+
+/// Whether the file at `path` is encrypted (false for missing or short files)
+pub fn file_is_encrypted(path: &Path) -> bool {
+    let mut header = [0u8; MAGIC.len()];
+    std::fs::File::open(path)
+        .and_then(|mut file| read_full(&mut file, &mut header))
+        .map(|n| n == MAGIC.len() && is_encrypted(&header))
+        .unwrap_or(false)
+}
+
+impl FileCipher {
+    pub fn new(key: &[u8; 32]) -> Self {
+        Self {
+            cipher: XChaCha20Poly1305::new(Key::from_slice(key)),
+        }
+    }
+
+    fn nonce(prefix: &[u8; PREFIX_LEN], index: u64) -> XNonce {
+        let mut nonce = [0u8; 24];
+        nonce[..PREFIX_LEN].copy_from_slice(prefix);
+        nonce[PREFIX_LEN..].copy_from_slice(&index.to_be_bytes());
+        *XNonce::from_slice(&nonce)
+    }
+
+    pub fn encrypt_stream(&self, reader: &mut impl Read, writer: &mut impl Write) -> io::Result<()> {
+        let mut prefix = [0u8; PREFIX_LEN];
+        rand::rngs::OsRng.fill_bytes(&mut prefix);
+        writer.write_all(MAGIC)?;
+        writer.write_all(&prefix)?;
+
+        let mut current = vec![0u8; CHUNK_SIZE];
+        let mut next = vec![0u8; CHUNK_SIZE];
+        let mut len = read_full(reader, &mut current)?;
+        let mut index = 0u64;
+        loop {
+            // Read ahead so the final chunk can be flagged
+            let next_len = if len == CHUNK_SIZE { read_full(reader, &mut next)? } else { 0 };
+            let last = next_len == 0;
+            let flag = [last as u8];
+            let ciphertext = self
+                .cipher
+                .encrypt(
+                    &Self::nonce(&prefix, index),
+                    Payload { msg: &current[..len], aad: &flag },
+                )
+                .map_err(|_| invalid("Encryption failed"))?;
+            writer.write_all(&flag)?;
+            writer.write_all(&(ciphertext.len() as u32).to_be_bytes())?;
+            writer.write_all(&ciphertext)?;
+            if last {
+                return writer.flush();
+            }
+            std::mem::swap(&mut current, &mut next);
+            len = next_len;
+            index += 1;
+        }
+    }
+
+    pub fn decrypt_stream(&self, reader: &mut impl Read, writer: &mut impl Write) -> io::Result<()> {
+        let mut magic = [0u8; MAGIC.len()];
+        if read_full(reader, &mut magic)? != MAGIC.len() || !is_encrypted(&magic) {
+            return Err(invalid("Not an encrypted file"));
+        }
+        let mut prefix = [0u8; PREFIX_LEN];
+        if read_full(reader, &mut prefix)? != PREFIX_LEN {
+            return Err(invalid("Encrypted file is truncated"));
+        }
+
+        let mut index = 0u64;
+        let mut ciphertext = Vec::with_capacity(CHUNK_SIZE + TAG_LEN);
+        loop {
+            let mut header = [0u8; 5];
+            if read_full(reader, &mut header)? != header.len() {
+                return Err(invalid("Encrypted file is truncated"));
+            }
+            let last = header[0] == 1;
+            let len = u32::from_be_bytes([header[1], header[2], header[3], header[4]]) as usize;
+            if len > CHUNK_SIZE + TAG_LEN {
+                return Err(invalid("Encrypted file is corrupted"));
+            }
+            ciphertext.resize(len, 0);
+            if read_full(reader, &mut ciphertext)? != len {
+                return Err(invalid("Encrypted file is truncated"));
+            }
+            let plaintext = self
+                .cipher
+                .decrypt(
+                    &Self::nonce(&prefix, index),
+                    Payload { msg: &ciphertext, aad: &header[..1] },
+                )
+                // Wrong key, or the file was modified
+                .map_err(|_| invalid("Encrypted file can't be decrypted with this key"))?;
+            writer.write_all(&plaintext)?;
+            if last {
+                if read_full(reader, &mut [0u8; 1])? != 0 {
+                    return Err(invalid("Unexpected data after the end of the encrypted file"));
+                }
+                return writer.flush();
+            }
+            index += 1;
+        }
+    }
+
+    pub fn encrypt(&self, plaintext: &[u8]) -> io::Result<Vec<u8>> {
+        let mut out = Vec::with_capacity(plaintext.len() + 64);
+        self.encrypt_stream(&mut io::Cursor::new(plaintext), &mut out)?;
+        Ok(out)
+    }
+
+    pub fn decrypt(&self, data: &[u8]) -> io::Result<Vec<u8>> {
+        let mut out = Vec::with_capacity(data.len());
+        self.decrypt_stream(&mut io::Cursor::new(data), &mut out)?;
+        Ok(out)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip_across_chunks() {
+        let cipher = FileCipher::new(&[3u8; 32]);
+        for size in [0, 10, CHUNK_SIZE, CHUNK_SIZE * 2 + 17] {
+            let plaintext: Vec<u8> = (0..size).map(|i| (i % 251) as u8).collect();
+            let encrypted = cipher.encrypt(&plaintext).unwrap();
+            assert!(is_encrypted(&encrypted));
+            assert_eq!(cipher.decrypt(&encrypted).unwrap(), plaintext, "size {}", size);
+        }
+        assert!(FileCipher::new(&[4u8; 32]).decrypt(&cipher.encrypt(b"secret").unwrap()).is_err());
+    }
+
+    #[test]
+    fn test_detects_truncation_and_tampering() {
+        let cipher = FileCipher::new(&[3u8; 32]);
+        let plaintext = vec![7u8; CHUNK_SIZE * 2 + 5];
+        let encrypted = cipher.encrypt(&plaintext).unwrap();
+
+        // Dropping the final chunk leaves a stream that ends on a non-final chunk
+        let first_chunk_end = MAGIC.len() + PREFIX_LEN + 5 + CHUNK_SIZE + TAG_LEN;
+        let second_chunk_end = first_chunk_end + 5 + CHUNK_SIZE + TAG_LEN;
+        assert!(cipher.decrypt(&encrypted[..second_chunk_end]).is_err());
+
+        // Marking an earlier chunk as last fails authentication
+        let mut flagged = encrypted.clone();
+        flagged[first_chunk_end] = 1;
+        assert!(cipher.decrypt(&flagged).is_err());
+
+        let mut flipped = encrypted.clone();
+        flipped[MAGIC.len() + PREFIX_LEN + 10] ^= 1;
+        assert!(cipher.decrypt(&flipped).is_err());
+    }
+}
@@ -0,0 +1,161 @@
+// encryption/keys.rs
+//
+// Passphrase handling. Argon2id stretches the passphrase into 64 bytes: the first half is
+// the SQLCipher key, the second half the recordings key. Only the salt, the KDF parameters
+// and a check value (a known string encrypted with the recordings key) are stored, in
+// `encryption.json`, so a wrong passphrase is reported before the database is opened.
+
+use super::files::FileCipher;
+use argon2::{Algorithm, Argon2, Params, Version};
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine;
+use rand::RngCore;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+pub const CONFIG_FILE: &str = "encryption.json";
+const CHECK_PLAINTEXT: &[u8] = b"meetily-encryption-check";
+const SALT_LEN: usize = 16;
+pub const MIN_PASSPHRASE_LEN: usize = 8;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EncryptionConfig {
+    pub version: u32,
+    pub kdf: String,
+    pub salt: String,
+    pub memory_kib: u32,
+    pub iterations: u32,
+    pub parallelism: u32,
+    pub check: String,
+}
+
+pub struct DerivedKeys {
+    pub database: [u8; 32],
+    pub files: [u8; 32],
+}
+
+impl Drop for DerivedKeys {
+    fn drop(&mut self) {
+        self.database.fill(0);
+        self.files.fill(0);
+    }
+}
+
+impl DerivedKeys {
+    /// Raw-key literal understood by SQLCipher (`PRAGMA key` / `ATTACH ... KEY`)
+    pub fn sqlcipher_key(&self) -> String {
+        let hex: String = self.database.iter().map(|b| format!("{:02x}", b)).collect();
+        format!("x'{}'", hex)
+    }
+}
+
+fn derive(passphrase: &str, config: &EncryptionConfig) -> Result<DerivedKeys, String> {
+    if config.kdf != "argon2id" {
+        return Err(format!("Unsupported key derivation: {}", config.kdf));
+    }
+    let salt = BASE64
+        .decode(&config.salt)
+        .map_err(|_| "Encryption settings are corrupted (salt)".to_string())?;
+    let params = Params::new(config.memory_kib, config.iterations, config.parallelism, Some(64))
+        .map_err(|e| format!("Invalid key derivation parameters: {}", e))?;
+    let mut output = [0u8; 64];
+    Argon2::new(Algorithm::Argon2id, Version::V0x13, params)
+        .hash_password_into(passphrase.as_bytes(), &salt, &mut output)
+        .map_err(|e| format!("Key derivation failed: {}", e))?;
+
+    let mut keys = DerivedKeys {
+        database: [0u8; 32],
+        files: [0u8; 32],
+    };
+    keys.database.copy_from_slice(&output[..32]);
+    keys.files.copy_from_slice(&output[32..]);
+    output.fill(0);
+    Ok(keys)
+}
+
+/// Fresh settings (random salt) for `passphrase`, with the keys they produce
+pub fn create_config(passphrase: &str) -> Result<(EncryptionConfig, DerivedKeys), String> {
+    if passphrase.chars().count() < MIN_PASSPHRASE_LEN {
+        return Err(format!(
+            "The passphrase must be at least {} characters long",
+            MIN_PASSPHRASE_LEN
+        ));
+    }
+    new_config(passphrase, 64 * 1024, 3)
+}
+
+fn new_config(
+    passphrase: &str,
+    memory_kib: u32,
+    iterations: u32,
+) -> Result<(EncryptionConfig, DerivedKeys), String> {
+    let mut salt = [0u8; SALT_LEN];
+    rand::rngs::OsRng.fill_bytes(&mut salt);
+    let mut config = EncryptionConfig {
+        version: 1,
+        kdf: "argon2id".to_string(),
+        salt: BASE64.encode(salt),
+        memory_kib,
+        iterations,
+        parallelism: 1,
+        check: String::new(),
+    };
+    let keys = derive(passphrase, &config)?;
+    let check = FileCipher::new(&keys.files)
+        .encrypt(CHECK_PLAINTEXT)
+        .map_err(|e| e.to_string())?;
+    config.check = BASE64.encode(check);
+    Ok((config, keys))
+}
+
+/// Keys for `passphrase`, or an error if it is not the passphrase `config` was created with
+pub fn unlock_keys(passphrase: &str, config: &EncryptionConfig) -> Result<DerivedKeys, String> {
+    let keys = derive(passphrase, config)?;
+    let check = BASE64
+        .decode(&config.check)
+        .map_err(|_| "Encryption settings are corrupted (check)".to_string())?;
+    match FileCipher::new(&keys.files).decrypt(&check) {
+        Ok(plaintext) if plaintext == CHECK_PLAINTEXT => Ok(keys),
+        _ => Err("Wrong passphrase".to_string()),
+    }
+}
+
+pub fn load_config(app_data_dir: &Path) -> Result<Option<EncryptionConfig>, String> {
+    let path = app_data_dir.join(CONFIG_FILE);
+    if !path.exists() {
+        return Ok(None);
+    }
+    let content = std::fs::read_to_string(&path)
+        .map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+    serde_json::from_str(&content)
+        .map(Some)
+        .map_err(|e| format!("Encryption settings are corrupted: {}", e))
+}
+
+pub fn save_config(app_data_dir: &Path, config: &EncryptionConfig) -> Result<(), String> {
+    let json = serde_json::to_string_pretty(config).map_err(|e| e.to_string())?;
+    let temp_path = app_data_dir.join(format!(".{}.tmp", CONFIG_FILE));
+    std::fs::write(&temp_path, json)
+        .and_then(|_| std::fs::rename(&temp_path, app_data_dir.join(CONFIG_FILE)))
+        .map_err(|e| format!("Failed to write encryption settings: {}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unlock_checks_passphrase() {
+        // Cheap parameters keep the test fast
+        let (config, keys) = new_config("correct horse battery", 64, 1).unwrap();
+        let unlocked = unlock_keys("correct horse battery", &config).unwrap();
+        assert_eq!(unlocked.database, keys.database);
+        assert_eq!(unlocked.files, keys.files);
+        assert_ne!(keys.database, keys.files);
+        assert_eq!(unlock_keys("wrong horse battery", &config).err().unwrap(), "Wrong passphrase");
+
+        assert!(keys.sqlcipher_key().starts_with("x'"));
+        assert_eq!(keys.sqlcipher_key().len(), 2 + 64 + 1);
+        assert!(create_config("short").is_err());
+    }
+}
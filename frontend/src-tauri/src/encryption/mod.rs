@@ -0,0 +1,292 @@
+/// Opt-in encryption at rest for sensitive meetings.
+///
+/// When enabled, `meeting_minutes.sqlite` is a SQLCipher database and the recording and
+/// transcripts.json of every meeting folder are encrypted with a key derived from the
+/// user's passphrase (see `keys`). The app starts locked and nothing is opened until
+/// `api_unlock_database` is called with the passphrase.
+///
+/// Not covered: metadata.json and folder names (meeting title and date) stay readable so
+/// recovery tools can find recordings, and audio checkpoints are plaintext while a
+/// recording is running; they are merged into the encrypted recording when it stops.
+pub mod commands;
+pub mod files;
+pub mod keys;
+
+use files::FileCipher;
+use log::{info, warn};
+use once_cell::sync::OnceCell;
+use std::fs::File;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, RwLock};
+
+/// Decrypted copies of recordings for tools that need a file path (decoders, FFmpeg)
+const DECRYPTED_CACHE_DIR: &str = ".decrypted";
+
+static APP_DATA_DIR: OnceCell<PathBuf> = OnceCell::new();
+static FILE_CIPHER: RwLock<Option<Arc<FileCipher>>> = RwLock::new(None);
+
+/// Records the app data directory and removes decrypted copies left by a previous session
+pub fn init(app_data_dir: &Path) {
+    let _ = APP_DATA_DIR.set(app_data_dir.to_path_buf());
+    clear_decrypted_cache();
+}
+
+fn app_data_dir() -> io::Result<&'static PathBuf> {
+    APP_DATA_DIR
+        .get()
+        .ok_or_else(|| io::Error::new(io::ErrorKind::Other, "Encryption is not initialized"))
+}
+
+/// Whether encryption at rest is turned on (the app then starts locked)
+pub fn is_enabled() -> bool {
+    APP_DATA_DIR
+        .get()
+        .map(|dir| dir.join(keys::CONFIG_FILE).exists())
+        .unwrap_or(false)
+}
+
+fn file_cipher() -> Option<Arc<FileCipher>> {
+    FILE_CIPHER.read().ok().and_then(|cipher| cipher.clone())
+}
+
+fn set_file_cipher(cipher: Option<FileCipher>) {
+    if let Ok(mut current) = FILE_CIPHER.write() {
+        *current = cipher.map(Arc::new);
+    }
+}
+
+fn locked_error(path: &Path) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::PermissionDenied,
+        format!("{} is encrypted and the database is locked", path.display()),
+    )
+}
+
+/// Reads a file, decrypting it if it is encrypted
+pub fn read_file(path: &Path) -> io::Result<Vec<u8>> {
+    let data = std::fs::read(path)?;
+    if !files::is_encrypted(&data) {
+        return Ok(data);
+    }
+    file_cipher()
+        .ok_or_else(|| locked_error(path))?
+        .decrypt(&data)
+}
+
+pub fn read_to_string(path: &Path) -> io::Result<String> {
+    String::from_utf8(read_file(path)?)
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+}
+
+/// Streams the plaintext of `path` (decrypted if needed) into `writer`
+pub fn copy_decrypted(path: &Path, writer: &mut impl io::Write) -> io::Result<()> {
+    let mut file = io::BufReader::new(File::open(path)?);
+    if !files::file_is_encrypted(path) {
+        io::copy(&mut file, writer)?;
+        return Ok(());
+    }
+    file_cipher()
+        .ok_or_else(|| locked_error(path))?
+        .decrypt_stream(&mut file, writer)
+}
+
+/// Encrypts freshly written file contents when encryption is unlocked; plaintext otherwise
+pub fn seal_bytes(plaintext: Vec<u8>) -> io::Result<Vec<u8>> {
+    match file_cipher() {
+        Some(cipher) => cipher.encrypt(&plaintext),
+        None => Ok(plaintext),
+    }
+}
+
+/// Rewrites `path` through `transform` into a temp file next to it, then renames it over
+fn rewrite_file(
+    path: &Path,
+    transform: impl FnOnce(&mut io::BufReader<File>, &mut io::BufWriter<File>) -> io::Result<()>,
+) -> io::Result<()> {
+    let file_name = path
+        .file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_default();
+    let temp_path = path.with_file_name(format!(".{}.crypt-tmp", file_name));
+    let result = (|| {
+        let mut reader = io::BufReader::new(File::open(path)?);
+        let mut writer = io::BufWriter::new(File::create(&temp_path)?);
+        transform(&mut reader, &mut writer)?;
+        writer.into_inner().map_err(|e| e.into_error())?.sync_all()?;
+        std::fs::rename(&temp_path, path)
+    })();
+    if result.is_err() {
+        let _ = std::fs::remove_file(&temp_path);
+    }
+    result
+}
+
+/// Encrypts `path` in place. Returns false if it was already encrypted.
+fn seal_file(cipher: &FileCipher, path: &Path) -> io::Result<bool> {
+    if files::file_is_encrypted(path) {
+        return Ok(false);
+    }
+    rewrite_file(path, |reader, writer| cipher.encrypt_stream(reader, writer))?;
+    Ok(true)
+}
+
+/// Decrypts `path` in place. Returns false if it was not encrypted.
+fn unseal_file(cipher: &FileCipher, path: &Path) -> io::Result<bool> {
+    if !files::file_is_encrypted(path) {
+        return Ok(false);
+    }
+    rewrite_file(path, |reader, writer| cipher.decrypt_stream(reader, writer))?;
+    Ok(true)
+}
+
+/// Files of a meeting folder that hold meeting content
+fn sensitive_files(folder: &Path) -> Vec<PathBuf> {
+    vec![
+        crate::audio::recording_saver::meeting_audio_path(folder),
+        crate::audio::recording_saver::meeting_system_audio_path(folder),
+        folder.join("transcripts.json"),
+    ]
+    .into_iter()
+    .filter(|path| path.is_file())
+    .collect()
+}
+
+fn seal_folder_blocking(cipher: &FileCipher, folder: &Path) -> usize {
+    let mut sealed = 0;
+    for path in sensitive_files(folder) {
+        match seal_file(cipher, &path) {
+            Ok(true) => sealed += 1,
+            Ok(false) => {}
+            // Left for the sweep after the next unlock
+            Err(e) => warn!("Failed to encrypt {}: {}", path.display(), e),
+        }
+    }
+    sealed
+}
+
+/// Encrypts the recording and transcripts.json of a saved meeting folder. No-op unless
+/// encryption is enabled and unlocked.
+pub async fn seal_meeting_folder(folder: &Path) {
+    let Some(cipher) = file_cipher() else {
+        return;
+    };
+    let folder = folder.to_path_buf();
+    let result = tokio::task::spawn_blocking(move || seal_folder_blocking(&cipher, &folder)).await;
+    if let Err(e) = result {
+        warn!("Encryption task failed: {}", e);
+    }
+}
+
+/// Deletes a meeting folder. With encryption enabled, the recording and transcripts are
+/// overwritten before they are removed.
+pub fn remove_meeting_folder(folder: &Path) -> io::Result<()> {
+    if is_enabled() {
+        for path in sensitive_files(folder) {
+            commands::overwrite_and_remove(&path)?;
+        }
+    }
+    std::fs::remove_dir_all(folder)
+}
+
+/// Meeting folders (those with a metadata.json) directly under `recordings_dir`
+fn meeting_folders(recordings_dir: &Path) -> Vec<PathBuf> {
+    std::fs::read_dir(recordings_dir)
+        .map(|entries| {
+            entries
+                .flatten()
+                .map(|entry| entry.path())
+                .filter(|path| path.join("metadata.json").is_file())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Encrypts every meeting folder under `recordings_dir`, skipping `active` (the folder of a
+/// running recording). Returns the number of files encrypted.
+fn seal_recordings_blocking(cipher: &FileCipher, recordings_dir: &Path, active: Option<&Path>) -> usize {
+    let sealed: usize = meeting_folders(recordings_dir)
+        .iter()
+        .filter(|folder| Some(folder.as_path()) != active)
+        .map(|folder| seal_folder_blocking(cipher, folder))
+        .sum();
+    if sealed > 0 {
+        info!("🔐 Encrypted {} recording files in {}", sealed, recordings_dir.display());
+    }
+    sealed
+}
+
+/// Decrypts every meeting folder under `recordings_dir`, stopping at the first failure
+fn unseal_recordings_blocking(cipher: &FileCipher, recordings_dir: &Path) -> Result<usize, String> {
+    let mut unsealed = 0;
+    for folder in meeting_folders(recordings_dir) {
+        for path in sensitive_files(&folder) {
+            if unseal_file(cipher, &path)
+                .map_err(|e| format!("Failed to decrypt {}: {}", path.display(), e))?
+            {
+                unsealed += 1;
+            }
+        }
+    }
+    Ok(unsealed)
+}
+
+/// A path holding the plaintext of a file. Encrypted files are decrypted into a private
+/// cache under the app data directory; the copy is deleted when this is dropped.
+pub struct ReadablePath {
+    path: PathBuf,
+    temporary: bool,
+}
+
+impl ReadablePath {
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+impl Drop for ReadablePath {
+    fn drop(&mut self) {
+        if self.temporary {
+            let _ = std::fs::remove_file(&self.path);
+        }
+    }
+}
+
+/// Plaintext view of `path` for code that can only work from a file path
+pub fn readable_path(path: &Path) -> io::Result<ReadablePath> {
+    if !files::file_is_encrypted(path) {
+        return Ok(ReadablePath {
+            path: path.to_path_buf(),
+            temporary: false,
+        });
+    }
+
+    let cipher = file_cipher().ok_or_else(|| locked_error(path))?;
+    let cache_dir = app_data_dir()?.join(DECRYPTED_CACHE_DIR);
+    std::fs::create_dir_all(&cache_dir)?;
+    // Keep the extension: decoders use it as a format hint
+    let file_name = path
+        .file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_default();
+    let readable = ReadablePath {
+        path: cache_dir.join(format!("{}-{}", uuid::Uuid::new_v4(), file_name)),
+        temporary: true,
+    };
+
+    let mut reader = io::BufReader::new(File::open(path)?);
+    let mut writer = io::BufWriter::new(File::create(&readable.path)?);
+    cipher.decrypt_stream(&mut reader, &mut writer)?;
+    Ok(readable)
+}
+
+/// Removes all decrypted copies
+pub fn clear_decrypted_cache() {
+    if let Some(dir) = APP_DATA_DIR.get().map(|dir| dir.join(DECRYPTED_CACHE_DIR)) {
+        if dir.exists() {
+            if let Err(e) = std::fs::remove_dir_all(&dir) {
+                warn!("Failed to clear decrypted cache {}: {}", dir.display(), e);
+            }
+        }
+    }
+}
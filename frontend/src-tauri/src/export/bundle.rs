@@ -0,0 +1,110 @@
+// export/bundle.rs
+//
+// Meeting bundle: a zip archive with everything needed to restore a meeting on another
+// machine (metadata, transcript segments, summary and the recording).
+
+use crate::audio::recording_saver::meeting_audio_path;
+use crate::export::document::MeetingExport;
+use serde::{Deserialize, Serialize};
+use std::io::{Seek, Write};
+use zip::write::SimpleFileOptions;
+use zip::{CompressionMethod, ZipWriter};
+
+pub const BUNDLE_FORMAT: &str = "meetily-meeting-bundle";
+pub const BUNDLE_FORMAT_VERSION: u32 = 1;
+
+pub const METADATA_FILE: &str = "metadata.json";
+pub const TRANSCRIPTS_FILE: &str = "transcripts.json";
+pub const SUMMARY_FILE: &str = "summary.json";
+pub const SUMMARY_MARKDOWN_FILE: &str = "summary.md";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BundleMeeting {
+    pub id: String,
+    pub title: String,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+/// `metadata.json` of a bundle
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BundleMetadata {
+    pub format: String,
+    pub format_version: u32,
+    pub exported_at: String,
+    pub app_version: String,
+    pub meeting: BundleMeeting,
+    /// Name of the recording inside the archive, if it was included
+    pub audio_file: Option<String>,
+    pub has_summary: bool,
+}
+
+/// Writes the bundle for `export`. The recording is streamed from disk, so large
+/// audio files are not loaded into memory.
+pub fn write_bundle<W: Write + Seek>(writer: W, export: &MeetingExport) -> Result<(), String> {
+    let mut zip = ZipWriter::new(writer);
+    let options = SimpleFileOptions::default().compression_method(CompressionMethod::Deflated);
+    // Audio is already compressed
+    let stored = SimpleFileOptions::default()
+        .compression_method(CompressionMethod::Stored)
+        .large_file(true);
+
+    let audio_path = export
+        .folder_path
+        .as_deref()
+        .map(meeting_audio_path)
+        .filter(|path| path.is_file());
+    let audio_file = audio_path
+        .as_ref()
+        .and_then(|path| path.file_name())
+        .map(|name| name.to_string_lossy().to_string());
+
+    let details = &export.details;
+    let metadata = BundleMetadata {
+        format: BUNDLE_FORMAT.to_string(),
+        format_version: BUNDLE_FORMAT_VERSION,
+        exported_at: chrono::Utc::now().to_rfc3339(),
+        app_version: env!("CARGO_PKG_VERSION").to_string(),
+        meeting: BundleMeeting {
+            id: details.id.clone(),
+            title: details.title.clone(),
+            created_at: details.created_at.clone(),
+            updated_at: details.updated_at.clone(),
+        },
+        audio_file: audio_file.clone(),
+        has_summary: export.summary.is_some(),
+    };
+
+    let mut write_json = |name: &str, bytes: Vec<u8>| -> Result<(), String> {
+        zip.start_file(name, options).map_err(|e| e.to_string())?;
+        zip.write_all(&bytes).map_err(|e| e.to_string())
+    };
+    write_json(
+        METADATA_FILE,
+        serde_json::to_vec_pretty(&metadata).map_err(|e| e.to_string())?,
+    )?;
+    write_json(
+        TRANSCRIPTS_FILE,
+        serde_json::to_vec_pretty(&details.transcripts).map_err(|e| e.to_string())?,
+    )?;
+    if let Some(summary) = &export.summary {
+        write_json(
+            SUMMARY_FILE,
+            serde_json::to_vec_pretty(summary).map_err(|e| e.to_string())?,
+        )?;
+    }
+    if let Some(markdown) = &export.summary_markdown {
+        write_json(SUMMARY_MARKDOWN_FILE, markdown.clone().into_bytes())?;
+    }
+
+    if let (Some(path), Some(name)) = (audio_path, audio_file) {
+        zip.start_file(name.as_str(), stored)
+            .map_err(|e| e.to_string())?;
+        // Bundles are always plaintext, also when the recording is encrypted at rest
+        crate::encryption::copy_decrypted(&path, &mut zip)
+            .map_err(|e| format!("Failed to add recording {}: {}", path.display(), e))?;
+    }
+
+    zip.finish().map_err(|e| e.to_string())?;
+    Ok(())
+}
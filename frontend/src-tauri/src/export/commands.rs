@@ -0,0 +1,524 @@
+use crate::audio::recording_preferences::load_recording_preferences;
+use crate::database::repositories::meeting::{MeetingsRepository, NewMeeting};
+use crate::database::repositories::summary_version::{
+    NewSummaryVersion, SummaryVersionsRepository,
+};
+use crate::export::bundle::write_bundle;
+use crate::export::document::{document_blocks, unique_path, MeetingExport};
+use crate::export::docx::write_docx;
+use crate::export::import::{
+    materialize_folder, read_import_source, AudioSource, DuplicatePolicy, ImportedMeeting,
+};
+use crate::export::markdown::render_markdown;
+use crate::export::pdf::render_pdf;
+use crate::export::subtitles::{build_cues, render_srt, render_vtt};
+use crate::state::AppState;
+use log::{error as log_error, info as log_info, warn as log_warn};
+use serde::{Deserialize, Serialize};
+use sqlx::SqlitePool;
+use std::io::{BufWriter, Write};
+use std::path::{Path, PathBuf};
+use tauri::{AppHandle, Runtime};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ExportFormat {
+    Markdown,
+    Docx,
+    Pdf,
+    Srt,
+    Vtt,
+    /// Zip archive with metadata, transcript, summary and audio
+    Bundle,
+}
+
+impl ExportFormat {
+    pub fn extension(&self) -> &'static str {
+        match self {
+            ExportFormat::Markdown => "md",
+            ExportFormat::Docx => "docx",
+            ExportFormat::Pdf => "pdf",
+            ExportFormat::Srt => "srt",
+            ExportFormat::Vtt => "vtt",
+            ExportFormat::Bundle => "zip",
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct ExportedFile {
+    pub meeting_id: String,
+    pub path: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ExportFailure {
+    pub meeting_id: String,
+    pub title: String,
+    pub error: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct BatchExportResult {
+    pub exported: Vec<ExportedFile>,
+    pub failed: Vec<ExportFailure>,
+}
+
+fn default_export_dir() -> Result<PathBuf, String> {
+    dirs::download_dir()
+        .or_else(dirs::document_dir)
+        .or_else(dirs::home_dir)
+        .ok_or_else(|| "Could not determine an export directory".to_string())
+}
+
+/// Writes `export` in `format` to `path`
+fn write_export(export: &MeetingExport, format: ExportFormat, path: &Path) -> Result<(), String> {
+    // Check before creating the file so a failed subtitle export leaves nothing behind
+    let cues = match format {
+        ExportFormat::Srt | ExportFormat::Vtt => {
+            let cues = build_cues(&export.details.transcripts);
+            if cues.is_empty() {
+                return Err("This meeting has no timed transcript segments".to_string());
+            }
+            cues
+        }
+        _ => Vec::new(),
+    };
+
+    let file = std::fs::File::create(path)
+        .map_err(|e| format!("Failed to create {}: {}", path.display(), e))?;
+    let mut writer = BufWriter::new(file);
+
+    let result = match format {
+        ExportFormat::Markdown => writer
+            .write_all(render_markdown(export).as_bytes())
+            .map_err(|e| e.to_string()),
+        ExportFormat::Docx => write_docx(&mut writer, &document_blocks(export)),
+        ExportFormat::Pdf => render_pdf(&document_blocks(export))
+            .and_then(|pdf| writer.write_all(&pdf).map_err(|e| e.to_string())),
+        ExportFormat::Srt => writer
+            .write_all(render_srt(&cues).as_bytes())
+            .map_err(|e| e.to_string()),
+        ExportFormat::Vtt => writer
+            .write_all(render_vtt(&cues).as_bytes())
+            .map_err(|e| e.to_string()),
+        ExportFormat::Bundle => write_bundle(&mut writer, export),
+    }
+    .and_then(|_| writer.flush().map_err(|e| e.to_string()));
+
+    if result.is_err() {
+        drop(writer);
+        let _ = std::fs::remove_file(path);
+    }
+    result
+}
+
+/// Loads a meeting and writes it into `dir` (or to `file` when given) on a blocking thread
+async fn export_meeting(
+    pool: &SqlitePool,
+    meeting_id: &str,
+    format: ExportFormat,
+    dir: &Path,
+    file: Option<PathBuf>,
+) -> Result<ExportedFile, String> {
+    let export = MeetingExport::load(pool, meeting_id).await?;
+    let path = file.unwrap_or_else(|| {
+        unique_path(&dir.join(format!("{}.{}", export.file_stem(), format.extension())))
+    });
+
+    let meeting_id = meeting_id.to_string();
+    tokio::task::spawn_blocking(move || {
+        write_export(&export, format, &path)?;
+        Ok(ExportedFile {
+            meeting_id,
+            path: path.to_string_lossy().to_string(),
+        })
+    })
+    .await
+    .map_err(|e| format!("Export task failed: {}", e))?
+}
+
+/// Exports one meeting. `destination` may be a file path or a directory; by default the
+/// file goes to the Downloads folder.
+#[tauri::command]
+pub async fn api_export_meeting<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    meeting_id: String,
+    format: ExportFormat,
+    destination: Option<String>,
+) -> Result<ExportedFile, String> {
+    log_info!("api_export_meeting called for {} as {:?}", meeting_id, format);
+    let pool = state.db_manager.pool();
+
+    let (dir, file) = match destination.map(PathBuf::from) {
+        Some(path) if path.is_dir() => (path, None),
+        Some(path) => (
+            path.parent().map(Path::to_path_buf).unwrap_or_default(),
+            Some(path),
+        ),
+        None => (default_export_dir()?, None),
+    };
+
+    match export_meeting(pool, &meeting_id, format, &dir, file).await {
+        Ok(exported) => {
+            log_info!("📤 Exported meeting {} to {}", meeting_id, exported.path);
+            Ok(exported)
+        }
+        Err(e) => {
+            log_error!("❌ Failed to export meeting {}: {}", meeting_id, e);
+            Err(e)
+        }
+    }
+}
+
+/// Exports every meeting created between `date_from` and `date_to` (inclusive
+/// `YYYY-MM-DD` days) into one directory. A failing meeting does not stop the batch.
+#[tauri::command]
+pub async fn api_export_meetings<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    date_from: String,
+    date_to: String,
+    format: ExportFormat,
+    destination_dir: Option<String>,
+) -> Result<BatchExportResult, String> {
+    log_info!(
+        "api_export_meetings called for {}..{} as {:?}",
+        date_from,
+        date_to,
+        format
+    );
+    let pool = state.db_manager.pool();
+
+    let dir = match destination_dir {
+        Some(dir) => PathBuf::from(dir),
+        None => default_export_dir()?,
+    };
+    std::fs::create_dir_all(&dir)
+        .map_err(|e| format!("Failed to create {}: {}", dir.display(), e))?;
+
+    let meetings = MeetingsRepository::get_meetings_in_range(pool, &date_from, &date_to)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    let mut result = BatchExportResult {
+        exported: Vec::new(),
+        failed: Vec::new(),
+    };
+    for meeting in meetings {
+        match export_meeting(pool, &meeting.id, format, &dir, None).await {
+            Ok(exported) => result.exported.push(exported),
+            Err(error) => {
+                log_error!("❌ Failed to export meeting {}: {}", meeting.id, error);
+                result.failed.push(ExportFailure {
+                    meeting_id: meeting.id,
+                    title: meeting.title,
+                    error,
+                });
+            }
+        }
+    }
+
+    log_info!(
+        "📤 Batch export finished: {} exported, {} failed",
+        result.exported.len(),
+        result.failed.len()
+    );
+    Ok(result)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ImportStatus {
+    Imported,
+    Overwritten,
+    Skipped,
+}
+
+#[derive(Debug, Serialize)]
+pub struct MeetingImportResult {
+    pub status: ImportStatus,
+    pub meeting_id: String,
+    pub title: String,
+    pub folder_path: Option<String>,
+    pub segment_count: usize,
+}
+
+/// What an import would bring in, so the user can pick a duplicate policy
+#[derive(Debug, Serialize)]
+pub struct MeetingImportPreview {
+    pub meeting_id: Option<String>,
+    pub title: String,
+    pub created_at: String,
+    pub segment_count: usize,
+    pub has_summary: bool,
+    pub has_audio: bool,
+    /// Meeting in the database with the same id (or the same recording folder)
+    pub existing_meeting_id: Option<String>,
+}
+
+/// Folders inside the recordings directory are registered where they are instead of copied
+fn in_place_folder(meeting: &ImportedMeeting, recordings_dir: &Path) -> Option<String> {
+    let recordings_dir = recordings_dir
+        .canonicalize()
+        .unwrap_or_else(|_| recordings_dir.to_path_buf());
+    let folder = meeting.source_folder.as_ref()?;
+    folder
+        .canonicalize()
+        .unwrap_or_else(|_| folder.clone())
+        .starts_with(&recordings_dir)
+        .then(|| folder.to_string_lossy().to_string())
+}
+
+async fn recordings_folder<R: Runtime>(app: &AppHandle<R>) -> Result<PathBuf, String> {
+    load_recording_preferences(app)
+        .await
+        .map(|preferences| preferences.save_folder)
+        .map_err(|e| format!("Failed to load recording preferences: {}", e))
+}
+
+async fn read_source(path: &Path) -> Result<ImportedMeeting, String> {
+    let path = path.to_path_buf();
+    tokio::task::spawn_blocking(move || read_import_source(&path))
+        .await
+        .map_err(|e| format!("Import task failed: {}", e))?
+}
+
+/// Deletes the folder of a meeting replaced by an import, unless the import reuses it
+async fn remove_replaced_folder(old_folder: PathBuf, new_folder: Option<&str>) {
+    let same_folder = new_folder.is_some_and(|new_folder| {
+        let normalized = |path: &Path| path.canonicalize().unwrap_or_else(|_| path.to_path_buf());
+        normalized(Path::new(new_folder)) == normalized(&old_folder)
+    });
+    if same_folder || !old_folder.is_dir() {
+        return;
+    }
+
+    let result = tokio::task::spawn_blocking(move || {
+        crate::encryption::remove_meeting_folder(&old_folder)
+            .map(|_| old_folder.clone())
+            .map_err(|e| format!("{}: {}", old_folder.display(), e))
+    })
+    .await;
+    match result {
+        Ok(Ok(folder)) => log_info!("🗑️ Removed replaced meeting folder {}", folder.display()),
+        Ok(Err(e)) => log_warn!("Failed to remove replaced meeting folder {}", e),
+        Err(e) => log_warn!("Folder removal task failed: {}", e),
+    }
+}
+
+/// Stores an imported meeting according to `policy`. Folders outside `recordings_dir` (and
+/// bundles) are copied into a new meeting folder there. Used by the import command and by
+/// rescans of the recordings folder.
+pub async fn import_meeting(
+    pool: &SqlitePool,
+    imported: ImportedMeeting,
+    source_path: &Path,
+    recordings_dir: &Path,
+    policy: DuplicatePolicy,
+) -> Result<MeetingImportResult, String> {
+    let in_place = in_place_folder(&imported, recordings_dir);
+    let existing = MeetingsRepository::find_existing(
+        pool,
+        imported.meeting_id.as_deref(),
+        in_place.as_deref(),
+    )
+    .await
+    .map_err(|e| e.to_string())?;
+
+    let duplicating = existing.is_some() && policy == DuplicatePolicy::Duplicate;
+    let (meeting_id, status) = match (existing, policy) {
+        (Some(existing_id), DuplicatePolicy::Skip) => {
+            log_info!("Skipping import of '{}': meeting {} exists", imported.title, existing_id);
+            return Ok(MeetingImportResult {
+                status: ImportStatus::Skipped,
+                meeting_id: existing_id,
+                title: imported.title,
+                folder_path: in_place,
+                segment_count: 0,
+            });
+        }
+        (Some(existing_id), DuplicatePolicy::Overwrite) => (existing_id, ImportStatus::Overwritten),
+        (Some(_), DuplicatePolicy::Duplicate) => (
+            format!("meeting-{}", uuid::Uuid::new_v4()),
+            ImportStatus::Imported,
+        ),
+        (None, _) => (
+            imported
+                .meeting_id
+                .clone()
+                .unwrap_or_else(|| format!("meeting-{}", uuid::Uuid::new_v4())),
+            ImportStatus::Imported,
+        ),
+    };
+
+    // The folder of an overwritten meeting is removed once the import is saved
+    let replaced_folder = if status == ImportStatus::Overwritten {
+        MeetingsRepository::get_meeting_folder_path(pool, &meeting_id)
+            .await
+            .map_err(|e| e.to_string())?
+            .filter(|folder| !folder.is_empty())
+    } else {
+        None
+    };
+
+    // A duplicate gets its own copy of the folder so deleting one meeting keeps the other's audio
+    let (imported, folder_path, created_folder) = if in_place.is_some() && !duplicating {
+        (imported, in_place, false)
+    } else {
+        let bundle_path = source_path.to_path_buf();
+        let recordings_dir = recordings_dir.to_path_buf();
+        let id = meeting_id.clone();
+        tokio::task::spawn_blocking(move || {
+            let folder = materialize_folder(&imported, &id, Some(&bundle_path), &recordings_dir)?;
+            Ok::<_, String>((imported, Some(folder.to_string_lossy().to_string()), true))
+        })
+        .await
+        .map_err(|e| format!("Import task failed: {}", e))??
+    };
+
+    let inserted = MeetingsRepository::insert_meeting(
+        pool,
+        NewMeeting {
+            id: &meeting_id,
+            title: &imported.title,
+            created_at: imported.created_at,
+            updated_at: imported.updated_at,
+            folder_path: folder_path.as_deref(),
+            transcripts: &imported.transcripts,
+            summary: imported.summary.as_ref(),
+        },
+        status == ImportStatus::Overwritten,
+    )
+    .await;
+    if let Err(e) = inserted {
+        if let (true, Some(folder)) = (created_folder, &folder_path) {
+            let _ = std::fs::remove_dir_all(folder);
+        }
+        return Err(format!("Failed to save imported meeting: {}", e));
+    }
+
+    if let Some(summary) = &imported.summary {
+        if let Err(e) = SummaryVersionsRepository::add_version(
+            pool,
+            NewSummaryVersion {
+                meeting_id: &meeting_id,
+                source: "imported",
+                template_id: None,
+                provider: None,
+                model: None,
+                custom_prompt: None,
+                restored_from: None,
+                result: summary,
+            },
+        )
+        .await
+        {
+            log_warn!("Failed to record imported summary version for {}: {}", meeting_id, e);
+        }
+    }
+
+    if let Some(folder) = &folder_path {
+        crate::encryption::seal_meeting_folder(Path::new(folder)).await;
+    }
+    if let Some(old_folder) = replaced_folder {
+        remove_replaced_folder(PathBuf::from(old_folder), folder_path.as_deref()).await;
+    }
+
+    Ok(MeetingImportResult {
+        status,
+        meeting_id,
+        title: imported.title,
+        folder_path,
+        segment_count: imported.transcripts.len(),
+    })
+}
+
+/// Reads a bundle (.zip) or a meeting folder without importing it
+#[tauri::command]
+pub async fn api_preview_meeting_import<R: Runtime>(
+    app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    path: String,
+) -> Result<MeetingImportPreview, String> {
+    let recordings_dir = recordings_folder(&app).await?;
+    let imported = read_source(Path::new(&path)).await?;
+    let existing_meeting_id = MeetingsRepository::find_existing(
+        state.db_manager.pool(),
+        imported.meeting_id.as_deref(),
+        in_place_folder(&imported, &recordings_dir).as_deref(),
+    )
+    .await
+    .map_err(|e| e.to_string())?;
+
+    Ok(MeetingImportPreview {
+        meeting_id: imported.meeting_id,
+        title: imported.title,
+        created_at: imported.created_at.to_rfc3339(),
+        segment_count: imported.transcripts.len(),
+        has_summary: imported.summary.is_some(),
+        has_audio: imported.audio != AudioSource::None,
+        existing_meeting_id,
+    })
+}
+
+/// Imports a meeting bundle (.zip) or a meeting folder. `on_duplicate` decides what happens
+/// when the meeting already exists.
+#[tauri::command]
+pub async fn api_import_meeting<R: Runtime>(
+    app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    path: String,
+    on_duplicate: DuplicatePolicy,
+) -> Result<MeetingImportResult, String> {
+    log_info!("api_import_meeting called for {} ({:?})", path, on_duplicate);
+    let source_path = PathBuf::from(&path);
+    let recordings_dir = recordings_folder(&app).await?;
+
+    let result = match read_source(&source_path).await {
+        Ok(imported) => {
+            import_meeting(
+                state.db_manager.pool(),
+                imported,
+                &source_path,
+                &recordings_dir,
+                on_duplicate,
+            )
+            .await
+        }
+        Err(e) => Err(e),
+    };
+    match result {
+        Ok(result) => {
+            log_info!(
+                "📥 Import of {} finished: {:?} meeting {} ({} segments)",
+                path,
+                result.status,
+                result.meeting_id,
+                result.segment_count
+            );
+            Ok(result)
+        }
+        Err(e) => {
+            log_error!("❌ Failed to import {}: {}", path, e);
+            Err(e)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_export_format_serde() {
+        let format: ExportFormat = serde_json::from_str("\"markdown\"").unwrap();
+        assert_eq!(format, ExportFormat::Markdown);
+        assert_eq!(format.extension(), "md");
+        assert_eq!(
+            serde_json::from_str::<ExportFormat>("\"bundle\"").unwrap().extension(),
+            "zip"
+        );
+    }
+}
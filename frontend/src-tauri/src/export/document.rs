@@ -0,0 +1,262 @@
+// export/document.rs
+//
+// Everything an export needs about one meeting, plus a small block model
+// (headings, paragraphs, bullets with bold spans) shared by the DOCX and PDF writers.
+
+use crate::api::{MeetingDetails, MeetingTranscript};
+use crate::database::repositories::{
+    meeting::MeetingsRepository, summary::SummaryProcessesRepository,
+};
+use crate::summary::versions::summary_markdown;
+use serde_json::Value;
+use sqlx::SqlitePool;
+use std::path::{Path, PathBuf};
+
+/// A meeting with its summary, ready to be rendered
+pub struct MeetingExport {
+    pub details: MeetingDetails,
+    /// Stored summary result (`{"markdown": ..., "summary_json": ...}`)
+    pub summary: Option<Value>,
+    pub summary_markdown: Option<String>,
+    /// Recording folder (holds the audio), if the meeting has one
+    pub folder_path: Option<PathBuf>,
+}
+
+impl MeetingExport {
+    pub async fn load(pool: &SqlitePool, meeting_id: &str) -> Result<Self, String> {
+        let details = MeetingsRepository::get_meeting(pool, meeting_id)
+            .await
+            .map_err(|e| e.to_string())?
+            .ok_or_else(|| format!("Meeting {} not found", meeting_id))?;
+
+        let result = SummaryProcessesRepository::get_summary_data(pool, meeting_id)
+            .await
+            .map_err(|e| e.to_string())?
+            .and_then(|process| process.result);
+        let summary = result
+            .as_deref()
+            .and_then(|r| serde_json::from_str::<Value>(r).ok());
+        let summary_markdown = result
+            .as_deref()
+            .map(summary_markdown)
+            .filter(|m| !m.trim().is_empty());
+
+        let folder_path = MeetingsRepository::get_meeting_folder_path(pool, meeting_id)
+            .await
+            .map_err(|e| e.to_string())?
+            .map(PathBuf::from);
+
+        Ok(Self {
+            details,
+            summary,
+            summary_markdown,
+            folder_path,
+        })
+    }
+
+    /// Date part of `created_at` (YYYY-MM-DD)
+    pub fn date(&self) -> &str {
+        self.details.created_at.get(..10).unwrap_or(&self.details.created_at)
+    }
+
+    /// Distinct speaker labels in order of first appearance
+    pub fn speakers(&self) -> Vec<&str> {
+        let mut speakers: Vec<&str> = Vec::new();
+        for label in self.details.transcripts.iter().filter_map(speaker_label) {
+            if !speakers.contains(&label) {
+                speakers.push(label);
+            }
+        }
+        speakers
+    }
+
+    /// Recording length from the last segment end, in seconds
+    pub fn duration_seconds(&self) -> Option<f64> {
+        self.details
+            .transcripts
+            .iter()
+            .filter_map(|t| t.audio_end_time.or(t.audio_start_time))
+            .fold(None, |max: Option<f64>, end| Some(max.map_or(end, |m| m.max(end))))
+    }
+
+    /// `<date> <title>` made safe for file names
+    pub fn file_stem(&self) -> String {
+        let title: String = self
+            .details
+            .title
+            .chars()
+            .map(|c| match c {
+                '/' | '\\' | ':' | '*' | '?' | '"' | '<' | '>' | '|' => '-',
+                c if c.is_control() => ' ',
+                c => c,
+            })
+            .collect();
+        let title = title.trim().trim_matches('.');
+        let title: String = title.chars().take(80).collect();
+        if title.is_empty() {
+            format!("{} meeting", self.date())
+        } else {
+            format!("{} {}", self.date(), title)
+        }
+    }
+}
+
+/// Diarized speaker name, falling back to the channel tag ("You" / "Remote")
+pub fn speaker_label(transcript: &MeetingTranscript) -> Option<&str> {
+    transcript
+        .speaker
+        .as_deref()
+        .or(transcript.source.as_deref())
+        .filter(|s| !s.is_empty())
+}
+
+/// `HH:MM:SS` for a number of seconds
+pub fn format_clock(seconds: f64) -> String {
+    let total = seconds.max(0.0) as u64;
+    format!("{:02}:{:02}:{:02}", total / 3600, (total / 60) % 60, total % 60)
+}
+
+/// `path`, or `path` with " (2)", " (3)", ... before the extension if it already exists
+pub fn unique_path(path: &Path) -> PathBuf {
+    if !path.exists() {
+        return path.to_path_buf();
+    }
+    let stem = path.file_stem().and_then(|s| s.to_str()).unwrap_or("export");
+    let extension = path.extension().and_then(|e| e.to_str());
+    (2..)
+        .map(|n| {
+            let name = match extension {
+                Some(ext) => format!("{} ({}).{}", stem, n, ext),
+                None => format!("{} ({})", stem, n),
+            };
+            path.with_file_name(name)
+        })
+        .find(|candidate| !candidate.exists())
+        .expect("unbounded range")
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Span {
+    pub text: String,
+    pub bold: bool,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Block {
+    Title(String),
+    /// Level 1..=3
+    Heading(u8, String),
+    Paragraph(Vec<Span>),
+    Bullet(Vec<Span>),
+}
+
+/// Splits `**bold**` markers into spans; other inline markdown is kept as text
+pub fn inline_spans(text: &str) -> Vec<Span> {
+    text.split("**")
+        .enumerate()
+        .filter(|(_, part)| !part.is_empty())
+        .map(|(i, part)| Span {
+            text: part.to_string(),
+            bold: i % 2 == 1,
+        })
+        .collect()
+}
+
+/// Block structure of a markdown text (headings, bullets and paragraphs)
+pub fn markdown_blocks(markdown: &str, heading_offset: u8) -> Vec<Block> {
+    markdown
+        .lines()
+        .map(str::trim_end)
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| {
+            let trimmed = line.trim_start();
+            let hashes = trimmed.chars().take_while(|&c| c == '#').count();
+            if (1..=6).contains(&hashes) && trimmed[hashes..].starts_with(' ') {
+                let level = (hashes as u8 + heading_offset).min(3);
+                return Block::Heading(level, trimmed[hashes..].trim().replace("**", ""));
+            }
+            for marker in ["- ", "* ", "+ "] {
+                if let Some(rest) = trimmed.strip_prefix(marker) {
+                    let rest = rest
+                        .strip_prefix("[ ] ")
+                        .or_else(|| rest.strip_prefix("[x] "))
+                        .unwrap_or(rest);
+                    return Block::Bullet(inline_spans(rest));
+                }
+            }
+            Block::Paragraph(inline_spans(trimmed))
+        })
+        .collect()
+}
+
+/// Document layout used by DOCX and PDF: title, date line, summary, transcript
+pub fn document_blocks(export: &MeetingExport) -> Vec<Block> {
+    let mut blocks = vec![Block::Title(export.details.title.clone())];
+
+    let mut info = format!("Date: {}", export.date());
+    if let Some(duration) = export.duration_seconds() {
+        info.push_str(&format!("  ·  Duration: {}", format_clock(duration)));
+    }
+    let speakers = export.speakers();
+    if !speakers.is_empty() {
+        info.push_str(&format!("  ·  Speakers: {}", speakers.join(", ")));
+    }
+    blocks.push(Block::Paragraph(vec![Span {
+        text: info,
+        bold: false,
+    }]));
+
+    if let Some(markdown) = &export.summary_markdown {
+        blocks.push(Block::Heading(1, "Summary".to_string()));
+        blocks.extend(markdown_blocks(markdown, 1));
+    }
+
+    if !export.details.transcripts.is_empty() {
+        blocks.push(Block::Heading(1, "Transcript".to_string()));
+        for transcript in &export.details.transcripts {
+            let label = match speaker_label(transcript) {
+                Some(speaker) => format!("[{}] {}: ", transcript.timestamp, speaker),
+                None => format!("[{}] ", transcript.timestamp),
+            };
+            blocks.push(Block::Paragraph(vec![
+                Span {
+                    text: label,
+                    bold: true,
+                },
+                Span {
+                    text: transcript.text.trim().to_string(),
+                    bold: false,
+                },
+            ]));
+        }
+    }
+
+    blocks
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_markdown_blocks() {
+        let blocks = markdown_blocks("# Decisions\n\n- Keep **price** at $10\nPlain text\n### Deep", 1);
+        assert_eq!(blocks[0], Block::Heading(2, "Decisions".to_string()));
+        assert_eq!(
+            blocks[1],
+            Block::Bullet(vec![
+                Span { text: "Keep ".to_string(), bold: false },
+                Span { text: "price".to_string(), bold: true },
+                Span { text: " at $10".to_string(), bold: false },
+            ])
+        );
+        assert!(matches!(blocks[2], Block::Paragraph(_)));
+        assert_eq!(blocks[3], Block::Heading(3, "Deep".to_string()));
+    }
+
+    #[test]
+    fn test_format_clock() {
+        assert_eq!(format_clock(0.0), "00:00:00");
+        assert_eq!(format_clock(3725.9), "01:02:05");
+    }
+}
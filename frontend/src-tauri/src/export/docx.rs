@@ -0,0 +1,157 @@
+// export/docx.rs
+//
+// Minimal WordprocessingML writer: a document part plus a style sheet with title and
+// heading styles, so headings show up in Word's navigation pane.
+
+use crate::export::document::{Block, Span};
+use std::io::{Seek, Write};
+use zip::write::SimpleFileOptions;
+use zip::{CompressionMethod, ZipWriter};
+
+const CONTENT_TYPES: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<Types xmlns="http://schemas.openxmlformats.org/package/2006/content-types"><Default Extension="rels" ContentType="application/vnd.openxmlformats-package.relationships+xml"/><Default Extension="xml" ContentType="application/xml"/><Override PartName="/word/document.xml" ContentType="application/vnd.openxmlformats-officedocument.wordprocessingml.document.main+xml"/><Override PartName="/word/styles.xml" ContentType="application/vnd.openxmlformats-officedocument.wordprocessingml.styles+xml"/></Types>"#;
+
+const PACKAGE_RELS: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships"><Relationship Id="rId1" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/officeDocument" Target="word/document.xml"/></Relationships>"#;
+
+const DOCUMENT_RELS: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships"><Relationship Id="rId1" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/styles" Target="styles.xml"/></Relationships>"#;
+
+const STYLES: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<w:styles xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main">
+<w:docDefaults><w:rPrDefault><w:rPr><w:rFonts w:ascii="Calibri" w:hAnsi="Calibri" w:eastAsia="Calibri" w:cs="Calibri"/><w:sz w:val="22"/></w:rPr></w:rPrDefault><w:pPrDefault><w:pPr><w:spacing w:after="120" w:line="276" w:lineRule="auto"/></w:pPr></w:pPrDefault></w:docDefaults>
+<w:style w:type="paragraph" w:default="1" w:styleId="Normal"><w:name w:val="Normal"/></w:style>
+<w:style w:type="paragraph" w:styleId="Title"><w:name w:val="Title"/><w:basedOn w:val="Normal"/><w:next w:val="Normal"/><w:pPr><w:spacing w:after="240"/></w:pPr><w:rPr><w:b/><w:sz w:val="48"/></w:rPr></w:style>
+<w:style w:type="paragraph" w:styleId="Heading1"><w:name w:val="heading 1"/><w:basedOn w:val="Normal"/><w:next w:val="Normal"/><w:pPr><w:keepNext/><w:spacing w:before="360" w:after="120"/><w:outlineLvl w:val="0"/></w:pPr><w:rPr><w:b/><w:sz w:val="32"/></w:rPr></w:style>
+<w:style w:type="paragraph" w:styleId="Heading2"><w:name w:val="heading 2"/><w:basedOn w:val="Normal"/><w:next w:val="Normal"/><w:pPr><w:keepNext/><w:spacing w:before="240" w:after="80"/><w:outlineLvl w:val="1"/></w:pPr><w:rPr><w:b/><w:sz w:val="26"/></w:rPr></w:style>
+<w:style w:type="paragraph" w:styleId="Heading3"><w:name w:val="heading 3"/><w:basedOn w:val="Normal"/><w:next w:val="Normal"/><w:pPr><w:keepNext/><w:spacing w:before="200" w:after="60"/><w:outlineLvl w:val="2"/></w:pPr><w:rPr><w:b/><w:sz w:val="24"/></w:rPr></w:style>
+</w:styles>"#;
+
+fn escape_xml(text: &str) -> String {
+    text.chars()
+        // Control characters other than tab/newline are not allowed in XML 1.0
+        .filter(|&c| !c.is_control() || c == '\t')
+        .fold(String::with_capacity(text.len()), |mut out, c| {
+            match c {
+                '&' => out.push_str("&amp;"),
+                '<' => out.push_str("&lt;"),
+                '>' => out.push_str("&gt;"),
+                '"' => out.push_str("&quot;"),
+                c => out.push(c),
+            }
+            out
+        })
+}
+
+fn run(span: &Span) -> String {
+    let properties = if span.bold { "<w:rPr><w:b/></w:rPr>" } else { "" };
+    format!(
+        r#"<w:r>{}<w:t xml:space="preserve">{}</w:t></w:r>"#,
+        properties,
+        escape_xml(&span.text)
+    )
+}
+
+fn paragraph(style: Option<&str>, indent: bool, runs: String) -> String {
+    let mut properties = String::new();
+    if let Some(style) = style {
+        properties.push_str(&format!(r#"<w:pStyle w:val="{}"/>"#, style));
+    }
+    if indent {
+        properties.push_str(r#"<w:ind w:left="360" w:hanging="240"/>"#);
+    }
+    if properties.is_empty() {
+        format!("<w:p>{}</w:p>", runs)
+    } else {
+        format!("<w:p><w:pPr>{}</w:pPr>{}</w:p>", properties, runs)
+    }
+}
+
+/// `word/document.xml` for the given blocks
+pub fn document_xml(blocks: &[Block]) -> String {
+    let body: String = blocks
+        .iter()
+        .map(|block| match block {
+            Block::Title(text) => paragraph(
+                Some("Title"),
+                false,
+                run(&Span {
+                    text: text.clone(),
+                    bold: false,
+                }),
+            ),
+            Block::Heading(level, text) => paragraph(
+                Some(&format!("Heading{}", (*level).clamp(1, 3))),
+                false,
+                run(&Span {
+                    text: text.clone(),
+                    bold: false,
+                }),
+            ),
+            Block::Paragraph(spans) => paragraph(None, false, spans.iter().map(run).collect()),
+            Block::Bullet(spans) => {
+                let bullet = run(&Span {
+                    text: "•\t".to_string(),
+                    bold: false,
+                });
+                paragraph(None, true, bullet + &spans.iter().map(run).collect::<String>())
+            }
+        })
+        .collect();
+
+    format!(
+        r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<w:document xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main"><w:body>{}<w:sectPr><w:pgSz w:w="11906" w:h="16838"/><w:pgMar w:top="1134" w:right="1134" w:bottom="1134" w:left="1134" w:header="709" w:footer="709" w:gutter="0"/></w:sectPr></w:body></w:document>"#,
+        body
+    )
+}
+
+/// Writes a .docx package for the given blocks
+pub fn write_docx<W: Write + Seek>(writer: W, blocks: &[Block]) -> Result<(), String> {
+    let mut zip = ZipWriter::new(writer);
+    let options = SimpleFileOptions::default().compression_method(CompressionMethod::Deflated);
+
+    let document = document_xml(blocks);
+    let parts: [(&str, &str); 5] = [
+        ("[Content_Types].xml", CONTENT_TYPES),
+        ("_rels/.rels", PACKAGE_RELS),
+        ("word/_rels/document.xml.rels", DOCUMENT_RELS),
+        ("word/styles.xml", STYLES),
+        ("word/document.xml", document.as_str()),
+    ];
+    for (name, content) in parts {
+        zip.start_file(name, options).map_err(|e| e.to_string())?;
+        zip.write_all(content.as_bytes()).map_err(|e| e.to_string())?;
+    }
+
+    zip.finish().map_err(|e| e.to_string())?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_document_xml_escapes_and_styles() {
+        let xml = document_xml(&[
+            Block::Title("Q3 <Planning> & more".to_string()),
+            Block::Heading(2, "Decisions".to_string()),
+            Block::Bullet(vec![Span {
+                text: "Ship it".to_string(),
+                bold: true,
+            }]),
+        ]);
+
+        assert!(xml.contains("Q3 &lt;Planning&gt; &amp; more"));
+        assert!(xml.contains(r#"<w:pStyle w:val="Heading2"/>"#));
+        assert!(xml.contains("<w:rPr><w:b/></w:rPr><w:t xml:space=\"preserve\">Ship it</w:t>"));
+    }
+
+    #[test]
+    fn test_write_docx_produces_zip() {
+        let mut buffer = std::io::Cursor::new(Vec::new());
+        write_docx(&mut buffer, &[Block::Title("Hi".to_string())]).unwrap();
+        assert!(buffer.get_ref().starts_with(b"PK"));
+    }
+}
@@ -0,0 +1,455 @@
+// export/import.rs
+//
+// Import of a meeting bundle (see bundle.rs, zipped or extracted) or of a recording folder
+// written by `RecordingSaver` (metadata.json, transcripts.json and audio), e.g. copied from
+// another machine or left behind in meetily-recordings after its database rows were lost.
+
+use crate::api::TranscriptSegment;
+use crate::audio::audio_processing::create_meeting_folder;
+use crate::audio::recording_saver::{self, meeting_audio_path, DeviceInfo, MeetingMetadata};
+use crate::audio::transcription::batch::display_timestamp;
+use crate::export::bundle::{
+    BundleMetadata, BUNDLE_FORMAT, BUNDLE_FORMAT_VERSION, METADATA_FILE, SUMMARY_FILE,
+    TRANSCRIPTS_FILE,
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::fs::File;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use zip::ZipArchive;
+
+/// What to do when the imported meeting already exists
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DuplicatePolicy {
+    /// Leave the existing meeting alone
+    Skip,
+    /// Replace the existing meeting (and everything derived from it) with the import
+    Overwrite,
+    /// Import as an additional meeting with a new id
+    Duplicate,
+}
+
+/// Where the recording of an import comes from
+#[derive(Debug, Clone, PartialEq)]
+pub enum AudioSource {
+    None,
+    File(PathBuf),
+    /// Entry name inside the bundle archive
+    ZipEntry(String),
+}
+
+/// A meeting read from a bundle or folder, not yet stored
+#[derive(Debug)]
+pub struct ImportedMeeting {
+    /// Original id; folders written during recording don't carry one
+    pub meeting_id: Option<String>,
+    pub title: String,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub transcripts: Vec<TranscriptSegment>,
+    pub summary: Option<Value>,
+    pub audio: AudioSource,
+    /// Folder the meeting was read from (folder imports only)
+    pub source_folder: Option<PathBuf>,
+}
+
+fn parse_time(value: &str) -> Option<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(value)
+        .ok()
+        .map(|dt| dt.with_timezone(&Utc))
+}
+
+/// Only the file name of an archive entry, so a crafted bundle can't write elsewhere
+fn safe_file_name(name: &str) -> Option<String> {
+    Path::new(name)
+        .file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .filter(|n| !n.is_empty() && n != METADATA_FILE && n != TRANSCRIPTS_FILE)
+}
+
+/// Parses bundle contents; `read` returns the text of a file in the bundle, if present
+fn parse_bundle(
+    read: &mut dyn FnMut(&str) -> Result<Option<String>, String>,
+) -> Result<(BundleMetadata, Vec<TranscriptSegment>, Option<Value>), String> {
+    let metadata = read(METADATA_FILE)?.ok_or("Bundle has no metadata.json")?;
+    let metadata: BundleMetadata =
+        serde_json::from_str(&metadata).map_err(|e| format!("Invalid metadata.json: {}", e))?;
+    if metadata.format != BUNDLE_FORMAT {
+        return Err(format!("Unknown bundle format '{}'", metadata.format));
+    }
+    if metadata.format_version > BUNDLE_FORMAT_VERSION {
+        return Err(format!(
+            "Bundle format version {} is newer than this app supports ({})",
+            metadata.format_version, BUNDLE_FORMAT_VERSION
+        ));
+    }
+
+    let transcripts = match read(TRANSCRIPTS_FILE)? {
+        Some(content) => serde_json::from_str(&content)
+            .map_err(|e| format!("Invalid transcripts.json: {}", e))?,
+        None => Vec::new(),
+    };
+    let summary = match read(SUMMARY_FILE)? {
+        Some(content) => Some(
+            serde_json::from_str(&content).map_err(|e| format!("Invalid summary.json: {}", e))?,
+        ),
+        None => None,
+    };
+    Ok((metadata, transcripts, summary))
+}
+
+fn from_bundle_metadata(
+    metadata: BundleMetadata,
+    transcripts: Vec<TranscriptSegment>,
+    summary: Option<Value>,
+    audio: AudioSource,
+    source_folder: Option<PathBuf>,
+) -> ImportedMeeting {
+    let created_at = parse_time(&metadata.meeting.created_at).unwrap_or_else(Utc::now);
+    ImportedMeeting {
+        meeting_id: Some(metadata.meeting.id).filter(|id| !id.is_empty()),
+        title: metadata.meeting.title,
+        created_at,
+        updated_at: parse_time(&metadata.meeting.updated_at).unwrap_or(created_at),
+        transcripts,
+        summary,
+        audio,
+        source_folder,
+    }
+}
+
+/// Reads a zipped meeting bundle
+pub fn read_bundle(path: &Path) -> Result<ImportedMeeting, String> {
+    let file = File::open(path).map_err(|e| format!("Failed to open {}: {}", path.display(), e))?;
+    let mut archive =
+        ZipArchive::new(file).map_err(|e| format!("Not a meeting bundle: {}", e))?;
+
+    let (metadata, transcripts, summary) = parse_bundle(&mut |name| {
+        let mut entry = match archive.by_name(name) {
+            Ok(entry) => entry,
+            Err(zip::result::ZipError::FileNotFound) => return Ok(None),
+            Err(e) => return Err(e.to_string()),
+        };
+        let mut content = String::new();
+        entry
+            .read_to_string(&mut content)
+            .map_err(|e| format!("Failed to read {}: {}", name, e))?;
+        Ok(Some(content))
+    })?;
+
+    let audio = match metadata.audio_file.as_deref() {
+        Some(name) if archive.index_for_name(name).is_some() => {
+            AudioSource::ZipEntry(name.to_string())
+        }
+        _ => AudioSource::None,
+    };
+    Ok(from_bundle_metadata(metadata, transcripts, summary, audio, None))
+}
+
+/// Reads a recording folder, or a bundle that was extracted into a folder
+pub fn read_meeting_folder(folder: &Path) -> Result<ImportedMeeting, String> {
+    let read = |name: &str| -> Result<Option<String>, String> {
+        let path = folder.join(name);
+        if !path.is_file() {
+            return Ok(None);
+        }
+        crate::encryption::read_to_string(&path)
+            .map(Some)
+            .map_err(|e| format!("Failed to read {}: {}", path.display(), e))
+    };
+
+    let metadata = read(METADATA_FILE)?
+        .ok_or_else(|| format!("{} has no metadata.json", folder.display()))?;
+    let metadata: Value =
+        serde_json::from_str(&metadata).map_err(|e| format!("Invalid metadata.json: {}", e))?;
+
+    if metadata.get("format").and_then(Value::as_str) == Some(BUNDLE_FORMAT) {
+        let (metadata, transcripts, summary) = parse_bundle(&mut |name| read(name))?;
+        let audio = metadata
+            .audio_file
+            .as_deref()
+            .and_then(safe_file_name)
+            .map(|name| folder.join(name))
+            .filter(|path| path.is_file())
+            .map_or(AudioSource::None, AudioSource::File);
+        return Ok(from_bundle_metadata(
+            metadata,
+            transcripts,
+            summary,
+            audio,
+            Some(folder.to_path_buf()),
+        ));
+    }
+
+    let metadata: MeetingMetadata = serde_json::from_value(metadata)
+        .map_err(|e| format!("Invalid recording metadata.json: {}", e))?;
+    let recording_start = parse_time(&metadata.created_at);
+
+    #[derive(Deserialize)]
+    struct SavedTranscripts {
+        segments: Vec<recording_saver::TranscriptSegment>,
+    }
+    let saved = match read(&metadata.transcript_file)? {
+        Some(content) => serde_json::from_str::<SavedTranscripts>(&content)
+            .map_err(|e| format!("Invalid {}: {}", metadata.transcript_file, e))?
+            .segments,
+        None => Vec::new(),
+    };
+    let transcripts = saved
+        .into_iter()
+        .filter(|s| !s.text.trim().is_empty())
+        .map(|s| TranscriptSegment {
+            id: s.id,
+            timestamp: display_timestamp(recording_start, s.audio_start_time),
+            text: s.text,
+            audio_start_time: Some(s.audio_start_time),
+            audio_end_time: Some(s.audio_end_time),
+            duration: Some(s.duration),
+            source: s.source,
+            speaker: None,
+            confidence: s.confidence,
+            words: s.words,
+        })
+        .collect();
+
+    let audio_path = meeting_audio_path(folder);
+    let title = metadata
+        .meeting_name
+        .filter(|name| !name.trim().is_empty())
+        .or_else(|| folder.file_name().map(|n| n.to_string_lossy().to_string()))
+        .unwrap_or_else(|| "Imported Meeting".to_string());
+    let created_at = recording_start.unwrap_or_else(Utc::now);
+
+    Ok(ImportedMeeting {
+        meeting_id: metadata.meeting_id.filter(|id| !id.is_empty()),
+        title,
+        created_at,
+        updated_at: metadata
+            .completed_at
+            .as_deref()
+            .and_then(parse_time)
+            .unwrap_or(created_at),
+        transcripts,
+        summary: None,
+        audio: if audio_path.is_file() {
+            AudioSource::File(audio_path)
+        } else {
+            AudioSource::None
+        },
+        source_folder: Some(folder.to_path_buf()),
+    })
+}
+
+/// Reads a bundle (.zip) or a folder
+pub fn read_import_source(path: &Path) -> Result<ImportedMeeting, String> {
+    let meeting = if path.is_dir() {
+        read_meeting_folder(path)?
+    } else if path.is_file() {
+        read_bundle(path)?
+    } else {
+        return Err(format!("{} does not exist", path.display()));
+    };
+
+    if meeting.transcripts.is_empty() && meeting.audio == AudioSource::None {
+        return Err("Nothing to import: no transcript and no recording".to_string());
+    }
+    Ok(meeting)
+}
+
+/// Copies the import into a new folder under `recordings_dir`, in the same layout as a
+/// live recording (metadata.json with the meeting id, transcripts.json and the audio)
+pub fn materialize_folder(
+    meeting: &ImportedMeeting,
+    meeting_id: &str,
+    bundle_path: Option<&Path>,
+    recordings_dir: &Path,
+) -> Result<PathBuf, String> {
+    let folder = create_meeting_folder(&recordings_dir.to_path_buf(), &meeting.title)
+        .map_err(|e| format!("Failed to create meeting folder: {}", e))?;
+
+    let result = write_folder_contents(meeting, meeting_id, bundle_path, &folder);
+    if let Err(e) = result {
+        let _ = std::fs::remove_dir_all(&folder);
+        return Err(e);
+    }
+    Ok(folder)
+}
+
+fn write_folder_contents(
+    meeting: &ImportedMeeting,
+    meeting_id: &str,
+    bundle_path: Option<&Path>,
+    folder: &Path,
+) -> Result<(), String> {
+    let audio_file = match &meeting.audio {
+        AudioSource::None => None,
+        AudioSource::File(path) => {
+            let name = path
+                .file_name()
+                .map(|n| n.to_string_lossy().to_string())
+                .unwrap_or_else(|| "audio.mp4".to_string());
+            std::fs::copy(path, folder.join(&name))
+                .map_err(|e| format!("Failed to copy recording: {}", e))?;
+            Some(name)
+        }
+        AudioSource::ZipEntry(entry_name) => {
+            let bundle_path = bundle_path.ok_or("Bundle path missing for archived audio")?;
+            let name = safe_file_name(entry_name).unwrap_or_else(|| "audio.mp4".to_string());
+            let file = File::open(bundle_path).map_err(|e| e.to_string())?;
+            let mut archive = ZipArchive::new(file).map_err(|e| e.to_string())?;
+            let mut entry = archive.by_name(entry_name).map_err(|e| e.to_string())?;
+            let mut target = File::create(folder.join(&name)).map_err(|e| e.to_string())?;
+            std::io::copy(&mut entry, &mut target)
+                .map_err(|e| format!("Failed to extract recording: {}", e))?;
+            Some(name)
+        }
+    };
+
+    let saved: Vec<recording_saver::TranscriptSegment> = meeting
+        .transcripts
+        .iter()
+        .enumerate()
+        .filter_map(|(i, t)| {
+            let start = t.audio_start_time?;
+            let end = t.audio_end_time.unwrap_or(start);
+            Some(recording_saver::TranscriptSegment {
+                id: t.id.clone(),
+                text: t.text.clone(),
+                audio_start_time: start,
+                audio_end_time: end,
+                duration: t.duration.unwrap_or(end - start),
+                display_time: display_timestamp(None, start),
+                confidence: t.confidence,
+                sequence_id: i as u64,
+                source: t.source.clone(),
+                words: t.words.clone(),
+            })
+        })
+        .collect();
+    let transcripts = serde_json::json!({
+        "version": "1.0",
+        "segments": saved,
+        "last_updated": Utc::now().to_rfc3339(),
+        "total_segments": saved.len()
+    });
+    write_json(folder, TRANSCRIPTS_FILE, &transcripts)?;
+
+    let duration_seconds = meeting
+        .transcripts
+        .iter()
+        .filter_map(|t| t.audio_end_time)
+        .fold(None, |max: Option<f64>, end| Some(max.map_or(end, |m| m.max(end))));
+    let metadata = MeetingMetadata {
+        version: "1.0".to_string(),
+        meeting_id: Some(meeting_id.to_string()),
+        meeting_name: Some(meeting.title.clone()),
+        created_at: meeting.created_at.to_rfc3339(),
+        completed_at: Some(meeting.updated_at.to_rfc3339()),
+        duration_seconds,
+        devices: DeviceInfo {
+            microphone: None,
+            system_audio: None,
+        },
+        audio_file: audio_file.unwrap_or_default(),
+        transcript_file: TRANSCRIPTS_FILE.to_string(),
+        sample_rate: 48000,
+        status: "completed".to_string(),
+        audio_quality: Vec::new(),
+    };
+    write_json(folder, METADATA_FILE, &metadata)
+}
+
+fn write_json<T: Serialize>(folder: &Path, file_name: &str, value: &T) -> Result<(), String> {
+    let json = serde_json::to_string_pretty(value).map_err(|e| e.to_string())?;
+    std::fs::write(folder.join(file_name), json)
+        .map_err(|e| format!("Failed to write {}: {}", file_name, e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_safe_file_name() {
+        assert_eq!(safe_file_name("audio.mp4").as_deref(), Some("audio.mp4"));
+        assert_eq!(safe_file_name("../../etc/passwd").as_deref(), Some("passwd"));
+        assert_eq!(safe_file_name("metadata.json"), None);
+        assert_eq!(safe_file_name(".."), None);
+    }
+
+    #[test]
+    fn test_bundle_round_trip() {
+        use crate::api::{MeetingDetails, MeetingTranscript};
+        use crate::export::bundle::write_bundle;
+        use crate::export::document::MeetingExport;
+
+        let export = MeetingExport {
+            details: MeetingDetails {
+                id: "meeting-1".to_string(),
+                title: "Planning".to_string(),
+                created_at: "2025-11-03T09:00:00+00:00".to_string(),
+                updated_at: "2025-11-03T10:00:00+00:00".to_string(),
+                transcripts: vec![MeetingTranscript {
+                    id: "t1".to_string(),
+                    text: "Let's start".to_string(),
+                    timestamp: "09:00:05".to_string(),
+                    audio_start_time: Some(5.0),
+                    audio_end_time: Some(6.5),
+                    duration: Some(1.5),
+                    source: None,
+                    speaker: Some("Alice".to_string()),
+                    confidence: Some(0.9),
+                    words: Vec::new(),
+                }],
+            },
+            summary: Some(serde_json::json!({ "markdown": "# Notes" })),
+            summary_markdown: Some("# Notes".to_string()),
+            folder_path: None,
+        };
+        let path = std::env::temp_dir().join(format!("meetily-bundle-test-{}.zip", uuid::Uuid::new_v4()));
+        write_bundle(File::create(&path).unwrap(), &export).unwrap();
+
+        let meeting = read_bundle(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(meeting.meeting_id.as_deref(), Some("meeting-1"));
+        assert_eq!(meeting.title, "Planning");
+        assert_eq!(meeting.created_at.to_rfc3339(), "2025-11-03T09:00:00+00:00");
+        assert_eq!(meeting.transcripts[0].speaker.as_deref(), Some("Alice"));
+        assert_eq!(meeting.summary, export.summary);
+        assert_eq!(meeting.audio, AudioSource::None);
+    }
+
+    #[test]
+    fn test_read_recording_folder() {
+        let folder = std::env::temp_dir().join(format!("meetily-import-test-{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&folder).unwrap();
+        std::fs::write(
+            folder.join(METADATA_FILE),
+            r#"{"version":"1.0","meeting_id":null,"meeting_name":"Standup","created_at":"2025-11-03T09:00:00Z",
+                "completed_at":null,"duration_seconds":null,"devices":{"microphone":null,"system_audio":null},
+                "audio_file":"audio.mp4","transcript_file":"transcripts.json","sample_rate":48000,"status":"completed"}"#,
+        )
+        .unwrap();
+        std::fs::write(
+            folder.join(TRANSCRIPTS_FILE),
+            r#"{"version":"1.0","segments":[{"id":"seg_0","text":"Morning all","audio_start_time":65.0,
+                "audio_end_time":67.5,"duration":2.5,"display_time":"[01:05]","confidence":0.9,"sequence_id":0,
+                "source":"You"}]}"#,
+        )
+        .unwrap();
+
+        let meeting = read_meeting_folder(&folder).unwrap();
+        std::fs::remove_dir_all(&folder).unwrap();
+
+        assert_eq!(meeting.meeting_id, None);
+        assert_eq!(meeting.title, "Standup");
+        assert_eq!(meeting.audio, AudioSource::None);
+        assert_eq!(meeting.transcripts.len(), 1);
+        assert_eq!(meeting.transcripts[0].timestamp, "09:01:05");
+        assert_eq!(meeting.transcripts[0].source.as_deref(), Some("You"));
+    }
+}
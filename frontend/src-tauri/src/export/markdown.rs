@@ -0,0 +1,68 @@
+// export/markdown.rs
+//
+// Markdown export with YAML front-matter, so notes tools (Obsidian, Logseq, ...) can
+// index the meeting's metadata.
+
+use crate::export::document::{format_clock, speaker_label, MeetingExport};
+
+/// YAML scalar: JSON strings are valid YAML and take care of quoting and escaping
+fn yaml_string(value: &str) -> String {
+    serde_json::to_string(value).unwrap_or_else(|_| "\"\"".to_string())
+}
+
+pub fn render_markdown(export: &MeetingExport) -> String {
+    let details = &export.details;
+    let mut out = String::from("---\n");
+    out.push_str(&format!("title: {}\n", yaml_string(&details.title)));
+    out.push_str(&format!("meeting_id: {}\n", yaml_string(&details.id)));
+    out.push_str(&format!("date: {}\n", export.date()));
+    out.push_str(&format!("created_at: {}\n", yaml_string(&details.created_at)));
+    out.push_str(&format!("updated_at: {}\n", yaml_string(&details.updated_at)));
+    if let Some(duration) = export.duration_seconds() {
+        out.push_str(&format!("duration: {}\n", yaml_string(&format_clock(duration))));
+    }
+    let speakers = export.speakers();
+    if !speakers.is_empty() {
+        let list: Vec<String> = speakers.iter().map(|s| yaml_string(s)).collect();
+        out.push_str(&format!("speakers: [{}]\n", list.join(", ")));
+    }
+    out.push_str("---\n\n");
+
+    out.push_str(&format!("# {}\n\n", details.title));
+
+    if let Some(summary) = &export.summary_markdown {
+        out.push_str("## Summary\n\n");
+        // Nest the summary's own headings below "Summary"
+        for line in summary.trim().lines() {
+            if line.starts_with('#') {
+                out.push('#');
+            }
+            out.push_str(line);
+            out.push('\n');
+        }
+        out.push('\n');
+    }
+
+    if !details.transcripts.is_empty() {
+        out.push_str("## Transcript\n\n");
+        for transcript in &details.transcripts {
+            match speaker_label(transcript) {
+                Some(speaker) => out.push_str(&format!(
+                    "**[{}] {}:** {}\n\n",
+                    transcript.timestamp,
+                    speaker,
+                    transcript.text.trim()
+                )),
+                None => out.push_str(&format!(
+                    "**[{}]** {}\n\n",
+                    transcript.timestamp,
+                    transcript.text.trim()
+                )),
+            }
+        }
+    }
+
+    out.truncate(out.trim_end().len());
+    out.push('\n');
+    out
+}
@@ -0,0 +1,23 @@
+/// Export module - meetings as shareable documents and portable bundles
+///
+/// This module contains:
+/// - Markdown (with YAML front-matter), DOCX and PDF documents
+/// - SRT/WebVTT subtitles from the segment timings
+/// - Zip bundles with metadata, transcript, summary and audio
+/// - Import of bundles and recording folders with a duplicate policy
+/// - Tauri commands to export one meeting or a date range, and to import meetings
+
+pub mod bundle;
+pub mod commands;
+pub mod document;
+pub mod docx;
+pub mod import;
+pub mod markdown;
+pub mod pdf;
+pub mod subtitles;
+
+pub use commands::{
+    __cmd__api_export_meeting, __cmd__api_export_meetings, __cmd__api_import_meeting,
+    __cmd__api_preview_meeting_import, api_export_meeting, api_export_meetings,
+    api_import_meeting, api_preview_meeting_import,
+};
@@ -0,0 +1,400 @@
+// export/pdf.rs
+//
+// Small text-only PDF writer (A4, the built-in Helvetica fonts, WinAnsi encoding).
+// Text outside WinAnsi (e.g. CJK, Cyrillic) is rejected rather than written lossily;
+// the DOCX and Markdown exports keep full Unicode.
+
+use crate::export::document::{Block, Span};
+
+const PAGE_WIDTH: f32 = 595.0;
+const PAGE_HEIGHT: f32 = 842.0;
+const MARGIN: f32 = 56.0;
+const BODY_SIZE: f32 = 10.5;
+const BULLET_INDENT: f32 = 14.0;
+
+/// Helvetica advance widths (1/1000 em) for ASCII 32..=126
+const HELVETICA_WIDTHS: [u16; 95] = [
+    278, 278, 355, 556, 556, 889, 667, 191, 333, 333, 389, 584, 278, 333, 278, 278, // space../
+    556, 556, 556, 556, 556, 556, 556, 556, 556, 556, 278, 278, 584, 584, 584, 556, // 0..?
+    1015, 667, 667, 722, 722, 667, 611, 778, 722, 278, 500, 667, 556, 833, 722, 778, // @..O
+    667, 778, 722, 667, 611, 722, 667, 944, 667, 667, 611, 278, 278, 278, 469, 556, // P.._
+    333, 556, 556, 500, 556, 556, 278, 556, 556, 222, 222, 500, 222, 833, 556, 556, // `..o
+    556, 556, 333, 500, 278, 556, 500, 722, 500, 500, 500, 334, 260, 334, 584, // p..~
+];
+
+/// WinAnsi byte for a character, if it has one
+fn win_ansi(c: char) -> Option<u8> {
+    let byte = match c {
+        ' '..='~' => c as u8,
+        '\u{a0}'..='\u{ff}' => c as u32 as u8,
+        '€' => 0x80,
+        '‚' => 0x82,
+        '„' => 0x84,
+        '…' => 0x85,
+        '‘' => 0x91,
+        '’' => 0x92,
+        '“' => 0x93,
+        '”' => 0x94,
+        '•' => 0x95,
+        '–' => 0x96,
+        '—' => 0x97,
+        '™' => 0x99,
+        '\t' => b' ',
+        _ => return None,
+    };
+    Some(byte)
+}
+
+/// Error naming the first characters the built-in fonts can't show
+fn check_encodable(blocks: &[Block]) -> Result<(), String> {
+    let mut unsupported: Vec<char> = Vec::new();
+    for block in blocks {
+        let texts: Vec<&str> = match block {
+            Block::Title(text) | Block::Heading(_, text) => vec![text.as_str()],
+            Block::Paragraph(spans) | Block::Bullet(spans) => {
+                spans.iter().map(|s| s.text.as_str()).collect()
+            }
+        };
+        for c in texts.into_iter().flat_map(str::chars) {
+            if win_ansi(c).is_none() && !unsupported.contains(&c) {
+                unsupported.push(c);
+            }
+        }
+    }
+    if unsupported.is_empty() {
+        return Ok(());
+    }
+    let shown: String = unsupported.iter().take(5).collect();
+    Err(format!(
+        "The PDF export only supports Latin text and can't write characters like \"{}\". Export as DOCX or Markdown instead.",
+        shown
+    ))
+}
+
+fn char_width(c: char, size: f32, bold: bool) -> f32 {
+    let units = match c {
+        ' '..='~' => HELVETICA_WIDTHS[c as usize - 32] as f32,
+        _ => 556.0,
+    };
+    // Helvetica-Bold runs about 5% wider
+    let units = if bold { units * 1.05 } else { units };
+    units * size / 1000.0
+}
+
+fn text_width(text: &str, size: f32, bold: bool) -> f32 {
+    text.chars().map(|c| char_width(c, size, bold)).sum()
+}
+
+/// PDF string literal, non-ASCII bytes as octal escapes. `render_pdf` has already
+/// rejected text outside WinAnsi.
+fn pdf_string(text: &str) -> String {
+    let mut out = String::from("(");
+    for byte in text.chars().filter_map(win_ansi) {
+        match byte {
+            b'(' | b')' | b'\\' => {
+                out.push('\\');
+                out.push(byte as char);
+            }
+            0x20..=0x7e => out.push(byte as char),
+            _ => out.push_str(&format!("\\{:03o}", byte)),
+        }
+    }
+    out.push(')');
+    out
+}
+
+/// A piece of a line in one font
+struct Word {
+    text: String,
+    bold: bool,
+}
+
+/// Byte index at which `text` gets wider than `width`, after at least one character
+fn overflow_index(text: &str, size: f32, bold: bool, width: f32) -> usize {
+    let mut used = 0.0;
+    for (i, c) in text.char_indices() {
+        used += char_width(c, size, bold);
+        if used > width {
+            return if i == 0 { c.len_utf8() } else { i };
+        }
+    }
+    text.len()
+}
+
+/// Greedy word wrap of styled spans into lines no wider than `width`. Words wider
+/// than a whole line (long URLs) are broken across lines.
+fn wrap_spans(spans: &[Span], size: f32, width: f32) -> Vec<Vec<Word>> {
+    let mut lines: Vec<Vec<Word>> = vec![Vec::new()];
+    let mut line_width = 0.0;
+
+    for span in spans {
+        for (i, piece) in span.text.split(' ').enumerate() {
+            let text = if i == 0 { piece.to_string() } else { format!(" {}", piece) };
+            let piece_width = text_width(&text, size, span.bold);
+
+            if line_width + piece_width <= width {
+                line_width += piece_width;
+                lines.last_mut().expect("at least one line").push(Word {
+                    text,
+                    bold: span.bold,
+                });
+                continue;
+            }
+
+            let mut text = text.trim_start().to_string();
+            if !lines.last().expect("at least one line").is_empty() {
+                lines.push(Vec::new());
+            }
+            while text_width(&text, size, span.bold) > width {
+                let rest = text.split_off(overflow_index(&text, size, span.bold, width));
+                lines.last_mut().expect("at least one line").push(Word {
+                    text,
+                    bold: span.bold,
+                });
+                lines.push(Vec::new());
+                text = rest;
+            }
+            line_width = text_width(&text, size, span.bold);
+            lines.last_mut().expect("at least one line").push(Word {
+                text,
+                bold: span.bold,
+            });
+        }
+    }
+    lines
+}
+
+struct PageWriter {
+    pages: Vec<String>,
+    current: String,
+    y: f32,
+}
+
+impl PageWriter {
+    fn new() -> Self {
+        Self {
+            pages: Vec::new(),
+            current: String::new(),
+            y: PAGE_HEIGHT - MARGIN,
+        }
+    }
+
+    fn new_page(&mut self) {
+        self.pages.push(std::mem::take(&mut self.current));
+        self.y = PAGE_HEIGHT - MARGIN;
+    }
+
+    fn space(&mut self, points: f32) {
+        if self.y < PAGE_HEIGHT - MARGIN {
+            self.y -= points;
+        }
+    }
+
+    fn line(&mut self, words: &[Word], x: f32, size: f32) {
+        let leading = size * 1.35;
+        if self.y - leading < MARGIN {
+            self.new_page();
+        }
+        self.y -= leading;
+
+        let mut ops = format!("BT {:.2} {:.2} Td", x, self.y);
+        let mut font = None;
+        for word in words {
+            let name = if word.bold { "F2" } else { "F1" };
+            if font != Some(name) {
+                ops.push_str(&format!(" /{} {} Tf", name, size));
+                font = Some(name);
+            }
+            ops.push_str(&format!(" {} Tj", pdf_string(&word.text)));
+        }
+        ops.push_str(" ET\n");
+        self.current.push_str(&ops);
+    }
+
+    fn paragraph(&mut self, spans: &[Span], size: f32, indent: f32) {
+        let width = PAGE_WIDTH - 2.0 * MARGIN - indent;
+        for line in wrap_spans(spans, size, width) {
+            self.line(&line, MARGIN + indent, size);
+        }
+    }
+
+    fn finish(mut self) -> Vec<String> {
+        if !self.current.is_empty() || self.pages.is_empty() {
+            self.pages.push(self.current);
+        }
+        self.pages
+    }
+}
+
+fn heading_size(level: u8) -> f32 {
+    match level {
+        1 => 16.0,
+        2 => 13.0,
+        _ => 11.5,
+    }
+}
+
+/// Lays out the blocks and returns the bytes of a PDF file
+pub fn render_pdf(blocks: &[Block]) -> Result<Vec<u8>, String> {
+    check_encodable(blocks)?;
+
+    let mut writer = PageWriter::new();
+    for block in blocks {
+        match block {
+            Block::Title(text) => {
+                let spans = [Span {
+                    text: text.clone(),
+                    bold: true,
+                }];
+                writer.paragraph(&spans, 20.0, 0.0);
+                writer.space(6.0);
+            }
+            Block::Heading(level, text) => {
+                writer.space(10.0);
+                let spans = [Span {
+                    text: text.clone(),
+                    bold: true,
+                }];
+                writer.paragraph(&spans, heading_size(*level), 0.0);
+                writer.space(2.0);
+            }
+            Block::Paragraph(spans) => {
+                writer.paragraph(spans, BODY_SIZE, 0.0);
+                writer.space(4.0);
+            }
+            Block::Bullet(spans) => {
+                let mut with_bullet = vec![Span {
+                    text: "• ".to_string(),
+                    bold: false,
+                }];
+                with_bullet.extend_from_slice(spans);
+                writer.paragraph(&with_bullet, BODY_SIZE, BULLET_INDENT);
+                writer.space(2.0);
+            }
+        }
+    }
+    let pages = writer.finish();
+
+    // Objects: 1 catalog, 2 page tree, 3-4 fonts, then a page and its content per page
+    let mut objects: Vec<String> = Vec::new();
+    let kids: Vec<String> = (0..pages.len())
+        .map(|i| format!("{} 0 R", 5 + i * 2))
+        .collect();
+    objects.push("<< /Type /Catalog /Pages 2 0 R >>".to_string());
+    objects.push(format!(
+        "<< /Type /Pages /Kids [{}] /Count {} >>",
+        kids.join(" "),
+        pages.len()
+    ));
+    objects.push(
+        "<< /Type /Font /Subtype /Type1 /BaseFont /Helvetica /Encoding /WinAnsiEncoding >>"
+            .to_string(),
+    );
+    objects.push(
+        "<< /Type /Font /Subtype /Type1 /BaseFont /Helvetica-Bold /Encoding /WinAnsiEncoding >>"
+            .to_string(),
+    );
+    for (i, content) in pages.iter().enumerate() {
+        objects.push(format!(
+            "<< /Type /Page /Parent 2 0 R /MediaBox [0 0 {} {}] /Resources << /Font << /F1 3 0 R /F2 4 0 R >> >> /Contents {} 0 R >>",
+            PAGE_WIDTH,
+            PAGE_HEIGHT,
+            6 + i * 2
+        ));
+        objects.push(format!(
+            "<< /Length {} >>\nstream\n{}endstream",
+            content.len(),
+            content
+        ));
+    }
+
+    // Content is ASCII only (see pdf_string), so byte offsets equal string lengths
+    let mut out = String::from("%PDF-1.4\n");
+    let mut offsets = Vec::with_capacity(objects.len());
+    for (i, object) in objects.iter().enumerate() {
+        offsets.push(out.len());
+        out.push_str(&format!("{} 0 obj\n{}\nendobj\n", i + 1, object));
+    }
+    let xref_offset = out.len();
+    out.push_str(&format!("xref\n0 {}\n0000000000 65535 f \n", objects.len() + 1));
+    for offset in offsets {
+        out.push_str(&format!("{:010} 00000 n \n", offset));
+    }
+    out.push_str(&format!(
+        "trailer\n<< /Size {} /Root 1 0 R >>\nstartxref\n{}\n%%EOF\n",
+        objects.len() + 1,
+        xref_offset
+    ));
+    Ok(out.into_bytes())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pdf_string_escapes() {
+        assert_eq!(pdf_string("a (b) \\ c"), "(a \\(b\\) \\\\ c)");
+        assert_eq!(pdf_string("café – ok"), "(caf\\351 \\226 ok)");
+    }
+
+    #[test]
+    fn test_render_pdf_rejects_non_win_ansi_text() {
+        let blocks = [Block::Title("Résumé 日本".to_string())];
+        let err = render_pdf(&blocks).unwrap_err();
+        assert!(err.contains("日本"));
+        assert!(render_pdf(&[Block::Title("Résumé – ok".to_string())]).is_ok());
+    }
+
+    #[test]
+    fn test_wrap_spans_respects_width() {
+        let spans = [Span {
+            text: "word ".repeat(100),
+            bold: false,
+        }];
+        let lines = wrap_spans(&spans, BODY_SIZE, 200.0);
+        assert!(lines.len() > 1);
+        for line in &lines {
+            let width: f32 = line.iter().map(|w| text_width(&w.text, BODY_SIZE, w.bold)).sum();
+            assert!(width <= 200.0 + 0.01);
+        }
+    }
+
+    #[test]
+    fn test_wrap_spans_breaks_words_wider_than_a_line() {
+        let url = format!("https://example.com/{}", "a".repeat(200));
+        let spans = [Span {
+            text: format!("See {} for details", url),
+            bold: false,
+        }];
+        let lines = wrap_spans(&spans, BODY_SIZE, 200.0);
+        assert!(lines.len() > 3);
+        for line in &lines {
+            let width: f32 = line.iter().map(|w| text_width(&w.text, BODY_SIZE, w.bold)).sum();
+            assert!(width <= 200.0 + 0.01);
+        }
+        let joined: String = lines.iter().flatten().map(|w| w.text.as_str()).collect();
+        assert_eq!(joined, format!("See{} for details", url));
+    }
+
+    #[test]
+    fn test_render_pdf_structure() {
+        let blocks: Vec<Block> = (0..200)
+            .map(|i| Block::Paragraph(vec![Span {
+                text: format!("Paragraph {}", i),
+                bold: false,
+            }]))
+            .collect();
+        let pdf = String::from_utf8(render_pdf(&blocks).unwrap()).unwrap();
+
+        assert!(pdf.starts_with("%PDF-1.4"));
+        assert!(pdf.ends_with("%%EOF\n"));
+        // Long documents flow onto several pages
+        assert!(!pdf.contains("/Count 1 "));
+
+        // xref offsets point at the objects
+        let xref = pdf.find("xref\n").unwrap();
+        let first_offset: usize = pdf[xref..].lines().nth(3).unwrap()[..10].parse().unwrap();
+        assert!(pdf[first_offset..].starts_with("1 0 obj"));
+    }
+}
@@ -0,0 +1,162 @@
+// export/subtitles.rs
+//
+// SRT and WebVTT subtitles from the recording-relative segment timings
+// (`audio_start_time` / `audio_end_time`).
+
+use crate::api::MeetingTranscript;
+use crate::export::document::speaker_label;
+
+/// Shortest cue shown, in seconds
+const MIN_CUE_SECONDS: f64 = 0.5;
+/// Cue length when a segment has neither an end time nor a duration
+const DEFAULT_CUE_SECONDS: f64 = 3.0;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Cue {
+    pub start: f64,
+    pub end: f64,
+    pub speaker: Option<String>,
+    pub text: String,
+}
+
+/// Cues of all segments with a start time, in playback order. Missing end times are
+/// filled from the duration or the next segment's start.
+pub fn build_cues(transcripts: &[MeetingTranscript]) -> Vec<Cue> {
+    let mut timed: Vec<&MeetingTranscript> = transcripts
+        .iter()
+        .filter(|t| t.audio_start_time.is_some() && !t.text.trim().is_empty())
+        .collect();
+    timed.sort_by(|a, b| {
+        a.audio_start_time
+            .partial_cmp(&b.audio_start_time)
+            .unwrap_or(std::cmp::Ordering::Equal)
+    });
+
+    timed
+        .iter()
+        .enumerate()
+        .map(|(i, t)| {
+            let start = t.audio_start_time.unwrap_or_default().max(0.0);
+            let next_start = timed.get(i + 1).and_then(|n| n.audio_start_time);
+            let end = t
+                .audio_end_time
+                .or_else(|| t.duration.map(|d| start + d))
+                .or(next_start)
+                .unwrap_or(start + DEFAULT_CUE_SECONDS);
+            Cue {
+                start,
+                end: end.max(start + MIN_CUE_SECONDS),
+                speaker: speaker_label(t).map(str::to_string),
+                text: t.text.trim().to_string(),
+            }
+        })
+        .collect()
+}
+
+/// `HH:MM:SS<separator>mmm`
+fn cue_time(seconds: f64, separator: char) -> String {
+    let millis = (seconds.max(0.0) * 1000.0).round() as u64;
+    format!(
+        "{:02}:{:02}:{:02}{}{:03}",
+        millis / 3_600_000,
+        (millis / 60_000) % 60,
+        (millis / 1000) % 60,
+        separator,
+        millis % 1000
+    )
+}
+
+pub fn render_srt(cues: &[Cue]) -> String {
+    cues.iter()
+        .enumerate()
+        .map(|(i, cue)| {
+            let text = match &cue.speaker {
+                Some(speaker) => format!("{}: {}", speaker, cue.text),
+                None => cue.text.clone(),
+            };
+            format!(
+                "{}\n{} --> {}\n{}\n",
+                i + 1,
+                cue_time(cue.start, ','),
+                cue_time(cue.end, ','),
+                text
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+pub fn render_vtt(cues: &[Cue]) -> String {
+    let mut out = String::from("WEBVTT\n");
+    for cue in cues {
+        // Cue text is markup: escape it, and keep the voice tag well-formed
+        let text = cue
+            .text
+            .replace('&', "&amp;")
+            .replace('<', "&lt;")
+            .replace('>', "&gt;");
+        let text = match &cue.speaker {
+            Some(speaker) => format!("<v {}>{}", speaker.replace('>', ""), text),
+            None => text,
+        };
+        out.push_str(&format!(
+            "\n{} --> {}\n{}\n",
+            cue_time(cue.start, '.'),
+            cue_time(cue.end, '.'),
+            text
+        ));
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn segment(text: &str, start: Option<f64>, end: Option<f64>, speaker: Option<&str>) -> MeetingTranscript {
+        MeetingTranscript {
+            id: text.to_string(),
+            text: text.to_string(),
+            timestamp: "10:00:00".to_string(),
+            audio_start_time: start,
+            audio_end_time: end,
+            duration: None,
+            source: None,
+            speaker: speaker.map(str::to_string),
+            confidence: None,
+            words: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_build_cues_fills_missing_end_times() {
+        let cues = build_cues(&[
+            segment("second", Some(5.0), None, None),
+            segment("untimed", None, None, None),
+            segment("first", Some(1.0), Some(4.2), Some("Alice")),
+            segment("last", Some(3725.5), None, None),
+        ]);
+
+        assert_eq!(cues.len(), 3);
+        assert_eq!(cues[0].text, "first");
+        assert_eq!(cues[1].end, 3725.5);
+        assert_eq!(cues[2].end, 3725.5 + DEFAULT_CUE_SECONDS);
+    }
+
+    #[test]
+    fn test_render_srt_and_vtt() {
+        let cues = build_cues(&[
+            segment("Hello <team>", Some(1.0), Some(4.25), Some("Alice")),
+            segment("Hi", Some(3725.5), Some(3727.0), None),
+        ]);
+
+        assert_eq!(
+            render_srt(&cues),
+            "1\n00:00:01,000 --> 00:00:04,250\nAlice: Hello <team>\n\n2\n01:02:05,500 --> 01:02:07,000\nHi\n"
+        );
+        assert_eq!(
+            render_vtt(&cues),
+            "WEBVTT\n\n00:00:01.000 --> 00:00:04.250\n<v Alice>Hello &lt;team&gt;\n\n01:02:05.500 --> 01:02:07.000\nHi\n"
+        );
+    }
+}
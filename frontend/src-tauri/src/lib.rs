@@ -35,26 +35,35 @@ pub(crate) use perf_trace;
 // Re-export async logging macros for external use (removed due to macro conflicts)
 
 // Declare audio module
+pub mod action_items;
 pub mod analytics;
 pub mod api;
 pub mod audio;
+pub mod audio_v2;
 pub mod console_utils;
 pub mod database;
+pub mod diarization;
+pub mod encryption;
+pub mod export;
+pub mod meeting_chat;
 pub mod notifications;
 pub mod ollama;
 pub mod openrouter;
 pub mod parakeet_engine;
+pub mod secrets;
+pub mod semantic_search;
 pub mod state;
 pub mod summary;
 pub mod tray;
 pub mod utils;
+pub mod vocabulary;
 pub mod whisper_engine;
 
 use audio::{list_audio_devices, AudioDevice};
 use log::{error as log_error, info as log_info};
 use notifications::commands::NotificationManagerState;
 use std::sync::Arc;
-use tauri::{AppHandle, Manager, Runtime};
+use tauri::{AppHandle, Emitter, Manager, Runtime};
 use tokio::sync::RwLock;
 
 static RECORDING_FLAG: AtomicBool = AtomicBool::new(false);
@@ -216,7 +225,7 @@ fn get_transcription_status() -> TranscriptionStatus {
 
 #[tauri::command]
 fn read_audio_file(file_path: String) -> Result<Vec<u8>, String> {
-    match std::fs::read(&file_path) {
+    match encryption::read_file(std::path::Path::new(&file_path)) {
         Ok(data) => Ok(data),
         Err(e) => Err(format!("Failed to read audio file: {}", e)),
     }
@@ -432,6 +441,18 @@ pub fn run() {
                 }
             });
 
+            // Load the transcription channel mode (mixed or dual-channel)
+            audio::recording_preferences::load_transcription_channel_mode(_app.handle());
+
+            // Load VAD settings (picked up by the first recording)
+            audio::vad_settings::load_vad_settings(_app.handle());
+
+            // Load DSP chain settings (picked up by the first recording)
+            audio::dsp_settings::load_dsp_settings(_app.handle());
+
+            // Load audio engine settings (picked up by the first recording)
+            audio_v2::settings::load_audio_engine_settings(_app.handle());
+
             // Set models directory to use app_data_dir (unified storage location)
             whisper_engine::commands::set_models_directory(&_app.handle());
 
@@ -445,6 +466,9 @@ pub fn run() {
             // Set Parakeet models directory
             parakeet_engine::commands::set_models_directory(&_app.handle());
 
+            // Set diarization (speaker embedding) models directory
+            diarization::commands::set_models_directory(&_app.handle());
+
             // Initialize Parakeet engine on startup
             tauri::async_runtime::spawn(async {
                 if let Err(e) = parakeet_engine::commands::parakeet_init().await {
@@ -468,6 +492,19 @@ pub fn run() {
             })
             .expect("Failed to initialize database");
 
+            // Look for recordings interrupted by a crash so the UI can offer recovery
+            let app_for_recovery = _app.handle().clone();
+            tauri::async_runtime::spawn(async move {
+                match audio::recovery::scan_interrupted_recordings(&app_for_recovery).await {
+                    Ok(interrupted) if !interrupted.is_empty() => {
+                        log::warn!("Found {} interrupted recording(s)", interrupted.len());
+                        let _ = app_for_recovery.emit("interrupted-recordings-detected", &interrupted);
+                    }
+                    Ok(_) => {}
+                    Err(e) => log::error!("Failed to scan for interrupted recordings: {}", e),
+                }
+            });
+
             // Initialize bundled templates directory for dynamic template discovery
             log::info!("Initializing bundled templates directory...");
             if let Ok(resource_path) = _app.handle().path().resource_dir() {
@@ -538,6 +575,19 @@ pub fn run() {
             parakeet_engine::commands::parakeet_cancel_download,
             parakeet_engine::commands::parakeet_delete_corrupted_model,
             parakeet_engine::commands::open_parakeet_models_folder,
+            diarization::commands::diarize_meeting,
+            audio::transcription::retranscribe::retranscribe_meeting,
+            audio::transcription::import::import_audio_file,
+            audio::recovery::api_list_interrupted_recordings,
+            audio::recovery::api_recover_recording,
+            audio::recovery::api_dismiss_interrupted_recording,
+            encryption::commands::api_get_encryption_status,
+            encryption::commands::api_unlock_database,
+            encryption::commands::api_lock_database,
+            encryption::commands::api_enable_encryption,
+            encryption::commands::api_disable_encryption,
+            diarization::commands::diarization_is_model_available,
+            diarization::commands::diarization_get_models_directory,
             // Parallel processing commands
             whisper_engine::parallel_commands::initialize_parallel_processor,
             whisper_engine::parallel_commands::start_parallel_processing,
@@ -580,6 +630,7 @@ pub fn run() {
             ollama::get_ollama_model_context,
             api::api_get_meetings,
             api::api_search_transcripts,
+            api::api_get_low_confidence_regions,
             api::api_get_profile,
             api::api_save_profile,
             api::api_update_profile,
@@ -594,6 +645,7 @@ pub fn run() {
             api::api_delete_meeting,
             api::api_get_meeting,
             api::api_save_meeting_title,
+            api::api_rename_speaker,
             api::api_save_transcript,
             api::open_meeting_folder,
             api::test_backend_connection,
@@ -602,12 +654,38 @@ pub fn run() {
             // Summary commands
             summary::api_process_transcript,
             summary::api_get_summary,
+            summary::api_cancel_summary,
             summary::api_save_meeting_summary,
+            summary::api_list_summary_versions,
+            summary::api_diff_summary_versions,
+            summary::api_restore_summary_version,
+            // Semantic search and meeting Q&A
+            semantic_search::api_build_semantic_index,
+            semantic_search::api_semantic_search,
+            semantic_search::api_ask_meetings,
+            // Meeting chat commands
+            meeting_chat::api_get_meeting_chat,
+            meeting_chat::api_send_meeting_chat_message,
+            meeting_chat::api_clear_meeting_chat,
+            // Action item commands
+            action_items::api_extract_action_items,
+            action_items::api_list_action_items,
+            action_items::api_list_open_action_items,
+            action_items::api_update_action_item_status,
+            action_items::api_get_action_item_source,
+            // Export commands
+            export::api_export_meeting,
+            export::api_export_meetings,
+            export::api_preview_meeting_import,
+            export::api_import_meeting,
             // Template commands
             summary::api_list_templates,
             summary::api_get_template_details,
             summary::api_validate_template,
             openrouter::get_openrouter_models,
+            summary::get_custom_openai_models,
+            summary::api_get_custom_provider_config,
+            summary::api_save_custom_provider_config,
             audio::recording_preferences::get_recording_preferences,
             audio::recording_preferences::set_recording_preferences,
             audio::recording_preferences::get_default_recordings_folder_path,
@@ -617,9 +695,30 @@ pub fn run() {
             audio::recording_preferences::get_current_audio_backend,
             audio::recording_preferences::set_audio_backend,
             audio::recording_preferences::get_audio_backend_info,
+            // VAD tuning commands
+            audio::vad_settings::get_vad_settings,
+            audio::vad_settings::set_vad_settings,
+            audio::vad_settings::get_vad_presets,
+            audio::vad_settings::apply_vad_preset,
+            audio::vad_calibration::calibrate_vad,
+            // DSP chain commands
+            audio::dsp_settings::get_dsp_settings,
+            audio::dsp_settings::set_dsp_settings,
+            audio::dsp_settings::get_dsp_stage_costs,
+            audio::dsp_settings::benchmark_dsp_stages,
+            // Audio engine commands
+            audio_v2::settings::get_audio_engine_settings,
+            audio_v2::settings::set_audio_engine_settings,
+            audio_v2::settings::get_audio_quality_metrics,
             // Language preference commands
             get_language_preference,
             set_language_preference,
+            // Custom vocabulary commands
+            vocabulary::api_get_vocabulary,
+            vocabulary::api_add_vocabulary_terms,
+            vocabulary::api_remove_vocabulary_term,
+            vocabulary::api_clear_vocabulary,
+            vocabulary::api_import_vocabulary,
             // Notification system commands
             notifications::commands::get_notification_settings,
             notifications::commands::set_notification_settings,
@@ -653,6 +752,8 @@ pub fn run() {
             database::commands::check_homebrew_database,
             database::commands::import_and_initialize_database,
             database::commands::initialize_fresh_database,
+            // Database recovery commands
+            database::recovery::api_rescan_recordings,
             // Database and Models path commands
             database::commands::get_database_directory,
             database::commands::open_database_folder,
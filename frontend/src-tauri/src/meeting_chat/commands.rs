@@ -0,0 +1,158 @@
+use crate::database::models::MeetingChatMessage;
+use crate::database::repositories::{
+    meeting_chat::MeetingChatRepository, setting::SettingsRepository,
+    transcript::TranscriptsRepository,
+};
+use crate::meeting_chat::context::{build_chat_prompt, format_transcript};
+use crate::state::AppState;
+use crate::summary::llm_client::{generate_chat_stream, ChatMessage};
+use crate::summary::service::{ResolvedProvider, SummaryService};
+use log::{error as log_error, info as log_info, warn as log_warn};
+use reqwest::Client;
+use serde::Serialize;
+use tauri::{AppHandle, Emitter, Runtime};
+
+/// Payload of `meeting-chat-delta` events while an answer streams in
+#[derive(Debug, Clone, Serialize)]
+pub struct MeetingChatDelta {
+    pub meeting_id: String,
+    /// Answer text generated so far
+    pub content: String,
+}
+
+/// Returns the conversation of a meeting, oldest turn first
+#[tauri::command]
+pub async fn api_get_meeting_chat<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    meeting_id: String,
+) -> Result<Vec<MeetingChatMessage>, String> {
+    MeetingChatRepository::list_messages(state.db_manager.pool(), &meeting_id)
+        .await
+        .map_err(|e| {
+            log_error!("Failed to load chat for meeting {}: {}", meeting_id, e);
+            e.to_string()
+        })
+}
+
+/// Asks a follow-up question about a meeting. The answer streams as `meeting-chat-delta`
+/// events; both turns are stored once it completes and the assistant turn is returned.
+/// Provider and model default to the configured summary model.
+#[tauri::command]
+pub async fn api_send_meeting_chat_message<R: Runtime>(
+    app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    meeting_id: String,
+    message: String,
+    model_provider: Option<String>,
+    model_name: Option<String>,
+) -> Result<MeetingChatMessage, String> {
+    let question = message.trim();
+    if question.is_empty() {
+        return Err("Message is empty".to_string());
+    }
+    log_info!("api_send_meeting_chat_message called for meeting_id: {}", meeting_id);
+
+    let pool = state.db_manager.pool();
+
+    let transcripts = TranscriptsRepository::get_transcripts_for_meeting(pool, &meeting_id)
+        .await
+        .map_err(|e| e.to_string())?;
+    let transcript = format_transcript(&transcripts);
+    if transcript.is_empty() {
+        return Err("This meeting has no transcript to chat about".to_string());
+    }
+
+    let (model_provider, model_name) = match (model_provider, model_name) {
+        (Some(provider), Some(model)) => (provider, model),
+        (provider, model) => {
+            let config = SettingsRepository::get_model_config(pool)
+                .await
+                .map_err(|e| e.to_string())?
+                .ok_or_else(|| "No summary model configured".to_string())?;
+            (
+                provider.unwrap_or(config.provider),
+                model.unwrap_or(config.model),
+            )
+        }
+    };
+    let ResolvedProvider {
+        provider,
+        api_key,
+        ollama_endpoint,
+    } = SummaryService::resolve_provider(pool, &model_provider).await?;
+
+    let mut history: Vec<ChatMessage> = MeetingChatRepository::list_messages(pool, &meeting_id)
+        .await
+        .map_err(|e| e.to_string())?
+        .into_iter()
+        .map(|m| ChatMessage {
+            role: m.role,
+            content: m.content,
+        })
+        .collect();
+    history.push(ChatMessage::user(question));
+
+    let context_tokens = SummaryService::context_token_limit(
+        &provider,
+        &model_name,
+        &api_key,
+        ollama_endpoint.as_deref(),
+    )
+    .await;
+    let (system_prompt, messages) = build_chat_prompt(&transcript, &history, context_tokens);
+
+    let mut on_text = |text: &str| {
+        let delta = MeetingChatDelta {
+            meeting_id: meeting_id.clone(),
+            content: text.to_string(),
+        };
+        if let Err(e) = app.emit("meeting-chat-delta", delta) {
+            log_warn!("Failed to emit meeting-chat-delta: {}", e);
+        }
+    };
+    let answer = generate_chat_stream(
+        &Client::new(),
+        &provider,
+        &model_name,
+        &api_key,
+        &system_prompt,
+        &messages,
+        ollama_endpoint.as_deref(),
+        &mut on_text,
+    )
+    .await
+    .map_err(|e| {
+        log_error!("Meeting chat failed for {}: {}", meeting_id, e);
+        e
+    })?;
+
+    // Store the question only with its answer, so a failed request can simply be retried
+    MeetingChatRepository::add_message(pool, &meeting_id, "user", question, None, None)
+        .await
+        .map_err(|e| e.to_string())?;
+    MeetingChatRepository::add_message(
+        pool,
+        &meeting_id,
+        "assistant",
+        &answer,
+        Some(&model_provider),
+        Some(&model_name),
+    )
+    .await
+    .map_err(|e| e.to_string())
+}
+
+/// Deletes the conversation of a meeting
+#[tauri::command]
+pub async fn api_clear_meeting_chat<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    meeting_id: String,
+) -> Result<(), String> {
+    let removed = MeetingChatRepository::clear(state.db_manager.pool(), &meeting_id)
+        .await
+        .map_err(|e| e.to_string())?;
+    log_info!("Cleared {} chat messages for meeting_id: {}", removed, meeting_id);
+    Ok(())
+}
@@ -0,0 +1,186 @@
+// meeting_chat/context.rs
+//
+// Prompt building for the meeting chat: the transcript goes into the system prompt
+// (whole, or the most relevant chunks when it exceeds the model context) and the
+// conversation is trimmed from the oldest turn to fit its share of the budget.
+
+use crate::database::models::Transcript;
+use crate::summary::llm_client::ChatMessage;
+use crate::summary::processor::{chunk_text, rough_token_count};
+use std::collections::HashSet;
+
+/// Tokens kept free for the model's answer
+pub const ANSWER_RESERVE_TOKENS: usize = 1024;
+/// Share of the remaining budget the conversation history may take
+const HISTORY_BUDGET_DIVISOR: usize = 4;
+const MAX_CHUNK_TOKENS: usize = 1500;
+const MIN_CHUNK_TOKENS: usize = 200;
+const CHUNK_OVERLAP_TOKENS: usize = 50;
+
+const STOPWORDS: &[&str] = &[
+    "the", "and", "for", "are", "but", "not", "you", "all", "any", "can", "had", "her", "was",
+    "one", "our", "out", "has", "him", "his", "how", "its", "who", "did", "get", "may", "what",
+    "when", "where", "which", "with", "that", "this", "they", "them", "then", "there", "from",
+    "have", "about", "would", "could", "should", "will", "into", "were", "been", "does",
+];
+
+pub const SYSTEM_PROMPT: &str = "You are an assistant answering follow-up questions about a \
+single meeting. Answer only from the meeting transcript below; if it doesn't contain the \
+answer, say so. Refer to speakers by name and include the [timestamp] of the statements you \
+rely on. Be concise and answer in the language of the question.";
+
+/// Transcript as `[timestamp] Speaker: text` lines
+pub fn format_transcript(transcripts: &[Transcript]) -> String {
+    transcripts
+        .iter()
+        .filter(|t| !t.transcript.trim().is_empty())
+        .map(|t| {
+            match t.speaker.as_deref().or(t.source.as_deref()) {
+                Some(speaker) => format!("[{}] {}: {}", t.timestamp, speaker, t.transcript.trim()),
+                None => format!("[{}] {}", t.timestamp, t.transcript.trim()),
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn terms(text: &str) -> HashSet<String> {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|w| w.chars().count() >= 3)
+        .map(str::to_lowercase)
+        .filter(|w| !STOPWORDS.contains(&w.as_str()))
+        .collect()
+}
+
+/// The transcript itself when it fits `budget_tokens`; otherwise the chunks sharing the
+/// most terms with `query`, in transcript order, as many as fit the budget
+pub fn select_transcript_context(transcript: &str, query: &str, budget_tokens: usize) -> String {
+    if rough_token_count(transcript) <= budget_tokens {
+        return transcript.to_string();
+    }
+
+    let chunk_tokens = (budget_tokens / 4).clamp(MIN_CHUNK_TOKENS, MAX_CHUNK_TOKENS);
+    let chunks = chunk_text(transcript, chunk_tokens, CHUNK_OVERLAP_TOKENS);
+    let query_terms = terms(query);
+
+    let mut ranked: Vec<(usize, usize)> = chunks
+        .iter()
+        .enumerate()
+        .map(|(i, chunk)| (i, terms(chunk).intersection(&query_terms).count()))
+        .collect();
+    // Best match first; ties keep transcript order
+    ranked.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+
+    let mut selected = Vec::new();
+    let mut used = 0;
+    for (i, _) in ranked {
+        let tokens = rough_token_count(&chunks[i]);
+        if used + tokens > budget_tokens {
+            continue;
+        }
+        used += tokens;
+        selected.push(i);
+    }
+    selected.sort_unstable();
+
+    selected
+        .into_iter()
+        .map(|i| chunks[i].as_str())
+        .collect::<Vec<_>>()
+        .join("\n[...]\n")
+}
+
+/// Most recent turns that fit `budget_tokens`. The latest turn is always kept and the
+/// result starts with a user turn, as Claude requires.
+pub fn trim_history(history: &[ChatMessage], budget_tokens: usize) -> Vec<ChatMessage> {
+    let mut used = 0;
+    let mut start = history.len();
+    while start > 0 {
+        let tokens = rough_token_count(&history[start - 1].content);
+        if start < history.len() && used + tokens > budget_tokens {
+            break;
+        }
+        used += tokens;
+        start -= 1;
+    }
+    while start < history.len().saturating_sub(1) && history[start].role != "user" {
+        start += 1;
+    }
+    history[start..].to_vec()
+}
+
+/// System prompt and messages for a chat turn. `history` ends with the new question.
+pub fn build_chat_prompt(
+    transcript: &str,
+    history: &[ChatMessage],
+    context_tokens: usize,
+) -> (String, Vec<ChatMessage>) {
+    let budget = context_tokens
+        .saturating_sub(ANSWER_RESERVE_TOKENS)
+        .saturating_sub(rough_token_count(SYSTEM_PROMPT));
+    let messages = trim_history(history, budget / HISTORY_BUDGET_DIVISOR);
+    let history_tokens: usize = messages.iter().map(|m| rough_token_count(&m.content)).sum();
+
+    // Retrieve with the latest questions so follow-ups ("by when?") keep their topic
+    let query = messages
+        .iter()
+        .rev()
+        .filter(|m| m.role == "user")
+        .take(2)
+        .map(|m| m.content.as_str())
+        .collect::<Vec<_>>()
+        .join(" ");
+    let context = select_transcript_context(
+        transcript,
+        &query,
+        budget.saturating_sub(history_tokens).max(MIN_CHUNK_TOKENS),
+    );
+
+    (
+        format!("{}\n\nMeeting transcript:\n{}", SYSTEM_PROMPT, context),
+        messages,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_short_transcript_is_used_whole() {
+        let transcript = "[00:01] Alice: I'll send the pricing deck on Friday.";
+        assert_eq!(select_transcript_context(transcript, "pricing", 1000), transcript);
+    }
+
+    #[test]
+    fn test_long_transcript_keeps_relevant_chunks() {
+        let filler = "We talked about the weather and lunch options at length. ".repeat(40);
+        let transcript = format!(
+            "{}\n[00:30] Alice: I commit to shipping the invoice export by Friday.\n{}",
+            filler, filler
+        );
+
+        let context = select_transcript_context(&transcript, "What did Alice commit to?", 400);
+        assert!(context.contains("invoice export"));
+        assert!(rough_token_count(&context) < rough_token_count(&transcript));
+    }
+
+    #[test]
+    fn test_trim_history_keeps_latest_and_starts_with_user() {
+        let history = vec![
+            ChatMessage::user("a ".repeat(200)),
+            ChatMessage::assistant("b ".repeat(200)),
+            ChatMessage::user("c".to_string()),
+            ChatMessage::assistant("d".to_string()),
+            ChatMessage::user("What about Alice?".to_string()),
+        ];
+
+        let trimmed = trim_history(&history, 50);
+        assert_eq!(trimmed.len(), 3);
+        assert_eq!(trimmed[0].role, "user");
+        assert_eq!(trimmed.last().unwrap().content, "What about Alice?");
+
+        // The new question is kept even when it alone exceeds the budget
+        assert_eq!(trim_history(&history, 0).len(), 1);
+    }
+}
@@ -0,0 +1,15 @@
+/// Meeting chat module - follow-up Q&A about a single meeting
+///
+/// This module contains:
+/// - Prompt building from the meeting transcript, with retrieval of the relevant chunks
+///   when the transcript exceeds the model context
+/// - Tauri commands to send messages, read and clear the conversation of a meeting
+
+pub mod commands;
+pub mod context;
+
+pub use commands::{
+    __cmd__api_clear_meeting_chat, __cmd__api_get_meeting_chat,
+    __cmd__api_send_meeting_chat_message, api_clear_meeting_chat, api_get_meeting_chat,
+    api_send_meeting_chat_message,
+};
@@ -512,3 +512,68 @@ pub async fn get_ollama_model_context(
         }
     }
 }
+
+#[derive(Debug, Deserialize)]
+struct EmbedResponse {
+    embeddings: Vec<Vec<f32>>,
+}
+
+/// Compute embeddings for a batch of texts with Ollama's `/api/embed` endpoint
+///
+/// # Arguments
+/// * `endpoint` - Optional custom Ollama endpoint
+/// * `model_name` - Embedding model (e.g., "nomic-embed-text")
+/// * `inputs` - Texts to embed; the result has one vector per input, in order
+pub async fn generate_embeddings(
+    endpoint: Option<&str>,
+    model_name: &str,
+    inputs: &[String],
+) -> Result<Vec<Vec<f32>>, String> {
+    if inputs.is_empty() {
+        return Ok(Vec::new());
+    }
+    if let Some(ep) = endpoint {
+        validate_endpoint_url(ep).map_err(|e| e.to_string())?;
+    }
+
+    let base_url = endpoint.filter(|ep| !ep.is_empty()).unwrap_or("http://localhost:11434");
+    let url = format!("{}/api/embed", base_url);
+
+    let response = Client::new()
+        .post(&url)
+        .json(&serde_json::json!({ "model": model_name, "input": inputs }))
+        .timeout(Duration::from_secs(120))
+        .send()
+        .await
+        .map_err(|e| {
+            if e.is_timeout() {
+                OllamaError::Timeout.to_string()
+            } else if e.is_connect() {
+                OllamaError::NetworkError(format!("Cannot connect to {}. Please check if the server is running.", base_url)).to_string()
+            } else {
+                OllamaError::NetworkError(e.to_string()).to_string()
+            }
+        })?;
+
+    if !response.status().is_success() {
+        let status = response.status();
+        let error_text = response.text().await.unwrap_or_else(|_| "Unknown error".to_string());
+        return Err(OllamaError::ServerError(format!("HTTP {}: {}", status, error_text)).to_string());
+    }
+
+    let embed_response: EmbedResponse = response
+        .json()
+        .await
+        .map_err(|e| OllamaError::ParseError(e.to_string()).to_string())?;
+
+    if embed_response.embeddings.len() != inputs.len() {
+        return Err(OllamaError::ParseError(format!(
+            "expected {} embeddings, got {}",
+            inputs.len(),
+            embed_response.embeddings.len()
+        ))
+        .to_string());
+    }
+
+    Ok(embed_response.embeddings)
+}
@@ -10,6 +10,8 @@ use regex::Regex;
 use std::fs;
 use std::path::Path;
 
+use crate::audio::transcription::confidence::argmax_probability;
+
 pub type DecoderState = (Array3<f32>, Array3<f32>);
 
 const SUBSAMPLING_FACTOR: usize = 8;
@@ -24,6 +26,8 @@ pub struct TimestampedResult {
     pub text: String,
     pub timestamps: Vec<f32>,
     pub tokens: Vec<String>,
+    /// Softmax probability of each emitted token
+    pub probabilities: Vec<f32>,
 }
 
 #[derive(thiserror::Error, Debug)]
@@ -331,9 +335,9 @@ impl ParakeetModel {
         // Decode for each batch item
         let mut results = Vec::new();
         for (encodings, &encodings_len) in encoder_out.outer_iter().zip(encoder_out_lens.iter()) {
-            let (tokens, timestamps) =
+            let (tokens, timestamps, probabilities) =
                 self.decode_sequence(&encodings.view(), encodings_len as usize)?;
-            let result = self.decode_tokens(tokens, timestamps);
+            let result = self.decode_tokens(tokens, timestamps, probabilities);
             results.push(result);
         }
 
@@ -344,10 +348,11 @@ impl ParakeetModel {
         &mut self,
         encodings: &ArrayViewD<f32>, // [time_steps, 1024]
         encodings_len: usize,
-    ) -> Result<(Vec<i32>, Vec<usize>), ParakeetError> {
+    ) -> Result<(Vec<i32>, Vec<usize>, Vec<f32>), ParakeetError> {
         let mut prev_state = self.create_decoder_state()?;
         let mut tokens = Vec::new();
         let mut timestamps = Vec::new();
+        let mut probabilities = Vec::new();
 
         let mut t = 0;
         let mut emitted_tokens = 0;
@@ -381,18 +386,16 @@ impl ParakeetModel {
                 vocab_logits_slice
             };
 
-            // Get argmax token from vocabulary logits only
-            let token = vocab_logits
-                .iter()
-                .enumerate()
-                .max_by(|(_, a), (_, b)| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal))
-                .map(|(idx, _)| idx as i32)
-                .unwrap_or(self.blank_idx);
+            // Get argmax token (and its probability) from vocabulary logits only
+            let (token, probability) = argmax_probability(vocab_logits)
+                .map(|(idx, p)| (idx as i32, p))
+                .unwrap_or((self.blank_idx, 0.0));
 
             if token != self.blank_idx {
                 prev_state = new_state;
                 tokens.push(token);
                 timestamps.push(t);
+                probabilities.push(probability);
                 emitted_tokens += 1;
             }
 
@@ -411,21 +414,27 @@ impl ParakeetModel {
             );
         }
 
-        Ok((tokens, timestamps))
+        Ok((tokens, timestamps, probabilities))
     }
 
-    fn decode_tokens(&self, ids: Vec<i32>, timestamps: Vec<usize>) -> TimestampedResult {
-        let tokens: Vec<String> = ids
-            .iter()
-            .filter_map(|&id| {
-                let idx = id as usize;
-                if idx < self.vocab.len() {
-                    Some(self.vocab[idx].clone())
-                } else {
-                    None
-                }
-            })
-            .collect();
+    fn decode_tokens(
+        &self,
+        ids: Vec<i32>,
+        timestamps: Vec<usize>,
+        probabilities: Vec<f32>,
+    ) -> TimestampedResult {
+        // Drop out-of-vocabulary ids together with their timestamps and probabilities
+        let mut tokens = Vec::with_capacity(ids.len());
+        let mut kept_timestamps = Vec::with_capacity(ids.len());
+        let mut kept_probabilities = Vec::with_capacity(ids.len());
+        for ((&id, &t), &p) in ids.iter().zip(&timestamps).zip(&probabilities) {
+            let idx = id as usize;
+            if idx < self.vocab.len() {
+                tokens.push(self.vocab[idx].clone());
+                kept_timestamps.push(t);
+                kept_probabilities.push(p);
+            }
+        }
 
         let text = match &*DECODE_SPACE_RE {
             Ok(regex) => regex
@@ -440,7 +449,7 @@ impl ParakeetModel {
             Err(_) => tokens.join(""), // Fallback if regex failed to compile
         };
 
-        let float_timestamps: Vec<f32> = timestamps
+        let float_timestamps: Vec<f32> = kept_timestamps
             .iter()
             .map(|&t| WINDOW_SIZE * SUBSAMPLING_FACTOR as f32 * t as f32)
             .collect();
@@ -449,6 +458,7 @@ impl ParakeetModel {
             text,
             timestamps: float_timestamps,
             tokens,
+            probabilities: kept_probabilities,
         }
     }
 
@@ -1,4 +1,4 @@
-use crate::parakeet_engine::model::ParakeetModel;
+use crate::parakeet_engine::model::{ParakeetModel, TimestampedResult};
 use anyhow::{anyhow, Result};
 use serde::{Deserialize, Serialize};
 use std::collections::{HashMap, HashSet};
@@ -319,6 +319,15 @@ impl ParakeetEngine {
 
     /// Transcribe audio samples using the loaded Parakeet model
     pub async fn transcribe_audio(&self, audio_data: Vec<f32>) -> Result<String> {
+        let result = self.transcribe_audio_with_timestamps(audio_data).await?;
+        Ok(result.text)
+    }
+
+    /// Transcribe audio and keep the per-token timestamps computed by the decoder
+    pub async fn transcribe_audio_with_timestamps(
+        &self,
+        audio_data: Vec<f32>,
+    ) -> Result<TimestampedResult> {
         let mut model_guard = self.current_model.write().await;
         let model = model_guard
             .as_mut()
@@ -338,7 +347,7 @@ impl ParakeetEngine {
 
         log::debug!("Parakeet transcription result: '{}'", result.text);
 
-        Ok(result.text)
+        Ok(result)
     }
 
     /// Get the models directory path
@@ -0,0 +1,121 @@
+// secrets/cipher.rs
+//
+// XChaCha20-Poly1305 with a random 256-bit key generated once per install. Ciphertexts
+// are stored as "v1:" + base64(nonce || ciphertext) so the format can change later.
+
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine;
+use chacha20poly1305::aead::{Aead, AeadCore, KeyInit, OsRng};
+use chacha20poly1305::{Key, XChaCha20Poly1305, XNonce};
+use std::io::Write;
+use std::path::Path;
+
+const FORMAT_PREFIX: &str = "v1:";
+const KEY_LEN: usize = 32;
+const NONCE_LEN: usize = 24;
+
+pub struct SecretCipher {
+    cipher: XChaCha20Poly1305,
+}
+
+impl SecretCipher {
+    pub fn new(key: &[u8; KEY_LEN]) -> Self {
+        Self {
+            cipher: XChaCha20Poly1305::new(Key::from_slice(key)),
+        }
+    }
+
+    /// Loads the install key from `path`, creating it (readable by the owner only) if missing
+    pub fn load_or_create(path: &Path) -> Result<Self, String> {
+        if path.exists() {
+            let bytes = std::fs::read(path)
+                .map_err(|e| format!("Failed to read secret key {}: {}", path.display(), e))?;
+            let key: [u8; KEY_LEN] = bytes
+                .as_slice()
+                .try_into()
+                .map_err(|_| format!("Secret key {} is corrupted", path.display()))?;
+            return Ok(Self::new(&key));
+        }
+
+        let key = XChaCha20Poly1305::generate_key(&mut OsRng);
+        let mut options = std::fs::OpenOptions::new();
+        options.write(true).create_new(true);
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::OpenOptionsExt;
+            options.mode(0o600);
+        }
+        let mut file = options
+            .open(path)
+            .map_err(|e| format!("Failed to create secret key {}: {}", path.display(), e))?;
+        file.write_all(&key)
+            .and_then(|_| file.sync_all())
+            .map_err(|e| format!("Failed to write secret key {}: {}", path.display(), e))?;
+
+        Ok(Self {
+            cipher: XChaCha20Poly1305::new(&key),
+        })
+    }
+
+    pub fn encrypt(&self, plaintext: &str) -> Result<String, String> {
+        let nonce = XChaCha20Poly1305::generate_nonce(&mut OsRng);
+        let ciphertext = self
+            .cipher
+            .encrypt(&nonce, plaintext.as_bytes())
+            .map_err(|_| "Failed to encrypt secret".to_string())?;
+
+        let mut payload = Vec::with_capacity(NONCE_LEN + ciphertext.len());
+        payload.extend_from_slice(&nonce);
+        payload.extend_from_slice(&ciphertext);
+        Ok(format!("{}{}", FORMAT_PREFIX, BASE64.encode(payload)))
+    }
+
+    pub fn decrypt(&self, stored: &str) -> Result<String, String> {
+        let encoded = stored
+            .strip_prefix(FORMAT_PREFIX)
+            .ok_or_else(|| "Unknown secret format".to_string())?;
+        let payload = BASE64
+            .decode(encoded)
+            .map_err(|_| "Secret is not valid base64".to_string())?;
+        if payload.len() < NONCE_LEN {
+            return Err("Secret is truncated".to_string());
+        }
+
+        let (nonce, ciphertext) = payload.split_at(NONCE_LEN);
+        let plaintext = self
+            .cipher
+            .decrypt(XNonce::from_slice(nonce), ciphertext)
+            // Wrong key (e.g. the key file was replaced) or tampered value
+            .map_err(|_| "Secret can't be decrypted with this install's key".to_string())?;
+        String::from_utf8(plaintext).map_err(|_| "Secret is not valid UTF-8".to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip_and_wrong_key() {
+        let cipher = SecretCipher::new(&[7u8; KEY_LEN]);
+        let stored = cipher.encrypt("sk-test-1234").unwrap();
+        assert!(stored.starts_with(FORMAT_PREFIX));
+        assert!(!stored.contains("sk-test"));
+        assert_eq!(cipher.decrypt(&stored).unwrap(), "sk-test-1234");
+        // Random nonce: the same key encrypts differently every time
+        assert_ne!(cipher.encrypt("sk-test-1234").unwrap(), stored);
+
+        let other = SecretCipher::new(&[8u8; KEY_LEN]);
+        assert!(other.decrypt(&stored).is_err());
+        assert!(cipher.decrypt("sk-plaintext").is_err());
+    }
+
+    #[test]
+    fn test_load_or_create_reuses_key() {
+        let path = std::env::temp_dir().join(format!("meetily-secret-key-{}", uuid::Uuid::new_v4()));
+        let stored = SecretCipher::load_or_create(&path).unwrap().encrypt("value").unwrap();
+        let reloaded = SecretCipher::load_or_create(&path).unwrap().decrypt(&stored);
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(reloaded.unwrap(), "value");
+    }
+}
@@ -0,0 +1,117 @@
+/// Encrypted storage for API keys.
+///
+/// Keys live in the `secrets` table, encrypted with a random per-install key kept next to
+/// the database (`secret.key`). Only masked values are sent to the webview; plaintext is
+/// read with `get_secret` by the summary service and the transcription providers.
+pub mod cipher;
+
+use crate::database::repositories::secret::SecretsRepository;
+use cipher::SecretCipher;
+use log::{info, warn};
+use once_cell::sync::OnceCell;
+use sqlx::SqlitePool;
+use std::path::Path;
+
+const KEY_FILE: &str = "secret.key";
+const MASK: &str = "••••••••";
+
+static CIPHER: OnceCell<SecretCipher> = OnceCell::new();
+
+/// Loads (or creates) the install key in the app data directory
+pub fn init(app_data_dir: &Path) -> Result<(), String> {
+    if CIPHER.get().is_some() {
+        return Ok(());
+    }
+    let cipher = SecretCipher::load_or_create(&app_data_dir.join(KEY_FILE))?;
+    let _ = CIPHER.set(cipher);
+    Ok(())
+}
+
+fn cipher() -> Result<&'static SecretCipher, String> {
+    CIPHER
+        .get()
+        .ok_or_else(|| "Secret store is not initialized".to_string())
+}
+
+/// Display form of a secret: the last four characters of long values, nothing of short ones
+pub fn mask(value: &str) -> String {
+    let chars: Vec<char> = value.chars().collect();
+    if chars.len() <= 12 {
+        return MASK.to_string();
+    }
+    let tail: String = chars[chars.len() - 4..].iter().collect();
+    format!("{}{}", MASK, tail)
+}
+
+/// Whether `value` is a masked value sent back unchanged by the frontend
+pub fn is_masked(value: &str) -> bool {
+    value.starts_with(MASK)
+}
+
+pub async fn set_secret(pool: &SqlitePool, name: &str, value: &str) -> Result<(), String> {
+    let encrypted = cipher()?.encrypt(value)?;
+    SecretsRepository::upsert(pool, name, &encrypted)
+        .await
+        .map_err(|e| format!("Failed to store secret {}: {}", name, e))
+}
+
+/// Decrypted value of a secret. Keep the result out of anything returned to the webview.
+pub async fn get_secret(pool: &SqlitePool, name: &str) -> Result<Option<String>, String> {
+    let stored = SecretsRepository::get(pool, name)
+        .await
+        .map_err(|e| format!("Failed to load secret {}: {}", name, e))?;
+    match stored {
+        Some(stored) => cipher()?.decrypt(&stored).map(Some),
+        None => Ok(None),
+    }
+}
+
+/// Masked value of a secret. An undecryptable secret reads as missing so the user is asked
+/// for the key again.
+pub async fn get_masked_secret(pool: &SqlitePool, name: &str) -> Result<Option<String>, String> {
+    match get_secret(pool, name).await {
+        Ok(value) => Ok(value.filter(|v| !v.is_empty()).map(|v| mask(&v))),
+        Err(e) => {
+            warn!("Secret {} is unreadable: {}", name, e);
+            Ok(None)
+        }
+    }
+}
+
+pub async fn delete_secret(pool: &SqlitePool, name: &str) -> Result<(), String> {
+    SecretsRepository::delete(pool, name)
+        .await
+        .map_err(|e| format!("Failed to delete secret {}: {}", name, e))
+}
+
+/// Encrypts values the migration moved out of the settings tables in plaintext
+pub async fn encrypt_migrated_secrets(pool: &SqlitePool) -> Result<(), String> {
+    let pending = SecretsRepository::get_unencrypted(pool)
+        .await
+        .map_err(|e| e.to_string())?;
+    if pending.is_empty() {
+        return Ok(());
+    }
+    let cipher = cipher()?;
+    for (name, value) in &pending {
+        let encrypted = cipher.encrypt(value)?;
+        SecretsRepository::upsert(pool, name, &encrypted)
+            .await
+            .map_err(|e| format!("Failed to encrypt secret {}: {}", name, e))?;
+    }
+    info!("🔐 Encrypted {} API keys moved out of the settings tables", pending.len());
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mask() {
+        assert_eq!(mask("sk-proj-abcdefghijkl1234"), "••••••••1234");
+        assert_eq!(mask("short"), MASK);
+        assert!(is_masked(&mask("sk-proj-abcdefghijkl1234")));
+        assert!(!is_masked("sk-proj-abcdefghijkl1234"));
+    }
+}
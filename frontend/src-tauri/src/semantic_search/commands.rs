@@ -0,0 +1,268 @@
+use crate::database::repositories::{
+    embedding::EmbeddingsRepository, setting::SettingsRepository,
+};
+use crate::semantic_search::index::{self, SemanticHit, DEFAULT_EMBEDDING_MODEL};
+use crate::state::AppState;
+use crate::summary::llm_client::generate_summary;
+use crate::summary::service::{ResolvedProvider, SummaryService};
+use log::{error as log_error, info as log_info, warn as log_warn};
+use reqwest::Client;
+use serde::{Deserialize, Serialize};
+use sqlx::SqlitePool;
+use tauri::{AppHandle, Emitter, Runtime};
+
+const DEFAULT_TOP_K: usize = 8;
+const MAX_TOP_K: usize = 50;
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SemanticIndexStatus {
+    pub model: String,
+    /// Pieces of text embedded by this run
+    pub embedded: usize,
+    /// Pieces still without an embedding (non-zero only if indexing stopped early)
+    pub pending: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SemanticIndexProgress {
+    pub embedded: usize,
+    pub total: usize,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct MeetingCitation {
+    /// Number used as `[n]` in the answer
+    pub index: usize,
+    #[serde(flatten)]
+    pub hit: SemanticHit,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct MeetingAnswer {
+    pub answer: String,
+    pub citations: Vec<MeetingCitation>,
+}
+
+fn embedding_model_or_default(embedding_model: Option<String>) -> String {
+    embedding_model
+        .filter(|m| !m.trim().is_empty())
+        .unwrap_or_else(|| DEFAULT_EMBEDDING_MODEL.to_string())
+}
+
+/// Brings the index up to date, emitting `semantic-index-progress` events
+async fn update_index<R: Runtime>(
+    app: &AppHandle<R>,
+    pool: &SqlitePool,
+    ollama_endpoint: Option<&str>,
+    model: &str,
+) -> Result<usize, String> {
+    let mut on_progress = |embedded: usize, total: usize| {
+        if let Err(e) = app.emit(
+            "semantic-index-progress",
+            SemanticIndexProgress { embedded, total },
+        ) {
+            log_warn!("Failed to emit semantic-index-progress: {}", e);
+        }
+    };
+    index::index_pending(pool, ollama_endpoint, model, &mut on_progress).await
+}
+
+/// Numbered context block for the answer prompt
+fn build_context(hits: &[SemanticHit]) -> String {
+    hits.iter()
+        .enumerate()
+        .map(|(i, hit)| {
+            let source = match (&hit.kind[..], &hit.timestamp) {
+                ("transcript", Some(timestamp)) => format!("transcript at {}", timestamp),
+                ("transcript", None) => "transcript".to_string(),
+                _ => "summary".to_string(),
+            };
+            format!(
+                "[{}] Meeting \"{}\" ({}), {}:\n{}",
+                i + 1,
+                hit.meeting_title,
+                hit.meeting_date,
+                source,
+                hit.text.trim()
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n\n")
+}
+
+/// Embeds all transcript segments and summaries that aren't indexed yet
+#[tauri::command]
+pub async fn api_build_semantic_index<R: Runtime>(
+    app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    embedding_model: Option<String>,
+) -> Result<SemanticIndexStatus, String> {
+    let pool = state.db_manager.pool();
+    let model = embedding_model_or_default(embedding_model);
+    log_info!("api_build_semantic_index called with model: {}", model);
+
+    let ollama_endpoint = SummaryService::ollama_endpoint(pool).await;
+    let embedded = update_index(&app, pool, ollama_endpoint.as_deref(), &model)
+        .await
+        .map_err(|e| {
+            log_error!("Failed to build semantic index: {}", e);
+            e
+        })?;
+    let pending = EmbeddingsRepository::count_pending(pool, &model)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    Ok(SemanticIndexStatus {
+        model,
+        embedded,
+        pending,
+    })
+}
+
+/// Finds the transcript segments and summary passages closest in meaning to `query`
+#[tauri::command]
+pub async fn api_semantic_search<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    query: String,
+    top_k: Option<usize>,
+    meeting_id: Option<String>,
+    embedding_model: Option<String>,
+) -> Result<Vec<SemanticHit>, String> {
+    if query.trim().is_empty() {
+        return Ok(Vec::new());
+    }
+    let pool = state.db_manager.pool();
+    let model = embedding_model_or_default(embedding_model);
+    let ollama_endpoint = SummaryService::ollama_endpoint(pool).await;
+
+    index::search(
+        pool,
+        ollama_endpoint.as_deref(),
+        &model,
+        query.trim(),
+        top_k.unwrap_or(DEFAULT_TOP_K).clamp(1, MAX_TOP_K),
+        meeting_id.as_deref(),
+    )
+    .await
+    .map_err(|e| {
+        log_error!("Semantic search failed: {}", e);
+        e
+    })
+}
+
+/// Answers a question across meetings: retrieves the most relevant passages and asks the
+/// configured summary model to answer from them, citing passages as `[n]`
+#[tauri::command]
+pub async fn api_ask_meetings<R: Runtime>(
+    app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    question: String,
+    top_k: Option<usize>,
+    meeting_id: Option<String>,
+    embedding_model: Option<String>,
+) -> Result<MeetingAnswer, String> {
+    let question = question.trim();
+    if question.is_empty() {
+        return Err("Question is empty".to_string());
+    }
+    log_info!("api_ask_meetings called: {}", question);
+
+    let pool = state.db_manager.pool();
+    let model = embedding_model_or_default(embedding_model);
+    let ollama_endpoint = SummaryService::ollama_endpoint(pool).await;
+
+    // Pick up meetings recorded since the last indexing run
+    update_index(&app, pool, ollama_endpoint.as_deref(), &model).await?;
+
+    let hits = index::search(
+        pool,
+        ollama_endpoint.as_deref(),
+        &model,
+        question,
+        top_k.unwrap_or(DEFAULT_TOP_K).clamp(1, MAX_TOP_K),
+        meeting_id.as_deref(),
+    )
+    .await?;
+    if hits.is_empty() {
+        return Ok(MeetingAnswer {
+            answer: "No meeting content has been indexed yet.".to_string(),
+            citations: Vec::new(),
+        });
+    }
+
+    let config = SettingsRepository::get_model_config(pool)
+        .await
+        .map_err(|e| e.to_string())?
+        .ok_or_else(|| "No summary model configured".to_string())?;
+    let ResolvedProvider {
+        provider,
+        api_key,
+        ollama_endpoint: llm_endpoint,
+    } = SummaryService::resolve_provider(pool, &config.provider).await?;
+
+    let system_prompt = "You answer questions about the user's meetings using only the numbered \
+        excerpts provided. Cite the excerpts you rely on as [n] right after the statement they \
+        support. If the excerpts don't contain the answer, say so instead of guessing.";
+    let user_prompt = format!(
+        "Meeting excerpts:\n\n{}\n\nQuestion: {}",
+        build_context(&hits),
+        question
+    );
+
+    let answer = generate_summary(
+        &Client::new(),
+        &provider,
+        &config.model,
+        &api_key,
+        system_prompt,
+        &user_prompt,
+        llm_endpoint.as_deref(),
+    )
+    .await
+    .map_err(|e| {
+        log_error!("Failed to answer meeting question: {}", e);
+        e
+    })?;
+
+    Ok(MeetingAnswer {
+        answer: answer.trim().to_string(),
+        citations: hits
+            .into_iter()
+            .enumerate()
+            .map(|(i, hit)| MeetingCitation { index: i + 1, hit })
+            .collect(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hit(kind: &str, timestamp: Option<&str>, text: &str) -> SemanticHit {
+        SemanticHit {
+            meeting_id: "m1".to_string(),
+            meeting_title: "Pricing sync".to_string(),
+            meeting_date: "2025-10-01".to_string(),
+            kind: kind.to_string(),
+            ref_id: "r1".to_string(),
+            text: text.to_string(),
+            timestamp: timestamp.map(str::to_string),
+            audio_start_time: None,
+            score: 0.9,
+        }
+    }
+
+    #[test]
+    fn test_build_context_numbers_excerpts() {
+        let context = build_context(&[
+            hit("transcript", Some("00:12:03"), " We keep the price. "),
+            hit("summary", None, "- Price unchanged"),
+        ]);
+        assert_eq!(
+            context,
+            "[1] Meeting \"Pricing sync\" (2025-10-01), transcript at 00:12:03:\nWe keep the price.\n\n\
+             [2] Meeting \"Pricing sync\" (2025-10-01), summary:\n- Price unchanged"
+        );
+    }
+}
@@ -0,0 +1,250 @@
+// semantic_search/index.rs
+//
+// Embedding index over transcript segments and summaries. Vectors are computed locally
+// with an Ollama embedding model and stored L2-normalized in the `embeddings` table, so
+// cosine similarity is a plain dot product at query time.
+
+use crate::database::repositories::embedding::{
+    EmbeddingsRepository, NewEmbedding, StoredEmbedding,
+};
+use crate::ollama::generate_embeddings;
+use crate::summary::processor::chunk_text;
+use crate::summary::versions::summary_markdown;
+use serde::{Deserialize, Serialize};
+use sqlx::SqlitePool;
+use std::cmp::Ordering;
+use tracing::{info, warn};
+
+/// Embedding model used when the caller doesn't pick one
+pub const DEFAULT_EMBEDDING_MODEL: &str = "nomic-embed-text";
+
+/// Texts sent to Ollama per embedding request
+const EMBED_BATCH_SIZE: usize = 32;
+/// Summaries are split into chunks of about this many tokens before embedding
+const SUMMARY_CHUNK_TOKENS: usize = 200;
+const SUMMARY_CHUNK_OVERLAP_TOKENS: usize = 20;
+
+/// One retrieved piece of text with the meeting it came from
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SemanticHit {
+    pub meeting_id: String,
+    pub meeting_title: String,
+    pub meeting_date: String,
+    /// "transcript" or "summary"
+    pub kind: String,
+    /// Transcript id for transcript hits, meeting id for summary hits
+    pub ref_id: String,
+    pub text: String,
+    pub timestamp: Option<String>,
+    pub audio_start_time: Option<f64>,
+    /// Cosine similarity with the query (-1.0 ..= 1.0)
+    pub score: f32,
+}
+
+/// Scales a vector to unit length (zero vectors are left unchanged)
+pub fn normalize(vector: &mut [f32]) {
+    let norm = vector.iter().map(|v| v * v).sum::<f32>().sqrt();
+    if norm > f32::EPSILON {
+        vector.iter_mut().for_each(|v| *v /= norm);
+    }
+}
+
+/// Normalized vector as little-endian f32 bytes
+pub fn encode_vector(vector: &[f32]) -> Vec<u8> {
+    let mut normalized = vector.to_vec();
+    normalize(&mut normalized);
+    normalized.iter().flat_map(|v| v.to_le_bytes()).collect()
+}
+
+pub fn decode_vector(bytes: &[u8]) -> Vec<f32> {
+    bytes
+        .chunks_exact(4)
+        .map(|b| f32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+        .collect()
+}
+
+pub fn dot(a: &[f32], b: &[f32]) -> f32 {
+    a.iter().zip(b).map(|(x, y)| x * y).sum()
+}
+
+/// Indices and scores of the `top_k` vectors most similar to `query`.
+/// Vectors of a different dimension (another model version) are ignored.
+pub fn top_k_similar(query: &[f32], vectors: &[Vec<f32>], top_k: usize) -> Vec<(usize, f32)> {
+    let mut scored: Vec<(usize, f32)> = vectors
+        .iter()
+        .enumerate()
+        .filter(|(_, v)| v.len() == query.len())
+        .map(|(i, v)| (i, dot(query, v)))
+        .collect();
+    scored.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(Ordering::Equal));
+    scored.truncate(top_k);
+    scored
+}
+
+/// Embeds every transcript segment and summary that has no embedding for `model` yet.
+/// `on_progress(embedded, total)` is called after each batch. Returns the number of
+/// pieces embedded.
+pub async fn index_pending(
+    pool: &SqlitePool,
+    ollama_endpoint: Option<&str>,
+    model: &str,
+    on_progress: &mut (dyn FnMut(usize, usize) + Send),
+) -> Result<usize, String> {
+    let total = EmbeddingsRepository::count_pending(pool, model)
+        .await
+        .map_err(|e| e.to_string())? as usize;
+    if total == 0 {
+        return Ok(0);
+    }
+    info!("🔎 Indexing {} pending items with embedding model {}", total, model);
+
+    let mut embedded = 0;
+
+    // Transcript segments, one embedding per segment
+    loop {
+        let pending = EmbeddingsRepository::pending_transcripts(pool, model, EMBED_BATCH_SIZE as i64)
+            .await
+            .map_err(|e| e.to_string())?;
+        if pending.is_empty() {
+            break;
+        }
+
+        let texts: Vec<String> = pending.iter().map(|(_, _, text)| text.trim().to_string()).collect();
+        let vectors = generate_embeddings(ollama_endpoint, model, &texts).await?;
+        let rows: Vec<NewEmbedding> = pending
+            .into_iter()
+            .zip(texts)
+            .zip(vectors)
+            .map(|(((transcript_id, meeting_id, _), content), vector)| NewEmbedding {
+                meeting_id,
+                kind: "transcript",
+                ref_id: transcript_id,
+                chunk_index: 0,
+                content,
+                dimensions: vector.len() as i64,
+                vector: encode_vector(&vector),
+            })
+            .collect();
+
+        EmbeddingsRepository::insert_embeddings(pool, model, &rows)
+            .await
+            .map_err(|e| e.to_string())?;
+        embedded += rows.len();
+        on_progress(embedded.min(total), total);
+    }
+
+    // Summaries, split into chunks so a hit points at a specific part of the summary
+    let summaries = EmbeddingsRepository::pending_summaries(pool, model)
+        .await
+        .map_err(|e| e.to_string())?;
+    for (meeting_id, result) in summaries {
+        let markdown = summary_markdown(&result);
+        let chunks = chunk_text(&markdown, SUMMARY_CHUNK_TOKENS, SUMMARY_CHUNK_OVERLAP_TOKENS);
+        if chunks.is_empty() {
+            continue;
+        }
+
+        let mut rows = Vec::with_capacity(chunks.len());
+        for (batch_index, batch) in chunks.chunks(EMBED_BATCH_SIZE).enumerate() {
+            let vectors = generate_embeddings(ollama_endpoint, model, batch).await?;
+            for (i, (content, vector)) in batch.iter().zip(vectors).enumerate() {
+                rows.push(NewEmbedding {
+                    meeting_id: meeting_id.clone(),
+                    kind: "summary",
+                    ref_id: meeting_id.clone(),
+                    chunk_index: (batch_index * EMBED_BATCH_SIZE + i) as i64,
+                    content: content.clone(),
+                    dimensions: vector.len() as i64,
+                    vector: encode_vector(&vector),
+                });
+            }
+        }
+
+        EmbeddingsRepository::insert_embeddings(pool, model, &rows)
+            .await
+            .map_err(|e| e.to_string())?;
+        embedded += 1;
+        on_progress(embedded.min(total), total);
+    }
+
+    info!("✅ Semantic index up to date ({} items embedded)", embedded);
+    Ok(embedded)
+}
+
+/// Embeds `query` and returns the `top_k` most similar indexed pieces of text
+pub async fn search(
+    pool: &SqlitePool,
+    ollama_endpoint: Option<&str>,
+    model: &str,
+    query: &str,
+    top_k: usize,
+    meeting_id: Option<&str>,
+) -> Result<Vec<SemanticHit>, String> {
+    let query_vector = generate_embeddings(ollama_endpoint, model, &[query.to_string()])
+        .await?
+        .pop()
+        .ok_or_else(|| "Embedding model returned no vector for the query".to_string())?;
+    let query_vector = decode_vector(&encode_vector(&query_vector));
+
+    let stored = EmbeddingsRepository::load_embeddings(pool, model, meeting_id)
+        .await
+        .map_err(|e| e.to_string())?;
+    if stored.is_empty() {
+        warn!("Semantic index for model {} is empty", model);
+        return Ok(Vec::new());
+    }
+
+    let vectors: Vec<Vec<f32>> = stored.iter().map(|e| decode_vector(&e.vector)).collect();
+    let ranked = top_k_similar(&query_vector, &vectors, top_k);
+
+    let mut stored: Vec<Option<StoredEmbedding>> = stored.into_iter().map(Some).collect();
+    Ok(ranked
+        .into_iter()
+        .filter_map(|(i, score)| {
+            stored[i].take().map(|e| SemanticHit {
+                meeting_id: e.meeting_id,
+                meeting_title: e.meeting_title,
+                meeting_date: e.meeting_created_at,
+                kind: e.kind,
+                ref_id: e.ref_id,
+                text: e.content,
+                timestamp: e.timestamp,
+                audio_start_time: e.audio_start_time,
+                score,
+            })
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encode_roundtrip_normalizes() {
+        let decoded = decode_vector(&encode_vector(&[3.0, 4.0]));
+        assert_eq!(decoded.len(), 2);
+        assert!((decoded[0] - 0.6).abs() < 1e-6);
+        assert!((decoded[1] - 0.8).abs() < 1e-6);
+        assert!((dot(&decoded, &decoded) - 1.0).abs() < 1e-6);
+
+        // Zero vectors must not turn into NaN
+        assert_eq!(decode_vector(&encode_vector(&[0.0, 0.0])), vec![0.0, 0.0]);
+    }
+
+    #[test]
+    fn test_top_k_similar() {
+        let query = vec![1.0, 0.0];
+        let vectors = vec![
+            vec![0.0, 1.0],
+            vec![1.0, 0.0],
+            vec![0.6, 0.8],
+            vec![1.0, 0.0, 0.0], // different dimension, ignored
+        ];
+
+        let ranked = top_k_similar(&query, &vectors, 2);
+        assert_eq!(ranked.iter().map(|(i, _)| *i).collect::<Vec<_>>(), vec![1, 2]);
+        assert!((ranked[1].1 - 0.6).abs() < 1e-6);
+        assert_eq!(top_k_similar(&query, &vectors, 10).len(), 3);
+    }
+}
@@ -0,0 +1,15 @@
+/// Semantic search module - embedding index over transcripts and summaries
+///
+/// This module contains:
+/// - Index maintenance: embeds new transcript segments and summary chunks through Ollama
+///   and stores the vectors in SQLite
+/// - Vector search (cosine similarity) over the stored embeddings
+/// - Tauri commands for semantic search and retrieval-augmented Q&A across meetings
+
+pub mod commands;
+pub mod index;
+
+pub use commands::{
+    __cmd__api_ask_meetings, __cmd__api_build_semantic_index, __cmd__api_semantic_search,
+    api_ask_meetings, api_build_semantic_index, api_semantic_search,
+};
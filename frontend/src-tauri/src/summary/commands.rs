@@ -1,8 +1,9 @@
 use crate::database::repositories::{
     meeting::MeetingsRepository, summary::SummaryProcessesRepository,
-    transcript_chunk::TranscriptChunksRepository,
+    summary_version::SummaryVersionsRepository, transcript_chunk::TranscriptChunksRepository,
 };
 use crate::state::AppState;
+use crate::summary::jobs;
 use crate::summary::service::SummaryService;
 use log::{error as log_error, info as log_info, warn as log_warn};
 use serde::{Deserialize, Serialize};
@@ -18,6 +19,9 @@ pub struct SummaryResponse {
     pub end: Option<String>,
     pub data: Option<serde_json::Value>,
     pub error: Option<String>,
+    /// Latest streamed partial result while the summary is still being generated
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub progress: Option<serde_json::Value>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -46,6 +50,9 @@ pub async fn api_save_meeting_summary<R: Runtime>(
     match SummaryProcessesRepository::update_meeting_summary(pool, &meeting_id, &summary).await {
         Ok(true) => {
             log_info!("Summary saved successfully for meeting_id: {}", meeting_id);
+            if let Err(e) = SummaryVersionsRepository::record_edit(pool, &meeting_id, &summary).await {
+                log_warn!("Failed to record edited summary version for {}: {}", meeting_id, e);
+            }
             Ok(serde_json::json!({
                 "message": "Meeting summary saved successfully"
             }))
@@ -102,6 +109,17 @@ pub async fn api_get_summary<R: Runtime>(
                 None
             };
 
+            // Partial output of a running generation (see SummaryService progress writer)
+            let progress = if status == "pending" || status == "processing" {
+                process
+                    .metadata
+                    .as_deref()
+                    .and_then(|m| serde_json::from_str::<serde_json::Value>(m).ok())
+                    .and_then(|m| m.get("progress").cloned())
+            } else {
+                None
+            };
+
             // Fetch meeting title from database
             let meeting_name = match MeetingsRepository::get_meeting(pool, &meeting_id).await {
                 Ok(Some(meeting_details)) => {
@@ -126,6 +144,7 @@ pub async fn api_get_summary<R: Runtime>(
                 end: process.end_time.map(|t| t.to_rfc3339()),
                 data,
                 error,
+                progress,
             };
 
             log_info!(
@@ -154,6 +173,7 @@ pub async fn api_get_summary<R: Runtime>(
                 end: None,
                 data: None,
                 error: None,
+                progress: None,
             })
         }
         Err(e) => {
@@ -189,6 +209,9 @@ pub async fn api_process_transcript<R: Runtime>(
         &model
     );
 
+    // Only one job per meeting; a re-click must not reset a running process
+    let (job_guard, cancel_token) = jobs::register_job(&m_id)?;
+
     let pool = state.db_manager.pool().clone();
     let final_prompt = custom_prompt.unwrap_or_else(|| "".to_string());
     let final_template_id = template_id.unwrap_or_else(|| "daily_standup".to_string());
@@ -221,6 +244,8 @@ pub async fn api_process_transcript<R: Runtime>(
     // Spawn background task for actual processing
     let meeting_id_clone = m_id.clone();
     tauri::async_runtime::spawn(async move {
+        // Unregisters the job when processing ends, however it ends
+        let _job_guard = job_guard;
         SummaryService::process_transcript_background(
             app,
             pool,
@@ -230,6 +255,7 @@ pub async fn api_process_transcript<R: Runtime>(
             model_name,
             final_prompt,
             final_template_id,
+            cancel_token,
         )
         .await;
     });
@@ -241,3 +267,24 @@ pub async fn api_process_transcript<R: Runtime>(
         process_id: m_id,
     })
 }
+
+/// Cancels the running summary job of a meeting.
+///
+/// In-flight LLM requests are abandoned; chunk summaries finished so far stay cached,
+/// so the next generation for the same transcript and model resumes from them.
+#[tauri::command]
+pub async fn api_cancel_summary<R: Runtime>(
+    _app: AppHandle<R>,
+    meeting_id: String,
+) -> Result<serde_json::Value, String> {
+    log_info!("api_cancel_summary called for meeting_id: {}", meeting_id);
+
+    if !jobs::cancel_job(&meeting_id) {
+        log_warn!("No running summary job for meeting_id: {}", meeting_id);
+        return Err("No summary is being generated for this meeting".to_string());
+    }
+
+    Ok(serde_json::json!({
+        "message": "Summary cancellation requested"
+    }))
+}
@@ -0,0 +1,290 @@
+use crate::database::repositories::setting::SettingsRepository;
+use crate::secrets;
+use crate::state::AppState;
+use crate::summary::llm_client::CustomOpenAIConfig;
+use log::{error as log_error, info as log_info};
+use reqwest::Client;
+use serde::{Deserialize, Serialize};
+use sqlx::SqlitePool;
+use std::collections::HashMap;
+use std::time::Duration;
+use tauri::{AppHandle, Runtime};
+
+/// Model listing should be quick; a hanging server is reported as unreachable
+const MODELS_REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CustomOpenAIModel {
+    pub id: String,
+    pub owned_by: Option<String>,
+    /// Context window when the server reports it (vLLM `max_model_len`, others `context_length`)
+    pub context_length: Option<usize>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CustomProviderSettings {
+    #[serde(rename = "baseUrl")]
+    pub base_url: String,
+    pub headers: HashMap<String, String>,
+    #[serde(rename = "apiKey")]
+    pub api_key: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ModelsResponse {
+    data: Vec<ApiModel>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ApiModel {
+    id: String,
+    #[serde(default)]
+    owned_by: Option<String>,
+    #[serde(default)]
+    max_model_len: Option<usize>,
+    #[serde(default)]
+    context_length: Option<usize>,
+}
+
+/// Load the saved custom provider configuration from settings
+pub async fn load_custom_config(pool: &SqlitePool) -> Result<CustomOpenAIConfig, String> {
+    let setting = SettingsRepository::get_model_config(pool)
+        .await
+        .map_err(|e| format!("Failed to load custom provider settings: {}", e))?
+        .ok_or_else(|| "Custom provider is not configured".to_string())?;
+
+    let base_url = setting
+        .custom_base_url
+        .filter(|url| !url.trim().is_empty())
+        .ok_or_else(|| "Custom provider has no base URL configured".to_string())?;
+
+    CustomOpenAIConfig::validated(&base_url, load_headers(pool).await?)
+}
+
+/// Decrypted extra headers of the custom provider
+async fn load_headers(pool: &SqlitePool) -> Result<HashMap<String, String>, String> {
+    let headers_json = SettingsRepository::get_custom_headers(pool, "custom")
+        .await
+        .map_err(|e| format!("Failed to load custom provider headers: {}", e))?;
+    parse_headers(headers_json.as_deref())
+}
+
+/// Replaces masked header values sent back by the form with the stored values.
+/// Headers whose stored value is gone are dropped.
+async fn unmask_headers(
+    pool: &SqlitePool,
+    headers: HashMap<String, String>,
+) -> Result<HashMap<String, String>, String> {
+    if !headers.values().any(|value| secrets::is_masked(value)) {
+        return Ok(headers);
+    }
+    let stored = load_headers(pool).await?;
+    Ok(headers
+        .into_iter()
+        .filter_map(|(name, value)| {
+            if secrets::is_masked(&value) {
+                stored.get(&name).map(|stored| (name, stored.clone()))
+            } else {
+                Some((name, value))
+            }
+        })
+        .collect())
+}
+
+fn parse_headers(headers_json: Option<&str>) -> Result<HashMap<String, String>, String> {
+    match headers_json.map(str::trim) {
+        None | Some("") => Ok(HashMap::new()),
+        Some(json) => serde_json::from_str(json)
+            .map_err(|e| format!("Invalid custom provider headers: {}", e)),
+    }
+}
+
+/// List models from the server's `/v1/models` endpoint
+pub async fn list_models(
+    client: &Client,
+    config: &CustomOpenAIConfig,
+    api_key: Option<&str>,
+) -> Result<Vec<CustomOpenAIModel>, String> {
+    let mut request = client
+        .get(config.models_url())
+        .headers(config.header_map()?)
+        .timeout(MODELS_REQUEST_TIMEOUT);
+    if let Some(key) = api_key.filter(|k| !k.is_empty()) {
+        request = request.bearer_auth(key);
+    }
+
+    let response = request
+        .send()
+        .await
+        .map_err(|e| format!("Failed to reach {}: {}", config.models_url(), e))?;
+
+    if !response.status().is_success() {
+        let status = response.status();
+        let body = response.text().await.unwrap_or_default();
+        return Err(format!(
+            "Model listing failed with status {}: {}",
+            status,
+            body.chars().take(300).collect::<String>()
+        ));
+    }
+
+    let body = response.text().await.map_err(|e| e.to_string())?;
+    parse_models(&body)
+}
+
+fn parse_models(body: &str) -> Result<Vec<CustomOpenAIModel>, String> {
+    let response: ModelsResponse = serde_json::from_str(body)
+        .map_err(|e| format!("Server did not return an OpenAI-compatible model list: {}", e))?;
+
+    let mut models: Vec<CustomOpenAIModel> = response
+        .data
+        .into_iter()
+        .map(|m| CustomOpenAIModel {
+            context_length: m.max_model_len.or(m.context_length),
+            id: m.id,
+            owned_by: m.owned_by,
+        })
+        .collect();
+    models.sort_by(|a, b| a.id.cmp(&b.id));
+    Ok(models)
+}
+
+/// Fetch the models of a custom OpenAI-compatible server (also used as a connection test).
+/// A masked `api_key` or header value (the form already holds the saved one) means the
+/// stored value.
+#[tauri::command]
+pub async fn get_custom_openai_models<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    base_url: String,
+    api_key: Option<String>,
+    headers: Option<HashMap<String, String>>,
+) -> Result<Vec<CustomOpenAIModel>, String> {
+    let headers = unmask_headers(state.db_manager.pool(), headers.unwrap_or_default()).await?;
+    let config = CustomOpenAIConfig::validated(&base_url, headers)?;
+    let api_key = match api_key {
+        Some(key) if secrets::is_masked(&key) => {
+            SettingsRepository::get_api_key(state.db_manager.pool(), "custom")
+                .await
+                .map_err(|e| e.to_string())?
+        }
+        other => other,
+    };
+    let models = list_models(&Client::new(), &config, api_key.as_deref()).await?;
+    log_info!(
+        "Found {} models on custom provider {}",
+        models.len(),
+        config.base_url
+    );
+    Ok(models)
+}
+
+#[tauri::command]
+pub async fn api_get_custom_provider_config<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+) -> Result<Option<CustomProviderSettings>, String> {
+    let pool = state.db_manager.pool();
+    let setting = SettingsRepository::get_model_config(pool)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    match setting {
+        Some(setting) => match setting.custom_base_url {
+            Some(base_url) => Ok(Some(CustomProviderSettings {
+                base_url,
+                headers: load_headers(pool)
+                    .await?
+                    .into_iter()
+                    .map(|(name, value)| (name, secrets::mask(&value)))
+                    .collect(),
+                api_key: SettingsRepository::get_masked_api_key(pool, "custom")
+                    .await
+                    .map_err(|e| e.to_string())?,
+            })),
+            None => Ok(None),
+        },
+        None => Ok(None),
+    }
+}
+
+/// Validate and save the custom provider's base URL, headers and optional API key.
+/// Headers are stored encrypted like the key; masked values keep the stored value.
+/// An empty API key removes the stored one (keyless local servers).
+#[tauri::command]
+pub async fn api_save_custom_provider_config<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    base_url: String,
+    api_key: Option<String>,
+    headers: Option<HashMap<String, String>>,
+) -> Result<serde_json::Value, String> {
+    let pool = state.db_manager.pool();
+    let headers = unmask_headers(pool, headers.unwrap_or_default()).await?;
+    let config = CustomOpenAIConfig::validated(&base_url, headers)?;
+    log_info!(
+        "💾 Saving custom provider config: base_url={}, {} extra headers",
+        config.base_url,
+        config.headers.len()
+    );
+
+    let headers_json = if config.headers.is_empty() {
+        None
+    } else {
+        Some(serde_json::to_string(&config.headers).map_err(|e| e.to_string())?)
+    };
+
+    if let Err(e) = SettingsRepository::save_custom_provider_config(pool, &config.base_url).await {
+        log_error!("❌ Failed to save custom provider config: {}", e);
+        return Err(e.to_string());
+    }
+    SettingsRepository::save_custom_headers(pool, "custom", headers_json.as_deref())
+        .await
+        .map_err(|e| e.to_string())?;
+
+    match api_key.as_deref().map(str::trim) {
+        Some("") => SettingsRepository::delete_api_key(pool, "custom")
+            .await
+            .map_err(|e| e.to_string())?,
+        Some(key) => SettingsRepository::save_api_key(pool, "custom", key)
+            .await
+            .map_err(|e| e.to_string())?,
+        None => {}
+    }
+
+    Ok(serde_json::json!({ "status": "success", "message": "Custom provider saved successfully" }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_models_reads_context_length() {
+        let body = r#"{"object": "list", "data": [
+            {"id": "qwen2.5-7b", "object": "model", "owned_by": "vllm", "max_model_len": 32768},
+            {"id": "llama-3.1-8b", "object": "model", "context_length": 8192},
+            {"id": "mistral"}
+        ]}"#;
+
+        let models = parse_models(body).unwrap();
+        assert_eq!(models.len(), 3);
+        assert_eq!(models[0].id, "llama-3.1-8b");
+        assert_eq!(models[0].context_length, Some(8192));
+        assert_eq!(models[2].context_length, Some(32768));
+        assert_eq!(models[1].owned_by, None);
+    }
+
+    #[test]
+    fn test_parse_models_rejects_non_openai_body() {
+        assert!(parse_models(r#"{"models": []}"#).is_err());
+    }
+
+    #[test]
+    fn test_parse_headers() {
+        assert!(parse_headers(None).unwrap().is_empty());
+        assert!(parse_headers(Some("  ")).unwrap().is_empty());
+        assert_eq!(parse_headers(Some(r#"{"X-Team": "a"}"#)).unwrap()["X-Team"], "a");
+        assert!(parse_headers(Some("not json")).is_err());
+    }
+}
@@ -0,0 +1,133 @@
+// summary/jobs.rs
+//
+// Registry of running summary jobs, keyed by meeting_id, with a cancellation token
+// per job so `api_cancel_summary` can stop a background generation.
+
+use once_cell::sync::Lazy;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+use tokio::sync::watch;
+
+static SUMMARY_JOBS: Lazy<Mutex<HashMap<String, RegisteredJob>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+static NEXT_JOB_ID: AtomicU64 = AtomicU64::new(1);
+
+struct RegisteredJob {
+    id: u64,
+    cancel: watch::Sender<bool>,
+}
+
+/// Cheap, cloneable cancellation signal shared with a running job
+#[derive(Clone)]
+pub struct CancelToken {
+    rx: watch::Receiver<bool>,
+}
+
+impl CancelToken {
+    pub fn is_cancelled(&self) -> bool {
+        *self.rx.borrow()
+    }
+
+    /// Resolves once the job is cancelled (never, if it finishes first)
+    pub async fn cancelled(&self) {
+        let mut rx = self.rx.clone();
+        // An error means the registry entry is gone, i.e. the job already finished
+        if rx.wait_for(|cancelled| *cancelled).await.is_err() {
+            std::future::pending::<()>().await;
+        }
+    }
+}
+
+/// Keeps a job registered while alive; dropping it unregisters the job
+pub struct SummaryJobGuard {
+    meeting_id: String,
+    id: u64,
+}
+
+impl Drop for SummaryJobGuard {
+    fn drop(&mut self) {
+        let mut jobs = SUMMARY_JOBS.lock().unwrap();
+        // A newer job for the same meeting may have replaced this one
+        if jobs.get(&self.meeting_id).map(|job| job.id) == Some(self.id) {
+            jobs.remove(&self.meeting_id);
+        }
+    }
+}
+
+/// Register a new job for `meeting_id`.
+/// Fails while another job for the same meeting is still running.
+pub fn register_job(meeting_id: &str) -> Result<(SummaryJobGuard, CancelToken), String> {
+    let mut jobs = SUMMARY_JOBS.lock().unwrap();
+    if jobs.contains_key(meeting_id) {
+        return Err("A summary is already being generated for this meeting".to_string());
+    }
+
+    let id = NEXT_JOB_ID.fetch_add(1, Ordering::Relaxed);
+    let (cancel, rx) = watch::channel(false);
+    jobs.insert(meeting_id.to_string(), RegisteredJob { id, cancel });
+
+    Ok((
+        SummaryJobGuard {
+            meeting_id: meeting_id.to_string(),
+            id,
+        },
+        CancelToken { rx },
+    ))
+}
+
+/// Signal cancellation of the running job for `meeting_id`.
+/// Returns false when no job is running.
+pub fn cancel_job(meeting_id: &str) -> bool {
+    let jobs = SUMMARY_JOBS.lock().unwrap();
+    match jobs.get(meeting_id) {
+        Some(job) => {
+            let _ = job.cancel.send(true);
+            true
+        }
+        None => false,
+    }
+}
+
+pub fn is_job_running(meeting_id: &str) -> bool {
+    SUMMARY_JOBS.lock().unwrap().contains_key(meeting_id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[tokio::test]
+    async fn test_cancel_wakes_running_job() {
+        let (guard, token) = register_job("meeting-cancel").unwrap();
+        assert!(register_job("meeting-cancel").is_err());
+        assert!(is_job_running("meeting-cancel"));
+
+        let waiter = tokio::spawn({
+            let token = token.clone();
+            async move { token.cancelled().await }
+        });
+        assert!(cancel_job("meeting-cancel"));
+        tokio::time::timeout(Duration::from_secs(1), waiter)
+            .await
+            .expect("cancellation not observed")
+            .unwrap();
+        assert!(token.is_cancelled());
+
+        drop(guard);
+        assert!(!is_job_running("meeting-cancel"));
+        assert!(!cancel_job("meeting-cancel"));
+    }
+
+    #[test]
+    fn test_stale_guard_keeps_newer_job() {
+        let (old_guard, _) = register_job("meeting-replace").unwrap();
+        // Simulate the entry being replaced by a newer job
+        SUMMARY_JOBS.lock().unwrap().remove("meeting-replace");
+        let (_new_guard, _) = register_job("meeting-replace").unwrap();
+
+        drop(old_guard);
+        assert!(is_job_running("meeting-replace"));
+    }
+}
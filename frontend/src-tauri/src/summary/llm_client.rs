@@ -1,14 +1,32 @@
+use futures_util::StreamExt;
 use reqwest::{header, Client};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use tracing::info;
 
 // Generic structure for OpenAI-compatible API chat messages
-#[derive(Debug, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ChatMessage {
     pub role: String,
     pub content: String,
 }
 
+impl ChatMessage {
+    pub fn user(content: impl Into<String>) -> Self {
+        Self {
+            role: "user".to_string(),
+            content: content.into(),
+        }
+    }
+
+    pub fn assistant(content: impl Into<String>) -> Self {
+        Self {
+            role: "assistant".to_string(),
+            content: content.into(),
+        }
+    }
+}
+
 // Generic structure for OpenAI-compatible API chat requests
 #[derive(Debug, Serialize)]
 pub struct ChatRequest {
@@ -52,6 +70,85 @@ pub struct ClaudeChatContent {
     pub text: String,
 }
 
+/// Connection settings of a self-hosted OpenAI-compatible server
+/// (vLLM, LM Studio, llama.cpp server, LiteLLM gateway, ...)
+#[derive(Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct CustomOpenAIConfig {
+    /// Server root or API root, e.g. `http://localhost:8000` or `http://gateway/v1`
+    pub base_url: String,
+    /// Extra headers sent with every request (e.g. gateway routing or tenant headers)
+    #[serde(default)]
+    pub headers: HashMap<String, String>,
+}
+
+impl CustomOpenAIConfig {
+    /// Validate the URL and headers and return a normalized copy
+    pub fn validated(base_url: &str, headers: HashMap<String, String>) -> Result<Self, String> {
+        let base_url = base_url.trim().trim_end_matches('/').to_string();
+        if base_url.is_empty() {
+            return Err("Base URL is required for the custom provider".to_string());
+        }
+        let parsed = reqwest::Url::parse(&base_url)
+            .map_err(|e| format!("Invalid base URL '{}': {}", base_url, e))?;
+        if parsed.scheme() != "http" && parsed.scheme() != "https" {
+            return Err(format!("Base URL must use http or https, got '{}'", parsed.scheme()));
+        }
+
+        let headers: HashMap<String, String> = headers
+            .into_iter()
+            .map(|(name, value)| (name.trim().to_string(), value.trim().to_string()))
+            .filter(|(name, _)| !name.is_empty())
+            .collect();
+
+        let config = Self { base_url, headers };
+        config.header_map()?;
+        Ok(config)
+    }
+
+    /// API root ending in `/v1` (appended when the user entered the server root)
+    pub fn api_root(&self) -> String {
+        let base = self.base_url.trim_end_matches('/');
+        if base.ends_with("/v1") {
+            base.to_string()
+        } else {
+            format!("{}/v1", base)
+        }
+    }
+
+    pub fn chat_completions_url(&self) -> String {
+        format!("{}/chat/completions", self.api_root())
+    }
+
+    pub fn models_url(&self) -> String {
+        format!("{}/models", self.api_root())
+    }
+
+    /// Extra headers as a `HeaderMap` (fails on invalid header names or values)
+    pub fn header_map(&self) -> Result<header::HeaderMap, String> {
+        let mut header_map = header::HeaderMap::new();
+        for (name, value) in &self.headers {
+            let name = header::HeaderName::from_bytes(name.as_bytes())
+                .map_err(|_| format!("Invalid header name '{}'", name))?;
+            let value = header::HeaderValue::from_str(value)
+                .map_err(|_| format!("Invalid value for header '{}'", name))?;
+            header_map.insert(name, value);
+        }
+        Ok(header_map)
+    }
+}
+
+// Header values often carry gateway credentials; keep them out of logs
+impl std::fmt::Debug for CustomOpenAIConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut header_names: Vec<&String> = self.headers.keys().collect();
+        header_names.sort();
+        f.debug_struct("CustomOpenAIConfig")
+            .field("base_url", &self.base_url)
+            .field("headers", &header_names)
+            .finish()
+    }
+}
+
 /// LLM Provider enumeration for multi-provider support
 #[derive(Debug, Clone, PartialEq)]
 pub enum LLMProvider {
@@ -60,10 +157,12 @@ pub enum LLMProvider {
     Groq,
     Ollama,
     OpenRouter,
+    Custom(CustomOpenAIConfig),
 }
 
 impl LLMProvider {
-    /// Parse provider from string (case-insensitive)
+    /// Parse provider from string (case-insensitive).
+    /// `custom` comes back with an empty config; callers fill it from settings.
     pub fn from_str(s: &str) -> Result<Self, String> {
         match s.to_lowercase().as_str() {
             "openai" => Ok(Self::OpenAI),
@@ -71,33 +170,112 @@ impl LLMProvider {
             "groq" => Ok(Self::Groq),
             "ollama" => Ok(Self::Ollama),
             "openrouter" => Ok(Self::OpenRouter),
+            "custom" => Ok(Self::Custom(CustomOpenAIConfig::default())),
             _ => Err(format!("Unsupported LLM provider: {}", s)),
         }
     }
+
+    /// Providers that may run without an API key
+    pub fn api_key_optional(&self) -> bool {
+        matches!(self, Self::Ollama | Self::Custom(_))
+    }
 }
 
-/// Generates a summary using the specified LLM provider
-///
-/// # Arguments
-/// * `client` - Reqwest HTTP client (reused for performance)
-/// * `provider` - The LLM provider to use
-/// * `model_name` - The specific model to use (e.g., "gpt-4", "claude-3-opus")
-/// * `api_key` - API key for the provider (not needed for Ollama)
-/// * `system_prompt` - System instructions for the LLM
-/// * `user_prompt` - User query/content to process
-/// * `ollama_endpoint` - Optional custom Ollama endpoint (defaults to localhost:11434)
-///
-/// # Returns
-/// The generated summary text or an error message
-pub async fn generate_summary(
-    client: &Client,
+/// Wire format of a streaming chat response
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum StreamFormat {
+    /// OpenAI-style server-sent events (`data: {...}` / `data: [DONE]`)
+    OpenAISse,
+    /// Anthropic server-sent events (`content_block_delta`, `message_stop`, ...)
+    AnthropicSse,
+    /// Ollama native `/api/chat` newline-delimited JSON
+    OllamaNdjson,
+}
+
+impl StreamFormat {
+    pub fn for_provider(provider: &LLMProvider) -> Self {
+        match provider {
+            LLMProvider::Claude => Self::AnthropicSse,
+            LLMProvider::Ollama => Self::OllamaNdjson,
+            _ => Self::OpenAISse,
+        }
+    }
+}
+
+/// One parsed line of a streaming response
+#[derive(Debug, PartialEq)]
+pub enum StreamEvent {
+    Delta(String),
+    Done,
+    Skip,
+}
+
+/// Parse a single line of a streaming response body
+pub fn parse_stream_line(format: StreamFormat, line: &str) -> Result<StreamEvent, String> {
+    let line = line.trim();
+    if line.is_empty() {
+        return Ok(StreamEvent::Skip);
+    }
+
+    match format {
+        StreamFormat::OllamaNdjson => {
+            let value: serde_json::Value = serde_json::from_str(line)
+                .map_err(|e| format!("Invalid stream chunk from Ollama: {}", e))?;
+            if let Some(error) = value.get("error").and_then(|e| e.as_str()) {
+                return Err(format!("LLM API request failed: {}", error));
+            }
+            let content = value["message"]["content"].as_str().unwrap_or_default();
+            if !content.is_empty() {
+                Ok(StreamEvent::Delta(content.to_string()))
+            } else if value["done"].as_bool() == Some(true) {
+                Ok(StreamEvent::Done)
+            } else {
+                Ok(StreamEvent::Skip)
+            }
+        }
+        StreamFormat::OpenAISse | StreamFormat::AnthropicSse => {
+            // Comments, event names and ids carry nothing we need
+            let Some(data) = line.strip_prefix("data:").map(str::trim) else {
+                return Ok(StreamEvent::Skip);
+            };
+            if data == "[DONE]" {
+                return Ok(StreamEvent::Done);
+            }
+            let value: serde_json::Value = serde_json::from_str(data)
+                .map_err(|e| format!("Invalid stream chunk from LLM: {}", e))?;
+            if let Some(error) = value.get("error") {
+                let message = error["message"].as_str().unwrap_or("unknown error");
+                return Err(format!("LLM API request failed: {}", message));
+            }
+
+            let delta = if format == StreamFormat::AnthropicSse {
+                match value["type"].as_str() {
+                    Some("message_stop") => return Ok(StreamEvent::Done),
+                    Some("content_block_delta") => value["delta"]["text"].as_str(),
+                    _ => None,
+                }
+            } else {
+                value["choices"][0]["delta"]["content"].as_str()
+            };
+
+            Ok(match delta {
+                Some(text) if !text.is_empty() => StreamEvent::Delta(text.to_string()),
+                _ => StreamEvent::Skip,
+            })
+        }
+    }
+}
+
+/// Endpoint, headers and JSON body of a chat request for `provider`
+fn build_chat_request(
     provider: &LLMProvider,
     model_name: &str,
     api_key: &str,
     system_prompt: &str,
-    user_prompt: &str,
+    messages: &[ChatMessage],
     ollama_endpoint: Option<&str>,
-) -> Result<String, String> {
+    stream: bool,
+) -> Result<(String, header::HeaderMap, serde_json::Value), String> {
     let (api_url, mut headers) = match provider {
         LLMProvider::OpenAI => (
             "https://api.openai.com/v1/chat/completions".to_string(),
@@ -115,10 +293,9 @@ pub async fn generate_summary(
             let host = ollama_endpoint
                 .map(|s| s.to_string())
                 .unwrap_or_else(|| "http://localhost:11434".to_string());
-            (
-                format!("{}/v1/chat/completions", host),
-                header::HeaderMap::new(),
-            )
+            // Streaming uses the native API (NDJSON); the OpenAI-compatible one otherwise
+            let path = if stream { "api/chat" } else { "v1/chat/completions" };
+            (format!("{}/{}", host, path), header::HeaderMap::new())
         }
         LLMProvider::Claude => {
             let mut header_map = header::HeaderMap::new();
@@ -136,10 +313,17 @@ pub async fn generate_summary(
             );
             ("https://api.anthropic.com/v1/messages".to_string(), header_map)
         }
+        LLMProvider::Custom(config) => {
+            if config.base_url.is_empty() {
+                return Err("Custom provider has no base URL configured".to_string());
+            }
+            (config.chat_completions_url(), config.header_map()?)
+        }
     };
 
-    // Add authorization header for non-Claude providers
-    if provider != &LLMProvider::Claude {
+    // Add authorization header for non-Claude providers (keyless custom servers get none)
+    let skip_auth = matches!(provider, LLMProvider::Custom(_)) && api_key.is_empty();
+    if provider != &LLMProvider::Claude && !skip_auth {
         headers.insert(
             header::AUTHORIZATION,
             format!("Bearer {}", api_key)
@@ -154,32 +338,88 @@ pub async fn generate_summary(
             .map_err(|_| "Invalid content type".to_string())?,
     );
 
-    // Build request body based on provider
-    let request_body = if provider != &LLMProvider::Claude {
+    // Build request body based on provider: Claude takes the system prompt separately,
+    // OpenAI-compatible APIs as the first message
+    let mut request_body = if provider != &LLMProvider::Claude {
+        let mut chat_messages = Vec::with_capacity(messages.len() + 1);
+        chat_messages.push(ChatMessage {
+            role: "system".to_string(),
+            content: system_prompt.to_string(),
+        });
+        chat_messages.extend_from_slice(messages);
         serde_json::json!(ChatRequest {
             model: model_name.to_string(),
-            messages: vec![
-                ChatMessage {
-                    role: "system".to_string(),
-                    content: system_prompt.to_string(),
-                },
-                ChatMessage {
-                    role: "user".to_string(),
-                    content: user_prompt.to_string(),
-                }
-            ],
+            messages: chat_messages,
         })
     } else {
         serde_json::json!(ClaudeRequest {
             system: system_prompt.to_string(),
             model: model_name.to_string(),
             max_tokens: 2048,
-            messages: vec![ChatMessage {
-                role: "user".to_string(),
-                content: user_prompt.to_string(),
-            }]
+            messages: messages.to_vec(),
         })
     };
+    if stream {
+        request_body["stream"] = serde_json::Value::Bool(true);
+    }
+
+    Ok((api_url, headers, request_body))
+}
+
+/// Generates a summary using the specified LLM provider
+///
+/// # Arguments
+/// * `client` - Reqwest HTTP client (reused for performance)
+/// * `provider` - The LLM provider to use
+/// * `model_name` - The specific model to use (e.g., "gpt-4", "claude-3-opus")
+/// * `api_key` - API key for the provider (not needed for Ollama)
+/// * `system_prompt` - System instructions for the LLM
+/// * `user_prompt` - User query/content to process
+/// * `ollama_endpoint` - Optional custom Ollama endpoint (defaults to localhost:11434)
+///
+/// # Returns
+/// The generated summary text or an error message
+pub async fn generate_summary(
+    client: &Client,
+    provider: &LLMProvider,
+    model_name: &str,
+    api_key: &str,
+    system_prompt: &str,
+    user_prompt: &str,
+    ollama_endpoint: Option<&str>,
+) -> Result<String, String> {
+    generate_chat(
+        client,
+        provider,
+        model_name,
+        api_key,
+        system_prompt,
+        &[ChatMessage::user(user_prompt)],
+        ollama_endpoint,
+    )
+    .await
+}
+
+/// Multi-turn variant of [`generate_summary`]: sends the whole conversation
+/// (`user`/`assistant` messages, oldest first) after the system prompt
+pub async fn generate_chat(
+    client: &Client,
+    provider: &LLMProvider,
+    model_name: &str,
+    api_key: &str,
+    system_prompt: &str,
+    messages: &[ChatMessage],
+    ollama_endpoint: Option<&str>,
+) -> Result<String, String> {
+    let (api_url, headers, request_body) = build_chat_request(
+        provider,
+        model_name,
+        api_key,
+        system_prompt,
+        messages,
+        ollama_endpoint,
+        false,
+    )?;
 
     info!("🐞 LLM Request to {}: model={}", provider_name(provider), model_name);
 
@@ -235,6 +475,120 @@ pub async fn generate_summary(
     }
 }
 
+/// Streaming variant of [`generate_summary`]: SSE for OpenAI-compatible providers,
+/// Anthropic streaming events for Claude and NDJSON for Ollama.
+/// `on_text` is called with the full text generated so far after every delta.
+#[allow(clippy::too_many_arguments)]
+pub async fn generate_summary_stream(
+    client: &Client,
+    provider: &LLMProvider,
+    model_name: &str,
+    api_key: &str,
+    system_prompt: &str,
+    user_prompt: &str,
+    ollama_endpoint: Option<&str>,
+    on_text: &mut (dyn FnMut(&str) + Send),
+) -> Result<String, String> {
+    generate_chat_stream(
+        client,
+        provider,
+        model_name,
+        api_key,
+        system_prompt,
+        &[ChatMessage::user(user_prompt)],
+        ollama_endpoint,
+        on_text,
+    )
+    .await
+}
+
+/// Streaming variant of [`generate_chat`]
+#[allow(clippy::too_many_arguments)]
+pub async fn generate_chat_stream(
+    client: &Client,
+    provider: &LLMProvider,
+    model_name: &str,
+    api_key: &str,
+    system_prompt: &str,
+    messages: &[ChatMessage],
+    ollama_endpoint: Option<&str>,
+    on_text: &mut (dyn FnMut(&str) + Send),
+) -> Result<String, String> {
+    let (api_url, headers, request_body) = build_chat_request(
+        provider,
+        model_name,
+        api_key,
+        system_prompt,
+        messages,
+        ollama_endpoint,
+        true,
+    )?;
+    let format = StreamFormat::for_provider(provider);
+
+    info!(
+        "🐞 Streaming LLM Request to {}: model={}",
+        provider_name(provider),
+        model_name
+    );
+
+    let response = client
+        .post(api_url)
+        .headers(headers)
+        .json(&request_body)
+        .send()
+        .await
+        .map_err(|e| format!("Failed to send request to LLM: {}", e))?;
+
+    if !response.status().is_success() {
+        let error_body = response
+            .text()
+            .await
+            .unwrap_or_else(|_| "Unknown error".to_string());
+        return Err(format!("LLM API request failed: {}", error_body));
+    }
+
+    let mut text = String::new();
+    let mut pending: Vec<u8> = Vec::new();
+    let mut stream = response.bytes_stream();
+
+    'read: while let Some(chunk) = stream.next().await {
+        let chunk = chunk.map_err(|e| format!("LLM stream interrupted: {}", e))?;
+        pending.extend_from_slice(&chunk);
+
+        // Only decode complete lines so multi-byte characters are never split
+        while let Some(newline) = pending.iter().position(|&b| b == b'\n') {
+            let line: Vec<u8> = pending.drain(..=newline).collect();
+            match parse_stream_line(format, &String::from_utf8_lossy(&line))? {
+                StreamEvent::Delta(delta) => {
+                    text.push_str(&delta);
+                    on_text(&text);
+                }
+                StreamEvent::Done => break 'read,
+                StreamEvent::Skip => {}
+            }
+        }
+    }
+
+    // A final line without trailing newline
+    if let StreamEvent::Delta(delta) = parse_stream_line(format, &String::from_utf8_lossy(&pending))
+        .unwrap_or(StreamEvent::Skip)
+    {
+        text.push_str(&delta);
+        on_text(&text);
+    }
+
+    info!(
+        "🐞 LLM stream finished from {} ({} chars)",
+        provider_name(provider),
+        text.len()
+    );
+
+    if text.trim().is_empty() {
+        return Err("No content in LLM response".to_string());
+    }
+    Ok(text.trim().to_string())
+}
+
 /// Helper function to get provider name for logging
 fn provider_name(provider: &LLMProvider) -> &str {
     match provider {
@@ -243,5 +597,170 @@ fn provider_name(provider: &LLMProvider) -> &str {
         LLMProvider::Groq => "Groq",
         LLMProvider::Ollama => "Ollama",
         LLMProvider::OpenRouter => "OpenRouter",
+        LLMProvider::Custom(_) => "Custom",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_custom_config_urls() {
+        let root = CustomOpenAIConfig::validated("http://localhost:8000/", HashMap::new()).unwrap();
+        assert_eq!(root.chat_completions_url(), "http://localhost:8000/v1/chat/completions");
+        assert_eq!(root.models_url(), "http://localhost:8000/v1/models");
+
+        let api = CustomOpenAIConfig::validated("https://gateway.local/v1", HashMap::new()).unwrap();
+        assert_eq!(api.chat_completions_url(), "https://gateway.local/v1/chat/completions");
+    }
+
+    #[test]
+    fn test_custom_config_validation() {
+        assert!(CustomOpenAIConfig::validated("", HashMap::new()).is_err());
+        assert!(CustomOpenAIConfig::validated("localhost:8000", HashMap::new()).is_err());
+        assert!(CustomOpenAIConfig::validated("ftp://host", HashMap::new()).is_err());
+
+        let bad_header = HashMap::from([("Bad Header".to_string(), "x".to_string())]);
+        assert!(CustomOpenAIConfig::validated("http://host", bad_header).is_err());
+
+        let headers = HashMap::from([
+            (" X-Tenant ".to_string(), " team-a ".to_string()),
+            ("".to_string(), "ignored".to_string()),
+        ]);
+        let config = CustomOpenAIConfig::validated("http://host", headers).unwrap();
+        assert_eq!(config.headers.len(), 1);
+        assert_eq!(config.headers["X-Tenant"], "team-a");
+    }
+
+    #[test]
+    fn test_custom_config_debug_hides_header_values() {
+        let headers = HashMap::from([("Authorization".to_string(), "Bearer gateway-token".to_string())]);
+        let config = CustomOpenAIConfig::validated("http://host", headers).unwrap();
+        let logged = format!("{:?}", LLMProvider::Custom(config));
+        assert!(logged.contains("Authorization"));
+        assert!(!logged.contains("gateway-token"));
+    }
+
+    #[test]
+    fn test_parse_openai_sse_lines() {
+        let format = StreamFormat::OpenAISse;
+        assert_eq!(
+            parse_stream_line(format, r#"data: {"choices":[{"delta":{"content":"Hel"}}]}"#).unwrap(),
+            StreamEvent::Delta("Hel".to_string())
+        );
+        assert_eq!(
+            parse_stream_line(format, r#"data: {"choices":[{"delta":{"role":"assistant"}}]}"#).unwrap(),
+            StreamEvent::Skip
+        );
+        assert_eq!(parse_stream_line(format, ": keep-alive").unwrap(), StreamEvent::Skip);
+        assert_eq!(parse_stream_line(format, "data: [DONE]").unwrap(), StreamEvent::Done);
+        assert!(parse_stream_line(format, r#"data: {"error":{"message":"rate limited"}}"#).is_err());
+    }
+
+    #[test]
+    fn test_parse_anthropic_sse_lines() {
+        let format = StreamFormat::AnthropicSse;
+        assert_eq!(parse_stream_line(format, "event: content_block_delta").unwrap(), StreamEvent::Skip);
+        assert_eq!(
+            parse_stream_line(
+                format,
+                r##"data: {"type":"content_block_delta","index":0,"delta":{"type":"text_delta","text":"# Sum"}}"##
+            )
+            .unwrap(),
+            StreamEvent::Delta("# Sum".to_string())
+        );
+        assert_eq!(
+            parse_stream_line(format, r#"data: {"type":"message_start","message":{}}"#).unwrap(),
+            StreamEvent::Skip
+        );
+        assert_eq!(
+            parse_stream_line(format, r#"data: {"type":"message_stop"}"#).unwrap(),
+            StreamEvent::Done
+        );
+        assert!(parse_stream_line(
+            format,
+            r#"data: {"type":"error","error":{"type":"overloaded_error","message":"Overloaded"}}"#
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_parse_ollama_ndjson_lines() {
+        let format = StreamFormat::OllamaNdjson;
+        assert_eq!(
+            parse_stream_line(format, r#"{"message":{"role":"assistant","content":"Hi"},"done":false}"#).unwrap(),
+            StreamEvent::Delta("Hi".to_string())
+        );
+        assert_eq!(
+            parse_stream_line(format, r#"{"message":{"role":"assistant","content":""},"done":true}"#).unwrap(),
+            StreamEvent::Done
+        );
+        assert!(parse_stream_line(format, r#"{"error":"model not found"}"#).is_err());
+    }
+
+    #[test]
+    fn test_stream_request_uses_native_ollama_api() {
+        let (url, _, body) = build_chat_request(
+            &LLMProvider::Ollama,
+            "llama3.2",
+            "",
+            "system",
+            &[ChatMessage::user("user")],
+            None,
+            true,
+        )
+        .unwrap();
+        assert_eq!(url, "http://localhost:11434/api/chat");
+        assert_eq!(body["stream"], true);
+
+        let (url, _, body) =
+            build_chat_request(
+            &LLMProvider::Ollama,
+            "llama3.2",
+            "",
+            "s",
+            &[ChatMessage::user("u")],
+            None,
+            false,
+        )
+        .unwrap();
+        assert_eq!(url, "http://localhost:11434/v1/chat/completions");
+        assert!(body.get("stream").is_none());
+    }
+
+    #[test]
+    fn test_multi_turn_request_bodies() {
+        let turns = [
+            ChatMessage::user("Who owns the launch?"),
+            ChatMessage::assistant("Alice."),
+            ChatMessage::user("By when?"),
+        ];
+
+        let (_, _, body) =
+            build_chat_request(&LLMProvider::OpenAI, "gpt-4o", "key", "sys", &turns, None, false)
+                .unwrap();
+        let messages = body["messages"].as_array().unwrap();
+        assert_eq!(messages.len(), 4);
+        assert_eq!(messages[0]["role"], "system");
+        assert_eq!(messages[2]["role"], "assistant");
+        assert_eq!(messages[3]["content"], "By when?");
+
+        let (_, _, body) =
+            build_chat_request(&LLMProvider::Claude, "claude", "key", "sys", &turns, None, false)
+                .unwrap();
+        assert_eq!(body["system"], "sys");
+        assert_eq!(body["messages"].as_array().unwrap().len(), 3);
+        assert_eq!(body["messages"][0]["role"], "user");
+    }
+
+    #[test]
+    fn test_from_str_custom() {
+        assert_eq!(
+            LLMProvider::from_str("Custom").unwrap(),
+            LLMProvider::Custom(CustomOpenAIConfig::default())
+        );
+        assert!(LLMProvider::from_str("custom").unwrap().api_key_optional());
+        assert!(!LLMProvider::from_str("openai").unwrap().api_key_optional());
     }
 }
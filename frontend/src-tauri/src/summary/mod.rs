@@ -1,23 +1,37 @@
 /// Summary module - handles all meeting summary generation functionality
 ///
 /// This module contains:
-/// - LLM client for communicating with various AI providers (OpenAI, Claude, Groq, Ollama, OpenRouter)
+/// - LLM client for communicating with various AI providers (OpenAI, Claude, Groq, Ollama, OpenRouter,
+///   custom OpenAI-compatible servers)
 /// - Processor for chunking transcripts and generating summaries
 /// - Service layer for orchestrating summary generation
+/// - Job registry for cancelling running generations
+/// - Version history of generated and edited summaries
 /// - Templates for structured meeting summary generation
 /// - Tauri commands for frontend integration
 
 pub mod commands;
+pub mod custom_provider;
+pub mod jobs;
 pub mod llm_client;
 pub mod processor;
 pub mod service;
 pub mod template_commands;
 pub mod templates;
+pub mod versions;
 
 // Re-export Tauri commands (with their generated __cmd__ variants)
 pub use commands::{
-    __cmd__api_get_summary, __cmd__api_process_transcript, __cmd__api_save_meeting_summary,
-    api_get_summary, api_process_transcript, api_save_meeting_summary,
+    __cmd__api_cancel_summary, __cmd__api_get_summary, __cmd__api_process_transcript,
+    __cmd__api_save_meeting_summary, api_cancel_summary, api_get_summary, api_process_transcript,
+    api_save_meeting_summary,
+};
+
+// Re-export summary version history commands
+pub use versions::{
+    __cmd__api_diff_summary_versions, __cmd__api_list_summary_versions,
+    __cmd__api_restore_summary_version, api_diff_summary_versions, api_list_summary_versions,
+    api_restore_summary_version,
 };
 
 // Re-export template commands
@@ -26,10 +40,17 @@ pub use template_commands::{
     api_get_template_details, api_list_templates, api_validate_template,
 };
 
+// Re-export custom provider commands
+pub use custom_provider::{
+    __cmd__api_get_custom_provider_config, __cmd__api_save_custom_provider_config,
+    __cmd__get_custom_openai_models, api_get_custom_provider_config,
+    api_save_custom_provider_config, get_custom_openai_models,
+};
+
 // Re-export commonly used items
-pub use llm_client::LLMProvider;
+pub use llm_client::{CustomOpenAIConfig, LLMProvider};
 pub use processor::{
     chunk_text, clean_llm_markdown_output, extract_meeting_name_from_markdown,
-    generate_meeting_summary, rough_token_count,
+    generate_meeting_summary, rough_token_count, SummaryHooks, SummaryProgress, SummaryStage,
 };
 pub use service::SummaryService;
@@ -1,9 +1,152 @@
-use crate::summary::llm_client::{generate_summary, LLMProvider};
+use crate::summary::jobs::CancelToken;
+use crate::summary::llm_client::{generate_summary, generate_summary_stream, LLMProvider};
 use crate::summary::templates;
+use async_trait::async_trait;
 use regex::Regex;
 use reqwest::Client;
+use serde::{Deserialize, Serialize};
 use tracing::{error, info};
 
+/// Phase of the (possibly multi-level) summary pipeline
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SummaryStage {
+    /// Summarizing one transcript chunk
+    Chunk,
+    /// Merging the chunk summaries
+    Combine,
+    /// Filling the template into the final report
+    Final,
+}
+
+/// Incremental progress of a summary generation
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SummaryProgress {
+    pub stage: SummaryStage,
+    /// Zero-based chunk being processed (0 outside of the chunk stage)
+    pub chunk_index: usize,
+    pub total_chunks: usize,
+    /// Text generated so far for the current stage
+    pub partial_markdown: String,
+}
+
+pub type ProgressCallback<'a> = &'a mut (dyn FnMut(SummaryProgress) + Send);
+
+/// Error returned when a run is stopped through its cancellation token
+pub const SUMMARY_CANCELLED: &str = "Summary generation cancelled";
+
+/// Storage for per-chunk summaries of multi-level runs
+#[async_trait]
+pub trait ChunkCache: Send + Sync {
+    async fn get(&self, chunk_index: usize, fingerprint: &str) -> Option<String>;
+    async fn put(&self, chunk_index: usize, fingerprint: &str, summary: &str);
+}
+
+/// Optional extensions of a summary run
+#[derive(Default)]
+pub struct SummaryHooks<'a> {
+    /// Streams responses and reports partial output per stage
+    pub on_progress: Option<ProgressCallback<'a>>,
+    /// Aborts the run, including an in-flight LLM request
+    pub cancel: Option<&'a CancelToken>,
+    /// Reuses chunk summaries of an earlier, interrupted run
+    pub chunk_cache: Option<&'a dyn ChunkCache>,
+}
+
+/// Identifies a chunk summary: same model and same chunk text (64-bit FNV-1a)
+pub fn chunk_fingerprint(model_name: &str, chunk: &str) -> String {
+    let mut hash: u64 = 0xcbf29ce484222325;
+    for byte in model_name.bytes().chain([0u8]).chain(chunk.bytes()) {
+        hash ^= byte as u64;
+        hash = hash.wrapping_mul(0x100000001b3);
+    }
+    format!("{:016x}", hash)
+}
+
+/// Provider settings shared by every LLM call of one summary run
+struct LlmTarget<'a> {
+    client: &'a Client,
+    provider: &'a LLMProvider,
+    model_name: &'a str,
+    api_key: &'a str,
+    ollama_endpoint: Option<&'a str>,
+    cancel: Option<&'a CancelToken>,
+}
+
+impl LlmTarget<'_> {
+    /// Run one LLM call; it is abandoned as soon as the run is cancelled
+    async fn complete(
+        &self,
+        system_prompt: &str,
+        user_prompt: &str,
+        on_progress: &mut Option<ProgressCallback<'_>>,
+        stage: SummaryStage,
+        chunk_index: usize,
+        total_chunks: usize,
+    ) -> Result<String, String> {
+        let Some(cancel) = self.cancel else {
+            return self
+                .request(system_prompt, user_prompt, on_progress, stage, chunk_index, total_chunks)
+                .await;
+        };
+        if cancel.is_cancelled() {
+            return Err(SUMMARY_CANCELLED.to_string());
+        }
+
+        tokio::select! {
+            result = self.request(system_prompt, user_prompt, on_progress, stage, chunk_index, total_chunks) => result,
+            _ = cancel.cancelled() => Err(SUMMARY_CANCELLED.to_string()),
+        }
+    }
+
+    /// Send the request, streaming it when a progress callback is registered
+    async fn request(
+        &self,
+        system_prompt: &str,
+        user_prompt: &str,
+        on_progress: &mut Option<ProgressCallback<'_>>,
+        stage: SummaryStage,
+        chunk_index: usize,
+        total_chunks: usize,
+    ) -> Result<String, String> {
+        let Some(callback) = on_progress.as_deref_mut() else {
+            return generate_summary(
+                self.client,
+                self.provider,
+                self.model_name,
+                self.api_key,
+                system_prompt,
+                user_prompt,
+                self.ollama_endpoint,
+            )
+            .await;
+        };
+
+        let mut report = |partial: &str| {
+            callback(SummaryProgress {
+                stage,
+                chunk_index,
+                total_chunks,
+                partial_markdown: partial.to_string(),
+            })
+        };
+        // Announce the stage before the first token arrives
+        report("");
+
+        generate_summary_stream(
+            self.client,
+            self.provider,
+            self.model_name,
+            self.api_key,
+            system_prompt,
+            user_prompt,
+            self.ollama_endpoint,
+            &mut report,
+        )
+        .await
+    }
+}
+
 /// Rough token count estimation (4 characters ≈ 1 token)
 pub fn rough_token_count(s: &str) -> usize {
     (s.chars().count() as f64 / 4.0).ceil() as usize
@@ -129,9 +272,11 @@ pub fn extract_meeting_name_from_markdown(markdown: &str) -> Option<String> {
 /// * `template_id` - Template identifier (e.g., "daily_standup", "standard_meeting")
 /// * `token_threshold` - Token limit for single-pass processing (default 4000)
 /// * `ollama_endpoint` - Optional custom Ollama endpoint
+/// * `hooks` - Progress streaming, cancellation and chunk caching (all optional)
 ///
 /// # Returns
 /// Tuple of (final_summary_markdown, number_of_chunks_processed)
+#[allow(clippy::too_many_arguments)]
 pub async fn generate_meeting_summary(
     client: &Client,
     provider: &LLMProvider,
@@ -142,7 +287,21 @@ pub async fn generate_meeting_summary(
     template_id: &str,
     token_threshold: usize,
     ollama_endpoint: Option<&str>,
+    hooks: SummaryHooks<'_>,
 ) -> Result<(String, i64), String> {
+    let SummaryHooks {
+        mut on_progress,
+        cancel,
+        chunk_cache,
+    } = hooks;
+    let llm = LlmTarget {
+        client,
+        provider,
+        model_name,
+        api_key,
+        ollama_endpoint,
+        cancel,
+    };
     info!(
         "Starting summary generation with provider: {:?}, model: {}",
         provider, model_name
@@ -155,8 +314,9 @@ pub async fn generate_meeting_summary(
     let successful_chunk_count: i64;
 
     // Strategy: Use single-pass for cloud providers or short transcripts
-    // Use multi-level chunking for Ollama with long transcripts
-    if provider != &LLMProvider::Ollama || total_tokens < token_threshold {
+    // Use multi-level chunking for local/self-hosted models (Ollama, custom) with long transcripts
+    let is_local = matches!(provider, LLMProvider::Ollama | LLMProvider::Custom(_));
+    if !is_local || total_tokens < token_threshold {
         info!(
             "Using single-pass summarization (tokens: {}, threshold: {})",
             total_tokens, token_threshold
@@ -179,24 +339,37 @@ pub async fn generate_meeting_summary(
         let user_prompt_template_chunk = "Provide a concise but comprehensive summary of the following transcript chunk. Capture all key points, decisions, action items, and mentioned individuals.\n\n<transcript_chunk>\n{}\n</transcript_chunk>";
 
         for (i, chunk) in chunks.iter().enumerate() {
+            let fingerprint = chunk_fingerprint(model_name, chunk);
+            if let Some(cache) = chunk_cache {
+                if let Some(summary) = cache.get(i, &fingerprint).await {
+                    info!("♻️ Reusing cached summary for chunk {}/{}", i + 1, num_chunks);
+                    chunk_summaries.push(summary);
+                    continue;
+                }
+            }
+
             info!("⏲️ Processing chunk {}/{}", i + 1, num_chunks);
             let user_prompt_chunk = user_prompt_template_chunk.replace("{}", chunk.as_str());
 
-            match generate_summary(
-                client,
-                provider,
-                model_name,
-                api_key,
-                system_prompt_chunk,
-                &user_prompt_chunk,
-                ollama_endpoint,
-            )
-            .await
+            match llm
+                .complete(
+                    system_prompt_chunk,
+                    &user_prompt_chunk,
+                    &mut on_progress,
+                    SummaryStage::Chunk,
+                    i,
+                    num_chunks,
+                )
+                .await
             {
                 Ok(summary) => {
+                    if let Some(cache) = chunk_cache {
+                        cache.put(i, &fingerprint, &summary).await;
+                    }
                     chunk_summaries.push(summary);
                     info!("✓ Chunk {}/{} processed successfully", i + 1, num_chunks);
                 }
+                Err(e) if e == SUMMARY_CANCELLED => return Err(e),
                 Err(e) => {
                     error!("⚠️ Failed processing chunk {}/{}: {}", i + 1, num_chunks, e);
                 }
@@ -227,14 +400,13 @@ pub async fn generate_meeting_summary(
             let user_prompt_combine_template = "The following are consecutive summaries of a meeting. Combine them into a single, coherent, and detailed narrative summary that retains all important details, organized logically.\n\n<summaries>\n{}\n</summaries>";
 
             let user_prompt_combine = user_prompt_combine_template.replace("{}", &combined_text);
-            generate_summary(
-                client,
-                provider,
-                model_name,
-                api_key,
+            llm.complete(
                 system_prompt_combine,
                 &user_prompt_combine,
-                ollama_endpoint,
+                &mut on_progress,
+                SummaryStage::Combine,
+                0,
+                num_chunks,
             )
             .await?
         } else {
@@ -288,16 +460,17 @@ pub async fn generate_meeting_summary(
         final_user_prompt.push_str("\n</user_context>");
     }
 
-    let raw_markdown = generate_summary(
-        client,
-        provider,
-        model_name,
-        api_key,
-        &final_system_prompt,
-        &final_user_prompt,
-        ollama_endpoint,
-    )
-    .await?;
+    let total_chunks = successful_chunk_count as usize;
+    let raw_markdown = llm
+        .complete(
+            &final_system_prompt,
+            &final_user_prompt,
+            &mut on_progress,
+            SummaryStage::Final,
+            0,
+            total_chunks,
+        )
+        .await?;
 
     // Clean the output
     let final_markdown = clean_llm_markdown_output(&raw_markdown);
@@ -1,12 +1,22 @@
+use crate::action_items::commands::extract_for_meeting;
 use crate::database::repositories::{
     meeting::MeetingsRepository, setting::SettingsRepository, summary::SummaryProcessesRepository,
+    summary_chunk::SummaryChunkCacheRepository,
+    summary_version::{NewSummaryVersion, SummaryVersionsRepository},
 };
+use crate::summary::custom_provider::{list_models, load_custom_config};
+use crate::summary::jobs::CancelToken;
 use crate::summary::llm_client::LLMProvider;
-use crate::summary::processor::{extract_meeting_name_from_markdown, generate_meeting_summary};
+use crate::summary::processor::{
+    extract_meeting_name_from_markdown, generate_meeting_summary, ChunkCache, SummaryHooks,
+    SummaryProgress, SUMMARY_CANCELLED,
+};
+use async_trait::async_trait;
 use crate::ollama::metadata::ModelMetadataCache;
 use sqlx::SqlitePool;
 use std::time::{Duration, Instant};
-use tauri::AppHandle;
+use tauri::{AppHandle, Emitter};
+use tokio::sync::watch;
 use tracing::{error, info, warn};
 use once_cell::sync::Lazy;
 
@@ -15,17 +25,178 @@ static METADATA_CACHE: Lazy<ModelMetadataCache> = Lazy::new(|| {
     ModelMetadataCache::new(Duration::from_secs(300))
 });
 
+/// Minimum gap between `summary-progress` events within one stage
+const PROGRESS_EMIT_INTERVAL: Duration = Duration::from_millis(200);
+/// Minimum gap between writes of the partial summary to `summary_processes.metadata`
+const PROGRESS_PERSIST_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Chunk summaries of a meeting, stored in `summary_chunk_cache`
+struct DbChunkCache<'a> {
+    pool: &'a SqlitePool,
+    meeting_id: &'a str,
+}
+
+#[async_trait]
+impl ChunkCache for DbChunkCache<'_> {
+    async fn get(&self, chunk_index: usize, fingerprint: &str) -> Option<String> {
+        SummaryChunkCacheRepository::get_chunk(self.pool, self.meeting_id, chunk_index, fingerprint)
+            .await
+            .unwrap_or_else(|e| {
+                warn!("⚠️ Failed to read chunk cache for {}: {}", self.meeting_id, e);
+                None
+            })
+    }
+
+    async fn put(&self, chunk_index: usize, fingerprint: &str, summary: &str) {
+        if let Err(e) = SummaryChunkCacheRepository::save_chunk(
+            self.pool,
+            self.meeting_id,
+            chunk_index,
+            fingerprint,
+            summary,
+        )
+        .await
+        {
+            warn!("⚠️ Failed to cache chunk {} for {}: {}", chunk_index, self.meeting_id, e);
+        }
+    }
+}
+
+/// An LLM provider with everything needed to call it
+pub struct ResolvedProvider {
+    pub provider: LLMProvider,
+    pub api_key: String,
+    pub ollama_endpoint: Option<String>,
+}
+
 /// Summary service - handles all summary generation logic
 pub struct SummaryService;
 
 impl SummaryService {
+    /// Parses a provider name and loads its API key, custom server config and
+    /// Ollama endpoint from settings
+    pub async fn resolve_provider(
+        pool: &SqlitePool,
+        model_provider: &str,
+    ) -> Result<ResolvedProvider, String> {
+        let mut provider = LLMProvider::from_str(model_provider)?;
+
+        // Custom OpenAI-compatible provider: load base URL and headers from settings
+        if let LLMProvider::Custom(ref mut config) = provider {
+            *config = load_custom_config(pool).await?;
+        }
+
+        // Validate and setup api_key, Flexible for Ollama
+        let api_key = match SettingsRepository::get_api_key(pool, model_provider).await {
+            Ok(Some(key)) if !key.is_empty() => key,
+            Ok(None) | Ok(Some(_)) => {
+                if !provider.api_key_optional() {
+                    return Err(format!("Api key not found for {}", model_provider));
+                }
+                String::new()
+            }
+            Err(e) => {
+                return Err(format!(
+                    "Failed to retrieve api key for {} : {}",
+                    model_provider, e
+                ));
+            }
+        };
+
+        // Get Ollama endpoint if provider is Ollama
+        let ollama_endpoint = if provider == LLMProvider::Ollama {
+            Self::ollama_endpoint(pool).await
+        } else {
+            None
+        };
+
+        Ok(ResolvedProvider {
+            provider,
+            api_key,
+            ollama_endpoint,
+        })
+    }
+
+    /// Configured Ollama endpoint (None means the default localhost endpoint)
+    pub async fn ollama_endpoint(pool: &SqlitePool) -> Option<String> {
+        match SettingsRepository::get_model_config(pool).await {
+            Ok(Some(config)) => config.ollama_endpoint,
+            Ok(None) => None,
+            Err(e) => {
+                info!("Failed to retrieve Ollama endpoint: {}, using default", e);
+                None
+            }
+        }
+    }
+
+    /// Usable context of a model in tokens, with room reserved for prompt overhead.
+    /// Ollama and custom servers report their context window; cloud providers are
+    /// treated as effectively unlimited.
+    pub async fn context_token_limit(
+        provider: &LLMProvider,
+        model_name: &str,
+        api_key: &str,
+        ollama_endpoint: Option<&str>,
+    ) -> usize {
+        // Dynamically fetch context size for Ollama models
+        if *provider == LLMProvider::Ollama {
+            match METADATA_CACHE.get_or_fetch(model_name, ollama_endpoint).await {
+                Ok(metadata) => {
+                    // Reserve 300 tokens for prompt overhead
+                    let optimal = metadata.context_size.saturating_sub(300);
+                    info!(
+                        "✓ Using dynamic context for {}: {} tokens (chunk size: {})",
+                        model_name, metadata.context_size, optimal
+                    );
+                    optimal
+                }
+                Err(e) => {
+                    warn!(
+                        "⚠️ Failed to fetch context for {}: {}. Using default 4000",
+                        model_name, e
+                    );
+                    4000  // Fallback to safe default
+                }
+            }
+        } else if let LLMProvider::Custom(config) = provider {
+            // Self-hosted servers often run small context windows; use the one reported
+            // by /v1/models when available
+            let client = reqwest::Client::new();
+            let key = (!api_key.is_empty()).then_some(api_key);
+            match list_models(&client, config, key).await {
+                Ok(models) => match models
+                    .iter()
+                    .find(|m| m.id == model_name)
+                    .and_then(|m| m.context_length)
+                {
+                    Some(context_size) => {
+                        let optimal = context_size.saturating_sub(300);
+                        info!(
+                            "✓ Using reported context for {}: {} tokens (chunk size: {})",
+                            model_name, context_size, optimal
+                        );
+                        optimal
+                    }
+                    None => 100000,
+                },
+                Err(e) => {
+                    warn!("⚠️ Failed to fetch models from custom provider: {}", e);
+                    100000
+                }
+            }
+        } else {
+            // Cloud providers (OpenAI, Claude, Groq) handle large contexts automatically
+            100000  // Effectively unlimited for single-pass processing
+        }
+    }
+
     /// Processes transcript in the background and generates summary
     ///
     /// This function is designed to be spawned as an async task and does not block
     /// the main thread. It updates the database with progress and results.
     ///
     /// # Arguments
-    /// * `_app` - Tauri app handle (for future use)
+    /// * `app` - Tauri app handle, used to emit `summary-progress` events
     /// * `pool` - SQLx connection pool
     /// * `meeting_id` - Unique identifier for the meeting
     /// * `text` - Full transcript text
@@ -33,8 +204,10 @@ impl SummaryService {
     /// * `model_name` - Specific model (e.g., "gpt-4", "llama3.2:latest")
     /// * `custom_prompt` - Optional user-provided context
     /// * `template_id` - Template identifier (e.g., "daily_standup", "standard_meeting")
+    /// * `cancel` - Cancellation token from the summary job registry
+    #[allow(clippy::too_many_arguments)]
     pub async fn process_transcript_background<R: tauri::Runtime>(
-        _app: AppHandle<R>,
+        app: AppHandle<R>,
         pool: SqlitePool,
         meeting_id: String,
         text: String,
@@ -42,6 +215,7 @@ impl SummaryService {
         model_name: String,
         custom_prompt: String,
         template_id: String,
+        cancel: CancelToken,
     ) {
         let start_time = Instant::now();
         info!(
@@ -49,70 +223,59 @@ impl SummaryService {
             meeting_id
         );
 
-        // Parse provider
-        let provider = match LLMProvider::from_str(&model_provider) {
-            Ok(p) => p,
+        let ResolvedProvider {
+            provider,
+            api_key,
+            ollama_endpoint,
+        } = match Self::resolve_provider(&pool, &model_provider).await {
+            Ok(resolved) => resolved,
             Err(e) => {
                 Self::update_process_failed(&pool, &meeting_id, &e).await;
                 return;
             }
         };
 
-        // Validate and setup api_key, Flexible for Ollama
-        let api_key = match SettingsRepository::get_api_key(&pool, &model_provider).await {
-            Ok(Some(key)) if !key.is_empty() => key,
-            Ok(None) | Ok(Some(_)) => {
-                if provider != LLMProvider::Ollama {
-                    let err_msg = format!("Api key not found for {}", &model_provider);
-                    Self::update_process_failed(&pool, &meeting_id, &err_msg).await;
-                    return;
-                }
-                String::new()
-            }
-            Err(e) => {
-                let err_msg = format!("Failed to retrieve api key for {} : {}", &model_provider, e);
-                Self::update_process_failed(&pool, &meeting_id, &err_msg).await;
+        let token_threshold =
+            Self::context_token_limit(&provider, &model_name, &api_key, ollama_endpoint.as_deref())
+                .await;
+
+        // Stream the summary: events for the live view, throttled DB writes so a
+        // reloaded window can pick the partial output up again
+        let (progress_tx, progress_rx) = watch::channel(None);
+        let progress_writer = tokio::spawn(Self::persist_progress(
+            pool.clone(),
+            meeting_id.clone(),
+            progress_rx,
+        ));
+        let mut last_emit: Option<(SummaryProgress, Instant)> = None;
+        let mut on_progress = |progress: SummaryProgress| {
+            let stage_changed = last_emit.as_ref().map_or(true, |(last, _)| {
+                last.stage != progress.stage || last.chunk_index != progress.chunk_index
+            });
+            let due = last_emit
+                .as_ref()
+                .map_or(true, |(_, at)| at.elapsed() >= PROGRESS_EMIT_INTERVAL);
+            if !stage_changed && !due {
                 return;
             }
-        };
 
-        // Get Ollama endpoint if provider is Ollama
-        let ollama_endpoint = if provider == LLMProvider::Ollama {
-            match SettingsRepository::get_model_config(&pool).await {
-                Ok(Some(config)) => config.ollama_endpoint,
-                Ok(None) => None,
-                Err(e) => {
-                    info!("Failed to retrieve Ollama endpoint: {}, using default", e);
-                    None
-                }
+            let payload = serde_json::json!({
+                "meeting_id": meeting_id,
+                "stage": progress.stage,
+                "chunk_index": progress.chunk_index,
+                "total_chunks": progress.total_chunks,
+                "partial_markdown": progress.partial_markdown,
+            });
+            if let Err(e) = app.emit("summary-progress", &payload) {
+                warn!("Failed to emit summary-progress for {}: {}", meeting_id, e);
             }
-        } else {
-            None
+            let _ = progress_tx.send(Some(payload));
+            last_emit = Some((progress, Instant::now()));
         };
 
-        // Dynamically fetch context size for Ollama models
-        let token_threshold = if provider == LLMProvider::Ollama {
-            match METADATA_CACHE.get_or_fetch(&model_name, ollama_endpoint.as_deref()).await {
-                Ok(metadata) => {
-                    // Reserve 300 tokens for prompt overhead
-                    let optimal = metadata.context_size.saturating_sub(300);
-                    info!(
-                        "✓ Using dynamic context for {}: {} tokens (chunk size: {})",
-                        model_name, metadata.context_size, optimal
-                    );
-                    optimal
-                }
-                Err(e) => {
-                    warn!(
-                        "⚠️ Failed to fetch context for {}: {}. Using default 4000",
-                        model_name, e
-                    );
-                    4000  // Fallback to safe default
-                }
-            }
-        } else {
-            // Cloud providers (OpenAI, Claude, Groq) handle large contexts automatically
-            100000  // Effectively unlimited for single-pass processing
+        let chunk_cache = DbChunkCache {
+            pool: &pool,
+            meeting_id: &meeting_id,
         };
 
         // Generate summary
@@ -127,9 +290,21 @@ impl SummaryService {
             &template_id,
             token_threshold,
             ollama_endpoint.as_deref(),
+            SummaryHooks {
+                on_progress: Some(&mut on_progress),
+                cancel: Some(&cancel),
+                chunk_cache: Some(&chunk_cache),
+            },
         )
         .await;
 
+        // Closing the channel stops the writer; wait so it can't overwrite the final state
+        drop(on_progress);
+        drop(progress_tx);
+        if let Err(e) = progress_writer.await {
+            warn!("Summary progress writer for {} failed: {}", meeting_id, e);
+        }
+
         let duration = start_time.elapsed().as_secs_f64();
 
         match result {
@@ -187,6 +362,25 @@ impl SummaryService {
                     "markdown": final_markdown,
                 });
 
+                // Keep the generated summary in the version history
+                if let Err(e) = SummaryVersionsRepository::add_version(
+                    &pool,
+                    NewSummaryVersion {
+                        meeting_id: &meeting_id,
+                        source: "generated",
+                        template_id: Some(&template_id),
+                        provider: Some(&model_provider),
+                        model: Some(&model_name),
+                        custom_prompt: Some(&custom_prompt),
+                        restored_from: None,
+                        result: &result_json,
+                    },
+                )
+                .await
+                {
+                    error!("⚠️ Failed to record summary version for {}: {}", meeting_id, e);
+                }
+
                 // Update database with completed status
                 if let Err(e) = SummaryProcessesRepository::update_process_completed(
                     &pool,
@@ -207,6 +401,37 @@ impl SummaryService {
                         meeting_id
                     );
                 }
+
+                if let Err(e) = SummaryChunkCacheRepository::clear(&pool, &meeting_id).await {
+                    warn!("⚠️ Failed to clear chunk cache for {}: {}", meeting_id, e);
+                }
+
+                // Structured action items; best effort, the summary is already saved
+                match extract_for_meeting(
+                    &pool,
+                    &meeting_id,
+                    &provider,
+                    &model_name,
+                    &api_key,
+                    ollama_endpoint.as_deref(),
+                )
+                .await
+                {
+                    Ok(items) => info!(
+                        "📋 Extracted {} action items for meeting_id: {}",
+                        items.len(),
+                        meeting_id
+                    ),
+                    Err(e) => warn!("⚠️ Action item extraction failed for {}: {}", meeting_id, e),
+                }
+            }
+            Err(e) if e == SUMMARY_CANCELLED => {
+                info!("⏹️ Summary generation cancelled for meeting_id: {}", meeting_id);
+                if let Err(e) =
+                    SummaryProcessesRepository::update_process_cancelled(&pool, &meeting_id).await
+                {
+                    error!("⚠️ Failed to mark summary as cancelled for {}: {}", meeting_id, e);
+                }
             }
             Err(e) => {
                 Self::update_process_failed(&pool, &meeting_id, &e).await;
@@ -214,6 +439,27 @@ impl SummaryService {
         }
     }
 
+    /// Persists the latest progress snapshot at most once per `PROGRESS_PERSIST_INTERVAL`
+    /// until the sender side is dropped
+    async fn persist_progress(
+        pool: SqlitePool,
+        meeting_id: String,
+        mut progress_rx: watch::Receiver<Option<serde_json::Value>>,
+    ) {
+        while progress_rx.changed().await.is_ok() {
+            let progress = progress_rx.borrow_and_update().clone();
+            if let Some(progress) = progress {
+                if let Err(e) =
+                    SummaryProcessesRepository::update_process_progress(&pool, &meeting_id, &progress)
+                        .await
+                {
+                    warn!("⚠️ Failed to persist summary progress for {}: {}", meeting_id, e);
+                }
+            }
+            tokio::time::sleep(PROGRESS_PERSIST_INTERVAL).await;
+        }
+    }
+
     /// Updates the summary process status to failed with error message
     ///
     /// # Arguments
@@ -0,0 +1,242 @@
+// summary/versions.rs
+//
+// Summary version history: list, diff and restore earlier summaries of a meeting.
+
+use crate::database::models::SummaryVersion;
+use crate::database::repositories::summary_version::SummaryVersionsRepository;
+use crate::state::AppState;
+use log::{error as log_error, info as log_info};
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Runtime};
+
+/// Length of the markdown preview shown in the version list
+const PREVIEW_CHARS: usize = 200;
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SummaryVersionInfo {
+    pub id: String,
+    pub version_number: i64,
+    pub source: String,
+    pub template_id: Option<String>,
+    pub provider: Option<String>,
+    pub model: Option<String>,
+    pub custom_prompt: Option<String>,
+    pub restored_from: Option<String>,
+    pub created_at: String,
+    pub updated_at: String,
+    pub preview: String,
+}
+
+impl From<SummaryVersion> for SummaryVersionInfo {
+    fn from(version: SummaryVersion) -> Self {
+        let preview = summary_markdown(&version.result)
+            .chars()
+            .take(PREVIEW_CHARS)
+            .collect();
+        Self {
+            id: version.id,
+            version_number: version.version_number,
+            source: version.source,
+            template_id: version.template_id,
+            provider: version.provider,
+            model: version.model,
+            custom_prompt: version.custom_prompt,
+            restored_from: version.restored_from,
+            created_at: version.created_at.to_rfc3339(),
+            updated_at: version.updated_at.to_rfc3339(),
+            preview,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DiffKind {
+    Equal,
+    Added,
+    Removed,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct DiffLine {
+    pub kind: DiffKind,
+    pub text: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SummaryVersionDiff {
+    pub from: SummaryVersionInfo,
+    pub to: SummaryVersionInfo,
+    pub lines: Vec<DiffLine>,
+    pub added: usize,
+    pub removed: usize,
+}
+
+/// Markdown of a stored summary result (`{"markdown": ...}`); legacy section-based
+/// results are rendered as pretty JSON so they can still be compared
+pub fn summary_markdown(result: &str) -> String {
+    match serde_json::from_str::<serde_json::Value>(result) {
+        Ok(value) => match value.get("markdown").and_then(|m| m.as_str()) {
+            Some(markdown) => markdown.to_string(),
+            None => serde_json::to_string_pretty(&value).unwrap_or_else(|_| result.to_string()),
+        },
+        Err(_) => result.to_string(),
+    }
+}
+
+/// Line diff of two texts (longest common subsequence)
+pub fn diff_lines(old: &str, new: &str) -> Vec<DiffLine> {
+    let old: Vec<&str> = old.lines().collect();
+    let new: Vec<&str> = new.lines().collect();
+    let (n, m) = (old.len(), new.len());
+
+    // lcs[i][j] = LCS length of old[i..] and new[j..]
+    let mut lcs = vec![vec![0usize; m + 1]; n + 1];
+    for i in (0..n).rev() {
+        for j in (0..m).rev() {
+            lcs[i][j] = if old[i] == new[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let line = |kind, text: &str| DiffLine {
+        kind,
+        text: text.to_string(),
+    };
+    let mut lines = Vec::with_capacity(n.max(m));
+    let (mut i, mut j) = (0, 0);
+    while i < n && j < m {
+        if old[i] == new[j] {
+            lines.push(line(DiffKind::Equal, old[i]));
+            i += 1;
+            j += 1;
+        } else if lcs[i + 1][j] >= lcs[i][j + 1] {
+            lines.push(line(DiffKind::Removed, old[i]));
+            i += 1;
+        } else {
+            lines.push(line(DiffKind::Added, new[j]));
+            j += 1;
+        }
+    }
+    lines.extend(old[i..].iter().map(|text| line(DiffKind::Removed, text)));
+    lines.extend(new[j..].iter().map(|text| line(DiffKind::Added, text)));
+    lines
+}
+
+async fn load_version(
+    pool: &sqlx::SqlitePool,
+    meeting_id: &str,
+    version_id: &str,
+) -> Result<SummaryVersion, String> {
+    SummaryVersionsRepository::get_version(pool, meeting_id, version_id)
+        .await
+        .map_err(|e| e.to_string())?
+        .ok_or_else(|| format!("Summary version {} not found", version_id))
+}
+
+/// Lists the summary versions of a meeting, newest first
+#[tauri::command]
+pub async fn api_list_summary_versions<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    meeting_id: String,
+) -> Result<Vec<SummaryVersionInfo>, String> {
+    let versions = SummaryVersionsRepository::list_versions(state.db_manager.pool(), &meeting_id)
+        .await
+        .map_err(|e| {
+            log_error!("Failed to list summary versions for {}: {}", meeting_id, e);
+            e.to_string()
+        })?;
+    Ok(versions.into_iter().map(SummaryVersionInfo::from).collect())
+}
+
+/// Line diff of the markdown of two versions (`from` → `to`)
+#[tauri::command]
+pub async fn api_diff_summary_versions<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    meeting_id: String,
+    from_version_id: String,
+    to_version_id: String,
+) -> Result<SummaryVersionDiff, String> {
+    let pool = state.db_manager.pool();
+    let from = load_version(pool, &meeting_id, &from_version_id).await?;
+    let to = load_version(pool, &meeting_id, &to_version_id).await?;
+
+    let lines = diff_lines(&summary_markdown(&from.result), &summary_markdown(&to.result));
+    let added = lines.iter().filter(|l| l.kind == DiffKind::Added).count();
+    let removed = lines.iter().filter(|l| l.kind == DiffKind::Removed).count();
+
+    Ok(SummaryVersionDiff {
+        from: from.into(),
+        to: to.into(),
+        lines,
+        added,
+        removed,
+    })
+}
+
+/// Makes an earlier version the current summary (recorded as a new "restored" version)
+#[tauri::command]
+pub async fn api_restore_summary_version<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    meeting_id: String,
+    version_id: String,
+) -> Result<SummaryVersionInfo, String> {
+    log_info!(
+        "api_restore_summary_version called for meeting_id: {}, version: {}",
+        meeting_id,
+        version_id
+    );
+
+    match SummaryVersionsRepository::restore_version(state.db_manager.pool(), &meeting_id, &version_id)
+        .await
+    {
+        Ok(Some(restored)) => Ok(restored.into()),
+        Ok(None) => Err(format!("Summary version {} not found", version_id)),
+        Err(e) => {
+            log_error!("Failed to restore summary version {}: {}", version_id, e);
+            Err(e.to_string())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn kinds(lines: &[DiffLine]) -> Vec<(DiffKind, &str)> {
+        lines.iter().map(|l| (l.kind, l.text.as_str())).collect()
+    }
+
+    #[test]
+    fn test_diff_lines() {
+        let old = "# Summary\n- a\n- b\n- c";
+        let new = "# Summary\n- a\n- B\n- c\n- d";
+
+        assert_eq!(
+            kinds(&diff_lines(old, new)),
+            vec![
+                (DiffKind::Equal, "# Summary"),
+                (DiffKind::Equal, "- a"),
+                (DiffKind::Removed, "- b"),
+                (DiffKind::Added, "- B"),
+                (DiffKind::Equal, "- c"),
+                (DiffKind::Added, "- d"),
+            ]
+        );
+        assert!(diff_lines("same", "same").iter().all(|l| l.kind == DiffKind::Equal));
+        assert_eq!(diff_lines("", "new").len(), 1);
+    }
+
+    #[test]
+    fn test_summary_markdown() {
+        assert_eq!(summary_markdown(r##"{"markdown": "# Hi", "summary_json": []}"##), "# Hi");
+        assert!(summary_markdown(r#"{"KeyPoints": {"blocks": []}}"#).contains("KeyPoints"));
+        assert_eq!(summary_markdown("not json"), "not json");
+    }
+}
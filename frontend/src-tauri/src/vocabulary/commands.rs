@@ -0,0 +1,135 @@
+use crate::database::models::VocabularyTerm;
+use crate::database::repositories::vocabulary::VocabularyRepository;
+use crate::state::AppState;
+use log::{error as log_error, info as log_info};
+use serde::Serialize;
+use std::path::Path;
+use tauri::{AppHandle, Runtime};
+
+#[derive(Debug, Serialize)]
+pub struct VocabularyImportResult {
+    pub added: u64,
+    /// Terms already in the list
+    pub duplicates: usize,
+    /// Entries that were empty, too long or contained control characters
+    pub invalid: usize,
+}
+
+/// The user's list changed: live transcription picks it up right away
+async fn refresh_if_user_list<R: Runtime>(app: &AppHandle<R>, meeting_id: Option<&str>) {
+    if meeting_id.is_none() {
+        super::refresh_active(app).await;
+    }
+}
+
+/// Terms of the user's list, or of a meeting's list when `meeting_id` is given
+#[tauri::command]
+pub async fn api_get_vocabulary(
+    state: tauri::State<'_, AppState>,
+    meeting_id: Option<String>,
+) -> Result<Vec<VocabularyTerm>, String> {
+    VocabularyRepository::list_terms(state.db_manager.pool(), meeting_id.as_deref())
+        .await
+        .map_err(|e| {
+            log_error!("Failed to load vocabulary: {}", e);
+            format!("Failed to load vocabulary: {}", e)
+        })
+}
+
+/// Adds terms to a list and returns the updated list
+#[tauri::command]
+pub async fn api_add_vocabulary_terms<R: Runtime>(
+    app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    terms: Vec<String>,
+    meeting_id: Option<String>,
+) -> Result<Vec<VocabularyTerm>, String> {
+    let normalized: Vec<String> = terms
+        .iter()
+        .map(|term| {
+            super::normalize_term(term).ok_or_else(|| {
+                format!(
+                    "Invalid term '{}': terms must be 1-{} characters",
+                    term.trim(),
+                    super::MAX_TERM_CHARS
+                )
+            })
+        })
+        .collect::<Result<_, _>>()?;
+
+    let pool = state.db_manager.pool();
+    VocabularyRepository::add_terms(pool, meeting_id.as_deref(), &normalized, false)
+        .await
+        .map_err(|e| format!("Failed to save vocabulary: {}", e))?;
+    refresh_if_user_list(&app, meeting_id.as_deref()).await;
+
+    VocabularyRepository::list_terms(pool, meeting_id.as_deref())
+        .await
+        .map_err(|e| format!("Failed to load vocabulary: {}", e))
+}
+
+#[tauri::command]
+pub async fn api_remove_vocabulary_term<R: Runtime>(
+    app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    id: i64,
+) -> Result<(), String> {
+    let removed = VocabularyRepository::remove_term(state.db_manager.pool(), id)
+        .await
+        .map_err(|e| format!("Failed to remove vocabulary term: {}", e))?;
+    if !removed {
+        return Err(format!("Vocabulary term {} not found", id));
+    }
+    // The term may have been on either list; reloading the user's list is cheap
+    super::refresh_active(&app).await;
+    Ok(())
+}
+
+/// Empties a list. Returns the number of terms removed.
+#[tauri::command]
+pub async fn api_clear_vocabulary<R: Runtime>(
+    app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    meeting_id: Option<String>,
+) -> Result<u64, String> {
+    let removed = VocabularyRepository::clear_terms(state.db_manager.pool(), meeting_id.as_deref())
+        .await
+        .map_err(|e| format!("Failed to clear vocabulary: {}", e))?;
+    refresh_if_user_list(&app, meeting_id.as_deref()).await;
+    Ok(removed)
+}
+
+/// Imports terms from a text file (one per line or comma-separated, `#` comments).
+/// With `replace`, the list is emptied first.
+#[tauri::command]
+pub async fn api_import_vocabulary<R: Runtime>(
+    app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    path: String,
+    meeting_id: Option<String>,
+    replace: Option<bool>,
+) -> Result<VocabularyImportResult, String> {
+    log_info!("api_import_vocabulary called for {}", path);
+    let content = std::fs::read_to_string(Path::new(&path))
+        .map_err(|e| format!("Failed to read {}: {}", path, e))?;
+    let (terms, invalid) = super::parse_terms(&content);
+    if terms.is_empty() {
+        return Err("The file contains no vocabulary terms".to_string());
+    }
+
+    let added = VocabularyRepository::add_terms(
+        state.db_manager.pool(),
+        meeting_id.as_deref(),
+        &terms,
+        replace.unwrap_or(false),
+    )
+    .await
+    .map_err(|e| format!("Failed to save vocabulary: {}", e))?;
+    refresh_if_user_list(&app, meeting_id.as_deref()).await;
+
+    Ok(VocabularyImportResult {
+        added,
+        duplicates: terms.len() - added as usize,
+        invalid,
+    })
+}
@@ -0,0 +1,171 @@
+/// Custom vocabulary module - product names, acronyms and colleague names that
+/// transcription keeps mangling
+///
+/// This module contains:
+/// - The vocabulary a transcription uses: the user's own list, plus a meeting's list when a
+///   saved meeting is re-transcribed
+/// - The Whisper initial prompt built from it (post-correction lives in
+///   `audio::post_processor`)
+/// - Parsing of vocabulary text files and Tauri commands to manage the lists
+
+pub mod commands;
+
+pub use commands::{
+    __cmd__api_add_vocabulary_terms, __cmd__api_clear_vocabulary, __cmd__api_get_vocabulary,
+    __cmd__api_import_vocabulary, __cmd__api_remove_vocabulary_term, api_add_vocabulary_terms,
+    api_clear_vocabulary, api_get_vocabulary, api_import_vocabulary, api_remove_vocabulary_term,
+};
+
+use crate::database::repositories::vocabulary::VocabularyRepository;
+use crate::state::AppState;
+use log::{info, warn};
+use sqlx::SqlitePool;
+use std::collections::HashSet;
+use std::sync::{Arc, LazyLock, RwLock};
+use tauri::{AppHandle, Manager, Runtime};
+
+/// Longest accepted term, in characters
+pub const MAX_TERM_CHARS: usize = 100;
+
+/// Whisper keeps at most 224 prompt tokens; stay well below so the prompt is never cut
+const MAX_PROMPT_CHARS: usize = 600;
+
+/// Vocabulary of live recordings (the user's list), reloaded when the list changes
+static ACTIVE_VOCABULARY: LazyLock<RwLock<Arc<Vocabulary>>> = LazyLock::new(Default::default);
+
+#[derive(Debug, Clone, Default)]
+pub struct Vocabulary {
+    terms: Vec<String>,
+}
+
+impl Vocabulary {
+    /// Keeps the first spelling of terms that differ only in case
+    pub fn new(terms: impl IntoIterator<Item = String>) -> Self {
+        let mut seen = HashSet::new();
+        let terms = terms
+            .into_iter()
+            .filter(|term| seen.insert(term.to_lowercase()))
+            .collect();
+        Self { terms }
+    }
+
+    /// The user's list plus, for a saved meeting, that meeting's list (listed first)
+    pub async fn load(pool: &SqlitePool, meeting_id: Option<&str>) -> Self {
+        match VocabularyRepository::terms_for_transcription(pool, meeting_id).await {
+            Ok(terms) => Self::new(terms),
+            Err(e) => {
+                warn!("Failed to load custom vocabulary: {}", e);
+                Self::default()
+            }
+        }
+    }
+
+    pub fn terms(&self) -> &[String] {
+        &self.terms
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.terms.is_empty()
+    }
+
+    /// Whisper initial prompt naming the terms, so the decoder favours their spellings.
+    /// Terms that don't fit the prompt budget are still used for post-correction.
+    pub fn initial_prompt(&self) -> Option<String> {
+        let mut prompt = String::from("Glossary:");
+        let mut included = 0;
+        for term in &self.terms {
+            // Separator, term and the closing period
+            if prompt.len() + term.len() + 3 > MAX_PROMPT_CHARS {
+                break;
+            }
+            prompt.push_str(if included == 0 { " " } else { ", " });
+            prompt.push_str(term);
+            included += 1;
+        }
+        if included == 0 {
+            return None;
+        }
+        prompt.push('.');
+        Some(prompt)
+    }
+}
+
+/// Vocabulary for live transcription
+pub fn active() -> Arc<Vocabulary> {
+    ACTIVE_VOCABULARY
+        .read()
+        .map(|vocabulary| vocabulary.clone())
+        .unwrap_or_default()
+}
+
+/// Reloads the user's list into the live vocabulary (no-op while the database is locked)
+pub async fn refresh_active<R: Runtime>(app: &AppHandle<R>) {
+    let Some(state) = app.try_state::<AppState>() else {
+        return;
+    };
+    let vocabulary = Vocabulary::load(state.db_manager.pool(), None).await;
+    info!("📖 Custom vocabulary: {} terms", vocabulary.terms().len());
+    if let Ok(mut active) = ACTIVE_VOCABULARY.write() {
+        *active = Arc::new(vocabulary);
+    }
+}
+
+/// Trims a term and collapses inner whitespace. None if it is empty, too long or
+/// contains control characters.
+pub fn normalize_term(term: &str) -> Option<String> {
+    let term = term.split_whitespace().collect::<Vec<_>>().join(" ");
+    if term.is_empty() || term.chars().count() > MAX_TERM_CHARS || term.chars().any(char::is_control) {
+        return None;
+    }
+    Some(term)
+}
+
+/// Terms of a vocabulary text file: one per line or comma-separated, `#` starts a comment.
+/// Returns the valid terms and the number of entries skipped as invalid.
+pub fn parse_terms(content: &str) -> (Vec<String>, usize) {
+    let mut terms = Vec::new();
+    let mut skipped = 0;
+    for line in content.trim_start_matches('\u{feff}').lines() {
+        let line = line.split('#').next().unwrap_or_default();
+        for entry in line.split(',').filter(|entry| !entry.trim().is_empty()) {
+            match normalize_term(entry) {
+                Some(term) => terms.push(term),
+                None => skipped += 1,
+            }
+        }
+    }
+    (terms, skipped)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_terms() {
+        let content = "\u{feff}Meetily\n# product names\nKubernetes,  gRPC \n\nAnya   Petrova # PM\n,\n";
+        let (terms, skipped) = parse_terms(content);
+        assert_eq!(terms, vec!["Meetily", "Kubernetes", "gRPC", "Anya Petrova"]);
+        assert_eq!(skipped, 0);
+
+        let (terms, skipped) = parse_terms(&format!("ok\n{}\n", "x".repeat(MAX_TERM_CHARS + 1)));
+        assert_eq!(terms, vec!["ok"]);
+        assert_eq!(skipped, 1);
+    }
+
+    #[test]
+    fn test_initial_prompt() {
+        assert_eq!(Vocabulary::default().initial_prompt(), None);
+
+        let vocabulary = Vocabulary::new(vec![
+            "Meetily".to_string(),
+            "meetily".to_string(),
+            "gRPC".to_string(),
+        ]);
+        assert_eq!(vocabulary.terms(), ["Meetily", "gRPC"]);
+        assert_eq!(vocabulary.initial_prompt().unwrap(), "Glossary: Meetily, gRPC.");
+
+        let long = Vocabulary::new((0..200).map(|i| format!("Term{}", i)));
+        assert!(long.initial_prompt().unwrap().len() <= MAX_PROMPT_CHARS);
+    }
+}
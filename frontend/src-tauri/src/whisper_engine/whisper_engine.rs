@@ -7,6 +7,8 @@ use tokio::sync::RwLock;
 use whisper_rs::{WhisperContext, WhisperContextParameters, FullParams, SamplingStrategy};
 use serde::{Serialize, Deserialize};
 use anyhow::{Result, anyhow};
+use crate::audio::transcription::confidence::sequence_confidence;
+use crate::audio::transcription::provider::{words_from_tokens, TokenTiming, WordTiming};
 use reqwest::Client;
 use tokio::fs;
 use tokio::io::AsyncWriteExt;
@@ -32,6 +34,15 @@ pub struct ModelInfo {
     pub description: String,
 }
 
+/// Detailed result of a single Whisper transcription
+#[derive(Debug, Clone)]
+pub struct WhisperTranscription {
+    pub text: String,
+    pub confidence: f32,
+    pub is_partial: bool,
+    pub words: Vec<WordTiming>,
+}
+
 pub struct WhisperEngine {
     models_dir: PathBuf,
     current_context: Arc<RwLock<Option<WhisperContext>>>,
@@ -529,8 +540,16 @@ impl WhisperEngine {
         repeated_words as f32 / total_words
     }
     
-    /// Transcribe audio with streaming support for partial results and adaptive quality
-    pub async fn transcribe_audio_with_confidence(&self, audio_data: Vec<f32>, language: Option<String>) -> Result<(String, f32, bool)> {
+    /// Transcribe audio with streaming support for partial results and adaptive quality.
+    /// `initial_prompt` (e.g. a custom vocabulary glossary) biases the decoder towards its spellings.
+    pub async fn transcribe_audio_with_confidence(&self, audio_data: Vec<f32>, language: Option<String>, initial_prompt: Option<&str>) -> Result<(String, f32, bool)> {
+        let result = self.transcribe_audio_detailed(audio_data, language, initial_prompt).await?;
+        Ok((result.text, result.confidence, result.is_partial))
+    }
+
+    /// Transcribe audio returning text, confidence, partial flag and word-level timestamps
+    /// (word times are in seconds relative to the start of `audio_data`)
+    pub async fn transcribe_audio_detailed(&self, audio_data: Vec<f32>, language: Option<String>, initial_prompt: Option<&str>) -> Result<WhisperTranscription> {
         let ctx_lock = self.current_context.read().await;
         let ctx = ctx_lock.as_ref()
             .ok_or_else(|| anyhow!("No model loaded. Please load a model first."))?;
@@ -557,6 +576,11 @@ impl WhisperEngine {
         params.set_language(language_code);
         params.set_translate(should_translate);
 
+        // Custom vocabulary: whisper conditions on the prompt as if it were preceding text
+        if let Some(prompt) = initial_prompt {
+            params.set_initial_prompt(prompt);
+        }
+
         // CRITICAL: Disable timestamp tokens to prevent whisper.cpp chunking heuristics
         // The "single timestamp ending - skip entire chunk" optimization incorrectly discards
         // complete, valid transcriptions. Disabling timestamps forces whisper to return ALL text.
@@ -606,8 +630,7 @@ impl WhisperEngine {
             // Suppressor dropped here, stderr restored
         };
         let mut result = String::new();
-        let mut total_confidence = 0.0;
-        let mut segment_count = 0;
+        let mut tokens: Vec<TokenTiming> = Vec::new();
 
         let num_segments = num_segments?;
         for i in 0..num_segments {
@@ -616,15 +639,26 @@ impl WhisperEngine {
                 Err(_) => continue,
             };
 
-            // Calculate confidence based on segment length and duration (simplified approach)
-            let segment_length = segment_text.len() as f32;
-            let segment_confidence = if segment_length > 0.0 {
-                (segment_length / 100.0).min(0.9) + 0.1 // 0.1 to 1.0 confidence based on text length
-            } else {
-                0.1
-            };
-            total_confidence += segment_confidence;
-            segment_count += 1;
+            // Collect token timings (t0/t1 are in centiseconds) and probabilities,
+            // skipping special tokens
+            let num_tokens = state.full_n_tokens(i).unwrap_or(0);
+            for j in 0..num_tokens {
+                let token_text = match state.full_get_token_text_lossy(i, j) {
+                    Ok(text) => text,
+                    Err(_) => continue,
+                };
+                if Self::is_special_token(&token_text) {
+                    continue;
+                }
+                if let Ok(data) = state.full_get_token_data(i, j) {
+                    tokens.push(TokenTiming {
+                        text: token_text,
+                        start: data.t0 as f64 / 100.0,
+                        end: data.t1 as f64 / 100.0,
+                        probability: Some(data.p),
+                    });
+                }
+            }
 
             let cleaned_text = segment_text.trim();
             if !cleaned_text.is_empty() {
@@ -638,13 +672,30 @@ impl WhisperEngine {
         let final_result = result.trim().to_string();
         let cleaned_result = Self::clean_repetitive_text(&final_result);
 
-        let avg_confidence = if segment_count > 0 {
-            total_confidence / segment_count as f32
+        // Geometric mean of the probabilities of every text token in the chunk
+        let confidence = sequence_confidence(tokens.iter().filter_map(|t| t.probability))
+            .unwrap_or(0.0);
+
+        // Repetition cleanup rewrites the text, so token timings would no longer line up
+        let words = if cleaned_result == final_result {
+            words_from_tokens(&tokens)
         } else {
-            0.0
+            Vec::new()
         };
 
-        Ok((cleaned_result, avg_confidence, is_partial))
+        Ok(WhisperTranscription {
+            text: cleaned_result,
+            confidence,
+            is_partial,
+            words,
+        })
+    }
+
+    /// Whisper control tokens such as `[_BEG_]`, `[_TT_150]` or `<|endoftext|>`
+    fn is_special_token(text: &str) -> bool {
+        let trimmed = text.trim();
+        (trimmed.starts_with("[_") && trimmed.ends_with(']'))
+            || (trimmed.starts_with("<|") && trimmed.ends_with("|>"))
     }
 
     pub async fn transcribe_audio(&self, audio_data: Vec<f32>, language: Option<String>) -> Result<String> {
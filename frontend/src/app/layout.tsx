@@ -12,6 +12,8 @@ import { useState, useEffect } from 'react'
 import { listen } from '@tauri-apps/api/event'
 import { invoke } from '@tauri-apps/api/core'
 import { LegacyDatabaseImport } from '@/components/DatabaseImport/LegacyDatabaseImport'
+import { InterruptedRecordingsDialog } from '@/components/RecordingRecovery/InterruptedRecordingsDialog'
+import { UnlockDatabaseDialog, useDatabaseLocked } from '@/components/Encryption/UnlockDatabaseDialog'
 import { TooltipProvider } from '@/components/ui/tooltip'
 import { RecordingStateProvider } from '@/contexts/RecordingStateContext'
 import { OllamaDownloadProvider } from '@/contexts/OllamaDownloadContext'
@@ -30,6 +32,7 @@ export default function RootLayout({
   children: React.ReactNode
 }) {
   const [showImportDialog, setShowImportDialog] = useState(false)
+  const databaseLocked = useDatabaseLocked()
 
   useEffect(() => {
     // Check first launch state immediately on mount (reliable)
@@ -86,6 +89,8 @@ export default function RootLayout({
           isOpen={showImportDialog}
           onComplete={() => setShowImportDialog(false)}
         />
+        <UnlockDatabaseDialog isOpen={databaseLocked} />
+        {!showImportDialog && !databaseLocked && <InterruptedRecordingsDialog />}
       </body>
     </html>
   )
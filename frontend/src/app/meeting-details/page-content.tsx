@@ -6,6 +6,7 @@ import { useSidebar } from '@/components/Sidebar/SidebarProvider';
 import Analytics from '@/lib/analytics';
 import { TranscriptPanel } from '@/components/MeetingDetails/TranscriptPanel';
 import { SummaryPanel } from '@/components/MeetingDetails/SummaryPanel';
+import { MeetingChatPanel } from '@/components/MeetingDetails/MeetingChatPanel';
 
 // Custom hooks
 import { useMeetingData } from '@/hooks/meeting-details/useMeetingData';
@@ -126,6 +127,7 @@ export default function PageContent({
           onOpenFolder={meetingOperations.handleOpenMeetingFolder}
           aiSummary={meetingData.aiSummary}
           summaryStatus={summaryGeneration.summaryStatus}
+          summaryProgress={summaryGeneration.summaryProgress}
           transcripts={meetingData.transcripts}
           modelConfig={modelConfig.modelConfig}
           setModelConfig={modelConfig.setModelConfig}
@@ -138,6 +140,7 @@ export default function PageContent({
           onDirtyChange={meetingData.setIsSummaryDirty}
           summaryError={summaryGeneration.summaryError}
           onRegenerateSummary={summaryGeneration.handleRegenerateSummary}
+          onCancelSummary={summaryGeneration.handleCancelSummary}
           getSummaryStatusMessage={summaryGeneration.getSummaryStatusMessage}
           availableTemplates={templates.availableTemplates}
           selectedTemplate={templates.selectedTemplate}
@@ -145,6 +148,8 @@ export default function PageContent({
           isModelConfigLoading={modelConfig.isLoading}
         />
 
+        <MeetingChatPanel meetingId={meeting.id} />
+
       </div>
     </motion.div>
   );
@@ -35,7 +35,7 @@ import { ButtonGroup } from '@/components/ui/button-group';
 
 
 interface ModelConfig {
-  provider: 'ollama' | 'groq' | 'claude' | 'openrouter';
+  provider: 'ollama' | 'groq' | 'claude' | 'openrouter' | 'custom';
   model: string;
   whisperModel: string;
 }
@@ -179,6 +179,7 @@ export default function Home() {
     claude: ['claude-3-5-sonnet-latest'],
     groq: ['llama-3.3-70b-versatile'],
     openrouter: [],
+    custom: modelConfig.provider === 'custom' && modelConfig.model ? [modelConfig.model] : [],
   };
 
   useEffect(() => {
@@ -455,6 +456,8 @@ export default function Home() {
             audio_start_time: event.payload.audio_start_time,
             audio_end_time: event.payload.audio_end_time,
             duration: event.payload.duration,
+            source: event.payload.source,
+            words: event.payload.words,
           };
 
           // Add to buffer
@@ -517,6 +520,8 @@ export default function Home() {
             audio_start_time: segment.audio_start_time,
             audio_end_time: segment.audio_end_time,
             duration: segment.duration,
+            source: segment.source,
+            words: segment.words,
           }));
 
           setTranscripts(formattedTranscripts);
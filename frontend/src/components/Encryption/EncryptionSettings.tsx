@@ -0,0 +1,147 @@
+'use client';
+
+import { useEffect, useState } from 'react';
+import { invoke } from '@tauri-apps/api/core';
+import { listen } from '@tauri-apps/api/event';
+import { toast } from 'sonner';
+import { Loader2, Lock, ShieldCheck } from 'lucide-react';
+import type { EncryptionStatus } from './UnlockDatabaseDialog';
+
+const MIN_PASSPHRASE_LENGTH = 8;
+
+export function EncryptionSettings() {
+  const [status, setStatus] = useState<EncryptionStatus | null>(null);
+  const [passphrase, setPassphrase] = useState('');
+  const [confirmation, setConfirmation] = useState('');
+  const [busy, setBusy] = useState(false);
+  const [stage, setStage] = useState<string | null>(null);
+
+  useEffect(() => {
+    invoke<EncryptionStatus>('api_get_encryption_status')
+      .then(setStatus)
+      .catch((error) => console.error('Failed to get encryption status:', error));
+
+    const unlisten = listen<{ stage: string }>('encryption-progress', (event) => {
+      setStage(event.payload.stage);
+    });
+    return () => {
+      unlisten.then((fn) => fn());
+    };
+  }, []);
+
+  const run = async (command: string, args: Record<string, unknown>, success: string) => {
+    setBusy(true);
+    setStage(null);
+    try {
+      const result = await invoke<{ notice?: string } | null>(command, args);
+      toast.success(success, result?.notice ? { description: result.notice } : undefined);
+      // The app restarts itself after switching
+    } catch (error) {
+      console.error(`${command} failed:`, error);
+      toast.error(String(error));
+      setBusy(false);
+      setStage(null);
+    }
+  };
+
+  if (!status) {
+    return null;
+  }
+
+  if (!status.available && !status.enabled) {
+    return (
+      <div>
+        <h3 className="text-lg font-semibold text-gray-900 mb-2 flex items-center gap-2">
+          <ShieldCheck className="w-5 h-5" />
+          Encryption at rest
+        </h3>
+        <p className="text-sm text-gray-600">
+          This build of Meetily doesn&apos;t include encryption at rest. Release builds do.
+        </p>
+      </div>
+    );
+  }
+
+  const canEnable =
+    passphrase.length >= MIN_PASSPHRASE_LENGTH && passphrase === confirmation && !busy;
+
+  return (
+    <div>
+      <h3 className="text-lg font-semibold text-gray-900 mb-2 flex items-center gap-2">
+        <ShieldCheck className="w-5 h-5" />
+        Encryption at rest
+      </h3>
+      <p className="text-sm text-gray-600 mb-4">
+        {status.enabled
+          ? 'Meetings, transcripts, summaries and recordings are encrypted with your passphrase. Meetily asks for it at every start.'
+          : 'Encrypt the meeting database and recordings with a passphrase. Meetily will ask for it at every start.'}
+      </p>
+
+      {status.enabled ? (
+        <div className="space-y-3">
+          <button
+            onClick={() => run('api_lock_database', {}, 'Locking...')}
+            disabled={busy}
+            className="flex items-center gap-2 px-3 py-2 text-sm border border-gray-300 rounded-md hover:bg-gray-100 disabled:cursor-not-allowed"
+          >
+            <Lock className="w-4 h-4" />
+            Lock now
+          </button>
+          <div className="flex gap-2">
+            <input
+              type="password"
+              value={passphrase}
+              onChange={(e) => setPassphrase(e.target.value)}
+              placeholder="Passphrase"
+              disabled={busy}
+              className="flex-1 px-3 py-2 text-sm border border-gray-300 rounded-md"
+            />
+            <button
+              onClick={() => run('api_disable_encryption', { passphrase }, 'Encryption disabled, restarting...')}
+              disabled={busy || passphrase.length === 0}
+              className="flex items-center gap-2 px-3 py-2 text-sm text-red-700 border border-red-300 rounded-md hover:bg-red-50 disabled:cursor-not-allowed"
+            >
+              {busy && <Loader2 className="w-4 h-4 animate-spin" />}
+              Disable encryption
+            </button>
+          </div>
+        </div>
+      ) : (
+        <div className="space-y-2">
+          <input
+            type="password"
+            value={passphrase}
+            onChange={(e) => setPassphrase(e.target.value)}
+            placeholder={`Passphrase (at least ${MIN_PASSPHRASE_LENGTH} characters)`}
+            disabled={busy}
+            className="w-full px-3 py-2 text-sm border border-gray-300 rounded-md"
+          />
+          <input
+            type="password"
+            value={confirmation}
+            onChange={(e) => setConfirmation(e.target.value)}
+            placeholder="Repeat passphrase"
+            disabled={busy}
+            className="w-full px-3 py-2 text-sm border border-gray-300 rounded-md"
+          />
+          <button
+            onClick={() => run('api_enable_encryption', { passphrase }, 'Encryption enabled, restarting...')}
+            disabled={!canEnable}
+            className="flex items-center gap-2 px-3 py-2 text-sm bg-blue-600 text-white rounded-md hover:bg-blue-700 disabled:bg-gray-400 disabled:cursor-not-allowed"
+          >
+            {busy && <Loader2 className="w-4 h-4 animate-spin" />}
+            Enable encryption
+          </button>
+          <p className="text-xs text-amber-700">
+            There is no way to recover the data if the passphrase is lost. Copies made before
+            enabling (backups, filesystem snapshots) stay unencrypted.
+          </p>
+        </div>
+      )}
+
+      {busy && stage && (
+        <p className="text-xs text-blue-700 mt-2">{stage.replace(/_/g, ' ')}...</p>
+      )}
+    </div>
+  );
+}
@@ -0,0 +1,99 @@
+'use client';
+
+import { useEffect, useState } from 'react';
+import { invoke } from '@tauri-apps/api/core';
+import { listen } from '@tauri-apps/api/event';
+import { Dialog, DialogContent, DialogHeader, DialogTitle, DialogDescription } from '@/components/ui/dialog';
+import { Loader2, Lock } from 'lucide-react';
+
+export interface EncryptionStatus {
+  available: boolean;
+  enabled: boolean;
+  locked: boolean;
+}
+
+interface UnlockDatabaseDialogProps {
+  isOpen: boolean;
+}
+
+export function UnlockDatabaseDialog({ isOpen }: UnlockDatabaseDialogProps) {
+  const [passphrase, setPassphrase] = useState('');
+  const [unlocking, setUnlocking] = useState(false);
+  const [error, setError] = useState<string | null>(null);
+
+  const handleUnlock = async (e: React.FormEvent) => {
+    e.preventDefault();
+    setUnlocking(true);
+    setError(null);
+    try {
+      await invoke('api_unlock_database', { passphrase });
+      setPassphrase('');
+      // Pages loaded while locked have no data; start over with the database open
+      window.location.reload();
+    } catch (err) {
+      setError(String(err));
+      setUnlocking(false);
+    }
+  };
+
+  return (
+    <Dialog open={isOpen} onOpenChange={() => {}}>
+      <DialogContent
+        className="sm:max-w-[420px]"
+        onPointerDownOutside={(e) => e.preventDefault()}
+        onEscapeKeyDown={(e) => e.preventDefault()}
+      >
+        <DialogHeader>
+          <DialogTitle className="flex items-center gap-2">
+            <Lock className="h-5 w-5 text-gray-700" />
+            Meetings are locked
+          </DialogTitle>
+          <DialogDescription>
+            Your meetings and recordings are encrypted. Enter your passphrase to open them.
+          </DialogDescription>
+        </DialogHeader>
+
+        <form onSubmit={handleUnlock} className="space-y-3">
+          <input
+            type="password"
+            autoFocus
+            value={passphrase}
+            onChange={(e) => setPassphrase(e.target.value)}
+            placeholder="Passphrase"
+            disabled={unlocking}
+            className="w-full px-3 py-2 text-sm border border-gray-300 rounded-md focus:outline-none focus:ring-2 focus:ring-blue-500"
+          />
+          {error && <p className="text-sm text-red-600">{error}</p>}
+          <button
+            type="submit"
+            disabled={unlocking || passphrase.length === 0}
+            className="w-full flex items-center justify-center gap-2 px-4 py-2 text-sm bg-blue-600 text-white rounded-md hover:bg-blue-700 disabled:bg-gray-400 disabled:cursor-not-allowed"
+          >
+            {unlocking && <Loader2 className="h-4 w-4 animate-spin" />}
+            {unlocking ? 'Unlocking...' : 'Unlock'}
+          </button>
+        </form>
+      </DialogContent>
+    </Dialog>
+  );
+}
+
+/** Tracks whether the app is waiting for the encryption passphrase */
+export function useDatabaseLocked() {
+  const [locked, setLocked] = useState(false);
+
+  useEffect(() => {
+    invoke<EncryptionStatus>('api_get_encryption_status')
+      .then((status) => setLocked(status.locked))
+      .catch((error) => console.error('Failed to get encryption status:', error));
+
+    const unlistenLocked = listen('database-locked', () => setLocked(true));
+    const unlistenInit = listen('database-initialized', () => setLocked(false));
+    return () => {
+      unlistenLocked.then((fn) => fn());
+      unlistenInit.then((fn) => fn());
+    };
+  }, []);
+
+  return locked;
+}
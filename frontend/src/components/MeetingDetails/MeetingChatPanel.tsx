@@ -0,0 +1,80 @@
+"use client";
+
+import { useState } from 'react';
+import { useMeetingChat } from '@/hooks/meeting-details/useMeetingChat';
+
+interface MeetingChatPanelProps {
+  meetingId: string;
+}
+
+function Bubble({ role, content }: { role: string; content: string }) {
+  const isUser = role === 'user';
+  return (
+    <div className={`flex ${isUser ? 'justify-end' : 'justify-start'}`}>
+      <div
+        className={`max-w-[90%] rounded-lg px-3 py-2 text-sm whitespace-pre-wrap ${
+          isUser ? 'bg-blue-600 text-white' : 'bg-gray-100 text-gray-800'
+        }`}
+      >
+        {content}
+      </div>
+    </div>
+  );
+}
+
+export function MeetingChatPanel({ meetingId }: MeetingChatPanelProps) {
+  const { messages, pendingQuestion, streamingAnswer, sendMessage, clearChat } = useMeetingChat(meetingId);
+  const [input, setInput] = useState('');
+
+  const submit = () => {
+    if (!input.trim() || pendingQuestion) return;
+    sendMessage(input);
+    setInput('');
+  };
+
+  return (
+    <div className="hidden xl:flex w-80 min-w-0 border-l border-gray-200 bg-white flex-col shrink-0">
+      <div className="p-4 border-b border-gray-200 flex items-center justify-between">
+        <h3 className="text-sm font-semibold text-gray-800">Ask about this meeting</h3>
+        {messages.length > 0 && !pendingQuestion && (
+          <button onClick={clearChat} className="text-xs text-gray-500 hover:text-gray-700">
+            Clear
+          </button>
+        )}
+      </div>
+
+      <div className="flex-1 overflow-y-auto p-3 space-y-2">
+        {messages.length === 0 && !pendingQuestion && (
+          <p className="text-xs text-gray-500">
+            Ask follow-up questions, e.g. &quot;What did Alice commit to?&quot;
+          </p>
+        )}
+        {messages.map((message) => (
+          <Bubble key={message.id} role={message.role} content={message.content} />
+        ))}
+        {pendingQuestion && (
+          <>
+            <Bubble role="user" content={pendingQuestion} />
+            <Bubble role="assistant" content={streamingAnswer || 'Thinking…'} />
+          </>
+        )}
+      </div>
+
+      <div className="p-2 border-t border-gray-200">
+        <textarea
+          placeholder="Ask a question…"
+          className="w-full px-3 py-2 border border-gray-200 rounded-md text-sm focus:outline-none focus:ring-1 focus:ring-blue-500 focus:border-blue-500 bg-white shadow-sm min-h-[60px] resize-y"
+          value={input}
+          disabled={!!pendingQuestion}
+          onChange={(e) => setInput(e.target.value)}
+          onKeyDown={(e) => {
+            if (e.key === 'Enter' && !e.shiftKey) {
+              e.preventDefault();
+              submit();
+            }
+          }}
+        />
+      </div>
+    </div>
+  );
+}
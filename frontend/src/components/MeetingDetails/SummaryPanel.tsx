@@ -5,6 +5,7 @@ import { EditableTitle } from '@/components/EditableTitle';
 import { BlockNoteSummaryView, BlockNoteSummaryViewRef } from '@/components/AISummary/BlockNoteSummaryView';
 import { EmptyStateSummary } from '@/components/EmptyStateSummary';
 import { ModelConfig } from '@/components/ModelSettingsModal';
+import { SummaryProgress } from '@/hooks/meeting-details/useSummaryGeneration';
 import { SummaryGeneratorButtonGroup } from './SummaryGeneratorButtonGroup';
 import { SummaryUpdaterButtonGroup } from './SummaryUpdaterButtonGroup';
 import Analytics from '@/lib/analytics';
@@ -29,6 +30,7 @@ interface SummaryPanelProps {
   onOpenFolder: () => Promise<void>;
   aiSummary: Summary | null;
   summaryStatus: 'idle' | 'processing' | 'summarizing' | 'regenerating' | 'completed' | 'error';
+  summaryProgress?: SummaryProgress | null;
   transcripts: Transcript[];
   modelConfig: ModelConfig;
   setModelConfig: (config: ModelConfig | ((prev: ModelConfig) => ModelConfig)) => void;
@@ -41,6 +43,7 @@ interface SummaryPanelProps {
   onDirtyChange: (isDirty: boolean) => void;
   summaryError: string | null;
   onRegenerateSummary: () => Promise<void>;
+  onCancelSummary?: () => Promise<void>;
   getSummaryStatusMessage: (status: 'idle' | 'processing' | 'summarizing' | 'regenerating' | 'completed' | 'error') => string;
   availableTemplates: Array<{id: string, name: string, description: string}>;
   selectedTemplate: string;
@@ -63,6 +66,7 @@ export function SummaryPanel({
   onOpenFolder,
   aiSummary,
   summaryStatus,
+  summaryProgress = null,
   transcripts,
   modelConfig,
   setModelConfig,
@@ -75,6 +79,7 @@ export function SummaryPanel({
   onDirtyChange,
   summaryError,
   onRegenerateSummary,
+  onCancelSummary,
   getSummaryStatusMessage,
   availableTemplates,
   selectedTemplate,
@@ -152,13 +157,34 @@ export function SummaryPanel({
               isModelConfigLoading={isModelConfigLoading}
             />
           </div>
-          {/* Loading spinner */}
-          <div className="flex items-center justify-center flex-1">
-            <div className="text-center">
-              <div className="inline-block animate-spin rounded-full h-12 w-12 border-t-2 border-b-2 border-blue-500 mb-4"></div>
-              <p className="text-gray-600">Generating AI Summary...</p>
+          {onCancelSummary && (
+            <div className="flex justify-center pb-2">
+              <button
+                onClick={() => {
+                  Analytics.trackButtonClick('cancel_summary', 'meeting_details');
+                  onCancelSummary();
+                }}
+                className="px-3 py-1 text-sm text-gray-600 border border-gray-300 rounded-md hover:bg-gray-50"
+              >
+                Cancel
+              </button>
             </div>
-          </div>
+          )}
+          {summaryProgress?.partial_markdown ? (
+            /* Streamed partial output */
+            <div className="flex-1 overflow-y-auto min-h-0 p-6">
+              <p className="text-sm text-blue-600 mb-3">{getSummaryStatusMessage(summaryStatus)}</p>
+              <div className="text-sm text-gray-700 whitespace-pre-wrap">{summaryProgress.partial_markdown}</div>
+            </div>
+          ) : (
+            /* Loading spinner */
+            <div className="flex items-center justify-center flex-1">
+              <div className="text-center">
+                <div className="inline-block animate-spin rounded-full h-12 w-12 border-t-2 border-b-2 border-blue-500 mb-4"></div>
+                <p className="text-gray-600">Generating AI Summary...</p>
+              </div>
+            </div>
+          )}
         </div>
       ) : !aiSummary ? (
         <div className="flex flex-col h-full">
@@ -16,11 +16,11 @@ import { Alert, AlertDescription } from '@/components/ui/alert';
 import { ScrollArea } from '@/components/ui/scroll-area';
 import { Switch } from '@/components/ui/switch';
 import { Lock, Unlock, Eye, EyeOff, RefreshCw, CheckCircle2, XCircle, ChevronDown, ChevronUp, Download } from 'lucide-react';
-import { cn } from '@/lib/utils';
+import { cn, nextApiKeyValue } from '@/lib/utils';
 import { toast } from 'sonner';
 
 export interface ModelConfig {
-  provider: 'ollama' | 'groq' | 'claude' | 'openai' | 'openrouter';
+  provider: 'ollama' | 'groq' | 'claude' | 'openai' | 'openrouter' | 'custom';
   model: string;
   whisperModel: string;
   apiKey?: string | null;
@@ -42,6 +42,33 @@ interface OpenRouterModel {
   completion_price?: string;
 }
 
+interface CustomOpenAIModel {
+  id: string;
+  owned_by?: string | null;
+  context_length?: number | null;
+}
+
+interface CustomProviderSettings {
+  baseUrl: string;
+  headers: Record<string, string>;
+  apiKey?: string | null;
+}
+
+// "Name: value" per line <-> header map
+const parseHeaderLines = (text: string): Record<string, string> => {
+  const headers: Record<string, string> = {};
+  text.split('\n').forEach((line) => {
+    const idx = line.indexOf(':');
+    if (idx > 0) {
+      headers[line.slice(0, idx).trim()] = line.slice(idx + 1).trim();
+    }
+  });
+  return headers;
+};
+
+const formatHeaderLines = (headers: Record<string, string>): string =>
+  Object.entries(headers).map(([name, value]) => `${name}: ${value}`).join('\n');
+
 interface ModelSettingsModalProps {
   modelConfig: ModelConfig;
   setModelConfig: (config: ModelConfig | ((prev: ModelConfig) => ModelConfig)) => void;
@@ -75,6 +102,11 @@ export function ModelSettingsModal({
   const [autoGenerateEnabled, setAutoGenerateEnabled] = useState<boolean>(true); // Default to true
   const [searchQuery, setSearchQuery] = useState<string>('');
   const [isEndpointSectionCollapsed, setIsEndpointSectionCollapsed] = useState<boolean>(true); // Collapsed by default
+  const [customBaseUrl, setCustomBaseUrl] = useState<string>('');
+  const [customHeaders, setCustomHeaders] = useState<string>('');
+  const [customModels, setCustomModels] = useState<CustomOpenAIModel[]>([]);
+  const [customError, setCustomError] = useState<string>('');
+  const [isLoadingCustom, setIsLoadingCustom] = useState<boolean>(false);
 
   // Use global download context instead of local state
   const { isDownloading, getProgress, downloadingModels } = useOllamaDownload();
@@ -159,6 +191,9 @@ export function ModelSettingsModal({
       'gpt-3.5-turbo-1106'
     ],
     openrouter: openRouterModels.map((m) => m.id),
+    custom: customModels.length > 0
+      ? customModels.map((m) => m.id)
+      : modelConfig.provider === 'custom' && modelConfig.model ? [modelConfig.model] : [],
   };
 
   const requiresApiKey =
@@ -173,7 +208,8 @@ export function ModelSettingsModal({
 
   const isDoneDisabled =
     (requiresApiKey && (!apiKey || (typeof apiKey === 'string' && !apiKey.trim()))) ||
-    (modelConfig.provider === 'ollama' && ollamaEndpointChanged);
+    (modelConfig.provider === 'ollama' && ollamaEndpointChanged) ||
+    (modelConfig.provider === 'custom' && (!customBaseUrl.trim() || !modelConfig.model));
 
   useEffect(() => {
     const fetchModelConfig = async () => {
@@ -339,6 +375,43 @@ export function ModelSettingsModal({
     };
   }, [modelConfig.provider]); // Only depend on provider, NOT endpoint
 
+  // Load saved custom provider settings when the custom provider is selected
+  useEffect(() => {
+    if (modelConfig.provider !== 'custom') return;
+    invoke('api_get_custom_provider_config')
+      .then((data) => {
+        const settings = data as CustomProviderSettings | null;
+        if (settings) {
+          setCustomBaseUrl(settings.baseUrl);
+          setCustomHeaders(formatHeaderLines(settings.headers || {}));
+        }
+      })
+      .catch((err) => console.error('Failed to load custom provider settings:', err));
+  }, [modelConfig.provider]);
+
+  const fetchCustomModels = async () => {
+    try {
+      setIsLoadingCustom(true);
+      setCustomError('');
+      const data = (await invoke('get_custom_openai_models', {
+        baseUrl: customBaseUrl.trim(),
+        apiKey: typeof apiKey === 'string' && apiKey.trim() ? apiKey.trim() : null,
+        headers: parseHeaderLines(customHeaders),
+      })) as CustomOpenAIModel[];
+      setCustomModels(data);
+      if (data.length > 0 && !data.some((m) => m.id === modelConfig.model)) {
+        setModelConfig((prev: ModelConfig) => ({ ...prev, model: data[0].id }));
+      }
+      toast.success(`Found ${data.length} model${data.length === 1 ? '' : 's'}`);
+    } catch (err) {
+      console.error('Error loading custom provider models:', err);
+      setCustomModels([]);
+      setCustomError(typeof err === 'string' ? err : 'Failed to load models from the server');
+    } finally {
+      setIsLoadingCustom(false);
+    }
+  };
+
   const loadOpenRouterModels = async () => {
     if (openRouterModels.length > 0) return; // Already loaded
 
@@ -365,6 +438,21 @@ export function ModelSettingsModal({
         ? ollamaEndpoint.trim()
         : null,
     };
+
+    if (modelConfig.provider === 'custom') {
+      try {
+        await invoke('api_save_custom_provider_config', {
+          baseUrl: customBaseUrl.trim(),
+          apiKey: updatedConfig.apiKey ?? '',
+          headers: parseHeaderLines(customHeaders),
+        });
+      } catch (err) {
+        console.error('Failed to save custom provider settings:', err);
+        setCustomError(typeof err === 'string' ? err : 'Failed to save custom provider settings');
+        return;
+      }
+    }
+
     setModelConfig(updatedConfig);
     console.log('ModelSettingsModal - handleSave - Updated ModelConfig:', updatedConfig);
 
@@ -524,6 +612,7 @@ export function ModelSettingsModal({
                 <SelectItem value="ollama">Ollama</SelectItem>
                 <SelectItem value="openai">OpenAI</SelectItem>
                 <SelectItem value="openrouter">OpenRouter</SelectItem>
+                <SelectItem value="custom">Custom (OpenAI-compatible)</SelectItem>
               </SelectContent>
             </Select>
 
@@ -553,14 +642,63 @@ export function ModelSettingsModal({
           </div>
         </div>
 
-        {requiresApiKey && (
+        {modelConfig.provider === 'custom' && (
+          <div className="space-y-3">
+            <div>
+              <Label>Base URL</Label>
+              <p className="text-sm text-muted-foreground mt-1 mb-2">
+                vLLM, LM Studio, llama.cpp server or LiteLLM (e.g., http://localhost:8000/v1)
+              </p>
+              <div className="flex gap-2">
+                <Input
+                  type="url"
+                  value={customBaseUrl}
+                  onChange={(e) => {
+                    setCustomBaseUrl(e.target.value);
+                    setCustomError('');
+                  }}
+                  placeholder="http://localhost:8000/v1"
+                  className="flex-1"
+                />
+                <Button
+                  type="button"
+                  size={'sm'}
+                  onClick={fetchCustomModels}
+                  disabled={isLoadingCustom || !customBaseUrl.trim()}
+                  variant="outline"
+                  className="whitespace-nowrap"
+                >
+                  <RefreshCw className={cn('mr-2 h-4 w-4', isLoadingCustom && 'animate-spin')} />
+                  {isLoadingCustom ? 'Fetching...' : 'Fetch Models'}
+                </Button>
+              </div>
+            </div>
+            <div>
+              <Label>Extra Headers (optional)</Label>
+              <textarea
+                value={customHeaders}
+                onChange={(e) => setCustomHeaders(e.target.value)}
+                placeholder={'X-Tenant: my-team'}
+                rows={2}
+                className="mt-1 w-full rounded-md border border-input bg-background px-3 py-2 text-sm font-mono"
+              />
+            </div>
+            {customError && (
+              <Alert variant="destructive">
+                <AlertDescription>{customError}</AlertDescription>
+              </Alert>
+            )}
+          </div>
+        )}
+
+        {(requiresApiKey || modelConfig.provider === 'custom') && (
           <div>
-            <Label>API Key</Label>
+            <Label>{modelConfig.provider === 'custom' ? 'API Key (optional)' : 'API Key'}</Label>
             <div className="relative mt-1">
               <Input
                 type={showApiKey ? 'text' : 'password'}
                 value={apiKey || ''}
-                onChange={(e) => setApiKey(e.target.value)}
+                onChange={(e) => setApiKey(nextApiKeyValue(apiKey, e.target.value))}
                 disabled={isApiKeyLocked}
                 placeholder="Enter your API key"
                 className="pr-24"
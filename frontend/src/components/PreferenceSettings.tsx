@@ -6,6 +6,7 @@ import { FolderOpen } from "lucide-react"
 import { invoke } from "@tauri-apps/api/core"
 import Analytics from "@/lib/analytics"
 import AnalyticsConsentSwitch from "./AnalyticsConsentSwitch"
+import { EncryptionSettings } from "./Encryption/EncryptionSettings"
 
 interface StorageLocations {
   database: string
@@ -227,6 +228,11 @@ export function PreferenceSettings() {
         </div>
       </div>
 
+      {/* Encryption Section */}
+      <div className="bg-white rounded-lg border border-gray-200 p-6 shadow-sm">
+        <EncryptionSettings />
+      </div>
+
       {/* Analytics Section */}
       <div className="bg-white rounded-lg border border-gray-200 p-6 shadow-sm">
         <AnalyticsConsentSwitch />
@@ -0,0 +1,157 @@
+'use client';
+
+import { useEffect, useState } from 'react';
+import { invoke } from '@tauri-apps/api/core';
+import { listen } from '@tauri-apps/api/event';
+import { toast } from 'sonner';
+import { Dialog, DialogContent, DialogHeader, DialogTitle, DialogDescription } from '@/components/ui/dialog';
+import { Loader2, LifeBuoy } from 'lucide-react';
+
+interface InterruptedRecording {
+  folder_path: string;
+  meeting_name: string | null;
+  created_at: string | null;
+  checkpoint_count: number;
+  transcript_segments: number;
+  has_audio: boolean;
+}
+
+interface RecoveryResult {
+  meeting_id: string;
+  meeting_name: string;
+  segment_count: number;
+  retranscribed_segments: number;
+  audio_recovered: boolean;
+}
+
+interface RecoveryProgress {
+  folder_path: string;
+  stage: string;
+  percentage: number;
+}
+
+export function InterruptedRecordingsDialog() {
+  const [recordings, setRecordings] = useState<InterruptedRecording[]>([]);
+  const [busyFolder, setBusyFolder] = useState<string | null>(null);
+  const [progress, setProgress] = useState<RecoveryProgress | null>(null);
+  const [retranscribe, setRetranscribe] = useState(true);
+
+  useEffect(() => {
+    invoke<InterruptedRecording[]>('api_list_interrupted_recordings')
+      .then(setRecordings)
+      .catch((error) => console.error('Failed to list interrupted recordings:', error));
+
+    const unlistenDetected = listen<InterruptedRecording[]>('interrupted-recordings-detected', (event) => {
+      setRecordings(event.payload);
+    });
+    const unlistenProgress = listen<RecoveryProgress>('recording-recovery-progress', (event) => {
+      setProgress(event.payload);
+    });
+
+    return () => {
+      unlistenDetected.then((fn) => fn());
+      unlistenProgress.then((fn) => fn());
+    };
+  }, []);
+
+  const removeFolder = (folderPath: string) =>
+    setRecordings((current) => current.filter((r) => r.folder_path !== folderPath));
+
+  const handleRecover = async (recording: InterruptedRecording) => {
+    setBusyFolder(recording.folder_path);
+    setProgress(null);
+    try {
+      const result = await invoke<RecoveryResult>('api_recover_recording', {
+        folderPath: recording.folder_path,
+        retranscribe,
+      });
+      toast.success(`Recovered "${result.meeting_name}" (${result.segment_count} segments)`);
+      removeFolder(recording.folder_path);
+    } catch (error) {
+      console.error('Failed to recover recording:', error);
+      toast.error(`Recovery failed: ${error}`);
+    } finally {
+      setBusyFolder(null);
+      setProgress(null);
+    }
+  };
+
+  const handleDismiss = async (recording: InterruptedRecording) => {
+    try {
+      await invoke('api_dismiss_interrupted_recording', { folderPath: recording.folder_path });
+      removeFolder(recording.folder_path);
+    } catch (error) {
+      console.error('Failed to dismiss recording:', error);
+      toast.error(`Failed to dismiss: ${error}`);
+    }
+  };
+
+  return (
+    <Dialog open={recordings.length > 0} onOpenChange={() => {}}>
+      <DialogContent className="sm:max-w-[560px]" onPointerDownOutside={(e) => e.preventDefault()}>
+        <DialogHeader>
+          <DialogTitle className="flex items-center gap-2">
+            <LifeBuoy className="h-5 w-5 text-blue-600" />
+            Recover interrupted recordings
+          </DialogTitle>
+          <DialogDescription>
+            These recordings stopped unexpectedly and were not saved. Recovering merges the audio
+            and saves the meeting with the transcript captured so far.
+          </DialogDescription>
+        </DialogHeader>
+
+        <label className="flex items-center gap-2 text-sm text-gray-700">
+          <input
+            type="checkbox"
+            checked={retranscribe}
+            onChange={(e) => setRetranscribe(e.target.checked)}
+            disabled={busyFolder !== null}
+          />
+          Transcribe parts of the audio missing from the transcript
+        </label>
+
+        <div className="space-y-3 py-2">
+          {recordings.map((recording) => {
+            const busy = busyFolder === recording.folder_path;
+            return (
+              <div key={recording.folder_path} className="p-3 border border-gray-200 rounded-lg">
+                <p className="text-sm font-medium text-gray-900">
+                  {recording.meeting_name || recording.folder_path.split(/[\\/]/).pop()}
+                </p>
+                <p className="text-xs text-gray-500 mt-1">
+                  {recording.created_at ? new Date(recording.created_at).toLocaleString() : 'Unknown date'}
+                  {' · '}
+                  {recording.transcript_segments} transcript segments
+                  {recording.checkpoint_count > 0 && ` · ${recording.checkpoint_count} audio checkpoints`}
+                </p>
+                {busy && progress && (
+                  <p className="text-xs text-blue-700 mt-1">
+                    {progress.stage.replace(/_/g, ' ')}
+                    {progress.percentage > 0 && ` (${progress.percentage}%)`}
+                  </p>
+                )}
+                <div className="flex gap-2 mt-3">
+                  <button
+                    onClick={() => handleRecover(recording)}
+                    disabled={busyFolder !== null}
+                    className="flex items-center gap-2 px-3 py-1.5 text-sm bg-blue-600 text-white rounded-md hover:bg-blue-700 disabled:bg-gray-400 disabled:cursor-not-allowed"
+                  >
+                    {busy && <Loader2 className="h-4 w-4 animate-spin" />}
+                    {busy ? 'Recovering...' : 'Recover'}
+                  </button>
+                  <button
+                    onClick={() => handleDismiss(recording)}
+                    disabled={busyFolder !== null}
+                    className="px-3 py-1.5 text-sm text-gray-700 border border-gray-300 rounded-md hover:bg-gray-50 disabled:cursor-not-allowed"
+                  >
+                    Dismiss
+                  </button>
+                </div>
+              </div>
+            );
+          })}
+        </div>
+      </DialogContent>
+    </Dialog>
+  );
+}
@@ -1,17 +1,27 @@
 import React, { useState, useEffect } from 'react';
 import { Switch } from '@/components/ui/switch';
+import {
+  Select,
+  SelectContent,
+  SelectItem,
+  SelectTrigger,
+  SelectValue,
+} from '@/components/ui/select';
 import { FolderOpen } from 'lucide-react';
 import { invoke } from '@tauri-apps/api/core';
 import { DeviceSelection, SelectedDevices } from '@/components/DeviceSelection';
 import Analytics from '@/lib/analytics';
 import { toast } from 'sonner';
 
+export type TranscriptionChannelMode = 'mixed' | 'dual';
+
 export interface RecordingPreferences {
   save_folder: string;
   auto_save: boolean;
   file_format: string;
   preferred_mic_device: string | null;
   preferred_system_device: string | null;
+  transcription_channel_mode: TranscriptionChannelMode;
 }
 
 interface RecordingSettingsProps {
@@ -24,7 +34,8 @@ export function RecordingSettings({ onSave }: RecordingSettingsProps) {
     auto_save: true,
     file_format: 'mp4',
     preferred_mic_device: null,
-    preferred_system_device: null
+    preferred_system_device: null,
+    transcription_channel_mode: 'mixed'
   });
   const [loading, setLoading] = useState(true);
   const [saving, setSaving] = useState(false);
@@ -96,6 +107,16 @@ export function RecordingSettings({ onSave }: RecordingSettingsProps) {
     });
   };
 
+  const handleChannelModeChange = async (mode: TranscriptionChannelMode) => {
+    const newPreferences = { ...preferences, transcription_channel_mode: mode };
+    setPreferences(newPreferences);
+    await savePreferences(newPreferences);
+
+    await Analytics.track('transcription_channel_mode_changed', {
+      mode
+    });
+  };
+
   const handleOpenFolder = async () => {
     try {
       await invoke('open_recordings_folder');
@@ -213,6 +234,30 @@ export function RecordingSettings({ onSave }: RecordingSettingsProps) {
         </div>
       )}
 
+      {/* Transcription Channel Mode */}
+      <div className="flex items-center justify-between gap-4 p-4 border rounded-lg">
+        <div className="flex-1">
+          <div className="font-medium">Speaker Separation</div>
+          <div className="text-sm text-gray-600">
+            Separate channels transcribe your microphone and system audio independently, labelling
+            segments &quot;You&quot; and &quot;Remote&quot;. Applies to the next recording.
+          </div>
+        </div>
+        <Select
+          value={preferences.transcription_channel_mode}
+          onValueChange={(value) => handleChannelModeChange(value as TranscriptionChannelMode)}
+          disabled={saving}
+        >
+          <SelectTrigger className="w-48">
+            <SelectValue />
+          </SelectTrigger>
+          <SelectContent>
+            <SelectItem value="mixed">Mixed (single stream)</SelectItem>
+            <SelectItem value="dual">Separate channels</SelectItem>
+          </SelectContent>
+        </Select>
+      </div>
+
       {/* Recording Notification Toggle */}
       <div className="flex items-center justify-between p-4 border rounded-lg">
         <div className="flex-1">
@@ -23,7 +23,15 @@ interface TranscriptSearchResult {
   id: string;
   title: string;
   matchContext: string;
+  // Snippet with matched terms wrapped in <mark></mark>
+  highlightedContext: string;
   timestamp: string;
+  kind: 'transcript' | 'summary' | 'title';
+  transcriptId?: string;
+  // Recording-relative seconds, for jumping to the moment
+  audioStartTime?: number;
+  wordStartTime?: number;
+  rank: number;
 };
 
 interface SidebarContextType {
@@ -224,8 +232,8 @@ export function SidebarProvider({ children }: { children: React.ReactNode }) {
         // Call the update callback with result
         onUpdate(result);
 
-        // Stop polling if completed, error, failed, or idle (after initial processing)
-        if (result.status === 'completed' || result.status === 'error' || result.status === 'failed') {
+        // Stop polling if completed, error, failed, cancelled, or idle (after initial processing)
+        if (result.status === 'completed' || result.status === 'error' || result.status === 'failed' || result.status === 'cancelled') {
           console.log(`✅ Polling completed for ${meetingId}, status: ${result.status}`);
           clearInterval(pollInterval);
           setActiveSummaryPolls(prev => {
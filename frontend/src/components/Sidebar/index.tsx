@@ -523,12 +523,27 @@ const Sidebar: React.FC = () => {
     );
   };
 
-  // Find matching transcript snippet for a meeting item
+  // Find the best-ranked match for a meeting item (results are sorted by rank)
   const findMatchingSnippet = (itemId: string) => {
     if (!searchQuery.trim() || !searchResults.length) return null;
     return searchResults.find(result => result.id === itemId);
   };
 
+  const countMatches = (itemId: string) =>
+    searchResults.filter(result => result.id === itemId).length;
+
+  // Render a highlighted snippet without injecting HTML
+  const renderHighlighted = (snippet: string) =>
+    snippet.split(/(<mark>.*?<\/mark>)/g).map((part, i) =>
+      part.startsWith('<mark>') ? (
+        <mark key={i} className="bg-yellow-200 text-gray-800 rounded-sm">
+          {part.slice(6, -7)}
+        </mark>
+      ) : (
+        <span key={i}>{part}</span>
+      )
+    );
+
   const renderItem = (item: SidebarItem, depth = 0) => {
     const isExpanded = expandedFolders.has(item.id);
     const paddingLeft = `${depth * 12 + 12}px`;
@@ -625,7 +640,13 @@ const Sidebar: React.FC = () => {
               {/* Show transcript match snippet if available */}
               {hasTranscriptMatch && (
                 <div className="mt-1 ml-8 text-xs text-gray-500 bg-yellow-50 p-1.5 rounded border border-yellow-100 line-clamp-2">
-                  <span className="font-medium text-yellow-600">Match:</span> {matchingResult.matchContext}
+                  <span className="font-medium text-yellow-600">
+                    {matchingResult.kind === 'summary' ? 'Summary' : matchingResult.kind === 'title' ? 'Title' : 'Match'}:
+                  </span>{' '}
+                  {renderHighlighted(matchingResult.highlightedContext || matchingResult.matchContext)}
+                  {countMatches(item.id) > 1 && (
+                    <span className="ml-1 text-gray-400">(+{countMatches(item.id) - 1} more)</span>
+                  )}
                 </div>
               )}
             </div>
@@ -0,0 +1,77 @@
+import { useState, useCallback, useEffect } from 'react';
+import { invoke as invokeTauri } from '@tauri-apps/api/core';
+import { listen } from '@tauri-apps/api/event';
+import { toast } from 'sonner';
+
+export interface MeetingChatMessage {
+  id: string;
+  meeting_id: string;
+  role: 'user' | 'assistant';
+  content: string;
+  provider?: string | null;
+  model?: string | null;
+  created_at: string;
+}
+
+export function useMeetingChat(meetingId: string) {
+  const [messages, setMessages] = useState<MeetingChatMessage[]>([]);
+  const [pendingQuestion, setPendingQuestion] = useState<string | null>(null);
+  const [streamingAnswer, setStreamingAnswer] = useState('');
+
+  useEffect(() => {
+    invokeTauri<MeetingChatMessage[]>('api_get_meeting_chat', { meetingId })
+      .then(setMessages)
+      .catch((error) => console.error('Failed to load meeting chat:', error));
+  }, [meetingId]);
+
+  // Live answer text while a response streams in
+  useEffect(() => {
+    const unlisten = listen<{ meeting_id: string; content: string }>('meeting-chat-delta', (event) => {
+      if (event.payload.meeting_id === meetingId) {
+        setStreamingAnswer(event.payload.content);
+      }
+    });
+    return () => {
+      unlisten.then((fn) => fn());
+    };
+  }, [meetingId]);
+
+  const sendMessage = useCallback(async (message: string) => {
+    const question = message.trim();
+    if (!question || pendingQuestion) return;
+
+    setPendingQuestion(question);
+    setStreamingAnswer('');
+    try {
+      const answer = await invokeTauri<MeetingChatMessage>('api_send_meeting_chat_message', {
+        meetingId,
+        message: question,
+      });
+      const history = await invokeTauri<MeetingChatMessage[]>('api_get_meeting_chat', { meetingId });
+      setMessages(history.length > 0 ? history : [answer]);
+    } catch (error) {
+      console.error('Meeting chat failed:', error);
+      toast.error('Failed to get an answer', { description: String(error) });
+    } finally {
+      setPendingQuestion(null);
+      setStreamingAnswer('');
+    }
+  }, [meetingId, pendingQuestion]);
+
+  const clearChat = useCallback(async () => {
+    try {
+      await invokeTauri('api_clear_meeting_chat', { meetingId });
+      setMessages([]);
+    } catch (error) {
+      toast.error('Failed to clear chat', { description: String(error) });
+    }
+  }, [meetingId]);
+
+  return {
+    messages,
+    pendingQuestion,
+    streamingAnswer,
+    sendMessage,
+    clearChat,
+  };
+}
@@ -1,13 +1,22 @@
-import { useState, useCallback } from 'react';
+import { useState, useCallback, useEffect } from 'react';
 import { Transcript, Summary } from '@/types';
 import { ModelConfig } from '@/components/ModelSettingsModal';
 import { CurrentMeeting, useSidebar } from '@/components/Sidebar/SidebarProvider';
 import { invoke as invokeTauri } from '@tauri-apps/api/core';
+import { listen } from '@tauri-apps/api/event';
 import { toast } from 'sonner';
 import Analytics from '@/lib/analytics';
 
 type SummaryStatus = 'idle' | 'processing' | 'summarizing' | 'regenerating' | 'completed' | 'error';
 
+// Streamed partial output, from `summary-progress` events or the polled `progress` field
+export interface SummaryProgress {
+  stage: 'chunk' | 'combine' | 'final';
+  chunk_index: number;
+  total_chunks: number;
+  partial_markdown: string;
+}
+
 interface UseSummaryGenerationProps {
   meeting: any;
   transcripts: Transcript[];
@@ -32,15 +41,46 @@ export function useSummaryGeneration({
   const [summaryStatus, setSummaryStatus] = useState<SummaryStatus>('idle');
   const [summaryError, setSummaryError] = useState<string | null>(null);
   const [originalTranscript, setOriginalTranscript] = useState<string>('');
+  const [summaryProgress, setSummaryProgress] = useState<SummaryProgress | null>(null);
 
   const { startSummaryPolling } = useSidebar();
 
+  // Live partial output while the backend streams the summary
+  useEffect(() => {
+    let unlisten: (() => void) | undefined;
+    let cancelled = false;
+
+    listen<SummaryProgress & { meeting_id: string }>('summary-progress', (event) => {
+      if (event.payload.meeting_id !== meeting.id) return;
+      const { meeting_id, ...progress } = event.payload;
+      setSummaryProgress(progress);
+      setSummaryStatus(current => (current === 'processing' ? 'summarizing' : current));
+    }).then(fn => {
+      if (cancelled) {
+        fn();
+      } else {
+        unlisten = fn;
+      }
+    });
+
+    return () => {
+      cancelled = true;
+      unlisten?.();
+    };
+  }, [meeting.id]);
+
   // Helper to get status message
   const getSummaryStatusMessage = useCallback((status: SummaryStatus) => {
     switch (status) {
       case 'processing':
         return 'Processing transcript...';
       case 'summarizing':
+        if (summaryProgress?.stage === 'chunk') {
+          return `Summarizing part ${summaryProgress.chunk_index + 1} of ${summaryProgress.total_chunks}...`;
+        }
+        if (summaryProgress?.stage === 'combine') {
+          return `Combining ${summaryProgress.total_chunks} partial summaries...`;
+        }
         return 'Generating summary...';
       case 'regenerating':
         return 'Regenerating summary...';
@@ -51,7 +91,7 @@ export function useSummaryGeneration({
       default:
         return '';
     }
-  }, []);
+  }, [summaryProgress]);
 
   // Unified summary processing logic
   const processSummary = useCallback(async ({
@@ -65,6 +105,7 @@ export function useSummaryGeneration({
   }) => {
     setSummaryStatus(isRegeneration ? 'regenerating' : 'processing');
     setSummaryError(null);
+    setSummaryProgress(null);
 
     try {
       if (!transcriptText.trim()) {
@@ -112,6 +153,21 @@ export function useSummaryGeneration({
       startSummaryPolling(meeting.id, process_id, async (pollingResult) => {
         console.log('Summary status:', pollingResult);
 
+        // Partial output persisted by the backend (e.g. after a window reload)
+        if (pollingResult.progress) {
+          setSummaryProgress(current => current ?? pollingResult.progress);
+        }
+        if (pollingResult.status !== 'pending' && pollingResult.status !== 'processing') {
+          setSummaryProgress(null);
+        }
+
+        // Cancelled by the user: finished chunks stay cached for the next run
+        if (pollingResult.status === 'cancelled') {
+          setSummaryStatus(isRegeneration ? 'completed' : 'idle');
+          toast.info('Summary generation cancelled');
+          return;
+        }
+
         // Handle errors
         if (pollingResult.status === 'error' || pollingResult.status === 'failed') {
           console.error('Backend returned error:', pollingResult.error);
@@ -262,6 +318,18 @@ export function useSummaryGeneration({
     onMeetingUpdated,
   ]);
 
+  // Public API: Stop the running generation
+  const handleCancelSummary = useCallback(async () => {
+    try {
+      await invokeTauri('api_cancel_summary', { meetingId: meeting.id });
+    } catch (error) {
+      console.error('Failed to cancel summary:', error);
+      toast.error('Failed to cancel summary', {
+        description: error instanceof Error ? error.message : String(error),
+      });
+    }
+  }, [meeting.id]);
+
   // Public API: Generate summary from transcripts
   const handleGenerateSummary = useCallback(async (customPrompt: string = '') => {
     // Check if model config is still loading
@@ -327,8 +395,10 @@ export function useSummaryGeneration({
   return {
     summaryStatus,
     summaryError,
+    summaryProgress,
     handleGenerateSummary,
     handleRegenerateSummary,
+    handleCancelSummary,
     getSummaryStatusMessage,
   };
 }
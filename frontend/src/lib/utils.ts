@@ -4,3 +4,21 @@ import { twMerge } from "tailwind-merge"
 export function cn(...inputs: ClassValue[]) {
   return twMerge(clsx(inputs))
 }
+
+// Stored API keys come back from the backend masked ("••••••••a1b2").
+const API_KEY_MASK = '••••••••'
+
+export function isMaskedApiKey(value: string | null | undefined): boolean {
+  return !!value && value.startsWith(API_KEY_MASK)
+}
+
+// Editing a masked key starts a new key: typed characters replace the mask, deleting clears it
+export function nextApiKeyValue(current: string | null, input: string): string {
+  if (!current || !isMaskedApiKey(current)) {
+    return input
+  }
+  if (!input.includes('•')) {
+    return input // pasted over the whole mask
+  }
+  return input.startsWith(current) ? input.slice(current.length) : ''
+}
@@ -16,6 +16,19 @@ export interface Transcript {
   audio_start_time?: number; // Seconds from recording start (e.g., 125.3)
   audio_end_time?: number;   // Seconds from recording start (e.g., 128.6)
   duration?: number;          // Segment duration in seconds (e.g., 3.3)
+  // Speaker source tag: "You" (mic) / "Remote" (system) in dual-channel mode, "Audio" when mixed
+  source?: string;
+  // Diarized speaker label ("Speaker 1", ... or a user-assigned name)
+  speaker?: string;
+  // Word-level timings (seconds from recording start) for word-by-word highlighting
+  words?: WordTiming[];
+}
+
+export interface WordTiming {
+  word: string;
+  start: number; // Seconds from recording start
+  end: number;   // Seconds from recording start
+  probability?: number; // Decoder probability (0-1), absent for providers without scores
 }
 
 export interface TranscriptUpdate {
@@ -25,11 +38,12 @@ export interface TranscriptUpdate {
   sequence_id: number;
   chunk_start_time: number; // Legacy field
   is_partial: boolean;
-  confidence: number;
+  confidence?: number; // Absent for providers without scores
   // NEW: Recording-relative timestamps for playback sync
   audio_start_time: number; // Seconds from recording start
   audio_end_time: number;   // Seconds from recording start
   duration: number;          // Segment duration in seconds
+  words?: WordTiming[];       // Word-level timings (may be empty)
 }
 
 export interface Block {